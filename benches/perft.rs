@@ -0,0 +1,54 @@
+// Full move generation benchmarks
+use chess_move_gen::*;
+use criterion::{Criterion, criterion_group, criterion_main};
+
+const KIWIPETE_FEN: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -";
+
+fn perft_starting_position(c: &mut Criterion) {
+    let mut position = Position::from_fen(STARTING_POSITION_FEN).unwrap();
+
+    c.bench_function("perft_starting_position", |b| {
+        b.iter(|| perft(&mut position, 2, false, 0))
+    });
+}
+
+// Table based backends compete with the rest of perft for cache, so compare them on a full
+// search rather than only attack lookups
+fn bench_perft_with_sliders<S: SliderAttacks>(c: &mut Criterion) {
+    let mut position = Position::from_fen(KIWIPETE_FEN).unwrap();
+
+    c.bench_function(&format!("perft_{}", S::NAME), |b| {
+        b.iter(|| perft_with_sliders::<S>(&mut position, 2))
+    });
+}
+
+fn perft_sliders(c: &mut Criterion) {
+    bench_perft_with_sliders::<Magic>(c);
+    bench_perft_with_sliders::<BlackMagic>(c);
+    bench_perft_with_sliders::<KoggeStone>(c);
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("bmi2") {
+        bench_perft_with_sliders::<Bmi2>(c);
+    }
+}
+
+fn playout_starting_position(c: &mut Criterion) {
+    let position = Position::from_fen(STARTING_POSITION_FEN).unwrap();
+    let options = PlayoutOptions::default();
+    let mut seed = 0;
+
+    c.bench_function("playout", |b| {
+        b.iter(|| {
+            seed += 1;
+            playout(&position, seed, &options)
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    perft_starting_position,
+    perft_sliders,
+    playout_starting_position
+);
+criterion_main!(benches);
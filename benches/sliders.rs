@@ -0,0 +1,104 @@
+// Sliding piece attack lookups for each backend on the same random occupancies
+use chess_move_gen::bb::EMPTY;
+use chess_move_gen::*;
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const BOARD_DENSITY: f64 = 0.3;
+
+fn random_bb(rng: &mut StdRng) -> BB {
+    let mut bb = EMPTY;
+    for sq in 0..64 {
+        if rng.gen_bool(BOARD_DENSITY) {
+            bb |= BB::new(Square(sq));
+        }
+    }
+    bb
+}
+
+fn random_occupancies(size: usize) -> Vec<(Square, BB)> {
+    let mut rng = StdRng::seed_from_u64(0);
+    (0..size)
+        .map(|i| {
+            let from = Square(i % 64);
+            (from, random_bb(&mut rng) | BB::new(from))
+        })
+        .collect()
+}
+
+// Two attacking squares per occupancy, as when generating attacks for both rooks of a side
+fn random_occupancies_from_bb(size: usize) -> Vec<(BB, BB)> {
+    let mut rng = StdRng::seed_from_u64(0);
+    (0..size)
+        .map(|_| {
+            let from =
+                BB::new(Square(rng.gen_range(0..64))) | BB::new(Square(rng.gen_range(0..64)));
+            (from, random_bb(&mut rng) | from)
+        })
+        .collect()
+}
+
+fn bench_backend<S: SliderAttacks>(
+    c: &mut Criterion,
+    cases: &[(Square, BB)],
+    cases_from_bb: &[(BB, BB)],
+) {
+    let mut group = c.benchmark_group(S::NAME);
+    group.bench_function("rook_attacks_from_sq", |b| {
+        b.iter(|| {
+            let mut ret = EMPTY;
+            for &(sq, occupied) in cases {
+                ret ^= S::rook_attacks_from_sq(black_box(sq), black_box(occupied));
+            }
+            ret
+        })
+    });
+    group.bench_function("bishop_attacks_from_sq", |b| {
+        b.iter(|| {
+            let mut ret = EMPTY;
+            for &(sq, occupied) in cases {
+                ret ^= S::bishop_attacks_from_sq(black_box(sq), black_box(occupied));
+            }
+            ret
+        })
+    });
+    group.bench_function("rook_attacks", |b| {
+        b.iter(|| {
+            let mut ret = EMPTY;
+            for &(from, occupied) in cases_from_bb {
+                ret ^= S::rook_attacks(black_box(from), black_box(occupied));
+            }
+            ret
+        })
+    });
+    group.bench_function("bishop_attacks", |b| {
+        b.iter(|| {
+            let mut ret = EMPTY;
+            for &(from, occupied) in cases_from_bb {
+                ret ^= S::bishop_attacks(black_box(from), black_box(occupied));
+            }
+            ret
+        })
+    });
+    group.finish();
+}
+
+fn sliders(c: &mut Criterion) {
+    let cases = random_occupancies(640);
+    let cases_from_bb = random_occupancies_from_bb(640);
+
+    bench_backend::<Naive>(c, &cases, &cases_from_bb);
+    bench_backend::<Hyperbola>(c, &cases, &cases_from_bb);
+    bench_backend::<KoggeStone>(c, &cases, &cases_from_bb);
+    bench_backend::<Subtract>(c, &cases, &cases_from_bb);
+    bench_backend::<Magic>(c, &cases, &cases_from_bb);
+    bench_backend::<BlackMagic>(c, &cases, &cases_from_bb);
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("bmi2") {
+        bench_backend::<Bmi2>(c, &cases, &cases_from_bb);
+    }
+}
+
+criterion_group!(benches, sliders);
+criterion_main!(benches);
@@ -0,0 +1,147 @@
+// Generates the ~100k entry magic, PEXT and black magic slider attack tables so lookups index
+// plain statics. Building them with const evaluation takes about a minute per compile
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+#[path = "src/generation/slider/ray_black_magic/magic_numbers.rs"]
+mod black_magic_numbers;
+#[allow(dead_code)]
+#[path = "src/generation/slider/geometry.rs"]
+mod geometry;
+#[path = "src/generation/slider/ray_magic/magic_numbers.rs"]
+mod magic_numbers;
+
+use geometry::*;
+use magic_numbers::*;
+
+// Attacks for every relevant occupancy of every square, calling f with the square's entries
+// offset from the start of the shared table, bishops first
+fn for_each_occupancy(mut f: impl FnMut(usize, bool, usize, u64, u64)) {
+    let mut offset = 0;
+    for diagonal in [true, false] {
+        for sq in 0..64 {
+            let mask = relevant_mask(sq, diagonal);
+            for idx in 0..1u64 << mask.count_ones() {
+                let occupied = pdep(idx, mask);
+                f(sq, diagonal, offset, idx, occupied);
+            }
+            offset += 1 << mask.count_ones();
+        }
+    }
+}
+
+// Overlapping tables of every square, panics if two occupancies with different attacks map to the
+// same entry or an entry falls outside the table
+fn magic_attack_table() -> Vec<u64> {
+    let mut table = vec![0u64; MAGIC_TABLE_SIZE];
+
+    for_each_occupancy(|sq, diagonal, _, _, occupied| {
+        let (magic_numbers, bits) = if diagonal {
+            (&BISHOP_MAGIC_NUMBERS, MAGIC_BISHOP_BITS)
+        } else {
+            (&ROOK_MAGIC_NUMBERS, MAGIC_ROOK_BITS)
+        };
+        let (magic_number, offset) = magic_numbers[sq];
+        let attacks = slider_attacks(sq, occupied, diagonal);
+        let i = offset as usize + (occupied.wrapping_mul(magic_number) >> (64 - bits)) as usize;
+
+        // attack sets are never empty so zero marks an unused entry
+        assert!(
+            table[i] == 0 || table[i] == attacks,
+            "magic number for square {} maps different attacks to the same index",
+            sq
+        );
+        table[i] = attacks;
+    });
+
+    table
+}
+
+// Attacks compressed with PEXT along the rays of the attacking square
+fn pext_attack_table() -> Vec<u16> {
+    let mut table = vec![0u16; SHARED_TABLE_SIZE];
+
+    for_each_occupancy(|sq, diagonal, offset, idx, occupied| {
+        let rays = slider_attacks(sq, 0, diagonal);
+        let attacks = slider_attacks(sq, occupied, diagonal);
+        table[offset + idx as usize] = pext(attacks, rays) as u16;
+    });
+
+    table
+}
+
+// Overlapping tables of every square, panics if two occupancies with different attacks map to the
+// same entry or an entry falls outside the table
+fn black_magic_attack_table() -> Vec<u64> {
+    let mut table = vec![0u64; BLACK_MAGIC_TABLE_SIZE];
+
+    for_each_occupancy(|sq, diagonal, _, _, occupied| {
+        let (magic_numbers, bits) = if diagonal {
+            (
+                &black_magic_numbers::BISHOP_MAGIC_NUMBERS,
+                BLACK_MAGIC_BISHOP_BITS,
+            )
+        } else {
+            (
+                &black_magic_numbers::ROOK_MAGIC_NUMBERS,
+                BLACK_MAGIC_ROOK_BITS,
+            )
+        };
+        let (magic_number, offset) = magic_numbers[sq];
+        let not_mask = !relevant_mask(sq, diagonal);
+        let attacks = slider_attacks(sq, occupied, diagonal);
+        let i = offset as usize
+            + ((occupied | not_mask).wrapping_mul(magic_number) >> (64 - bits)) as usize;
+
+        assert!(
+            table[i] == 0 || table[i] == attacks,
+            "black magic for square {} maps different attacks to the same index",
+            sq
+        );
+        table[i] = attacks;
+    });
+
+    table
+}
+
+// Writes an array expression for include!
+fn write_table<T: Copy>(path: &Path, table: &[T], entry: impl Fn(T) -> String) {
+    let mut out = String::from("[\n");
+    for chunk in table.chunks(8) {
+        for &value in chunk {
+            write!(out, "{},", entry(value)).unwrap();
+        }
+        out.push('\n');
+    }
+    out.push(']');
+    fs::write(path, out).unwrap();
+}
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-changed=src/generation/slider/geometry.rs");
+    println!("cargo::rerun-if-changed=src/generation/slider/ray_magic/magic_numbers.rs");
+    println!("cargo::rerun-if-changed=src/generation/slider/ray_black_magic/magic_numbers.rs");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir);
+
+    write_table(
+        &out_dir.join("magic_attacks.rs"),
+        &magic_attack_table(),
+        |attacks| format!("{:#x}", attacks),
+    );
+    write_table(
+        &out_dir.join("pext_attacks.rs"),
+        &pext_attack_table(),
+        |idx| idx.to_string(),
+    );
+    write_table(
+        &out_dir.join("black_magic_attacks.rs"),
+        &black_magic_attack_table(),
+        |attacks| format!("{:#x}", attacks),
+    );
+}
@@ -1,7 +1,8 @@
 use crate::square::{Square, SquareInternal};
 use crate::util::grid_to_string;
-use std::fmt;
-use std::ops::*;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::*;
 
 #[cfg(test)]
 use rand;
@@ -432,6 +433,31 @@ pub const ROW_7: BB = BB(ROW_1.0 << (6 * 8));
 pub const ROW_8: BB = BB(ROW_1.0 << (7 * 8));
 pub const EDGES: BB = BB(FILE_A.0 | FILE_H.0 | ROW_1.0 | ROW_8.0);
 
+/// Squares of each file, indexed by column
+#[allow(dead_code)]
+pub const FILES: [BB; 8] = {
+    let mut files = [EMPTY; 8];
+    let mut col = 0;
+    while col < 8 {
+        files[col] = BB(FILE_A.0 << col);
+        col += 1;
+    }
+    files
+};
+
+/// Squares of the files either side of each file, indexed by column
+#[allow(dead_code)]
+pub const ADJACENT_FILES: [BB; 8] = {
+    let mut adjacent = [EMPTY; 8];
+    let mut col = 0;
+    while col < 8 {
+        let file = FILE_A.0 << col;
+        adjacent[col] = BB(((file << 1) & NOT_FILE_A.0) | ((file >> 1) & NOT_FILE_H.0));
+        col += 1;
+    }
+    adjacent
+};
+
 /// `BBIterator` iterates over set bits in a bitboard, from low to high,
 /// returning a Square and the bit-board with that bit set
 pub struct BBIterator(BB);
@@ -477,6 +503,15 @@ pub mod test {
     use crate::square::*;
     use unindent;
 
+    #[test]
+    fn test_file_masks() {
+        assert_eq!(FILES[0], FILE_A);
+        assert_eq!(FILES[7], FILE_H);
+        assert_eq!(ADJACENT_FILES[0], FILE_B);
+        assert_eq!(ADJACENT_FILES[7], FILE_G);
+        assert_eq!(ADJACENT_FILES[3], FILES[2] | FILES[4]);
+    }
+
     #[test]
     fn test_occluded_east_fill() {
         let expected = unindent::unindent(
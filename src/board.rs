@@ -2,6 +2,7 @@ use crate::mv::Move;
 use crate::piece::Piece;
 use crate::position::*;
 use crate::square::*;
+use alloc::vec::Vec;
 
 pub struct Board {
     position: Position,
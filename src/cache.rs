@@ -1,3 +1,7 @@
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec;
+
 #[derive(Copy, Clone)]
 struct Entry {
     key: u64,
@@ -1,6 +1,6 @@
 use crate::side::Side;
 use crate::square::*;
-use std::fmt;
+use core::fmt;
 
 /// Represents a castleove
 #[derive(PartialEq, PartialOrd, Copy, Clone)]
@@ -12,12 +12,12 @@ pub const KING_SIDE: Castle = Castle(1);
 const CASTLE_KING_MOVES: [[(Square, Square); 2]; 2] = [[(E1, C1), (E8, C8)], [(E1, G1), (E8, G8)]];
 const CASTLE_ROOK_MOVES: [[(Square, Square); 2]; 2] = [[(A1, D1), (A8, D8)], [(H1, F1), (H8, F8)]];
 
-pub fn castle_king_squares(side: Side, castle: Castle) -> (Square, Square) {
-    CASTLE_KING_MOVES[castle.to_usize()][side.to_usize()]
+pub const fn castle_king_squares(side: Side, castle: Castle) -> (Square, Square) {
+    CASTLE_KING_MOVES[castle.to_usize()][side.0]
 }
 
-pub fn castle_rook_squares(side: Side, castle: Castle) -> (Square, Square) {
-    CASTLE_ROOK_MOVES[castle.to_usize()][side.to_usize()]
+pub const fn castle_rook_squares(side: Side, castle: Castle) -> (Square, Square) {
+    CASTLE_ROOK_MOVES[castle.to_usize()][side.0]
 }
 
 impl Castle {
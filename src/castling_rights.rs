@@ -1,6 +1,8 @@
 use crate::castle::*;
 use crate::side::Side;
-use std::fmt;
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
 
 type Internal = u8;
 
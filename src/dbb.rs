@@ -1,15 +1,141 @@
 // Represents a double bitboard
-use std::{
-    ops::*,
-    simd::{simd_swizzle, u8x16, u64x2},
-};
+use core::ops::*;
 
 use crate::bb::BB;
-use std::mem::transmute;
 
-/// Double bitboard used with SSE3 intrinsics
+use self::lanes::U64x2;
+
+/// Double bitboard used with SSE intrinsics
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Copy, Clone)]
-pub struct DBB(pub u64x2);
+pub struct DBB(U64x2);
+
+// portable SIMD on nightly
+#[cfg(feature = "nightly")]
+mod lanes {
+    pub use core::simd::u64x2 as U64x2;
+}
+
+// SSE2 intrinsics on stable, every x86_64 CPU supports SSE2 so calling them is always sound
+#[cfg(all(not(feature = "nightly"), target_arch = "x86_64"))]
+#[allow(unused_unsafe)]
+mod lanes {
+    use core::arch::x86_64::*;
+    use core::mem::transmute;
+    use core::ops::*;
+
+    #[derive(Copy, Clone)]
+    pub struct U64x2(__m128i);
+
+    impl U64x2 {
+        pub const fn from_array(lanes: [u64; 2]) -> U64x2 {
+            U64x2(unsafe { transmute::<[u64; 2], __m128i>(lanes) })
+        }
+
+        pub fn splat(value: u64) -> U64x2 {
+            U64x2(unsafe { _mm_set1_epi64x(value as i64) })
+        }
+
+        pub fn to_array(self) -> [u64; 2] {
+            unsafe { transmute::<__m128i, [u64; 2]>(self.0) }
+        }
+    }
+
+    macro_rules! binary_op {
+        ($trait:ident, $fn:ident, $intrinsic:ident) => {
+            impl $trait for U64x2 {
+                type Output = U64x2;
+
+                #[inline(always)]
+                fn $fn(self, other: U64x2) -> U64x2 {
+                    U64x2(unsafe { $intrinsic(self.0, other.0) })
+                }
+            }
+        };
+    }
+
+    binary_op!(BitAnd, bitand, _mm_and_si128);
+    binary_op!(BitOr, bitor, _mm_or_si128);
+    binary_op!(BitXor, bitxor, _mm_xor_si128);
+    binary_op!(Sub, sub, _mm_sub_epi64);
+
+    impl Shl<u64> for U64x2 {
+        type Output = U64x2;
+
+        #[inline(always)]
+        fn shl(self, amount: u64) -> U64x2 {
+            U64x2(unsafe { _mm_sll_epi64(self.0, _mm_cvtsi64_si128(amount as i64)) })
+        }
+    }
+
+    impl Shr<u64> for U64x2 {
+        type Output = U64x2;
+
+        #[inline(always)]
+        fn shr(self, amount: u64) -> U64x2 {
+            U64x2(unsafe { _mm_srl_epi64(self.0, _mm_cvtsi64_si128(amount as i64)) })
+        }
+    }
+}
+
+// scalar fallback on stable for other architectures
+#[cfg(all(not(feature = "nightly"), not(target_arch = "x86_64")))]
+mod lanes {
+    use core::ops::*;
+
+    #[derive(Copy, Clone)]
+    pub struct U64x2([u64; 2]);
+
+    impl U64x2 {
+        pub const fn from_array(lanes: [u64; 2]) -> U64x2 {
+            U64x2(lanes)
+        }
+
+        pub fn splat(value: u64) -> U64x2 {
+            U64x2([value; 2])
+        }
+
+        pub fn to_array(self) -> [u64; 2] {
+            self.0
+        }
+    }
+
+    macro_rules! binary_op {
+        ($trait:ident, $fn:ident, $op:expr) => {
+            impl $trait for U64x2 {
+                type Output = U64x2;
+
+                #[inline(always)]
+                fn $fn(self, other: U64x2) -> U64x2 {
+                    U64x2([$op(self.0[0], other.0[0]), $op(self.0[1], other.0[1])])
+                }
+            }
+        };
+    }
+
+    binary_op!(BitAnd, bitand, |a: u64, b: u64| a & b);
+    binary_op!(BitOr, bitor, |a: u64, b: u64| a | b);
+    binary_op!(BitXor, bitxor, |a: u64, b: u64| a ^ b);
+    binary_op!(Sub, sub, u64::wrapping_sub);
+
+    impl Shl<u64> for U64x2 {
+        type Output = U64x2;
+
+        #[inline(always)]
+        fn shl(self, amount: u64) -> U64x2 {
+            U64x2([self.0[0] << amount, self.0[1] << amount])
+        }
+    }
+
+    impl Shr<u64> for U64x2 {
+        type Output = U64x2;
+
+        #[inline(always)]
+        fn shr(self, amount: u64) -> U64x2 {
+            U64x2([self.0[0] >> amount, self.0[1] >> amount])
+        }
+    }
+}
 
 impl Sub for DBB {
     type Output = DBB;
@@ -51,36 +177,46 @@ impl Shl<u64> for DBB {
     }
 }
 
-const NOT_FILE_A: DBB = DBB(u64x2::from_array([
-    !0x0101010101010101u64,
-    !0x0101010101010101u64,
-]));
-const NOT_FILE_H: DBB = DBB(u64x2::from_array([
+const NOT_FILE_A: DBB = DBB::from_array([!0x0101010101010101u64, !0x0101010101010101u64]);
+const NOT_FILE_H: DBB = DBB::from_array([
     !(0x0101010101010101u64 << 7),
     !(0x0101010101010101u64 << 7),
-]));
+]);
 
 impl DBB {
     pub fn new(a: BB, b: BB) -> DBB {
-        DBB(u64x2::from_array([a.to_u64(), b.to_u64()]))
+        DBB(U64x2::from_array([a.to_u64(), b.to_u64()]))
+    }
+
+    pub const fn from_array(lanes: [u64; 2]) -> DBB {
+        DBB(U64x2::from_array(lanes))
     }
 
     pub fn splat(source: BB) -> DBB {
-        DBB(u64x2::splat(source.to_u64()))
+        DBB(U64x2::splat(source.to_u64()))
     }
 
     pub fn extract(&self) -> (BB, BB) {
-        let arr = self.0.as_array();
+        let arr = self.0.to_array();
         (BB(arr[0]), BB(arr[1]))
     }
 
+    #[cfg(feature = "nightly")]
     pub fn bswap(&self) -> DBB {
+        use core::mem::transmute;
+        use core::simd::{simd_swizzle, u8x16};
+
         let bytes: u8x16 = unsafe { transmute(self.0) };
         let shuffled: u8x16 = simd_swizzle!(bytes, [
             7, 6, 5, 4, 3, 2, 1, 0, 15, 14, 13, 12, 11, 10, 9, 8
         ]);
-        let ret: u64x2 = unsafe { transmute(shuffled) };
-        DBB(ret)
+        DBB(unsafe { transmute::<u8x16, U64x2>(shuffled) })
+    }
+
+    /// Compiles to a byte shuffle when SSSE3 is available
+    #[cfg(not(feature = "nightly"))]
+    pub fn bswap(&self) -> DBB {
+        DBB(U64x2::from_array(self.0.to_array().map(u64::swap_bytes)))
     }
 
     pub fn occluded_east_fill(&self, empty: DBB) -> DBB {
@@ -291,10 +427,7 @@ mod test {
     #[test]
     fn test_bswap() {
         //bswap should reverse bytes in each u64
-        let input = DBB(u64x2::from_array([
-            !0x0102030405060708u64,
-            !0x0203040506070809u64,
-        ]));
+        let input = DBB::from_array([!0x0102030405060708u64, !0x0203040506070809u64]);
         let (expected_left, expected_right) =
             (BB(!0x0807060504030201u64), BB(!0x0908070605040302u64));
         let (output_left, output_right) = input.bswap().extract();
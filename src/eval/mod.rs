@@ -0,0 +1,302 @@
+// Hand-crafted evaluation, see https://www.chessprogramming.org/Evaluation
+//
+// Every term is a parameter multiplied by how often its feature occurs, so the evaluation is
+// linear in EvalParams and the same code can report the feature counts for tuning
+mod params;
+#[cfg(feature = "std")]
+mod tuner;
+
+use crate::bb::*;
+use crate::generation::slider::{bishop_attacks_from_sq, rook_attacks_from_sq};
+use crate::mv_list::{MAX_PHASE, TaperedScore};
+use crate::piece::*;
+use crate::position::Position;
+use crate::side::{BLACK, Side, WHITE};
+use crate::square::Square;
+
+pub use self::params::*;
+#[cfg(feature = "std")]
+pub use self::tuner::{TexelTuner, TexelTunerOptions};
+
+/// Receives each evaluation term as the index of its parameter in EvalParams::to_vec, the
+/// parameter and how often its feature occurs, counted positive for white and negative for
+/// black
+pub(crate) trait EvalTerms {
+    fn add(&mut self, param: usize, score: TaperedScore, count: i32);
+}
+
+// Sums the weighted terms
+#[derive(Default)]
+struct Score {
+    mg: i32,
+    eg: i32,
+}
+
+impl EvalTerms for Score {
+    #[inline(always)]
+    fn add(&mut self, _param: usize, score: TaperedScore, count: i32) {
+        self.mg += score.mg as i32 * count;
+        self.eg += score.eg as i32 * count;
+    }
+}
+
+/// Evaluates position relative to the side to move with the hand-crafted evaluation, blending
+/// midgame and endgame scores by Position::game_phase
+pub fn evaluate(position: &Position, params: &EvalParams) -> i16 {
+    let mut score = Score::default();
+    evaluate_terms(position, params, &mut score);
+
+    let phase = position.game_phase();
+    let white_score = (score.mg * phase + score.eg * (MAX_PHASE - phase)) / MAX_PHASE;
+    let score = if position.state().stm == WHITE {
+        white_score
+    } else {
+        -white_score
+    };
+
+    score.clamp(-(i16::MAX as i32), i16::MAX as i32) as i16
+}
+
+pub(crate) fn evaluate_terms<T: EvalTerms>(
+    position: &Position,
+    params: &EvalParams,
+    terms: &mut T,
+) {
+    for side in [WHITE, BLACK] {
+        side_terms(position, params, side, terms);
+    }
+}
+
+// Adds the terms for side. Bitboards are flipped vertically for black so each side's pawns
+// move north
+fn side_terms<T: EvalTerms>(position: &Position, params: &EvalParams, side: Side, terms: &mut T) {
+    let sign = if side == WHITE { 1 } else { -1 };
+    let orient = |bb: BB| if side == WHITE { bb } else { bb.bswap() };
+
+    let own = orient(position.bb_side(side));
+    let occupied = own | orient(position.bb_side(side.flip()));
+    let own_pawns = orient(position.bb_pc(PAWN.pc(side)));
+    let enemy_pawns = orient(position.bb_pc(PAWN.pc(side.flip())));
+    let enemy_pawn_attacks = ((enemy_pawns >> 7) & NOT_FILE_A) | ((enemy_pawns >> 9) & NOT_FILE_H);
+
+    // material and piece-square terms
+    for kind in Kind::iter() {
+        let pieces = orient(position.bb_pc(kind.pc(side)));
+        let k = kind.to_usize();
+
+        terms.add(
+            MATERIAL_OFFSET + k,
+            params.material[k],
+            sign * pieces.pop_count() as i32,
+        );
+        for (sq, _) in pieces.iter() {
+            terms.add(
+                PSQT_OFFSET + k * 64 + sq.to_usize(),
+                params.psqt[k][sq.to_usize()],
+                sign,
+            );
+        }
+    }
+
+    // mobility and attacks next to the enemy king
+    let mobility_area = !own & !enemy_pawn_attacks;
+    let enemy_king = orient(position.bb_pc(KING.pc(side.flip()))).bitscan();
+    let king_zone = enemy_king.king_moves() | BB::new(enemy_king);
+
+    for (i, &kind) in MOBILITY_KINDS.iter().enumerate() {
+        for (sq, _) in orient(position.bb_pc(kind.pc(side))).iter() {
+            let attacks = piece_attacks(kind, sq, occupied);
+
+            terms.add(
+                MOBILITY_OFFSET + i,
+                params.mobility[i],
+                sign * (attacks & mobility_area).pop_count() as i32,
+            );
+            terms.add(
+                KING_ZONE_ATTACK_OFFSET + i,
+                params.king_zone_attack[i],
+                sign * (attacks & king_zone).pop_count() as i32,
+            );
+        }
+    }
+
+    pawn_terms(
+        params,
+        own_pawns,
+        enemy_pawns,
+        enemy_pawn_attacks,
+        sign,
+        terms,
+    );
+
+    if orient(position.bb_pc(BISHOP.pc(side))).pop_count() >= 2 {
+        terms.add(BISHOP_PAIR_OFFSET, params.bishop_pair, sign);
+    }
+}
+
+fn pawn_terms<T: EvalTerms>(
+    params: &EvalParams,
+    own_pawns: BB,
+    enemy_pawns: BB,
+    enemy_pawn_attacks: BB,
+    sign: i32,
+    terms: &mut T,
+) {
+    let doubled = own_pawns & front_span(own_pawns);
+    terms.add(
+        DOUBLED_PAWN_OFFSET,
+        params.doubled_pawn,
+        sign * doubled.pop_count() as i32,
+    );
+
+    for (sq, bb) in own_pawns.iter() {
+        let col = sq.col();
+        let row = sq.row();
+
+        if own_pawns & ADJACENT_FILES[col] == EMPTY {
+            terms.add(ISOLATED_PAWN_OFFSET, params.isolated_pawn, sign);
+        } else {
+            // no pawn on an adjacent file level with or behind this one can defend it
+            let supporters = ADJACENT_FILES[col] & BB(!0u64 >> (56 - 8 * row));
+            let stop = bb << 8;
+            if own_pawns & supporters == EMPTY && enemy_pawn_attacks & stop != EMPTY {
+                terms.add(BACKWARD_PAWN_OFFSET, params.backward_pawn, sign);
+            }
+        }
+
+        let front = front_span(bb);
+        let passed_mask = front | ((front << 1) & NOT_FILE_A) | ((front >> 1) & NOT_FILE_H);
+        if enemy_pawns & passed_mask == EMPTY && own_pawns & front == EMPTY {
+            terms.add(PASSED_PAWN_OFFSET + row, params.passed_pawn[row], sign);
+        }
+    }
+}
+
+// Squares north of the pieces in source
+fn front_span(source: BB) -> BB {
+    (source << 8).occluded_north_fill(!EMPTY)
+}
+
+fn piece_attacks(kind: Kind, sq: Square, occupied: BB) -> BB {
+    if kind == KNIGHT {
+        sq.knight_moves()
+    } else if kind == BISHOP {
+        bishop_attacks_from_sq(sq, occupied)
+    } else if kind == ROOK {
+        rook_attacks_from_sq(sq, occupied)
+    } else {
+        bishop_attacks_from_sq(sq, occupied) | rook_attacks_from_sq(sq, occupied)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::position::{STARTING_POSITION_FEN, State};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    // Feature counts by parameter index
+    struct Counts(Vec<i32>);
+
+    impl EvalTerms for Counts {
+        fn add(&mut self, param: usize, _score: TaperedScore, count: i32) {
+            self.0[param] += count;
+        }
+    }
+
+    fn feature_counts(fen: &str) -> Vec<i32> {
+        let position = Position::from_fen(fen).unwrap();
+        let mut counts = Counts(vec![0; NUM_PARAMS]);
+        evaluate_terms(&position, &EvalParams::default(), &mut counts);
+        counts.0
+    }
+
+    // Swaps the colours and flips the board
+    fn mirror(position: &Position) -> Position {
+        let mut grid = [NULL_PIECE; 64];
+        for (idx, &pc) in position.grid().iter().enumerate() {
+            if pc.is_some() {
+                grid[Square(idx).flip().to_usize()] = pc.kind().pc(pc.side().flip());
+            }
+        }
+        let state = State {
+            stm: position.state().stm.flip(),
+            ..State::default()
+        };
+        Position::new(grid, state)
+    }
+
+    #[test]
+    fn test_starting_position() {
+        let position = Position::from_fen(STARTING_POSITION_FEN).unwrap();
+        assert_eq!(evaluate(&position, &EvalParams::default()), 0);
+    }
+
+    #[test]
+    fn test_symmetry() {
+        let params = EvalParams::default();
+        for fen in [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+            "r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1",
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        ] {
+            let position = Position::from_fen(fen).unwrap();
+            let score = evaluate(&position, &params);
+            assert_eq!(evaluate(&mirror(&position), &params), score, "{}", fen);
+        }
+    }
+
+    #[test]
+    fn test_material_advantage() {
+        let params = EvalParams::default();
+        // white is a queen up
+        let position = Position::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+        assert!(evaluate(&position, &params) > 800);
+
+        let mut black_to_move = position.clone();
+        black_to_move.make_null_move();
+        assert!(evaluate(&black_to_move, &params) < -800);
+    }
+
+    #[test]
+    fn test_pawn_structure() {
+        // white: doubled c pawns, isolated a and g pawns, passed a, c4 and g pawns
+        // black: backward e pawn whose stop square e5 the d4 pawn attacks, passed f pawn
+        let counts = feature_counts("4k3/8/4p1P1/5p2/2PP4/2P5/P7/4K3 w - - 0 1");
+
+        assert_eq!(counts[DOUBLED_PAWN_OFFSET], 1);
+        assert_eq!(counts[ISOLATED_PAWN_OFFSET], 2);
+        assert_eq!(counts[BACKWARD_PAWN_OFFSET], -1);
+        assert_eq!(counts[PASSED_PAWN_OFFSET + 1], 1);
+        // c4 and f5 are both on their fourth rank
+        assert_eq!(counts[PASSED_PAWN_OFFSET + 3], 0);
+        assert_eq!(counts[PASSED_PAWN_OFFSET + 5], 1);
+        assert_eq!(counts[MATERIAL_OFFSET + PAWN.to_usize()], 3);
+    }
+
+    #[test]
+    fn test_passed_pawns() {
+        // the white a and h pawns are passed, the black b pawn is passed on rank 6 from its side
+        let counts = feature_counts("4k3/8/8/7P/P7/1p6/8/4K3 w - - 0 1");
+
+        assert_eq!(counts[PASSED_PAWN_OFFSET + 3], 1);
+        assert_eq!(counts[PASSED_PAWN_OFFSET + 4], 1);
+        assert_eq!(counts[PASSED_PAWN_OFFSET + 5], -1);
+    }
+
+    #[test]
+    fn test_mobility_and_king_safety() {
+        let counts = feature_counts("6k1/8/8/8/8/8/8/1N2K1R1 w - - 0 1");
+
+        // knight on b1 reaches a3, c3 and d2
+        assert_eq!(counts[MOBILITY_OFFSET], 3);
+        // the rook on g1 attacks g7 and g8 next to the black king
+        assert_eq!(counts[KING_ZONE_ATTACK_OFFSET + 2], 2);
+        assert_eq!(counts[BISHOP_PAIR_OFFSET], 0);
+
+        let counts = feature_counts("2b1kb2/8/8/8/8/8/8/4K3 b - - 0 1");
+        assert_eq!(counts[BISHOP_PAIR_OFFSET], -1);
+    }
+}
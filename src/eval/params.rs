@@ -0,0 +1,369 @@
+use crate::mv_list::{TaperedPieceSquareTable, TaperedScore};
+use crate::piece::*;
+use crate::square::Square;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Pieces with mobility and king attack terms, in the order of those parameter arrays
+pub const MOBILITY_KINDS: [Kind; 4] = [KNIGHT, BISHOP, ROOK, QUEEN];
+
+// Offsets of each parameter group in EvalParams::to_vec
+pub const MATERIAL_OFFSET: usize = 0;
+pub const PSQT_OFFSET: usize = MATERIAL_OFFSET + 6;
+pub const MOBILITY_OFFSET: usize = PSQT_OFFSET + 6 * 64;
+pub const PASSED_PAWN_OFFSET: usize = MOBILITY_OFFSET + 4;
+pub const DOUBLED_PAWN_OFFSET: usize = PASSED_PAWN_OFFSET + 8;
+pub const ISOLATED_PAWN_OFFSET: usize = DOUBLED_PAWN_OFFSET + 1;
+pub const BACKWARD_PAWN_OFFSET: usize = ISOLATED_PAWN_OFFSET + 1;
+pub const KING_ZONE_ATTACK_OFFSET: usize = BACKWARD_PAWN_OFFSET + 1;
+pub const BISHOP_PAIR_OFFSET: usize = KING_ZONE_ATTACK_OFFSET + 4;
+/// Number of tapered parameters in EvalParams
+pub const NUM_PARAMS: usize = BISHOP_PAIR_OFFSET + 1;
+
+/// EvalParams holds the weights of the hand-crafted evaluation. Every term is a midgame and
+/// endgame score from white's point of view, multiplied by how often the feature occurs
+///
+/// Parameters convert to a flat vector for tuning with to_vec and from_slice, and to text with
+/// to_string and parse
+#[derive(Clone, PartialEq, Debug)]
+pub struct EvalParams {
+    /// Value of each piece, indexed by Kind
+    pub material: [TaperedScore; 6],
+    /// Positional value of each piece on each square from white's side, indexed by Kind then
+    /// Square. Added to material
+    pub psqt: [[TaperedScore; 64]; 6],
+    /// Per square a piece attacks that is not occupied by its own side or attacked by an enemy
+    /// pawn, in MOBILITY_KINDS order
+    pub mobility: [TaperedScore; 4],
+    /// Passed pawn by rank from its own side
+    pub passed_pawn: [TaperedScore; 8],
+    /// Per pawn with another pawn of its side behind it on the same file
+    pub doubled_pawn: TaperedScore,
+    /// Per pawn with no pawns of its side on adjacent files
+    pub isolated_pawn: TaperedScore,
+    /// Per pawn that no pawn of its side can defend and whose stop square an enemy pawn attacks
+    pub backward_pawn: TaperedScore,
+    /// Per square next to the enemy king a piece attacks, in MOBILITY_KINDS order
+    pub king_zone_attack: [TaperedScore; 4],
+    /// For having two or more bishops
+    pub bishop_pair: TaperedScore,
+}
+
+const fn s(mg: i16, eg: i16) -> TaperedScore {
+    TaperedScore::new(mg, eg)
+}
+
+const ZERO: TaperedScore = s(0, 0);
+
+// Simple centralisation and advancement bonuses for sq from white's side
+fn default_psqt(kind: Kind, sq: Square) -> TaperedScore {
+    let row = sq.row() as i16;
+    let col = sq.col() as i16;
+    // distance from the centre, 0 on the 4 centre squares up to 6 in the corners
+    let centre_distance = (2 * row - 7).abs() / 2 + (2 * col - 7).abs() / 2;
+
+    if kind == PAWN {
+        if (1..7).contains(&row) {
+            s(5 * (row - 1) - (2 * col - 7).abs(), 8 * (row - 1))
+        } else {
+            ZERO
+        }
+    } else if kind == KNIGHT {
+        s(12 - 6 * centre_distance, 9 - 4 * centre_distance)
+    } else if kind == BISHOP {
+        s(6 - 3 * centre_distance, 6 - 3 * centre_distance)
+    } else if kind == ROOK {
+        s(if row == 6 { 15 } else { 0 }, 0)
+    } else if kind == QUEEN {
+        s(3 - centre_distance, 6 - 2 * centre_distance)
+    } else {
+        s(
+            -15 * row + if row == 0 { 10 } else { 0 },
+            24 - 8 * centre_distance,
+        )
+    }
+}
+
+impl Default for EvalParams {
+    fn default() -> Self {
+        let psqt = core::array::from_fn(|k| {
+            core::array::from_fn(|sq| default_psqt(Kind(k as u8), Square(sq)))
+        });
+
+        EvalParams {
+            // indexed by Kind: bishop, queen, rook, knight, pawn, king
+            material: [
+                s(365, 297),
+                s(1025, 936),
+                s(477, 512),
+                s(337, 281),
+                s(82, 94),
+                ZERO,
+            ],
+            psqt,
+            mobility: [s(4, 4), s(5, 5), s(2, 4), s(1, 2)],
+            passed_pawn: [
+                ZERO,
+                s(5, 10),
+                s(5, 15),
+                s(10, 25),
+                s(20, 45),
+                s(35, 75),
+                s(60, 120),
+                ZERO,
+            ],
+            doubled_pawn: s(-10, -20),
+            isolated_pawn: s(-10, -15),
+            backward_pawn: s(-8, -10),
+            king_zone_attack: [s(8, 0), s(6, 0), s(8, 0), s(12, 0)],
+            bishop_pair: s(30, 50),
+        }
+    }
+}
+
+impl fmt::Display for EvalParams {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (name, scores) in self.groups() {
+            write!(f, "{}", name)?;
+            for score in scores {
+                write!(f, " {},{}", score.mg, score.eg)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl EvalParams {
+    /// Piece-square table of material plus positional value, eg for
+    /// Position::set_tapered_piece_square_table or move ordering
+    pub fn to_tapered_piece_square_table(&self) -> TaperedPieceSquareTable {
+        let mut mg = [[0i16; 64]; 6];
+        let mut eg = [[0i16; 64]; 6];
+
+        for kind in 0..6 {
+            for sq in 0..64 {
+                let score = self.material[kind] + self.psqt[kind][sq];
+                mg[kind][sq] = score.mg;
+                eg[kind][sq] = score.eg;
+            }
+        }
+
+        TaperedPieceSquareTable::new(mg, eg)
+    }
+
+    /// Material plus piece-square values of each piece blended at phase, in the layout
+    /// PieceSquareTable::new takes. Use MAX_PHASE for midgame values and 0 for endgame values
+    pub fn piece_square_values(&self, phase: i32) -> [[i16; 64]; 6] {
+        let mut values = [[0i16; 64]; 6];
+
+        for (kind, kind_values) in values.iter_mut().enumerate() {
+            for (sq, value) in kind_values.iter_mut().enumerate() {
+                *value = (self.material[kind] + self.psqt[kind][sq]).interpolate(phase);
+            }
+        }
+
+        values
+    }
+
+    /// All parameters in a flat vector, laid out by the *_OFFSET constants
+    pub fn to_vec(&self) -> Vec<TaperedScore> {
+        let mut params = Vec::with_capacity(NUM_PARAMS);
+        for (_, scores) in self.groups() {
+            params.extend_from_slice(&scores);
+        }
+        debug_assert_eq!(params.len(), NUM_PARAMS);
+        params
+    }
+
+    /// Parameters from a flat vector laid out like to_vec
+    pub fn from_slice(params: &[TaperedScore]) -> Result<EvalParams, String> {
+        if params.len() != NUM_PARAMS {
+            return Err(format!(
+                "Expected {} parameters, got {}",
+                NUM_PARAMS,
+                params.len()
+            ));
+        }
+
+        let mut eval_params = EvalParams::default();
+        let mut rest = params;
+        for group in eval_params.groups_mut() {
+            let (head, tail) = rest.split_at(group.len());
+            group.copy_from_slice(head);
+            rest = tail;
+        }
+
+        Ok(eval_params)
+    }
+
+    /// Parses parameters written by to_string. Groups missing from the text keep their default
+    /// values
+    pub fn parse(text: &str) -> Result<EvalParams, String> {
+        let mut eval_params = EvalParams::default();
+        let names = eval_params
+            .groups()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<String>>();
+
+        for (line_number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.split_whitespace();
+            let name = fields.next().unwrap_or_default();
+            let Some(index) = names.iter().position(|n| n == name) else {
+                return Err(format!(
+                    "Unknown parameter '{}' on line {}",
+                    name,
+                    line_number + 1
+                ));
+            };
+
+            let scores = fields
+                .map(parse_score)
+                .collect::<Result<Vec<TaperedScore>, String>>()
+                .map_err(|err| format!("{} on line {}", err, line_number + 1))?;
+
+            let group = eval_params.groups_mut().swap_remove(index);
+            if scores.len() != group.len() {
+                return Err(format!(
+                    "Expected {} scores for '{}' on line {}, got {}",
+                    group.len(),
+                    name,
+                    line_number + 1,
+                    scores.len()
+                ));
+            }
+            group.copy_from_slice(&scores);
+        }
+
+        Ok(eval_params)
+    }
+
+    // Named parameter groups in to_vec order
+    fn groups(&self) -> Vec<(String, Vec<TaperedScore>)> {
+        let mut groups = Vec::new();
+        groups.push(("material".to_string(), self.material.to_vec()));
+        for kind in Kind::iter() {
+            groups.push((
+                format!("psqt_{}", kind.to_string()),
+                self.psqt[kind.to_usize()].to_vec(),
+            ));
+        }
+        groups.push(("mobility".to_string(), self.mobility.to_vec()));
+        groups.push(("passed_pawn".to_string(), self.passed_pawn.to_vec()));
+        groups.push(("doubled_pawn".to_string(), [self.doubled_pawn].to_vec()));
+        groups.push(("isolated_pawn".to_string(), [self.isolated_pawn].to_vec()));
+        groups.push(("backward_pawn".to_string(), [self.backward_pawn].to_vec()));
+        groups.push((
+            "king_zone_attack".to_string(),
+            self.king_zone_attack.to_vec(),
+        ));
+        groups.push(("bishop_pair".to_string(), [self.bishop_pair].to_vec()));
+        groups
+    }
+
+    // Mutable parameter groups in to_vec order
+    fn groups_mut(&mut self) -> Vec<&mut [TaperedScore]> {
+        let mut groups: Vec<&mut [TaperedScore]> = Vec::new();
+        groups.push(&mut self.material);
+        for kind_psqt in self.psqt.iter_mut() {
+            groups.push(kind_psqt);
+        }
+        groups.push(&mut self.mobility);
+        groups.push(&mut self.passed_pawn);
+        groups.push(core::slice::from_mut(&mut self.doubled_pawn));
+        groups.push(core::slice::from_mut(&mut self.isolated_pawn));
+        groups.push(core::slice::from_mut(&mut self.backward_pawn));
+        groups.push(&mut self.king_zone_attack);
+        groups.push(core::slice::from_mut(&mut self.bishop_pair));
+        groups
+    }
+}
+
+fn parse_score(field: &str) -> Result<TaperedScore, String> {
+    let parse = |value: &str| {
+        value
+            .parse::<i16>()
+            .map_err(|_| format!("Invalid score '{}'", field))
+    };
+
+    match field.split_once(',') {
+        Some((mg, eg)) => Ok(TaperedScore::new(parse(mg)?, parse(eg)?)),
+        None => Err(format!("Invalid score '{}'", field)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mv_list::{MAX_PHASE, PieceSquareTable};
+
+    #[test]
+    fn test_piece_square_values() {
+        let params = EvalParams::default();
+        let tapered = params.to_tapered_piece_square_table();
+        let midgame = PieceSquareTable::new(params.piece_square_values(MAX_PHASE));
+
+        for kind in Kind::iter() {
+            for sq in 0..64 {
+                let expected = tapered.score(kind, Square(sq)).mg;
+                assert_eq!(midgame.score(kind, Square(sq)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_vec_round_trip() {
+        let params = EvalParams::default();
+        let vec = params.to_vec();
+
+        assert_eq!(vec.len(), NUM_PARAMS);
+        assert_eq!(
+            vec[MATERIAL_OFFSET + PAWN.to_usize()],
+            params.material[PAWN.to_usize()]
+        );
+        assert_eq!(
+            vec[PSQT_OFFSET + KNIGHT.to_usize() * 64 + 27],
+            params.psqt[KNIGHT.to_usize()][27]
+        );
+        assert_eq!(vec[PASSED_PAWN_OFFSET + 6], params.passed_pawn[6]);
+        assert_eq!(vec[BACKWARD_PAWN_OFFSET], params.backward_pawn);
+        assert_eq!(vec[BISHOP_PAIR_OFFSET], params.bishop_pair);
+        assert_eq!(EvalParams::from_slice(&vec).unwrap(), params);
+        assert!(EvalParams::from_slice(&vec[1..]).is_err());
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        let mut params = EvalParams {
+            bishop_pair: TaperedScore::new(-7, 11),
+            ..EvalParams::default()
+        };
+        params.psqt[ROOK.to_usize()][63] = TaperedScore::new(3, -4);
+
+        let text = params.to_string();
+        assert!(text.contains("bishop_pair -7,11\n"));
+        assert_eq!(EvalParams::parse(&text).unwrap(), params);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            EvalParams::parse("bishop_pair 1,2\nisolated_pawn -3,-4")
+                .unwrap()
+                .isolated_pawn,
+            TaperedScore::new(-3, -4)
+        );
+        assert!(EvalParams::parse("unknown 1,2").is_err());
+        assert!(EvalParams::parse("bishop_pair 1,2 3,4").is_err());
+        assert!(EvalParams::parse("bishop_pair 1").is_err());
+        assert!(EvalParams::parse("bishop_pair x,2").is_err());
+    }
+}
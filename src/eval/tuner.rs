@@ -0,0 +1,435 @@
+// Texel tuning, see https://www.chessprogramming.org/Texel%27s_Tuning_Method
+//
+// The evaluation is linear in EvalParams, so each position is stored once as the feature counts
+// of its parameters and the error and its gradient are computed without generating positions
+use crate::eval::{EvalParams, EvalTerms, NUM_PARAMS, evaluate_terms};
+use crate::game_result::GameResult;
+use crate::mv_list::{MAX_PHASE, TaperedScore};
+use crate::position::Position;
+use crate::side::WHITE;
+use crate::training_data::TrainingDataEntry;
+use std::io;
+use std::sync::Arc;
+use std::sync::mpsc::channel;
+use threadpool::ThreadPool;
+
+// Midgame and endgame weights of each parameter
+const NUM_WEIGHTS: usize = NUM_PARAMS * 2;
+
+/// Options used when tuning
+#[derive(Clone, Debug)]
+pub struct TexelTunerOptions {
+    /// Number of gradient descent steps over the whole dataset
+    pub epochs: usize,
+    /// Largest change to a parameter in one step, in centipawns
+    pub learning_rate: f64,
+    /// Threads the dataset is split between
+    pub threads: usize,
+    /// Scaling constant of the sigmoid mapping scores to expected results. Found with
+    /// TexelTuner::find_k when None
+    pub k: Option<f64>,
+}
+
+impl Default for TexelTunerOptions {
+    fn default() -> Self {
+        TexelTunerOptions {
+            epochs: 1000,
+            learning_rate: 1.0,
+            threads: num_cpus::get(),
+            k: None,
+        }
+    }
+}
+
+// A position reduced to the non-zero feature counts of its evaluation
+#[derive(Clone, Debug)]
+struct TuningPosition {
+    // white's result: 1 win, 0.5 draw, 0 loss
+    result: f64,
+    // midgame weight of the position, 1 at the starting phase and 0 with no pieces left
+    phase: f64,
+    features: Vec<(u16, i16)>,
+}
+
+// Dense feature counts of one position
+struct FeatureCounts(Vec<i32>);
+
+impl EvalTerms for FeatureCounts {
+    fn add(&mut self, param: usize, _score: TaperedScore, count: i32) {
+        self.0[param] += count;
+    }
+}
+
+impl TuningPosition {
+    fn new(position: &Position, result: GameResult) -> TuningPosition {
+        let mut counts = FeatureCounts(vec![0; NUM_PARAMS]);
+        evaluate_terms(position, &EvalParams::default(), &mut counts);
+
+        TuningPosition {
+            result: (result.score_for(WHITE) as f64 + 1.0) / 2.0,
+            phase: position.game_phase() as f64 / MAX_PHASE as f64,
+            features: counts
+                .0
+                .iter()
+                .enumerate()
+                .filter(|&(_, &count)| count != 0)
+                .map(|(param, &count)| (param as u16, count as i16))
+                .collect(),
+        }
+    }
+
+    // White's score with the weights laid out as [mg, eg] per parameter
+    fn score(&self, weights: &[f64]) -> f64 {
+        let mut mg = 0.0;
+        let mut eg = 0.0;
+        for &(param, count) in &self.features {
+            mg += weights[param as usize * 2] * count as f64;
+            eg += weights[param as usize * 2 + 1] * count as f64;
+        }
+        mg * self.phase + eg * (1.0 - self.phase)
+    }
+}
+
+/// TexelTuner tunes EvalParams against the results of games the positions came from, by
+/// gradient descent on the mean squared difference between each result and the expected result
+/// 1 / (1 + 10^(-k * score / 400)). The dataset should only contain quiet positions
+///
+/// Example usage:
+///
+/// let mut tuner = TexelTuner::new(TexelTunerOptions::default());
+/// tuner.load_epd(BufReader::new(File::open("quiet-labeled.epd")?))?;
+/// let params = tuner.tune(&EvalParams::default());
+/// let table = PieceSquareTable::new(params.piece_square_values(MAX_PHASE));
+///
+pub struct TexelTuner {
+    options: TexelTunerOptions,
+    positions: Arc<Vec<TuningPosition>>,
+    pool: ThreadPool,
+}
+
+impl TexelTuner {
+    pub fn new(options: TexelTunerOptions) -> TexelTuner {
+        let pool = ThreadPool::new(options.threads.max(1));
+        TexelTuner {
+            options,
+            positions: Arc::new(Vec::new()),
+            pool,
+        }
+    }
+
+    /// Number of positions in the dataset
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Adds a position from a game with the provided result
+    pub fn add_position(&mut self, position: &Position, result: GameResult) {
+        Arc::make_mut(&mut self.positions).push(TuningPosition::new(position, result));
+    }
+
+    /// Adds the position of a training data entry, eg from a PlainReader
+    pub fn add_entry(&mut self, entry: &TrainingDataEntry) {
+        let stm = entry.position.state().stm;
+        let result = match entry.result {
+            0 => GameResult::Draw,
+            r if r > 0 => GameResult::win_for(stm),
+            _ => GameResult::win_for(stm.flip()),
+        };
+        self.add_position(&entry.position, result);
+    }
+
+    /// Adds a position from an EPD line ending with white's result, eg
+    /// `rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - c9 "1/2-1/2";` or
+    /// `rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - [0.5]`
+    pub fn add_epd_line(&mut self, line: &str) -> Result<(), String> {
+        let (position, result) = parse_epd_line(line)?;
+        self.add_position(&position, result);
+        Ok(())
+    }
+
+    /// Adds every non-empty line of an EPD file, returning the number of positions added
+    pub fn load_epd<R: io::BufRead>(&mut self, reader: R) -> Result<usize, String> {
+        let mut added = 0;
+        for line in reader.lines() {
+            let line = line.map_err(|err| err.to_string())?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            self.add_epd_line(&line)?;
+            added += 1;
+        }
+        Ok(added)
+    }
+
+    /// Mean squared error of params over the dataset with scaling constant k
+    pub fn error(&self, params: &EvalParams, k: f64) -> f64 {
+        self.error_and_gradient(&weights_from_params(params), k, false)
+            .0
+    }
+
+    /// Finds the scaling constant k that minimises the error of params, to 4 decimal places
+    pub fn find_k(&self, params: &EvalParams) -> f64 {
+        let weights = weights_from_params(params);
+        let error = |k: f64| self.error_and_gradient(&weights, k, false).0;
+
+        let mut best = 1.0;
+        let mut best_error = error(best);
+        let mut step = 1.0;
+        for _ in 0..5 {
+            let start = (best - step * 10.0).max(0.0);
+            for i in 1..=20 {
+                let k = start + step * i as f64;
+                let e = error(k);
+                if e < best_error {
+                    best = k;
+                    best_error = e;
+                }
+            }
+            step /= 10.0;
+        }
+
+        best
+    }
+
+    /// Tunes every parameter starting from params and returns the result. Uses Adam, so each
+    /// step moves a parameter by about learning_rate whatever the scale of its gradient
+    pub fn tune(&self, params: &EvalParams) -> EvalParams {
+        const BETA1: f64 = 0.9;
+        const BETA2: f64 = 0.999;
+        const EPSILON: f64 = 1e-8;
+
+        let k = self.options.k.unwrap_or_else(|| self.find_k(params));
+        let mut weights = weights_from_params(params);
+        let mut momentum = vec![0.0; NUM_WEIGHTS];
+        let mut velocity = vec![0.0; NUM_WEIGHTS];
+
+        for epoch in 1..=self.options.epochs {
+            let (_, gradient) = self.error_and_gradient(&weights, k, true);
+
+            let momentum_correction = 1.0 - BETA1.powi(epoch as i32);
+            let velocity_correction = 1.0 - BETA2.powi(epoch as i32);
+            for i in 0..NUM_WEIGHTS {
+                momentum[i] = BETA1 * momentum[i] + (1.0 - BETA1) * gradient[i];
+                velocity[i] = BETA2 * velocity[i] + (1.0 - BETA2) * gradient[i] * gradient[i];
+
+                let m = momentum[i] / momentum_correction;
+                let v = velocity[i] / velocity_correction;
+                weights[i] -= self.options.learning_rate * m / (v.sqrt() + EPSILON);
+            }
+        }
+
+        params_from_weights(&weights)
+    }
+
+    // Splits the dataset between the threads and sums the error and, if requested, its
+    // gradient with respect to the weights. Partial sums are added in chunk order so the
+    // result does not depend on which thread finishes first
+    fn error_and_gradient(&self, weights: &[f64], k: f64, gradient: bool) -> (f64, Vec<f64>) {
+        let len = self.positions.len();
+        if len == 0 {
+            return (0.0, vec![0.0; NUM_WEIGHTS]);
+        }
+
+        let chunks = self.options.threads.clamp(1, len);
+        let chunk_len = len.div_ceil(chunks);
+        let weights = Arc::new(weights.to_vec());
+        let (tx, rx) = channel();
+
+        for chunk in 0..chunks {
+            let tx = tx.clone();
+            let positions = Arc::clone(&self.positions);
+            let weights = Arc::clone(&weights);
+
+            self.pool.execute(move || {
+                let end = ((chunk + 1) * chunk_len).min(positions.len());
+                let partial = chunk_error_and_gradient(
+                    &positions[chunk * chunk_len..end],
+                    &weights,
+                    k,
+                    gradient,
+                );
+                tx.send((chunk, partial)).unwrap();
+            });
+        }
+
+        let mut partials: Vec<(usize, (f64, Vec<f64>))> = rx.iter().take(chunks).collect();
+        partials.sort_by_key(|&(chunk, _)| chunk);
+
+        let mut error = 0.0;
+        let mut total_gradient = vec![0.0; NUM_WEIGHTS];
+        for (_, (chunk_error, chunk_gradient)) in partials {
+            error += chunk_error;
+            for (total, g) in total_gradient.iter_mut().zip(chunk_gradient) {
+                *total += g;
+            }
+        }
+
+        let n = len as f64;
+        for g in total_gradient.iter_mut() {
+            *g /= n;
+        }
+        (error / n, total_gradient)
+    }
+}
+
+fn chunk_error_and_gradient(
+    positions: &[TuningPosition],
+    weights: &[f64],
+    k: f64,
+    gradient: bool,
+) -> (f64, Vec<f64>) {
+    let mut error = 0.0;
+    let mut chunk_gradient = vec![0.0; if gradient { NUM_WEIGHTS } else { 0 }];
+    // derivative of the sigmoid is scale * s * (1 - s)
+    let scale = k * std::f64::consts::LN_10 / 400.0;
+
+    for position in positions {
+        let expected = sigmoid(k, position.score(weights));
+        let diff = position.result - expected;
+        error += diff * diff;
+
+        if gradient {
+            let d_score = -2.0 * diff * scale * expected * (1.0 - expected);
+            for &(param, count) in &position.features {
+                let d = d_score * count as f64;
+                chunk_gradient[param as usize * 2] += d * position.phase;
+                chunk_gradient[param as usize * 2 + 1] += d * (1.0 - position.phase);
+            }
+        }
+    }
+
+    (error, chunk_gradient)
+}
+
+fn sigmoid(k: f64, score: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-k * score / 400.0))
+}
+
+fn weights_from_params(params: &EvalParams) -> Vec<f64> {
+    params
+        .to_vec()
+        .iter()
+        .flat_map(|score| [score.mg as f64, score.eg as f64])
+        .collect()
+}
+
+fn params_from_weights(weights: &[f64]) -> EvalParams {
+    let round = |w: f64| w.round().clamp(i16::MIN as f64, i16::MAX as f64) as i16;
+    let scores: Vec<TaperedScore> = weights
+        .chunks(2)
+        .map(|w| TaperedScore::new(round(w[0]), round(w[1])))
+        .collect();
+    EvalParams::from_slice(&scores).unwrap()
+}
+
+fn parse_epd_line(line: &str) -> Result<(Position, GameResult), String> {
+    let line = line.trim();
+    let (fen, result) = if let Some((fen, rest)) = line.split_once('[') {
+        (fen, rest.trim_end_matches(']'))
+    } else if let Some((fen, rest)) = line.split_once('"') {
+        let fen = fen.trim_end().trim_end_matches("c9");
+        (fen, rest.split('"').next().unwrap_or_default())
+    } else {
+        return Err(format!("Missing result: {}", line));
+    };
+
+    let result = match result.trim() {
+        "1.0" | "1" => GameResult::WhiteWin,
+        "0.5" => GameResult::Draw,
+        "0.0" | "0" => GameResult::BlackWin,
+        result => GameResult::parse(result)?,
+    };
+
+    Ok((Position::from_fen(fen.trim())?, result))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::eval::{MATERIAL_OFFSET, evaluate};
+    use crate::piece::KNIGHT;
+
+    // A knight up usually wins, equal material usually draws
+    const DATASET: &str = "4k3/pp6/8/8/8/8/PP6/1N2K3 w - - c9 \"1-0\";
+4k3/pp6/8/8/8/8/PP6/4KN2 b - - c9 \"1-0\";
+4k3/1p6/p7/8/8/P7/1P6/2N1K3 w - - [0.5]
+1n2k3/pp6/8/8/8/8/PP6/4K3 w - - c9 \"0-1\";
+4kn2/pp6/8/8/8/8/PP6/4K3 b - - [0.0]
+2n1k3/1p6/p7/8/8/P7/1P6/4K3 b - - [0.5]
+4k3/pp6/8/8/8/8/PP6/4K3 w - - [0.5]
+4k3/1p6/p7/8/8/P7/1P6/4K3 b - - [0.5]
+3nk3/pp6/8/8/8/8/PP6/3NK3 w - - c9 \"1/2-1/2\";
+3nk3/1p6/p7/8/8/P7/1P6/3NK3 w - - [1.0]
+3nk3/1p6/p7/8/8/P7/1P6/3NK3 b - - [0.0]";
+
+    fn tuner(threads: usize) -> TexelTuner {
+        let mut tuner = TexelTuner::new(TexelTunerOptions {
+            epochs: 100,
+            learning_rate: 2.0,
+            threads,
+            k: None,
+        });
+        assert_eq!(tuner.load_epd(DATASET.as_bytes()).unwrap(), 11);
+        tuner
+    }
+
+    #[test]
+    fn test_parse_epd_line() {
+        let (position, result) =
+            parse_epd_line("4k3/8/8/8/8/8/8/4K3 w - - c9 \"1/2-1/2\";").unwrap();
+        assert_eq!(position.to_fen(), "4k3/8/8/8/8/8/8/4K3 w - - 0 1");
+        assert_eq!(result, GameResult::Draw);
+
+        let (_, result) = parse_epd_line("4k3/8/8/8/8/8/8/4K3 w - - [1.0]").unwrap();
+        assert_eq!(result, GameResult::WhiteWin);
+
+        assert!(parse_epd_line("4k3/8/8/8/8/8/8/4K3 w - -").is_err());
+        assert!(parse_epd_line("4k3/8/8/8/8/8/8/4K3 w - - [2.0]").is_err());
+    }
+
+    #[test]
+    fn test_score_matches_evaluate() {
+        let params = EvalParams::default();
+        let weights = weights_from_params(&params);
+        for line in DATASET.lines() {
+            let (position, result) = parse_epd_line(line).unwrap();
+            let score = TuningPosition::new(&position, result).score(&weights);
+            let white_score = if position.state().stm == WHITE {
+                evaluate(&position, &params)
+            } else {
+                -evaluate(&position, &params)
+            };
+            assert!((score - white_score as f64).abs() < 1.0, "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_find_k() {
+        let tuner = tuner(2);
+        let params = EvalParams::default();
+        let k = tuner.find_k(&params);
+
+        assert!(k > 0.0);
+        assert!(tuner.error(&params, k) <= tuner.error(&params, 1.0));
+        assert!(tuner.error(&params, k) <= tuner.error(&params, k * 2.0));
+    }
+
+    #[test]
+    fn test_tune() {
+        let tuner = tuner(3);
+        let params = EvalParams::default();
+        let k = tuner.find_k(&params);
+        let tuned = tuner.tune(&params);
+
+        assert!(tuner.error(&tuned, k) < tuner.error(&params, k));
+        // winning a knight up pushes its value up
+        let knight = MATERIAL_OFFSET + KNIGHT.to_usize();
+        assert!(tuned.to_vec()[knight].mg > params.to_vec()[knight].mg);
+        // the order threads finish in does not change the result
+        assert_eq!(tuner.tune(&params), tuned);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::side::{BLACK, Side, WHITE};
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+
+/// GameResult represents the outcome of a finished game
+#[derive(PartialEq, Eq, Copy, Clone)]
+pub enum GameResult {
+    WhiteWin,
+    BlackWin,
+    Draw,
+}
+
+impl GameResult {
+    /// Returns the side that won, if any
+    pub fn winner(self) -> Option<Side> {
+        match self {
+            GameResult::WhiteWin => Some(WHITE),
+            GameResult::BlackWin => Some(BLACK),
+            GameResult::Draw => None,
+        }
+    }
+
+    /// Returns a win for the provided side
+    pub fn win_for(side: Side) -> GameResult {
+        if side == WHITE {
+            GameResult::WhiteWin
+        } else {
+            GameResult::BlackWin
+        }
+    }
+
+    /// Returns 1 if side won, -1 if side lost and 0 for a draw
+    pub fn score_for(self, side: Side) -> i8 {
+        match self.winner() {
+            Some(winner) if winner == side => 1,
+            Some(_) => -1,
+            None => 0,
+        }
+    }
+
+    /// Convert to PGN string eg "1-0", "0-1" or "1/2-1/2"
+    pub fn pgn_string(self) -> &'static str {
+        match self {
+            GameResult::WhiteWin => "1-0",
+            GameResult::BlackWin => "0-1",
+            GameResult::Draw => "1/2-1/2",
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<GameResult, String> {
+        match s {
+            "1-0" => Ok(GameResult::WhiteWin),
+            "0-1" => Ok(GameResult::BlackWin),
+            "1/2-1/2" => Ok(GameResult::Draw),
+            _ => Err(format!("Invalid game result: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.pgn_string())
+    }
+}
+
+impl fmt::Debug for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.pgn_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn score_for() {
+        assert_eq!(GameResult::WhiteWin.score_for(WHITE), 1);
+        assert_eq!(GameResult::WhiteWin.score_for(BLACK), -1);
+        assert_eq!(GameResult::Draw.score_for(BLACK), 0);
+    }
+
+    #[test]
+    fn parse() {
+        for &result in &[GameResult::WhiteWin, GameResult::BlackWin, GameResult::Draw] {
+            assert_eq!(GameResult::parse(result.pgn_string()).unwrap(), result);
+        }
+        assert!(GameResult::parse("*").is_err());
+    }
+}
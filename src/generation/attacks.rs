@@ -75,8 +75,7 @@ pub fn king_danger_squares(king: BB, attacker: Side, position: &Position) -> BB
     let mut attacked_squares = EMPTY;
 
     let (diag_attackers, non_diag_attackers) = position.bb_sliders(attacker);
-    attacked_squares |= bishop_attacks(diag_attackers, occupied_without_king);
-    attacked_squares |= rook_attacks(non_diag_attackers, occupied_without_king);
+    attacked_squares |= slider_attacks(diag_attackers, non_diag_attackers, occupied_without_king);
 
     let knights = position.bb_pc(KNIGHT.pc(attacker));
     attacked_squares |= knight_moves_from_bb(knights);
@@ -100,8 +99,7 @@ pub fn attacked_squares_ignoring_ep(attacker: Side, position: &Position) -> BB {
     let mut attacked_squares = EMPTY;
 
     let (diag_attackers, non_diag_attackers) = position.bb_sliders(attacker);
-    attacked_squares |= rook_attacks(non_diag_attackers, occupied);
-    attacked_squares |= bishop_attacks(diag_attackers, occupied);
+    attacked_squares |= slider_attacks(diag_attackers, non_diag_attackers, occupied);
 
     let knights = position.bb_pc(KNIGHT.pc(attacker));
     attacked_squares |= knight_moves_from_bb(knights);
@@ -13,7 +13,7 @@ use self::pawn::*;
 use self::pinned::*;
 use self::slider::consts::squares_between;
 use self::slider::*;
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 use self::attacks::king_danger_squares;
 use crate::bb::{BB, EMPTY};
@@ -43,11 +43,24 @@ impl MoveGenPreprocessing {
     }
 }
 
-/// Adds all legal moves to the provided MoveAdder. Returns true if mover is in check
+/// Adds all legal moves to the provided MoveAdder. Returns true if mover is in check. See
+/// legal_moves_with_preprocessing() for choosing the slider backend once rather than per call
 pub fn legal_moves<L: MoveAdder>(position: &Position, list: &mut L) -> bool {
     legal_moves_with_preprocessing(position, list, movegen_preprocessing(position))
 }
 
+/// Adds all legal moves using the provided sliding piece attack backend
+pub fn legal_moves_with_sliders<S: SliderAttacks, L: MoveAdder>(
+    position: &Position,
+    list: &mut L,
+) -> bool {
+    legal_moves_with_preprocessing_and_sliders::<S, L>(
+        position,
+        list,
+        movegen_preprocessing(position),
+    )
+}
+
 /// Generates data about checkers, pinned pieces and pinners to be used by legal_moves_with_preprocessing()
 pub fn movegen_preprocessing(position: &Position) -> MoveGenPreprocessing {
     let stm = position.state().stm;
@@ -57,10 +70,42 @@ pub fn movegen_preprocessing(position: &Position) -> MoveGenPreprocessing {
 }
 
 /// Adds all legal moves to the provided MoveAdder. Returns true if moving side is in check
+///
+/// The slider backend is looked up on every call. Hot loops such as search should check
+/// slider_backend() once and call legal_moves_with_preprocessing_and_sliders::<S, _>() with the
+/// chosen backend, inside a #[target_feature(enable = "bmi2")] function for Bmi2, as perft does
+#[allow(unused_unsafe)]
 pub fn legal_moves_with_preprocessing<L: MoveAdder>(
     position: &Position,
     list: &mut L,
     preprocessed_data: MoveGenPreprocessing,
+) -> bool {
+    match slider_backend() {
+        // Bmi2 is only selected when the CPU supports it or by the slider-bmi2 feature
+        #[cfg(target_arch = "x86_64")]
+        SliderBackend::Bmi2 => unsafe { legal_moves_bmi2(position, list, preprocessed_data) },
+        _ => legal_moves_with_preprocessing_and_sliders::<DefaultSliders, L>(
+            position,
+            list,
+            preprocessed_data,
+        ),
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "bmi2")]
+fn legal_moves_bmi2<L: MoveAdder>(
+    position: &Position,
+    list: &mut L,
+    preprocessed_data: MoveGenPreprocessing,
+) -> bool {
+    legal_moves_with_preprocessing_and_sliders::<Bmi2, L>(position, list, preprocessed_data)
+}
+
+pub fn legal_moves_with_preprocessing_and_sliders<S: SliderAttacks, L: MoveAdder>(
+    position: &Position,
+    list: &mut L,
+    preprocessed_data: MoveGenPreprocessing,
 ) -> bool {
     let stm = position.state().stm;
     let kings = position.bb_pc(KING.pc(stm));
@@ -111,7 +156,7 @@ pub fn legal_moves_with_preprocessing<L: MoveAdder>(
     }
 
     // generate moves for pinned and unpinned sliders
-    slider_moves(position, capture_mask, push_mask, pinned, king_sq, list);
+    slider_moves::<S, L>(position, capture_mask, push_mask, pinned, king_sq, list);
 
     // generate moves for non-pinned knights (pinned knights can't move)
     knight_moves(position, capture_mask, push_mask, !pinned, list);
@@ -153,11 +198,54 @@ pub fn loud_legal_moves<L: MoveAdder>(position: &Position, list: &mut L) -> bool
     loud_legal_moves_with_preprocessing(position, list, movegen_preprocessing(position))
 }
 
+/// Adds 'loud' legal moves using the provided sliding piece attack backend
 #[allow(dead_code)]
+pub fn loud_legal_moves_with_sliders<S: SliderAttacks, L: MoveAdder>(
+    position: &Position,
+    list: &mut L,
+) -> bool {
+    loud_legal_moves_with_preprocessing_and_sliders::<S, L>(
+        position,
+        list,
+        movegen_preprocessing(position),
+    )
+}
+
+/// Adds 'loud' legal moves to the provided MoveAdder, looking up the slider backend on every
+/// call. Use loud_legal_moves_with_preprocessing_and_sliders::<S, _>() to choose it once instead
+#[allow(dead_code, unused_unsafe)]
 pub fn loud_legal_moves_with_preprocessing<L: MoveAdder>(
     position: &Position,
     list: &mut L,
     preprocessed_data: MoveGenPreprocessing,
+) -> bool {
+    match slider_backend() {
+        // Bmi2 is only selected when the CPU supports it or by the slider-bmi2 feature
+        #[cfg(target_arch = "x86_64")]
+        SliderBackend::Bmi2 => unsafe { loud_legal_moves_bmi2(position, list, preprocessed_data) },
+        _ => loud_legal_moves_with_preprocessing_and_sliders::<DefaultSliders, L>(
+            position,
+            list,
+            preprocessed_data,
+        ),
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "bmi2")]
+fn loud_legal_moves_bmi2<L: MoveAdder>(
+    position: &Position,
+    list: &mut L,
+    preprocessed_data: MoveGenPreprocessing,
+) -> bool {
+    loud_legal_moves_with_preprocessing_and_sliders::<Bmi2, L>(position, list, preprocessed_data)
+}
+
+#[allow(dead_code)]
+pub fn loud_legal_moves_with_preprocessing_and_sliders<S: SliderAttacks, L: MoveAdder>(
+    position: &Position,
+    list: &mut L,
+    preprocessed_data: MoveGenPreprocessing,
 ) -> bool {
     let stm = position.state().stm;
     let kings = position.bb_pc(KING.pc(stm));
@@ -210,7 +298,7 @@ pub fn loud_legal_moves_with_preprocessing<L: MoveAdder>(
             // No need for pawn pin ray moves since cannot move a pinned piece if in check
             king_moves(position, king_capture_mask, king_push_mask, list);
             knight_moves(position, capture_mask, push_mask, !pinned, list);
-            slider_moves(position, capture_mask, push_mask, pinned, king_sq, list);
+            slider_moves::<S, L>(position, capture_mask, push_mask, pinned, king_sq, list);
             pawn_moves(position, capture_mask, push_mask, !pinned, list);
         }
         Ordering::Less => {
@@ -224,7 +312,7 @@ pub fn loud_legal_moves_with_preprocessing<L: MoveAdder>(
             pawn_captures(position, capture_mask, push_mask, !pinned, list);
             pawn_pin_ray_captures(position, capture_mask & pinners, king_sq, pinned, stm, list);
             knight_captures(position, capture_mask, !pinned, list);
-            slider_captures(position, capture_mask, pinned, king_sq, list);
+            slider_captures::<S, L>(position, capture_mask, pinned, king_sq, list);
             king_captures(position, king_capture_mask, list);
         }
     }
@@ -0,0 +1,302 @@
+use crate::bb::BB;
+use crate::square::Square;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+/// SliderAttacks is implemented by each sliding piece attack backend. Move generation is generic
+/// over the backend so each one is monomorphised without per-call dispatch
+pub trait SliderAttacks {
+    #[allow(dead_code)]
+    const NAME: &'static str;
+
+    fn rook_attacks_from_sq(from: Square, occupied: BB) -> BB;
+
+    fn bishop_attacks_from_sq(from: Square, occupied: BB) -> BB;
+
+    /// Attacks of rooks on every square of from
+    #[allow(dead_code)]
+    fn rook_attacks(from: BB, occupied: BB) -> BB {
+        let mut attacks = BB(0);
+        for (sq, _) in from.iter() {
+            attacks |= Self::rook_attacks_from_sq(sq, occupied);
+        }
+        attacks
+    }
+
+    /// Attacks of bishops on every square of from
+    #[allow(dead_code)]
+    fn bishop_attacks(from: BB, occupied: BB) -> BB {
+        let mut attacks = BB(0);
+        for (sq, _) in from.iter() {
+            attacks |= Self::bishop_attacks_from_sq(sq, occupied);
+        }
+        attacks
+    }
+}
+
+macro_rules! slider_backend {
+    ($backend:ident, $module:ident, $name:expr) => {
+        #[allow(dead_code)]
+        pub struct $backend;
+
+        impl SliderAttacks for $backend {
+            const NAME: &'static str = $name;
+
+            #[inline(always)]
+            fn rook_attacks_from_sq(from: Square, occupied: BB) -> BB {
+                super::$module::rook_attacks_from_sq(from, occupied)
+            }
+
+            #[inline(always)]
+            fn bishop_attacks_from_sq(from: Square, occupied: BB) -> BB {
+                super::$module::bishop_attacks_from_sq(from, occupied)
+            }
+
+            #[inline(always)]
+            fn rook_attacks(from: BB, occupied: BB) -> BB {
+                super::$module::rook_attacks(from, occupied)
+            }
+
+            #[inline(always)]
+            fn bishop_attacks(from: BB, occupied: BB) -> BB {
+                super::$module::bishop_attacks(from, occupied)
+            }
+        }
+    };
+}
+
+slider_backend!(Naive, ray_naive, "naive");
+slider_backend!(Hyperbola, ray_hyperbola, "hyperbola");
+slider_backend!(KoggeStone, ray_kogge_stone, "kogge-stone");
+slider_backend!(Subtract, ray_subtract, "subtract");
+slider_backend!(Magic, ray_magic, "magic");
+slider_backend!(BlackMagic, ray_black_magic, "black-magic");
+
+/// PEXT/PDEP backend. Only use it directly on CPUs supporting BMI2
+#[cfg(target_arch = "x86_64")]
+#[allow(dead_code)]
+pub struct Bmi2;
+
+#[cfg(target_arch = "x86_64")]
+impl SliderAttacks for Bmi2 {
+    const NAME: &'static str = "bmi2";
+
+    #[inline(always)]
+    #[allow(unused_unsafe)]
+    fn rook_attacks_from_sq(from: Square, occupied: BB) -> BB {
+        unsafe { super::ray_bmi2::rook_attacks_from_sq(from, occupied) }
+    }
+
+    #[inline(always)]
+    #[allow(unused_unsafe)]
+    fn bishop_attacks_from_sq(from: Square, occupied: BB) -> BB {
+        unsafe { super::ray_bmi2::bishop_attacks_from_sq(from, occupied) }
+    }
+
+    #[inline(always)]
+    #[allow(unused_unsafe)]
+    fn rook_attacks(from: BB, occupied: BB) -> BB {
+        unsafe { super::ray_bmi2::rook_attacks(from, occupied) }
+    }
+
+    #[inline(always)]
+    #[allow(unused_unsafe)]
+    fn bishop_attacks(from: BB, occupied: BB) -> BB {
+        unsafe { super::ray_bmi2::bishop_attacks(from, occupied) }
+    }
+}
+
+/// Backend selected at compile time, used where runtime dispatch isn't possible. A slider-*
+/// cargo feature overrides it and runtime detection. If several are enabled the first of bmi2,
+/// magic, black-magic, hyperbola, kogge-stone and subtract is used
+pub use self::selected::DefaultSliders;
+
+#[cfg(feature = "slider-bmi2")]
+mod selected {
+    use super::SliderBackend;
+
+    #[cfg(not(target_arch = "x86_64"))]
+    compile_error!("the slider-bmi2 feature requires x86_64");
+
+    pub type DefaultSliders = super::Bmi2;
+    pub const FEATURE_BACKEND: Option<SliderBackend> = Some(SliderBackend::Bmi2);
+}
+
+#[cfg(all(feature = "slider-magic", not(feature = "slider-bmi2")))]
+mod selected {
+    use super::SliderBackend;
+
+    pub type DefaultSliders = super::Magic;
+    pub const FEATURE_BACKEND: Option<SliderBackend> = Some(SliderBackend::Magic);
+}
+
+#[cfg(all(
+    feature = "slider-black-magic",
+    not(any(feature = "slider-bmi2", feature = "slider-magic"))
+))]
+mod selected {
+    use super::SliderBackend;
+
+    pub type DefaultSliders = super::BlackMagic;
+    pub const FEATURE_BACKEND: Option<SliderBackend> = Some(SliderBackend::BlackMagic);
+}
+
+#[cfg(all(
+    feature = "slider-hyperbola",
+    not(any(
+        feature = "slider-bmi2",
+        feature = "slider-magic",
+        feature = "slider-black-magic"
+    ))
+))]
+mod selected {
+    use super::SliderBackend;
+
+    pub type DefaultSliders = super::Hyperbola;
+    pub const FEATURE_BACKEND: Option<SliderBackend> = Some(SliderBackend::Hyperbola);
+}
+
+#[cfg(all(
+    feature = "slider-kogge-stone",
+    not(any(
+        feature = "slider-bmi2",
+        feature = "slider-magic",
+        feature = "slider-black-magic",
+        feature = "slider-hyperbola"
+    ))
+))]
+mod selected {
+    use super::SliderBackend;
+
+    pub type DefaultSliders = super::KoggeStone;
+    pub const FEATURE_BACKEND: Option<SliderBackend> = Some(SliderBackend::KoggeStone);
+}
+
+#[cfg(all(
+    feature = "slider-subtract",
+    not(any(
+        feature = "slider-bmi2",
+        feature = "slider-magic",
+        feature = "slider-black-magic",
+        feature = "slider-hyperbola",
+        feature = "slider-kogge-stone"
+    ))
+))]
+mod selected {
+    use super::SliderBackend;
+
+    pub type DefaultSliders = super::Subtract;
+    pub const FEATURE_BACKEND: Option<SliderBackend> = Some(SliderBackend::Subtract);
+}
+
+#[cfg(not(any(
+    feature = "slider-bmi2",
+    feature = "slider-magic",
+    feature = "slider-black-magic",
+    feature = "slider-hyperbola",
+    feature = "slider-kogge-stone",
+    feature = "slider-subtract"
+)))]
+mod selected {
+    use super::SliderBackend;
+
+    #[cfg(target_feature = "bmi2")]
+    pub type DefaultSliders = super::Bmi2;
+
+    #[cfg(not(target_feature = "bmi2"))]
+    pub type DefaultSliders = super::Magic;
+
+    pub const FEATURE_BACKEND: Option<SliderBackend> = None;
+}
+
+/// Backends legal_moves() and the other non-generic generation functions can dispatch to.
+/// Everything except Bmi2 runs DefaultSliders
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SliderBackend {
+    Magic,
+    Bmi2,
+    BlackMagic,
+    Hyperbola,
+    KoggeStone,
+    Subtract,
+}
+
+#[cfg(feature = "std")]
+static SLIDER_BACKEND: OnceLock<SliderBackend> = OnceLock::new();
+
+/// Returns the backend selected by a slider-* cargo feature, otherwise the fastest backend
+/// supported by the CPU, detected on first use
+#[cfg(feature = "std")]
+pub fn slider_backend() -> SliderBackend {
+    *SLIDER_BACKEND.get_or_init(|| selected::FEATURE_BACKEND.unwrap_or_else(detect_slider_backend))
+}
+
+/// Returns the backend selected by a slider-* cargo feature, otherwise the fastest backend
+/// enabled by the target features. CPU features can't be detected at runtime without std
+#[cfg(not(feature = "std"))]
+pub fn slider_backend() -> SliderBackend {
+    selected::FEATURE_BACKEND.unwrap_or_else(detect_slider_backend)
+}
+
+fn detect_slider_backend() -> SliderBackend {
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    if is_x86_feature_detected!("bmi2") {
+        return SliderBackend::Bmi2;
+    }
+
+    #[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+    if cfg!(target_feature = "bmi2") {
+        return SliderBackend::Bmi2;
+    }
+
+    SliderBackend::Magic
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::testing::*;
+    use super::*;
+
+    #[test]
+    fn test_slider_backend() {
+        if let Some(backend) = selected::FEATURE_BACKEND {
+            assert_eq!(slider_backend(), backend);
+            return;
+        }
+
+        #[cfg(all(target_arch = "x86_64", feature = "std"))]
+        assert_eq!(
+            slider_backend() == SliderBackend::Bmi2,
+            is_x86_feature_detected!("bmi2")
+        );
+
+        #[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+        assert_eq!(
+            slider_backend() == SliderBackend::Bmi2,
+            cfg!(target_feature = "bmi2")
+        );
+
+        #[cfg(not(target_arch = "x86_64"))]
+        assert_eq!(slider_backend(), SliderBackend::Magic);
+    }
+
+    fn assert_attacks_from_bb<S: SliderAttacks>() {
+        test_rook_attacks_from_bb(S::rook_attacks);
+        test_bishop_attacks_from_bb(S::bishop_attacks);
+    }
+
+    #[test]
+    fn test_attacks_from_bb() {
+        assert_attacks_from_bb::<Naive>();
+        assert_attacks_from_bb::<Hyperbola>();
+        assert_attacks_from_bb::<KoggeStone>();
+        assert_attacks_from_bb::<Subtract>();
+        assert_attacks_from_bb::<Magic>();
+        assert_attacks_from_bb::<BlackMagic>();
+        #[cfg(all(target_arch = "x86_64", feature = "std"))]
+        if is_x86_feature_detected!("bmi2") {
+            assert_attacks_from_bb::<Bmi2>();
+        }
+    }
+}
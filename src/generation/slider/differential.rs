@@ -0,0 +1,215 @@
+use super::backend::*;
+use crate::bb::BB;
+use crate::generation::legal_moves_with_sliders;
+use crate::mv_list::MoveVec;
+use crate::perft::perft_with_sliders;
+use crate::piece::*;
+use crate::position::Position;
+use crate::square::*;
+use rand::{Rng, RngCore, SeedableRng, rngs::StdRng};
+
+type AttacksFromSq = fn(Square, BB) -> BB;
+
+/// Compares rook and bishop attacks of a backend against the naive backend on random
+/// occupancies. Returns the number of cases checked or a description of the first mismatch
+pub fn slider_attacks_differential<S: SliderAttacks>(
+    cases: usize,
+    seed: u64,
+) -> Result<usize, String> {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    for i in 0..cases {
+        let from = Square((i % 64) as SquareInternal);
+
+        // vary density so both open and blocked rays are covered
+        let occupied = match rng.gen_range(0..3) {
+            0 => rng.next_u64(),
+            1 => rng.next_u64() & rng.next_u64(),
+            _ => rng.next_u64() & rng.next_u64() & rng.next_u64(),
+        };
+
+        compare_attacks::<S>(from, BB(occupied) | BB::new(from))?;
+    }
+
+    Ok(cases)
+}
+
+/// Compares perft node counts of a backend against the naive backend. Slider attacks of every
+/// slider in every position of the tree are also compared so the first mismatching square and
+/// occupancy is reported. Returns the node count
+pub fn slider_perft_differential<S: SliderAttacks>(fen: &str, depth: usize) -> Result<u64, String> {
+    let mut position = Position::from_fen(fen)?;
+
+    compare_tree::<S>(&mut position, depth)?;
+
+    let expected = perft_with_sliders::<Naive>(&mut position, depth);
+    let actual = perft_with_sliders::<S>(&mut position, depth);
+    if actual != expected {
+        return Err(format!(
+            "{} perft({}) of {}: expected {} nodes, found {}",
+            S::NAME,
+            depth,
+            fen,
+            expected,
+            actual
+        ));
+    }
+
+    Ok(actual)
+}
+
+/// Runs the random occupancy and perft comparisons for every backend available on this target
+pub fn slider_differential_all_backends(
+    cases: usize,
+    seed: u64,
+    positions: &[(&str, usize)],
+) -> Result<(), String> {
+    macro_rules! check {
+        ($backend:ty) => {
+            slider_attacks_differential::<$backend>(cases, seed)?;
+            for &(fen, depth) in positions.iter() {
+                slider_perft_differential::<$backend>(fen, depth)?;
+            }
+        };
+    }
+
+    check!(Hyperbola);
+    check!(KoggeStone);
+    check!(Subtract);
+    check!(Magic);
+    check!(BlackMagic);
+
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("bmi2") {
+        check!(Bmi2);
+    }
+
+    Ok(())
+}
+
+fn compare_attacks<S: SliderAttacks>(from: Square, occupied: BB) -> Result<(), String> {
+    let checks: [(&str, AttacksFromSq, AttacksFromSq); 2] = [
+        ("rook", Naive::rook_attacks_from_sq, S::rook_attacks_from_sq),
+        (
+            "bishop",
+            Naive::bishop_attacks_from_sq,
+            S::bishop_attacks_from_sq,
+        ),
+    ];
+
+    for &(piece, naive, backend) in checks.iter() {
+        let expected = naive(from, occupied);
+        let actual = backend(from, occupied);
+        if actual != expected {
+            return Err(format!(
+                "{} {} attacks from {} with occupancy {:#018x}: expected {:#018x}, found {:#018x}",
+                S::NAME,
+                piece,
+                from,
+                occupied.0,
+                expected.0,
+                actual.0
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn compare_tree<S: SliderAttacks>(position: &mut Position, depth: usize) -> Result<(), String> {
+    let occupied = position.bb_occupied();
+    let sliders = position.bb_pc(WHITE_QUEEN)
+        | position.bb_pc(BLACK_QUEEN)
+        | position.bb_pc(WHITE_ROOK)
+        | position.bb_pc(BLACK_ROOK)
+        | position.bb_pc(WHITE_BISHOP)
+        | position.bb_pc(BLACK_BISHOP);
+
+    for (from, _) in sliders.iter() {
+        compare_attacks::<S>(from, occupied)
+            .map_err(|err| format!("{} in {}", err, position.to_fen()))?;
+    }
+
+    if depth == 0 {
+        return Ok(());
+    }
+
+    let mut moves = MoveVec::new();
+    legal_moves_with_sliders::<Naive, MoveVec>(position, &mut moves);
+
+    let state = *position.state();
+    let key = position.hash_key();
+    for &mv in moves.iter() {
+        let capture = position.make(mv);
+        let result = compare_tree::<S>(position, depth - 1);
+        position.unmake(mv, capture, &state, key);
+        result?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::position::STARTING_POSITION_FEN;
+
+    const POSITIONS: [(&str, usize); 3] = [
+        (STARTING_POSITION_FEN, 3),
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            2,
+        ),
+        ("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", 4),
+    ];
+
+    #[test]
+    fn test_all_backends() {
+        slider_differential_all_backends(1_000_000, 1, &POSITIONS).unwrap();
+    }
+
+    #[test]
+    fn test_perft_node_counts() {
+        assert_eq!(
+            slider_perft_differential::<Magic>(STARTING_POSITION_FEN, 3),
+            Ok(8902)
+        );
+        assert_eq!(
+            slider_perft_differential::<Naive>(POSITIONS[2].0, 4),
+            Ok(43238)
+        );
+    }
+
+    /// Backend with a rook missing attacks along the first rank
+    struct Broken;
+
+    impl SliderAttacks for Broken {
+        const NAME: &'static str = "broken";
+
+        fn rook_attacks_from_sq(from: Square, occupied: BB) -> BB {
+            let attacks = Naive::rook_attacks_from_sq(from, occupied);
+            if from == A1 {
+                attacks & !BB::new(B1)
+            } else {
+                attacks
+            }
+        }
+
+        fn bishop_attacks_from_sq(from: Square, occupied: BB) -> BB {
+            Naive::bishop_attacks_from_sq(from, occupied)
+        }
+    }
+
+    #[test]
+    fn test_reports_mismatch() {
+        let err = slider_attacks_differential::<Broken>(1000, 1).unwrap_err();
+        assert!(err.starts_with("broken rook attacks from a1"), "{}", err);
+
+        let err = slider_perft_differential::<Broken>(STARTING_POSITION_FEN, 1).unwrap_err();
+        assert!(
+            err.contains("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR"),
+            "{}",
+            err
+        );
+    }
+}
@@ -0,0 +1,110 @@
+// Sliding piece geometry shared with build.rs, which includes this file to generate the large
+// attack tables so it must not depend on the rest of the crate
+
+/// Number of attack sets for bishops on all squares when indexing by every relevant occupancy
+pub const BISHOP_TABLE_SIZE: usize = 5248;
+/// Number of attack sets for rooks on all squares when indexing by every relevant occupancy
+pub const ROOK_TABLE_SIZE: usize = 102400;
+/// Bishop attack sets followed by rook attack sets
+pub const SHARED_TABLE_SIZE: usize = BISHOP_TABLE_SIZE + ROOK_TABLE_SIZE;
+
+/// Index bits of every bishop magic
+pub const MAGIC_BISHOP_BITS: u32 = 9;
+/// Index bits of every rook magic
+pub const MAGIC_ROOK_BITS: u32 = 12;
+
+/// Entries in the overlapping magic attack table
+pub const MAGIC_TABLE_SIZE: usize = 89524;
+
+/// Index bits of every bishop black magic
+pub const BLACK_MAGIC_BISHOP_BITS: u32 = 9;
+/// Index bits of every rook black magic
+pub const BLACK_MAGIC_ROOK_BITS: u32 = 12;
+
+/// Entries in the overlapping black magic attack table
+pub const BLACK_MAGIC_TABLE_SIZE: usize = 88507;
+
+const BISHOP_DIRECTIONS: [(i32, i32); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+const ROOK_DIRECTIONS: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+
+const fn directions(diagonal: bool) -> [(i32, i32); 4] {
+    if diagonal {
+        BISHOP_DIRECTIONS
+    } else {
+        ROOK_DIRECTIONS
+    }
+}
+
+const fn on_board(row: i32, col: i32) -> bool {
+    row >= 0 && row < 8 && col >= 0 && col < 8
+}
+
+/// Attacks from a square stopping at the first occupied square in each direction
+pub const fn slider_attacks(sq: usize, occupied: u64, diagonal: bool) -> u64 {
+    let dirs = directions(diagonal);
+    let mut attacks = 0;
+    let mut d = 0;
+    while d < 4 {
+        let (row_step, col_step) = dirs[d];
+        let mut row = (sq / 8) as i32 + row_step;
+        let mut col = (sq % 8) as i32 + col_step;
+        while on_board(row, col) {
+            let bit = 1u64 << (row * 8 + col);
+            attacks |= bit;
+            if occupied & bit != 0 {
+                break;
+            }
+            row += row_step;
+            col += col_step;
+        }
+        d += 1;
+    }
+    attacks
+}
+
+/// Squares whose occupancy changes the attacks from a square, ie rays without the edge squares
+pub const fn relevant_mask(sq: usize, diagonal: bool) -> u64 {
+    let dirs = directions(diagonal);
+    let mut mask = 0;
+    let mut d = 0;
+    while d < 4 {
+        let (row_step, col_step) = dirs[d];
+        let mut row = (sq / 8) as i32 + row_step;
+        let mut col = (sq % 8) as i32 + col_step;
+        while on_board(row + row_step, col + col_step) {
+            mask |= 1u64 << (row * 8 + col);
+            row += row_step;
+            col += col_step;
+        }
+        d += 1;
+    }
+    mask
+}
+
+/// Software PEXT: gathers the bits of value selected by mask into the low bits
+pub const fn pext(value: u64, mut mask: u64) -> u64 {
+    let mut result = 0;
+    let mut bit = 1;
+    while mask != 0 {
+        if value & mask & mask.wrapping_neg() != 0 {
+            result |= bit;
+        }
+        mask &= mask - 1;
+        bit <<= 1;
+    }
+    result
+}
+
+/// Software PDEP: scatters the low bits of value to the bits set in mask
+pub const fn pdep(value: u64, mut mask: u64) -> u64 {
+    let mut result = 0;
+    let mut bit = 1;
+    while mask != 0 {
+        if value & bit != 0 {
+            result |= mask & mask.wrapping_neg();
+        }
+        mask &= mask - 1;
+        bit <<= 1;
+    }
+    result
+}
@@ -1,29 +1,42 @@
+mod backend;
+#[allow(dead_code)]
+#[cfg(feature = "std")]
+mod differential;
+#[allow(dead_code)]
+mod geometry;
+mod ray_black_magic;
 mod ray_hyperbola;
 mod ray_kogge_stone;
+mod ray_magic;
 mod ray_naive;
 mod ray_subtract;
+mod tables;
 
-#[cfg(target_feature = "bmi2")]
+#[cfg(target_arch = "x86_64")]
 mod ray_bmi2;
 
-#[cfg(not(target_feature = "bmi2"))]
-mod ray_magic;
-
 #[cfg(test)]
 mod testing;
 
 pub mod consts;
 
+pub use self::backend::*;
+#[cfg(feature = "std")]
+#[allow(unused_imports)]
+pub use self::differential::{
+    slider_attacks_differential, slider_differential_all_backends, slider_perft_differential,
+};
+
+#[cfg(feature = "std")]
+#[allow(unused_imports)]
+pub use self::tables::find_magic;
+
 pub use self::ray_hyperbola::rank_attacks_from_sq;
+#[allow(unused_imports)]
 pub use self::ray_kogge_stone::{bishop_attacks, rook_attacks};
+pub use self::ray_kogge_stone::slider_attacks;
 pub use self::ray_kogge_stone::{pin_ray_diag, pin_ray_non_diag};
 
-#[cfg(target_feature = "bmi2")]
-pub use self::ray_bmi2::{bishop_attacks_from_sq, rook_attacks_from_sq};
-
-#[cfg(not(target_feature = "bmi2"))]
-pub use self::ray_magic::{bishop_attacks_from_sq, rook_attacks_from_sq};
-
 use super::consts::lines_along;
 use crate::bb::BB;
 use crate::mv_list::MoveAdder;
@@ -31,7 +44,19 @@ use crate::piece::{BISHOP, QUEEN, ROOK};
 use crate::position::Position;
 use crate::square::Square;
 
-pub fn slider_moves<L: MoveAdder>(
+/// Rook attacks using the backend selected at compile time
+#[inline(always)]
+pub fn rook_attacks_from_sq(from: Square, occupied: BB) -> BB {
+    DefaultSliders::rook_attacks_from_sq(from, occupied)
+}
+
+/// Bishop attacks using the backend selected at compile time
+#[inline(always)]
+pub fn bishop_attacks_from_sq(from: Square, occupied: BB) -> BB {
+    DefaultSliders::bishop_attacks_from_sq(from, occupied)
+}
+
+pub fn slider_moves<S: SliderAttacks, L: MoveAdder>(
     position: &Position,
     capture_mask: BB,
     push_mask: BB,
@@ -49,7 +74,7 @@ pub fn slider_moves<L: MoveAdder>(
 
     for (from, _) in (non_diag_attackers & !pinned_mask).iter() {
         debug_assert!(position.at(from) == ROOK.pc(stm) || position.at(from) == QUEEN.pc(stm));
-        let targets = rook_attacks_from_sq(from, occupied);
+        let targets = S::rook_attacks_from_sq(from, occupied);
         list.add_captures(from, targets & capture_mask);
         list.add_non_captures(from, targets & push_mask);
     }
@@ -57,14 +82,14 @@ pub fn slider_moves<L: MoveAdder>(
     for (from, _) in (non_diag_attackers & pinned_mask).iter() {
         debug_assert!(position.at(from) == ROOK.pc(stm) || position.at(from) == QUEEN.pc(stm));
         let ray_mask = lines_along(from, king_sq);
-        let targets = rook_attacks_from_sq(from, occupied) & ray_mask;
+        let targets = S::rook_attacks_from_sq(from, occupied) & ray_mask;
         list.add_captures(from, targets & capture_mask);
         list.add_non_captures(from, targets & push_mask);
     }
 
     for (from, _) in (diag_attackers & !pinned_mask).iter() {
         debug_assert!(position.at(from) == BISHOP.pc(stm) || position.at(from) == QUEEN.pc(stm));
-        let targets = bishop_attacks_from_sq(from, occupied);
+        let targets = S::bishop_attacks_from_sq(from, occupied);
         list.add_captures(from, targets & capture_mask);
         list.add_non_captures(from, targets & push_mask);
     }
@@ -72,14 +97,14 @@ pub fn slider_moves<L: MoveAdder>(
     for (from, _) in (diag_attackers & pinned_mask).iter() {
         debug_assert!(position.at(from) == BISHOP.pc(stm) || position.at(from) == QUEEN.pc(stm));
         let ray_mask = lines_along(from, king_sq);
-        let targets = bishop_attacks_from_sq(from, occupied) & ray_mask;
+        let targets = S::bishop_attacks_from_sq(from, occupied) & ray_mask;
         list.add_captures(from, targets & capture_mask);
         list.add_non_captures(from, targets & push_mask);
     }
 }
 
 #[allow(dead_code)]
-pub fn slider_captures<L: MoveAdder>(
+pub fn slider_captures<S: SliderAttacks, L: MoveAdder>(
     position: &Position,
     capture_mask: BB,
     pinned_mask: BB,
@@ -96,33 +121,33 @@ pub fn slider_captures<L: MoveAdder>(
 
     for (from, _) in (non_diag_attackers & !pinned_mask).iter() {
         debug_assert!(position.at(from) == ROOK.pc(stm) || position.at(from) == QUEEN.pc(stm));
-        let targets = rook_attacks_from_sq(from, occupied);
+        let targets = S::rook_attacks_from_sq(from, occupied);
         list.add_captures(from, targets & capture_mask);
     }
 
     for (from, _) in (non_diag_attackers & pinned_mask).iter() {
         debug_assert!(position.at(from) == ROOK.pc(stm) || position.at(from) == QUEEN.pc(stm));
         let ray_mask = lines_along(from, king_sq);
-        let targets = rook_attacks_from_sq(from, occupied) & ray_mask;
+        let targets = S::rook_attacks_from_sq(from, occupied) & ray_mask;
         list.add_captures(from, targets & capture_mask);
     }
 
     for (from, _) in (diag_attackers & !pinned_mask).iter() {
         debug_assert!(position.at(from) == BISHOP.pc(stm) || position.at(from) == QUEEN.pc(stm));
-        let targets = bishop_attacks_from_sq(from, occupied);
+        let targets = S::bishop_attacks_from_sq(from, occupied);
         list.add_captures(from, targets & capture_mask);
     }
 
     for (from, _) in (diag_attackers & pinned_mask).iter() {
         debug_assert!(position.at(from) == BISHOP.pc(stm) || position.at(from) == QUEEN.pc(stm));
         let ray_mask = lines_along(from, king_sq);
-        let targets = bishop_attacks_from_sq(from, occupied) & ray_mask;
+        let targets = S::bishop_attacks_from_sq(from, occupied) & ray_mask;
         list.add_captures(from, targets & capture_mask);
     }
 }
 
 #[allow(dead_code)]
-pub fn non_diag_slider_moves<L: MoveAdder>(
+pub fn non_diag_slider_moves<S: SliderAttacks, L: MoveAdder>(
     position: &Position,
     capture_mask: BB,
     push_mask: BB,
@@ -136,14 +161,14 @@ pub fn non_diag_slider_moves<L: MoveAdder>(
     let non_diag_attackers = (queens | rooks) & from_mask;
 
     for (from, _) in non_diag_attackers.iter() {
-        let targets = rook_attacks_from_sq(from, occupied);
+        let targets = S::rook_attacks_from_sq(from, occupied);
         list.add_captures(from, targets & capture_mask);
         list.add_non_captures(from, targets & push_mask);
     }
 }
 
 #[allow(dead_code)]
-pub fn diag_slider_moves<L: MoveAdder>(
+pub fn diag_slider_moves<S: SliderAttacks, L: MoveAdder>(
     position: &Position,
     capture_mask: BB,
     push_mask: BB,
@@ -157,7 +182,7 @@ pub fn diag_slider_moves<L: MoveAdder>(
     let diag_attackers = (queens | bishops) & from_mask;
 
     for (from, _) in diag_attackers.iter() {
-        let targets = bishop_attacks_from_sq(from, occupied);
+        let targets = S::bishop_attacks_from_sq(from, occupied);
         list.add_captures(from, targets & capture_mask);
         list.add_non_captures(from, targets & push_mask);
     }
@@ -179,7 +204,7 @@ mod test {
         let capture_mask = position.bb_side(position.state().stm.flip());
         let push_mask = position.bb_empty();
 
-        slider_moves::<MoveVec>(
+        slider_moves::<DefaultSliders, MoveVec>(
             position,
             capture_mask,
             push_mask,
@@ -198,7 +223,7 @@ mod test {
         let capture_mask = position.bb_side(position.state().stm.flip());
         let push_mask = position.bb_empty();
 
-        slider_moves::<MoveVec>(
+        slider_moves::<DefaultSliders, MoveVec>(
             position,
             capture_mask,
             push_mask,
@@ -218,7 +243,7 @@ mod test {
         let capture_mask = position.bb_side(position.state().stm.flip());
         let push_mask = position.bb_empty();
 
-        slider_moves::<MoveVec>(
+        slider_moves::<DefaultSliders, MoveVec>(
             position,
             capture_mask,
             push_mask,
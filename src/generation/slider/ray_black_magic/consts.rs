@@ -0,0 +1,10 @@
+use super::super::tables::*;
+use super::magic_numbers::*;
+
+pub static BISHOP_MAGICS: [BlackMagic; 64] = black_magics(&BISHOP_MAGIC_NUMBERS, true);
+
+pub static ROOK_MAGICS: [BlackMagic; 64] = black_magics(&ROOK_MAGIC_NUMBERS, false);
+
+// Generated by build.rs from the magic numbers and offsets
+pub static SHARED_ATTACKS: [u64; BLACK_MAGIC_TABLE_SIZE] =
+    include!(concat!(env!("OUT_DIR"), "/black_magic_attacks.rs"));
@@ -0,0 +1,70 @@
+// black magic sliding piece attacks: fixed shift magics with overlapping tables
+// https://www.chessprogramming.org/Magic_Bitboards#Black_Magic_Bitboards
+
+use super::tables::{BLACK_MAGIC_BISHOP_BITS, BLACK_MAGIC_ROOK_BITS};
+use crate::bb::*;
+use crate::square::Square;
+
+mod consts;
+mod magic_numbers;
+
+pub fn bishop_attacks_from_sq(from: Square, occupied: BB) -> BB {
+    let magic = unsafe { *consts::BISHOP_MAGICS.get_unchecked(from.to_usize()) };
+    let mult = (occupied | magic.not_mask)
+        .to_u64()
+        .wrapping_mul(magic.magic_number);
+    let index = (mult >> (64 - BLACK_MAGIC_BISHOP_BITS)) as usize;
+    let offset = index + (magic.offset as usize);
+
+    BB(unsafe { *consts::SHARED_ATTACKS.get_unchecked(offset) })
+}
+
+pub fn rook_attacks_from_sq(from: Square, occupied: BB) -> BB {
+    let magic = unsafe { *consts::ROOK_MAGICS.get_unchecked(from.to_usize()) };
+    let mult = (occupied | magic.not_mask)
+        .to_u64()
+        .wrapping_mul(magic.magic_number);
+    let index = (mult >> (64 - BLACK_MAGIC_ROOK_BITS)) as usize;
+    let offset = index + (magic.offset as usize);
+
+    BB(unsafe { *consts::SHARED_ATTACKS.get_unchecked(offset) })
+}
+
+#[allow(dead_code)]
+pub fn rook_attacks(from: BB, occupied: BB) -> BB {
+    let mut attacks = EMPTY;
+    for (sq, _) in from.iter() {
+        attacks |= rook_attacks_from_sq(sq, occupied);
+    }
+    attacks
+}
+
+#[allow(dead_code)]
+pub fn bishop_attacks(from: BB, occupied: BB) -> BB {
+    let mut attacks = EMPTY;
+    for (sq, _) in from.iter() {
+        attacks |= bishop_attacks_from_sq(sq, occupied);
+    }
+    attacks
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::testing::*;
+    use super::*;
+
+    #[test]
+    fn t_rook_attacks() {
+        test_rook_attacks_from_sq(rook_attacks_from_sq);
+    }
+
+    #[test]
+    fn t_bishop_attacks() {
+        test_bishop_attacks_from_sq(bishop_attacks_from_sq);
+    }
+
+    #[test]
+    fn t_table_size() {
+        assert_eq!(consts::SHARED_ATTACKS.len(), 88507);
+    }
+}
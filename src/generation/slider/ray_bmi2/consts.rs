@@ -1,7192 +1,11 @@
-use crate::bb::BB;
+use super::super::tables::*;
 
-pub struct Offset {
-    pub inner_mask: BB,
-    pub base_offset: u32,
-}
+pub use super::super::tables::Offset;
 
-pub const BISHOP_OFFSETS: [Offset; 64] = [
-    Offset {
-        inner_mask: BB(18049651735527936),
-        base_offset: 0,
-    },
-    Offset {
-        inner_mask: BB(70506452091904),
-        base_offset: 64,
-    },
-    Offset {
-        inner_mask: BB(275415828992),
-        base_offset: 96,
-    },
-    Offset {
-        inner_mask: BB(1075975168),
-        base_offset: 128,
-    },
-    Offset {
-        inner_mask: BB(38021120),
-        base_offset: 160,
-    },
-    Offset {
-        inner_mask: BB(8657588224),
-        base_offset: 192,
-    },
-    Offset {
-        inner_mask: BB(2216338399232),
-        base_offset: 224,
-    },
-    Offset {
-        inner_mask: BB(567382630219776),
-        base_offset: 256,
-    },
-    Offset {
-        inner_mask: BB(9024825867763712),
-        base_offset: 320,
-    },
-    Offset {
-        inner_mask: BB(18049651735527424),
-        base_offset: 352,
-    },
-    Offset {
-        inner_mask: BB(70506452221952),
-        base_offset: 384,
-    },
-    Offset {
-        inner_mask: BB(275449643008),
-        base_offset: 416,
-    },
-    Offset {
-        inner_mask: BB(9733406720),
-        base_offset: 448,
-    },
-    Offset {
-        inner_mask: BB(2216342585344),
-        base_offset: 480,
-    },
-    Offset {
-        inner_mask: BB(567382630203392),
-        base_offset: 512,
-    },
-    Offset {
-        inner_mask: BB(1134765260406784),
-        base_offset: 544,
-    },
-    Offset {
-        inner_mask: BB(4512412933816832),
-        base_offset: 576,
-    },
-    Offset {
-        inner_mask: BB(9024825867633664),
-        base_offset: 608,
-    },
-    Offset {
-        inner_mask: BB(18049651768822272),
-        base_offset: 640,
-    },
-    Offset {
-        inner_mask: BB(70515108615168),
-        base_offset: 768,
-    },
-    Offset {
-        inner_mask: BB(2491752130560),
-        base_offset: 896,
-    },
-    Offset {
-        inner_mask: BB(567383701868544),
-        base_offset: 1024,
-    },
-    Offset {
-        inner_mask: BB(1134765256220672),
-        base_offset: 1152,
-    },
-    Offset {
-        inner_mask: BB(2269530512441344),
-        base_offset: 1184,
-    },
-    Offset {
-        inner_mask: BB(2256206450263040),
-        base_offset: 1216,
-    },
-    Offset {
-        inner_mask: BB(4512412900526080),
-        base_offset: 1248,
-    },
-    Offset {
-        inner_mask: BB(9024834391117824),
-        base_offset: 1280,
-    },
-    Offset {
-        inner_mask: BB(18051867805491712),
-        base_offset: 1408,
-    },
-    Offset {
-        inner_mask: BB(637888545440768),
-        base_offset: 1920,
-    },
-    Offset {
-        inner_mask: BB(1135039602493440),
-        base_offset: 2432,
-    },
-    Offset {
-        inner_mask: BB(2269529440784384),
-        base_offset: 2560,
-    },
-    Offset {
-        inner_mask: BB(4539058881568768),
-        base_offset: 2592,
-    },
-    Offset {
-        inner_mask: BB(1128098963916800),
-        base_offset: 2624,
-    },
-    Offset {
-        inner_mask: BB(2256197927833600),
-        base_offset: 2656,
-    },
-    Offset {
-        inner_mask: BB(4514594912477184),
-        base_offset: 2688,
-    },
-    Offset {
-        inner_mask: BB(9592139778506752),
-        base_offset: 2816,
-    },
-    Offset {
-        inner_mask: BB(19184279556981248),
-        base_offset: 3328,
-    },
-    Offset {
-        inner_mask: BB(2339762086609920),
-        base_offset: 3840,
-    },
-    Offset {
-        inner_mask: BB(4538784537380864),
-        base_offset: 3968,
-    },
-    Offset {
-        inner_mask: BB(9077569074761728),
-        base_offset: 4000,
-    },
-    Offset {
-        inner_mask: BB(562958610993152),
-        base_offset: 4032,
-    },
-    Offset {
-        inner_mask: BB(1125917221986304),
-        base_offset: 4064,
-    },
-    Offset {
-        inner_mask: BB(2814792987328512),
-        base_offset: 4096,
-    },
-    Offset {
-        inner_mask: BB(5629586008178688),
-        base_offset: 4224,
-    },
-    Offset {
-        inner_mask: BB(11259172008099840),
-        base_offset: 4352,
-    },
-    Offset {
-        inner_mask: BB(22518341868716544),
-        base_offset: 4480,
-    },
-    Offset {
-        inner_mask: BB(9007336962655232),
-        base_offset: 4608,
-    },
-    Offset {
-        inner_mask: BB(18014673925310464),
-        base_offset: 4640,
-    },
-    Offset {
-        inner_mask: BB(2216338399232),
-        base_offset: 4672,
-    },
-    Offset {
-        inner_mask: BB(4432676798464),
-        base_offset: 4704,
-    },
-    Offset {
-        inner_mask: BB(11064376819712),
-        base_offset: 4736,
-    },
-    Offset {
-        inner_mask: BB(22137335185408),
-        base_offset: 4768,
-    },
-    Offset {
-        inner_mask: BB(44272556441600),
-        base_offset: 4800,
-    },
-    Offset {
-        inner_mask: BB(87995357200384),
-        base_offset: 4832,
-    },
-    Offset {
-        inner_mask: BB(35253226045952),
-        base_offset: 4864,
-    },
-    Offset {
-        inner_mask: BB(70506452091904),
-        base_offset: 4896,
-    },
-    Offset {
-        inner_mask: BB(567382630219776),
-        base_offset: 4928,
-    },
-    Offset {
-        inner_mask: BB(1134765260406784),
-        base_offset: 4992,
-    },
-    Offset {
-        inner_mask: BB(2832480465846272),
-        base_offset: 5024,
-    },
-    Offset {
-        inner_mask: BB(5667157807464448),
-        base_offset: 5056,
-    },
-    Offset {
-        inner_mask: BB(11333774449049600),
-        base_offset: 5088,
-    },
-    Offset {
-        inner_mask: BB(22526811443298304),
-        base_offset: 5120,
-    },
-    Offset {
-        inner_mask: BB(9024825867763712),
-        base_offset: 5152,
-    },
-    Offset {
-        inner_mask: BB(18049651735527936),
-        base_offset: 5184,
-    },
-];
+pub static BISHOP_OFFSETS: [Offset; 64] = pext_offsets(true, 0);
 
-pub const ROOK_OFFSETS: [Offset; 64] = [
-    Offset {
-        inner_mask: BB(282578800148862),
-        base_offset: 5248,
-    },
-    Offset {
-        inner_mask: BB(565157600297596),
-        base_offset: 9344,
-    },
-    Offset {
-        inner_mask: BB(1130315200595066),
-        base_offset: 11392,
-    },
-    Offset {
-        inner_mask: BB(2260630401190006),
-        base_offset: 13440,
-    },
-    Offset {
-        inner_mask: BB(4521260802379886),
-        base_offset: 15488,
-    },
-    Offset {
-        inner_mask: BB(9042521604759646),
-        base_offset: 17536,
-    },
-    Offset {
-        inner_mask: BB(18085043209519166),
-        base_offset: 19584,
-    },
-    Offset {
-        inner_mask: BB(36170086419038334),
-        base_offset: 21632,
-    },
-    Offset {
-        inner_mask: BB(282578800180736),
-        base_offset: 25728,
-    },
-    Offset {
-        inner_mask: BB(565157600328704),
-        base_offset: 27776,
-    },
-    Offset {
-        inner_mask: BB(1130315200625152),
-        base_offset: 28800,
-    },
-    Offset {
-        inner_mask: BB(2260630401218048),
-        base_offset: 29824,
-    },
-    Offset {
-        inner_mask: BB(4521260802403840),
-        base_offset: 30848,
-    },
-    Offset {
-        inner_mask: BB(9042521604775424),
-        base_offset: 31872,
-    },
-    Offset {
-        inner_mask: BB(18085043209518592),
-        base_offset: 32896,
-    },
-    Offset {
-        inner_mask: BB(36170086419037696),
-        base_offset: 33920,
-    },
-    Offset {
-        inner_mask: BB(282578808340736),
-        base_offset: 35968,
-    },
-    Offset {
-        inner_mask: BB(565157608292864),
-        base_offset: 38016,
-    },
-    Offset {
-        inner_mask: BB(1130315208328192),
-        base_offset: 39040,
-    },
-    Offset {
-        inner_mask: BB(2260630408398848),
-        base_offset: 40064,
-    },
-    Offset {
-        inner_mask: BB(4521260808540160),
-        base_offset: 41088,
-    },
-    Offset {
-        inner_mask: BB(9042521608822784),
-        base_offset: 42112,
-    },
-    Offset {
-        inner_mask: BB(18085043209388032),
-        base_offset: 43136,
-    },
-    Offset {
-        inner_mask: BB(36170086418907136),
-        base_offset: 44160,
-    },
-    Offset {
-        inner_mask: BB(282580897300736),
-        base_offset: 46208,
-    },
-    Offset {
-        inner_mask: BB(565159647117824),
-        base_offset: 48256,
-    },
-    Offset {
-        inner_mask: BB(1130317180306432),
-        base_offset: 49280,
-    },
-    Offset {
-        inner_mask: BB(2260632246683648),
-        base_offset: 50304,
-    },
-    Offset {
-        inner_mask: BB(4521262379438080),
-        base_offset: 51328,
-    },
-    Offset {
-        inner_mask: BB(9042522644946944),
-        base_offset: 52352,
-    },
-    Offset {
-        inner_mask: BB(18085043175964672),
-        base_offset: 53376,
-    },
-    Offset {
-        inner_mask: BB(36170086385483776),
-        base_offset: 54400,
-    },
-    Offset {
-        inner_mask: BB(283115671060736),
-        base_offset: 56448,
-    },
-    Offset {
-        inner_mask: BB(565681586307584),
-        base_offset: 58496,
-    },
-    Offset {
-        inner_mask: BB(1130822006735872),
-        base_offset: 59520,
-    },
-    Offset {
-        inner_mask: BB(2261102847592448),
-        base_offset: 60544,
-    },
-    Offset {
-        inner_mask: BB(4521664529305600),
-        base_offset: 61568,
-    },
-    Offset {
-        inner_mask: BB(9042787892731904),
-        base_offset: 62592,
-    },
-    Offset {
-        inner_mask: BB(18085034619584512),
-        base_offset: 63616,
-    },
-    Offset {
-        inner_mask: BB(36170077829103616),
-        base_offset: 64640,
-    },
-    Offset {
-        inner_mask: BB(420017753620736),
-        base_offset: 66688,
-    },
-    Offset {
-        inner_mask: BB(699298018886144),
-        base_offset: 68736,
-    },
-    Offset {
-        inner_mask: BB(1260057572672512),
-        base_offset: 69760,
-    },
-    Offset {
-        inner_mask: BB(2381576680245248),
-        base_offset: 70784,
-    },
-    Offset {
-        inner_mask: BB(4624614895390720),
-        base_offset: 71808,
-    },
-    Offset {
-        inner_mask: BB(9110691325681664),
-        base_offset: 72832,
-    },
-    Offset {
-        inner_mask: BB(18082844186263552),
-        base_offset: 73856,
-    },
-    Offset {
-        inner_mask: BB(36167887395782656),
-        base_offset: 74880,
-    },
-    Offset {
-        inner_mask: BB(35466950888980736),
-        base_offset: 76928,
-    },
-    Offset {
-        inner_mask: BB(34905104758997504),
-        base_offset: 78976,
-    },
-    Offset {
-        inner_mask: BB(34344362452452352),
-        base_offset: 80000,
-    },
-    Offset {
-        inner_mask: BB(33222877839362048),
-        base_offset: 81024,
-    },
-    Offset {
-        inner_mask: BB(30979908613181440),
-        base_offset: 82048,
-    },
-    Offset {
-        inner_mask: BB(26493970160820224),
-        base_offset: 83072,
-    },
-    Offset {
-        inner_mask: BB(17522093256097792),
-        base_offset: 84096,
-    },
-    Offset {
-        inner_mask: BB(35607136465616896),
-        base_offset: 85120,
-    },
-    Offset {
-        inner_mask: BB(9079539427579068672),
-        base_offset: 87168,
-    },
-    Offset {
-        inner_mask: BB(8935706818303361536),
-        base_offset: 91264,
-    },
-    Offset {
-        inner_mask: BB(8792156787827803136),
-        base_offset: 93312,
-    },
-    Offset {
-        inner_mask: BB(8505056726876686336),
-        base_offset: 95360,
-    },
-    Offset {
-        inner_mask: BB(7930856604974452736),
-        base_offset: 97408,
-    },
-    Offset {
-        inner_mask: BB(6782456361169985536),
-        base_offset: 99456,
-    },
-    Offset {
-        inner_mask: BB(4485655873561051136),
-        base_offset: 101504,
-    },
-    Offset {
-        inner_mask: BB(9115426935197958144),
-        base_offset: 103552,
-    },
-];
+pub static ROOK_OFFSETS: [Offset; 64] = pext_offsets(false, BISHOP_TABLE_SIZE);
 
-pub const SHARED_ATTACK_INDICES: [u16; 107648] = [
-    127, 1, 3, 1, 7, 1, 3, 1, 15, 1, 3, 1, 7, 1, 3, 1, 31, 1, 3, 1, 7, 1, 3, 1, 15, 1, 3, 1, 7, 1,
-    3, 1, 63, 1, 3, 1, 7, 1, 3, 1, 15, 1, 3, 1, 7, 1, 3, 1, 31, 1, 3, 1, 7, 1, 3, 1, 15, 1, 3, 1,
-    7, 1, 3, 1, 127, 3, 7, 3, 15, 3, 7, 3, 31, 3, 7, 3, 15, 3, 7, 3, 63, 3, 7, 3, 15, 3, 7, 3, 31,
-    3, 7, 3, 15, 3, 7, 3, 127, 123, 7, 3, 15, 11, 7, 3, 31, 27, 7, 3, 15, 11, 7, 3, 63, 59, 7, 3,
-    15, 11, 7, 3, 31, 27, 7, 3, 15, 11, 7, 3, 127, 107, 23, 3, 111, 107, 7, 3, 31, 11, 23, 3, 15,
-    11, 7, 3, 63, 43, 23, 3, 47, 43, 7, 3, 31, 11, 23, 3, 15, 11, 7, 3, 127, 43, 87, 3, 47, 43, 7,
-    3, 95, 11, 87, 3, 15, 11, 7, 3, 63, 43, 23, 3, 47, 43, 7, 3, 31, 11, 23, 3, 15, 11, 7, 3, 127,
-    11, 119, 3, 15, 11, 7, 3, 31, 11, 23, 3, 15, 11, 7, 3, 63, 11, 55, 3, 15, 11, 7, 3, 31, 11, 23,
-    3, 15, 11, 7, 3, 127, 3, 7, 3, 15, 3, 7, 3, 31, 3, 7, 3, 15, 3, 7, 3, 63, 3, 7, 3, 15, 3, 7, 3,
-    31, 3, 7, 3, 15, 3, 7, 3, 127, 1, 3, 1, 7, 1, 3, 1, 15, 1, 3, 1, 7, 1, 3, 1, 31, 1, 3, 1, 7, 1,
-    3, 1, 15, 1, 3, 1, 7, 1, 3, 1, 63, 1, 3, 1, 7, 1, 3, 1, 15, 1, 3, 1, 7, 1, 3, 1, 31, 1, 3, 1,
-    7, 1, 3, 1, 15, 1, 3, 1, 7, 1, 3, 1, 127, 3, 7, 3, 15, 3, 7, 3, 31, 3, 7, 3, 15, 3, 7, 3, 63,
-    3, 7, 3, 15, 3, 7, 3, 31, 3, 7, 3, 15, 3, 7, 3, 511, 15, 31, 15, 63, 15, 31, 15, 127, 15, 31,
-    15, 63, 15, 31, 15, 255, 15, 31, 15, 63, 15, 31, 15, 127, 15, 31, 15, 63, 15, 31, 15, 511, 495,
-    31, 15, 63, 47, 31, 15, 127, 111, 31, 15, 63, 47, 31, 15, 255, 239, 31, 15, 63, 47, 31, 15,
-    127, 111, 31, 15, 63, 47, 31, 15, 511, 431, 95, 15, 447, 431, 31, 15, 127, 47, 95, 15, 63, 47,
-    31, 15, 255, 175, 95, 15, 191, 175, 31, 15, 127, 47, 95, 15, 63, 47, 31, 15, 511, 175, 351, 15,
-    191, 175, 31, 15, 383, 47, 351, 15, 63, 47, 31, 15, 255, 175, 95, 15, 191, 175, 31, 15, 127,
-    47, 95, 15, 63, 47, 31, 15, 511, 47, 479, 15, 63, 47, 31, 15, 127, 47, 95, 15, 63, 47, 31, 15,
-    255, 47, 223, 15, 63, 47, 31, 15, 127, 47, 95, 15, 63, 47, 31, 15, 511, 15, 31, 15, 63, 15, 31,
-    15, 127, 15, 31, 15, 63, 15, 31, 15, 255, 15, 31, 15, 63, 15, 31, 15, 127, 15, 31, 15, 63, 15,
-    31, 15, 127, 3, 7, 3, 15, 3, 7, 3, 31, 3, 7, 3, 15, 3, 7, 3, 63, 3, 7, 3, 15, 3, 7, 3, 31, 3,
-    7, 3, 15, 3, 7, 3, 127, 126, 7, 6, 15, 14, 7, 6, 31, 30, 7, 6, 15, 14, 7, 6, 63, 62, 7, 6, 15,
-    14, 7, 6, 31, 30, 7, 6, 15, 14, 7, 6, 511, 510, 31, 30, 63, 62, 31, 30, 127, 126, 31, 30, 63,
-    62, 31, 30, 255, 254, 31, 30, 63, 62, 31, 30, 127, 126, 31, 30, 63, 62, 31, 30, 2047, 2046,
-    2045, 2044, 1983, 1982, 1981, 1980, 127, 126, 125, 124, 63, 62, 61, 60, 255, 254, 253, 252,
-    191, 190, 189, 188, 127, 126, 125, 124, 63, 62, 61, 60, 511, 510, 509, 508, 447, 446, 445, 444,
-    127, 126, 125, 124, 63, 62, 61, 60, 255, 254, 253, 252, 191, 190, 189, 188, 127, 126, 125, 124,
-    63, 62, 61, 60, 1023, 1022, 1021, 1020, 959, 958, 957, 956, 127, 126, 125, 124, 63, 62, 61, 60,
-    255, 254, 253, 252, 191, 190, 189, 188, 127, 126, 125, 124, 63, 62, 61, 60, 511, 510, 509, 508,
-    447, 446, 445, 444, 127, 126, 125, 124, 63, 62, 61, 60, 255, 254, 253, 252, 191, 190, 189, 188,
-    127, 126, 125, 124, 63, 62, 61, 60, 2047, 2046, 2045, 2044, 1727, 1726, 1725, 1724, 383, 382,
-    381, 380, 63, 62, 61, 60, 1791, 1790, 1789, 1788, 1727, 1726, 1725, 1724, 127, 126, 125, 124,
-    63, 62, 61, 60, 511, 510, 509, 508, 191, 190, 189, 188, 383, 382, 381, 380, 63, 62, 61, 60,
-    255, 254, 253, 252, 191, 190, 189, 188, 127, 126, 125, 124, 63, 62, 61, 60, 1023, 1022, 1021,
-    1020, 703, 702, 701, 700, 383, 382, 381, 380, 63, 62, 61, 60, 767, 766, 765, 764, 703, 702,
-    701, 700, 127, 126, 125, 124, 63, 62, 61, 60, 511, 510, 509, 508, 191, 190, 189, 188, 383, 382,
-    381, 380, 63, 62, 61, 60, 255, 254, 253, 252, 191, 190, 189, 188, 127, 126, 125, 124, 63, 62,
-    61, 60, 2047, 2046, 2045, 2044, 703, 702, 701, 700, 1407, 1406, 1405, 1404, 63, 62, 61, 60,
-    767, 766, 765, 764, 703, 702, 701, 700, 127, 126, 125, 124, 63, 62, 61, 60, 1535, 1534, 1533,
-    1532, 191, 190, 189, 188, 1407, 1406, 1405, 1404, 63, 62, 61, 60, 255, 254, 253, 252, 191, 190,
-    189, 188, 127, 126, 125, 124, 63, 62, 61, 60, 1023, 1022, 1021, 1020, 703, 702, 701, 700, 383,
-    382, 381, 380, 63, 62, 61, 60, 767, 766, 765, 764, 703, 702, 701, 700, 127, 126, 125, 124, 63,
-    62, 61, 60, 511, 510, 509, 508, 191, 190, 189, 188, 383, 382, 381, 380, 63, 62, 61, 60, 255,
-    254, 253, 252, 191, 190, 189, 188, 127, 126, 125, 124, 63, 62, 61, 60, 2047, 2046, 2045, 2044,
-    191, 190, 189, 188, 1919, 1918, 1917, 1916, 63, 62, 61, 60, 255, 254, 253, 252, 191, 190, 189,
-    188, 127, 126, 125, 124, 63, 62, 61, 60, 511, 510, 509, 508, 191, 190, 189, 188, 383, 382, 381,
-    380, 63, 62, 61, 60, 255, 254, 253, 252, 191, 190, 189, 188, 127, 126, 125, 124, 63, 62, 61,
-    60, 1023, 1022, 1021, 1020, 191, 190, 189, 188, 895, 894, 893, 892, 63, 62, 61, 60, 255, 254,
-    253, 252, 191, 190, 189, 188, 127, 126, 125, 124, 63, 62, 61, 60, 511, 510, 509, 508, 191, 190,
-    189, 188, 383, 382, 381, 380, 63, 62, 61, 60, 255, 254, 253, 252, 191, 190, 189, 188, 127, 126,
-    125, 124, 63, 62, 61, 60, 511, 510, 31, 30, 63, 62, 31, 30, 127, 126, 31, 30, 63, 62, 31, 30,
-    255, 254, 31, 30, 63, 62, 31, 30, 127, 126, 31, 30, 63, 62, 31, 30, 127, 126, 7, 6, 15, 14, 7,
-    6, 31, 30, 7, 6, 15, 14, 7, 6, 63, 62, 7, 6, 15, 14, 7, 6, 31, 30, 7, 6, 15, 14, 7, 6, 127,
-    126, 124, 124, 15, 14, 12, 12, 31, 30, 28, 28, 15, 14, 12, 12, 63, 62, 60, 60, 15, 14, 12, 12,
-    31, 30, 28, 28, 15, 14, 12, 12, 511, 510, 508, 508, 63, 62, 60, 60, 127, 126, 124, 124, 63, 62,
-    60, 60, 255, 254, 252, 252, 63, 62, 60, 60, 127, 126, 124, 124, 63, 62, 60, 60, 2047, 2046,
-    2045, 2044, 2042, 2042, 2040, 2040, 1919, 1918, 1917, 1916, 1914, 1914, 1912, 1912, 255, 254,
-    253, 252, 250, 250, 248, 248, 127, 126, 125, 124, 122, 122, 120, 120, 511, 510, 509, 508, 506,
-    506, 504, 504, 383, 382, 381, 380, 378, 378, 376, 376, 255, 254, 253, 252, 250, 250, 248, 248,
-    127, 126, 125, 124, 122, 122, 120, 120, 1023, 1022, 1021, 1020, 1018, 1018, 1016, 1016, 895,
-    894, 893, 892, 890, 890, 888, 888, 255, 254, 253, 252, 250, 250, 248, 248, 127, 126, 125, 124,
-    122, 122, 120, 120, 511, 510, 509, 508, 506, 506, 504, 504, 383, 382, 381, 380, 378, 378, 376,
-    376, 255, 254, 253, 252, 250, 250, 248, 248, 127, 126, 125, 124, 122, 122, 120, 120, 8191,
-    8190, 8189, 8188, 8186, 8186, 8184, 8184, 8181, 8180, 8181, 8180, 8176, 8176, 8176, 8176, 6911,
-    6910, 6909, 6908, 6906, 6906, 6904, 6904, 6901, 6900, 6901, 6900, 6896, 6896, 6896, 6896, 1535,
-    1534, 1533, 1532, 1530, 1530, 1528, 1528, 1525, 1524, 1525, 1524, 1520, 1520, 1520, 1520, 255,
-    254, 253, 252, 250, 250, 248, 248, 245, 244, 245, 244, 240, 240, 240, 240, 7167, 7166, 7165,
-    7164, 7162, 7162, 7160, 7160, 7157, 7156, 7157, 7156, 7152, 7152, 7152, 7152, 6911, 6910, 6909,
-    6908, 6906, 6906, 6904, 6904, 6901, 6900, 6901, 6900, 6896, 6896, 6896, 6896, 511, 510, 509,
-    508, 506, 506, 504, 504, 501, 500, 501, 500, 496, 496, 496, 496, 255, 254, 253, 252, 250, 250,
-    248, 248, 245, 244, 245, 244, 240, 240, 240, 240, 2047, 2046, 2045, 2044, 2042, 2042, 2040,
-    2040, 2037, 2036, 2037, 2036, 2032, 2032, 2032, 2032, 767, 766, 765, 764, 762, 762, 760, 760,
-    757, 756, 757, 756, 752, 752, 752, 752, 1535, 1534, 1533, 1532, 1530, 1530, 1528, 1528, 1525,
-    1524, 1525, 1524, 1520, 1520, 1520, 1520, 255, 254, 253, 252, 250, 250, 248, 248, 245, 244,
-    245, 244, 240, 240, 240, 240, 1023, 1022, 1021, 1020, 1018, 1018, 1016, 1016, 1013, 1012, 1013,
-    1012, 1008, 1008, 1008, 1008, 767, 766, 765, 764, 762, 762, 760, 760, 757, 756, 757, 756, 752,
-    752, 752, 752, 511, 510, 509, 508, 506, 506, 504, 504, 501, 500, 501, 500, 496, 496, 496, 496,
-    255, 254, 253, 252, 250, 250, 248, 248, 245, 244, 245, 244, 240, 240, 240, 240, 4095, 4094,
-    4093, 4092, 4090, 4090, 4088, 4088, 4085, 4084, 4085, 4084, 4080, 4080, 4080, 4080, 2815, 2814,
-    2813, 2812, 2810, 2810, 2808, 2808, 2805, 2804, 2805, 2804, 2800, 2800, 2800, 2800, 1535, 1534,
-    1533, 1532, 1530, 1530, 1528, 1528, 1525, 1524, 1525, 1524, 1520, 1520, 1520, 1520, 255, 254,
-    253, 252, 250, 250, 248, 248, 245, 244, 245, 244, 240, 240, 240, 240, 3071, 3070, 3069, 3068,
-    3066, 3066, 3064, 3064, 3061, 3060, 3061, 3060, 3056, 3056, 3056, 3056, 2815, 2814, 2813, 2812,
-    2810, 2810, 2808, 2808, 2805, 2804, 2805, 2804, 2800, 2800, 2800, 2800, 511, 510, 509, 508,
-    506, 506, 504, 504, 501, 500, 501, 500, 496, 496, 496, 496, 255, 254, 253, 252, 250, 250, 248,
-    248, 245, 244, 245, 244, 240, 240, 240, 240, 2047, 2046, 2045, 2044, 2042, 2042, 2040, 2040,
-    2037, 2036, 2037, 2036, 2032, 2032, 2032, 2032, 767, 766, 765, 764, 762, 762, 760, 760, 757,
-    756, 757, 756, 752, 752, 752, 752, 1535, 1534, 1533, 1532, 1530, 1530, 1528, 1528, 1525, 1524,
-    1525, 1524, 1520, 1520, 1520, 1520, 255, 254, 253, 252, 250, 250, 248, 248, 245, 244, 245, 244,
-    240, 240, 240, 240, 1023, 1022, 1021, 1020, 1018, 1018, 1016, 1016, 1013, 1012, 1013, 1012,
-    1008, 1008, 1008, 1008, 767, 766, 765, 764, 762, 762, 760, 760, 757, 756, 757, 756, 752, 752,
-    752, 752, 511, 510, 509, 508, 506, 506, 504, 504, 501, 500, 501, 500, 496, 496, 496, 496, 255,
-    254, 253, 252, 250, 250, 248, 248, 245, 244, 245, 244, 240, 240, 240, 240, 8191, 8190, 8189,
-    8188, 8186, 8186, 8184, 8184, 8181, 8180, 8181, 8180, 8176, 8176, 8176, 8176, 2815, 2814, 2813,
-    2812, 2810, 2810, 2808, 2808, 2805, 2804, 2805, 2804, 2800, 2800, 2800, 2800, 5631, 5630, 5629,
-    5628, 5626, 5626, 5624, 5624, 5621, 5620, 5621, 5620, 5616, 5616, 5616, 5616, 255, 254, 253,
-    252, 250, 250, 248, 248, 245, 244, 245, 244, 240, 240, 240, 240, 3071, 3070, 3069, 3068, 3066,
-    3066, 3064, 3064, 3061, 3060, 3061, 3060, 3056, 3056, 3056, 3056, 2815, 2814, 2813, 2812, 2810,
-    2810, 2808, 2808, 2805, 2804, 2805, 2804, 2800, 2800, 2800, 2800, 511, 510, 509, 508, 506, 506,
-    504, 504, 501, 500, 501, 500, 496, 496, 496, 496, 255, 254, 253, 252, 250, 250, 248, 248, 245,
-    244, 245, 244, 240, 240, 240, 240, 6143, 6142, 6141, 6140, 6138, 6138, 6136, 6136, 6133, 6132,
-    6133, 6132, 6128, 6128, 6128, 6128, 767, 766, 765, 764, 762, 762, 760, 760, 757, 756, 757, 756,
-    752, 752, 752, 752, 5631, 5630, 5629, 5628, 5626, 5626, 5624, 5624, 5621, 5620, 5621, 5620,
-    5616, 5616, 5616, 5616, 255, 254, 253, 252, 250, 250, 248, 248, 245, 244, 245, 244, 240, 240,
-    240, 240, 1023, 1022, 1021, 1020, 1018, 1018, 1016, 1016, 1013, 1012, 1013, 1012, 1008, 1008,
-    1008, 1008, 767, 766, 765, 764, 762, 762, 760, 760, 757, 756, 757, 756, 752, 752, 752, 752,
-    511, 510, 509, 508, 506, 506, 504, 504, 501, 500, 501, 500, 496, 496, 496, 496, 255, 254, 253,
-    252, 250, 250, 248, 248, 245, 244, 245, 244, 240, 240, 240, 240, 4095, 4094, 4093, 4092, 4090,
-    4090, 4088, 4088, 4085, 4084, 4085, 4084, 4080, 4080, 4080, 4080, 2815, 2814, 2813, 2812, 2810,
-    2810, 2808, 2808, 2805, 2804, 2805, 2804, 2800, 2800, 2800, 2800, 1535, 1534, 1533, 1532, 1530,
-    1530, 1528, 1528, 1525, 1524, 1525, 1524, 1520, 1520, 1520, 1520, 255, 254, 253, 252, 250, 250,
-    248, 248, 245, 244, 245, 244, 240, 240, 240, 240, 3071, 3070, 3069, 3068, 3066, 3066, 3064,
-    3064, 3061, 3060, 3061, 3060, 3056, 3056, 3056, 3056, 2815, 2814, 2813, 2812, 2810, 2810, 2808,
-    2808, 2805, 2804, 2805, 2804, 2800, 2800, 2800, 2800, 511, 510, 509, 508, 506, 506, 504, 504,
-    501, 500, 501, 500, 496, 496, 496, 496, 255, 254, 253, 252, 250, 250, 248, 248, 245, 244, 245,
-    244, 240, 240, 240, 240, 2047, 2046, 2045, 2044, 2042, 2042, 2040, 2040, 2037, 2036, 2037,
-    2036, 2032, 2032, 2032, 2032, 767, 766, 765, 764, 762, 762, 760, 760, 757, 756, 757, 756, 752,
-    752, 752, 752, 1535, 1534, 1533, 1532, 1530, 1530, 1528, 1528, 1525, 1524, 1525, 1524, 1520,
-    1520, 1520, 1520, 255, 254, 253, 252, 250, 250, 248, 248, 245, 244, 245, 244, 240, 240, 240,
-    240, 1023, 1022, 1021, 1020, 1018, 1018, 1016, 1016, 1013, 1012, 1013, 1012, 1008, 1008, 1008,
-    1008, 767, 766, 765, 764, 762, 762, 760, 760, 757, 756, 757, 756, 752, 752, 752, 752, 511, 510,
-    509, 508, 506, 506, 504, 504, 501, 500, 501, 500, 496, 496, 496, 496, 255, 254, 253, 252, 250,
-    250, 248, 248, 245, 244, 245, 244, 240, 240, 240, 240, 2047, 2046, 2044, 2044, 2043, 2042,
-    2040, 2040, 383, 382, 380, 380, 379, 378, 376, 376, 1791, 1790, 1788, 1788, 1787, 1786, 1784,
-    1784, 127, 126, 124, 124, 123, 122, 120, 120, 511, 510, 508, 508, 507, 506, 504, 504, 383, 382,
-    380, 380, 379, 378, 376, 376, 255, 254, 252, 252, 251, 250, 248, 248, 127, 126, 124, 124, 123,
-    122, 120, 120, 1023, 1022, 1020, 1020, 1019, 1018, 1016, 1016, 383, 382, 380, 380, 379, 378,
-    376, 376, 767, 766, 764, 764, 763, 762, 760, 760, 127, 126, 124, 124, 123, 122, 120, 120, 511,
-    510, 508, 508, 507, 506, 504, 504, 383, 382, 380, 380, 379, 378, 376, 376, 255, 254, 252, 252,
-    251, 250, 248, 248, 127, 126, 124, 124, 123, 122, 120, 120, 511, 510, 508, 508, 63, 62, 60, 60,
-    127, 126, 124, 124, 63, 62, 60, 60, 255, 254, 252, 252, 63, 62, 60, 60, 127, 126, 124, 124, 63,
-    62, 60, 60, 127, 126, 124, 124, 15, 14, 12, 12, 31, 30, 28, 28, 15, 14, 12, 12, 63, 62, 60, 60,
-    15, 14, 12, 12, 31, 30, 28, 28, 15, 14, 12, 12, 127, 126, 124, 124, 120, 120, 120, 120, 31, 30,
-    28, 28, 24, 24, 24, 24, 63, 62, 60, 60, 56, 56, 56, 56, 31, 30, 28, 28, 24, 24, 24, 24, 511,
-    510, 508, 508, 504, 504, 504, 504, 127, 126, 124, 124, 120, 120, 120, 120, 255, 254, 252, 252,
-    248, 248, 248, 248, 127, 126, 124, 124, 120, 120, 120, 120, 2047, 2046, 2044, 2044, 2043, 2042,
-    2040, 2040, 2036, 2036, 2036, 2036, 2032, 2032, 2032, 2032, 1791, 1790, 1788, 1788, 1787, 1786,
-    1784, 1784, 1780, 1780, 1780, 1780, 1776, 1776, 1776, 1776, 511, 510, 508, 508, 507, 506, 504,
-    504, 500, 500, 500, 500, 496, 496, 496, 496, 255, 254, 252, 252, 251, 250, 248, 248, 244, 244,
-    244, 244, 240, 240, 240, 240, 1023, 1022, 1020, 1020, 1019, 1018, 1016, 1016, 1012, 1012, 1012,
-    1012, 1008, 1008, 1008, 1008, 767, 766, 764, 764, 763, 762, 760, 760, 756, 756, 756, 756, 752,
-    752, 752, 752, 511, 510, 508, 508, 507, 506, 504, 504, 500, 500, 500, 500, 496, 496, 496, 496,
-    255, 254, 252, 252, 251, 250, 248, 248, 244, 244, 244, 244, 240, 240, 240, 240, 8191, 8190,
-    8189, 8188, 8186, 8186, 8184, 8184, 8181, 8180, 8181, 8180, 8176, 8176, 8176, 8176, 8170, 8170,
-    8168, 8168, 8170, 8170, 8168, 8168, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 5631, 5630,
-    5629, 5628, 5626, 5626, 5624, 5624, 5621, 5620, 5621, 5620, 5616, 5616, 5616, 5616, 5610, 5610,
-    5608, 5608, 5610, 5610, 5608, 5608, 5600, 5600, 5600, 5600, 5600, 5600, 5600, 5600, 3071, 3070,
-    3069, 3068, 3066, 3066, 3064, 3064, 3061, 3060, 3061, 3060, 3056, 3056, 3056, 3056, 3050, 3050,
-    3048, 3048, 3050, 3050, 3048, 3048, 3040, 3040, 3040, 3040, 3040, 3040, 3040, 3040, 511, 510,
-    509, 508, 506, 506, 504, 504, 501, 500, 501, 500, 496, 496, 496, 496, 490, 490, 488, 488, 490,
-    490, 488, 488, 480, 480, 480, 480, 480, 480, 480, 480, 6143, 6142, 6141, 6140, 6138, 6138,
-    6136, 6136, 6133, 6132, 6133, 6132, 6128, 6128, 6128, 6128, 6122, 6122, 6120, 6120, 6122, 6122,
-    6120, 6120, 6112, 6112, 6112, 6112, 6112, 6112, 6112, 6112, 5631, 5630, 5629, 5628, 5626, 5626,
-    5624, 5624, 5621, 5620, 5621, 5620, 5616, 5616, 5616, 5616, 5610, 5610, 5608, 5608, 5610, 5610,
-    5608, 5608, 5600, 5600, 5600, 5600, 5600, 5600, 5600, 5600, 1023, 1022, 1021, 1020, 1018, 1018,
-    1016, 1016, 1013, 1012, 1013, 1012, 1008, 1008, 1008, 1008, 1002, 1002, 1000, 1000, 1002, 1002,
-    1000, 1000, 992, 992, 992, 992, 992, 992, 992, 992, 511, 510, 509, 508, 506, 506, 504, 504,
-    501, 500, 501, 500, 496, 496, 496, 496, 490, 490, 488, 488, 490, 490, 488, 488, 480, 480, 480,
-    480, 480, 480, 480, 480, 4095, 4094, 4093, 4092, 4090, 4090, 4088, 4088, 4085, 4084, 4085,
-    4084, 4080, 4080, 4080, 4080, 4074, 4074, 4072, 4072, 4074, 4074, 4072, 4072, 4064, 4064, 4064,
-    4064, 4064, 4064, 4064, 4064, 1535, 1534, 1533, 1532, 1530, 1530, 1528, 1528, 1525, 1524, 1525,
-    1524, 1520, 1520, 1520, 1520, 1514, 1514, 1512, 1512, 1514, 1514, 1512, 1512, 1504, 1504, 1504,
-    1504, 1504, 1504, 1504, 1504, 3071, 3070, 3069, 3068, 3066, 3066, 3064, 3064, 3061, 3060, 3061,
-    3060, 3056, 3056, 3056, 3056, 3050, 3050, 3048, 3048, 3050, 3050, 3048, 3048, 3040, 3040, 3040,
-    3040, 3040, 3040, 3040, 3040, 511, 510, 509, 508, 506, 506, 504, 504, 501, 500, 501, 500, 496,
-    496, 496, 496, 490, 490, 488, 488, 490, 490, 488, 488, 480, 480, 480, 480, 480, 480, 480, 480,
-    2047, 2046, 2045, 2044, 2042, 2042, 2040, 2040, 2037, 2036, 2037, 2036, 2032, 2032, 2032, 2032,
-    2026, 2026, 2024, 2024, 2026, 2026, 2024, 2024, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016,
-    1535, 1534, 1533, 1532, 1530, 1530, 1528, 1528, 1525, 1524, 1525, 1524, 1520, 1520, 1520, 1520,
-    1514, 1514, 1512, 1512, 1514, 1514, 1512, 1512, 1504, 1504, 1504, 1504, 1504, 1504, 1504, 1504,
-    1023, 1022, 1021, 1020, 1018, 1018, 1016, 1016, 1013, 1012, 1013, 1012, 1008, 1008, 1008, 1008,
-    1002, 1002, 1000, 1000, 1002, 1002, 1000, 1000, 992, 992, 992, 992, 992, 992, 992, 992, 511,
-    510, 509, 508, 506, 506, 504, 504, 501, 500, 501, 500, 496, 496, 496, 496, 490, 490, 488, 488,
-    490, 490, 488, 488, 480, 480, 480, 480, 480, 480, 480, 480, 8191, 8190, 8188, 8188, 8187, 8186,
-    8184, 8184, 8180, 8180, 8180, 8180, 8176, 8176, 8176, 8176, 8171, 8170, 8168, 8168, 8171, 8170,
-    8168, 8168, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 5631, 5630, 5628, 5628, 5627, 5626,
-    5624, 5624, 5620, 5620, 5620, 5620, 5616, 5616, 5616, 5616, 5611, 5610, 5608, 5608, 5611, 5610,
-    5608, 5608, 5600, 5600, 5600, 5600, 5600, 5600, 5600, 5600, 3071, 3070, 3068, 3068, 3067, 3066,
-    3064, 3064, 3060, 3060, 3060, 3060, 3056, 3056, 3056, 3056, 3051, 3050, 3048, 3048, 3051, 3050,
-    3048, 3048, 3040, 3040, 3040, 3040, 3040, 3040, 3040, 3040, 511, 510, 508, 508, 507, 506, 504,
-    504, 500, 500, 500, 500, 496, 496, 496, 496, 491, 490, 488, 488, 491, 490, 488, 488, 480, 480,
-    480, 480, 480, 480, 480, 480, 6143, 6142, 6140, 6140, 6139, 6138, 6136, 6136, 6132, 6132, 6132,
-    6132, 6128, 6128, 6128, 6128, 6123, 6122, 6120, 6120, 6123, 6122, 6120, 6120, 6112, 6112, 6112,
-    6112, 6112, 6112, 6112, 6112, 5631, 5630, 5628, 5628, 5627, 5626, 5624, 5624, 5620, 5620, 5620,
-    5620, 5616, 5616, 5616, 5616, 5611, 5610, 5608, 5608, 5611, 5610, 5608, 5608, 5600, 5600, 5600,
-    5600, 5600, 5600, 5600, 5600, 1023, 1022, 1020, 1020, 1019, 1018, 1016, 1016, 1012, 1012, 1012,
-    1012, 1008, 1008, 1008, 1008, 1003, 1002, 1000, 1000, 1003, 1002, 1000, 1000, 992, 992, 992,
-    992, 992, 992, 992, 992, 511, 510, 508, 508, 507, 506, 504, 504, 500, 500, 500, 500, 496, 496,
-    496, 496, 491, 490, 488, 488, 491, 490, 488, 488, 480, 480, 480, 480, 480, 480, 480, 480, 4095,
-    4094, 4092, 4092, 4091, 4090, 4088, 4088, 4084, 4084, 4084, 4084, 4080, 4080, 4080, 4080, 4075,
-    4074, 4072, 4072, 4075, 4074, 4072, 4072, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 1535,
-    1534, 1532, 1532, 1531, 1530, 1528, 1528, 1524, 1524, 1524, 1524, 1520, 1520, 1520, 1520, 1515,
-    1514, 1512, 1512, 1515, 1514, 1512, 1512, 1504, 1504, 1504, 1504, 1504, 1504, 1504, 1504, 3071,
-    3070, 3068, 3068, 3067, 3066, 3064, 3064, 3060, 3060, 3060, 3060, 3056, 3056, 3056, 3056, 3051,
-    3050, 3048, 3048, 3051, 3050, 3048, 3048, 3040, 3040, 3040, 3040, 3040, 3040, 3040, 3040, 511,
-    510, 508, 508, 507, 506, 504, 504, 500, 500, 500, 500, 496, 496, 496, 496, 491, 490, 488, 488,
-    491, 490, 488, 488, 480, 480, 480, 480, 480, 480, 480, 480, 2047, 2046, 2044, 2044, 2043, 2042,
-    2040, 2040, 2036, 2036, 2036, 2036, 2032, 2032, 2032, 2032, 2027, 2026, 2024, 2024, 2027, 2026,
-    2024, 2024, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1535, 1534, 1532, 1532, 1531, 1530,
-    1528, 1528, 1524, 1524, 1524, 1524, 1520, 1520, 1520, 1520, 1515, 1514, 1512, 1512, 1515, 1514,
-    1512, 1512, 1504, 1504, 1504, 1504, 1504, 1504, 1504, 1504, 1023, 1022, 1020, 1020, 1019, 1018,
-    1016, 1016, 1012, 1012, 1012, 1012, 1008, 1008, 1008, 1008, 1003, 1002, 1000, 1000, 1003, 1002,
-    1000, 1000, 992, 992, 992, 992, 992, 992, 992, 992, 511, 510, 508, 508, 507, 506, 504, 504,
-    500, 500, 500, 500, 496, 496, 496, 496, 491, 490, 488, 488, 491, 490, 488, 488, 480, 480, 480,
-    480, 480, 480, 480, 480, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040, 2039, 2038, 2036,
-    2036, 2032, 2032, 2032, 2032, 767, 766, 764, 764, 760, 760, 760, 760, 759, 758, 756, 756, 752,
-    752, 752, 752, 1535, 1534, 1532, 1532, 1528, 1528, 1528, 1528, 1527, 1526, 1524, 1524, 1520,
-    1520, 1520, 1520, 255, 254, 252, 252, 248, 248, 248, 248, 247, 246, 244, 244, 240, 240, 240,
-    240, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1015, 1014, 1012, 1012, 1008, 1008, 1008,
-    1008, 767, 766, 764, 764, 760, 760, 760, 760, 759, 758, 756, 756, 752, 752, 752, 752, 511, 510,
-    508, 508, 504, 504, 504, 504, 503, 502, 500, 500, 496, 496, 496, 496, 255, 254, 252, 252, 248,
-    248, 248, 248, 247, 246, 244, 244, 240, 240, 240, 240, 511, 510, 508, 508, 504, 504, 504, 504,
-    127, 126, 124, 124, 120, 120, 120, 120, 255, 254, 252, 252, 248, 248, 248, 248, 127, 126, 124,
-    124, 120, 120, 120, 120, 127, 126, 124, 124, 120, 120, 120, 120, 31, 30, 28, 28, 24, 24, 24,
-    24, 63, 62, 60, 60, 56, 56, 56, 56, 31, 30, 28, 28, 24, 24, 24, 24, 127, 126, 124, 124, 120,
-    120, 120, 120, 112, 112, 112, 112, 112, 112, 112, 112, 63, 62, 60, 60, 56, 56, 56, 56, 48, 48,
-    48, 48, 48, 48, 48, 48, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496,
-    496, 496, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 2047,
-    2046, 2044, 2044, 2040, 2040, 2040, 2040, 2039, 2038, 2036, 2036, 2032, 2032, 2032, 2032, 2024,
-    2024, 2024, 2024, 2024, 2024, 2024, 2024, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1535,
-    1534, 1532, 1532, 1528, 1528, 1528, 1528, 1527, 1526, 1524, 1524, 1520, 1520, 1520, 1520, 1512,
-    1512, 1512, 1512, 1512, 1512, 1512, 1512, 1504, 1504, 1504, 1504, 1504, 1504, 1504, 1504, 1023,
-    1022, 1020, 1020, 1016, 1016, 1016, 1016, 1015, 1014, 1012, 1012, 1008, 1008, 1008, 1008, 1000,
-    1000, 1000, 1000, 1000, 1000, 1000, 1000, 992, 992, 992, 992, 992, 992, 992, 992, 511, 510,
-    508, 508, 504, 504, 504, 504, 503, 502, 500, 500, 496, 496, 496, 496, 488, 488, 488, 488, 488,
-    488, 488, 488, 480, 480, 480, 480, 480, 480, 480, 480, 2047, 2046, 2045, 2044, 2042, 2042,
-    2040, 2040, 2037, 2036, 2037, 2036, 2032, 2032, 2032, 2032, 2026, 2026, 2024, 2024, 2026, 2026,
-    2024, 2024, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1535, 1534, 1533, 1532, 1530, 1530,
-    1528, 1528, 1525, 1524, 1525, 1524, 1520, 1520, 1520, 1520, 1514, 1514, 1512, 1512, 1514, 1514,
-    1512, 1512, 1504, 1504, 1504, 1504, 1504, 1504, 1504, 1504, 1023, 1022, 1021, 1020, 1018, 1018,
-    1016, 1016, 1013, 1012, 1013, 1012, 1008, 1008, 1008, 1008, 1002, 1002, 1000, 1000, 1002, 1002,
-    1000, 1000, 992, 992, 992, 992, 992, 992, 992, 992, 511, 510, 509, 508, 506, 506, 504, 504,
-    501, 500, 501, 500, 496, 496, 496, 496, 490, 490, 488, 488, 490, 490, 488, 488, 480, 480, 480,
-    480, 480, 480, 480, 480, 2047, 2046, 2044, 2044, 2043, 2042, 2040, 2040, 2036, 2036, 2036,
-    2036, 2032, 2032, 2032, 2032, 2027, 2026, 2024, 2024, 2027, 2026, 2024, 2024, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 1535, 1534, 1532, 1532, 1531, 1530, 1528, 1528, 1524, 1524, 1524,
-    1524, 1520, 1520, 1520, 1520, 1515, 1514, 1512, 1512, 1515, 1514, 1512, 1512, 1504, 1504, 1504,
-    1504, 1504, 1504, 1504, 1504, 1023, 1022, 1020, 1020, 1019, 1018, 1016, 1016, 1012, 1012, 1012,
-    1012, 1008, 1008, 1008, 1008, 1003, 1002, 1000, 1000, 1003, 1002, 1000, 1000, 992, 992, 992,
-    992, 992, 992, 992, 992, 511, 510, 508, 508, 507, 506, 504, 504, 500, 500, 500, 500, 496, 496,
-    496, 496, 491, 490, 488, 488, 491, 490, 488, 488, 480, 480, 480, 480, 480, 480, 480, 480, 2047,
-    2046, 2044, 2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2031,
-    2030, 2028, 2028, 2024, 2024, 2024, 2024, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1535,
-    1534, 1532, 1532, 1528, 1528, 1528, 1528, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1519,
-    1518, 1516, 1516, 1512, 1512, 1512, 1512, 1504, 1504, 1504, 1504, 1504, 1504, 1504, 1504, 1023,
-    1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1007,
-    1006, 1004, 1004, 1000, 1000, 1000, 1000, 992, 992, 992, 992, 992, 992, 992, 992, 511, 510,
-    508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 495, 494, 492, 492, 488,
-    488, 488, 488, 480, 480, 480, 480, 480, 480, 480, 480, 511, 510, 508, 508, 504, 504, 504, 504,
-    496, 496, 496, 496, 496, 496, 496, 496, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240,
-    240, 240, 240, 240, 240, 127, 126, 124, 124, 120, 120, 120, 120, 112, 112, 112, 112, 112, 112,
-    112, 112, 63, 62, 60, 60, 56, 56, 56, 56, 48, 48, 48, 48, 48, 48, 48, 48, 127, 126, 124, 124,
-    120, 120, 120, 120, 112, 112, 112, 112, 112, 112, 112, 112, 96, 96, 96, 96, 96, 96, 96, 96, 96,
-    96, 96, 96, 96, 96, 96, 96, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496,
-    496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    511, 510, 508, 508, 504, 504, 504, 504, 503, 502, 500, 500, 496, 496, 496, 496, 488, 488, 488,
-    488, 488, 488, 488, 488, 480, 480, 480, 480, 480, 480, 480, 480, 511, 510, 509, 508, 506, 506,
-    504, 504, 501, 500, 501, 500, 496, 496, 496, 496, 490, 490, 488, 488, 490, 490, 488, 488, 480,
-    480, 480, 480, 480, 480, 480, 480, 511, 510, 508, 508, 507, 506, 504, 504, 500, 500, 500, 500,
-    496, 496, 496, 496, 491, 490, 488, 488, 491, 490, 488, 488, 480, 480, 480, 480, 480, 480, 480,
-    480, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 495, 494,
-    492, 492, 488, 488, 488, 488, 480, 480, 480, 480, 480, 480, 480, 480, 511, 510, 508, 508, 504,
-    504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 127, 126, 124, 124, 120, 120, 120, 120, 112, 112, 112,
-    112, 112, 112, 112, 112, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 127,
-    126, 124, 124, 120, 120, 120, 120, 112, 112, 112, 112, 112, 112, 112, 112, 96, 96, 96, 96, 96,
-    96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-    64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 127, 126, 124, 124,
-    120, 120, 120, 120, 112, 112, 112, 112, 112, 112, 112, 112, 96, 96, 96, 96, 96, 96, 96, 96, 96,
-    96, 96, 96, 96, 96, 96, 96, 127, 126, 124, 124, 120, 120, 120, 120, 119, 118, 116, 116, 112,
-    112, 112, 112, 104, 104, 104, 104, 104, 104, 104, 104, 96, 96, 96, 96, 96, 96, 96, 96, 127,
-    126, 125, 124, 122, 122, 120, 120, 117, 116, 117, 116, 112, 112, 112, 112, 106, 106, 104, 104,
-    106, 106, 104, 104, 96, 96, 96, 96, 96, 96, 96, 96, 127, 126, 124, 124, 123, 122, 120, 120,
-    116, 116, 116, 116, 112, 112, 112, 112, 107, 106, 104, 104, 107, 106, 104, 104, 96, 96, 96, 96,
-    96, 96, 96, 96, 127, 126, 124, 124, 120, 120, 120, 120, 112, 112, 112, 112, 112, 112, 112, 112,
-    111, 110, 108, 108, 104, 104, 104, 104, 96, 96, 96, 96, 96, 96, 96, 96, 127, 126, 124, 124,
-    120, 120, 120, 120, 112, 112, 112, 112, 112, 112, 112, 112, 96, 96, 96, 96, 96, 96, 96, 96, 96,
-    96, 96, 96, 96, 96, 96, 96, 127, 126, 124, 124, 120, 120, 120, 120, 112, 112, 112, 112, 112,
-    112, 112, 112, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 64, 64, 64, 64,
-    64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-    64, 64, 64, 64, 16383, 16257, 16259, 16257, 16263, 16257, 16259, 16257, 16271, 16257, 16259,
-    16257, 16263, 16257, 16259, 16257, 16287, 16257, 16259, 16257, 16263, 16257, 16259, 16257,
-    16271, 16257, 16259, 16257, 16263, 16257, 16259, 16257, 16319, 16257, 16259, 16257, 16263,
-    16257, 16259, 16257, 16271, 16257, 16259, 16257, 16263, 16257, 16259, 16257, 16287, 16257,
-    16259, 16257, 16263, 16257, 16259, 16257, 16271, 16257, 16259, 16257, 16263, 16257, 16259,
-    16257, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159,
-    129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129,
-    135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131,
-    129, 143, 129, 131, 129, 135, 129, 131, 129, 511, 385, 387, 385, 391, 385, 387, 385, 399, 385,
-    387, 385, 391, 385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391,
-    385, 387, 385, 447, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385,
-    415, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 255, 129, 131,
-    129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129,
-    131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143,
-    129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129,
-    135, 129, 131, 129, 1023, 897, 899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903, 897, 899,
-    897, 927, 897, 899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903, 897, 899, 897, 959, 897,
-    899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903, 897, 899, 897, 927, 897, 899, 897, 903,
-    897, 899, 897, 911, 897, 899, 897, 903, 897, 899, 897, 255, 129, 131, 129, 135, 129, 131, 129,
-    143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131,
-    129, 135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129,
-    131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 511,
-    385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385, 387, 385,
-    391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 447, 385, 387, 385, 391, 385, 387,
-    385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385, 399, 385,
-    387, 385, 391, 385, 387, 385, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135,
-    129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129,
-    191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131,
-    129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 2047, 1921, 1923, 1921, 1927,
-    1921, 1923, 1921, 1935, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 1951, 1921, 1923, 1921, 1927,
-    1921, 1923, 1921, 1935, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 1983, 1921, 1923, 1921, 1927,
-    1921, 1923, 1921, 1935, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 1951, 1921, 1923, 1921, 1927,
-    1921, 1923, 1921, 1935, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 255, 129, 131, 129, 135, 129,
-    131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143,
-    129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129,
-    135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131,
-    129, 511, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385,
-    387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 447, 385, 387, 385, 391,
-    385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385,
-    399, 385, 387, 385, 391, 385, 387, 385, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131,
-    129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129,
-    131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159,
-    129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 1023, 897, 899, 897,
-    903, 897, 899, 897, 911, 897, 899, 897, 903, 897, 899, 897, 927, 897, 899, 897, 903, 897, 899,
-    897, 911, 897, 899, 897, 903, 897, 899, 897, 959, 897, 899, 897, 903, 897, 899, 897, 911, 897,
-    899, 897, 903, 897, 899, 897, 927, 897, 899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903,
-    897, 899, 897, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129,
-    159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129, 131,
-    129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129,
-    131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 511, 385, 387, 385, 391, 385, 387, 385, 399,
-    385, 387, 385, 391, 385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385,
-    391, 385, 387, 385, 447, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387,
-    385, 415, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 255, 129,
-    131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135,
-    129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131, 129,
-    143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131,
-    129, 135, 129, 131, 129, 4095, 3969, 3971, 3969, 3975, 3969, 3971, 3969, 3983, 3969, 3971,
-    3969, 3975, 3969, 3971, 3969, 3999, 3969, 3971, 3969, 3975, 3969, 3971, 3969, 3983, 3969, 3971,
-    3969, 3975, 3969, 3971, 3969, 4031, 3969, 3971, 3969, 3975, 3969, 3971, 3969, 3983, 3969, 3971,
-    3969, 3975, 3969, 3971, 3969, 3999, 3969, 3971, 3969, 3975, 3969, 3971, 3969, 3983, 3969, 3971,
-    3969, 3975, 3969, 3971, 3969, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135,
-    129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129,
-    191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131,
-    129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 511, 385, 387, 385, 391, 385,
-    387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385, 399,
-    385, 387, 385, 391, 385, 387, 385, 447, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385,
-    391, 385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387,
-    385, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129,
-    131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129, 135,
-    129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129,
-    143, 129, 131, 129, 135, 129, 131, 129, 1023, 897, 899, 897, 903, 897, 899, 897, 911, 897, 899,
-    897, 903, 897, 899, 897, 927, 897, 899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903, 897,
-    899, 897, 959, 897, 899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903, 897, 899, 897, 927,
-    897, 899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903, 897, 899, 897, 255, 129, 131, 129,
-    135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131,
-    129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143, 129,
-    131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135,
-    129, 131, 129, 511, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385,
-    415, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 447, 385, 387,
-    385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385, 387, 385, 391, 385,
-    387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 255, 129, 131, 129, 135, 129, 131, 129, 143,
-    129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129,
-    135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131,
-    129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 2047,
-    1921, 1923, 1921, 1927, 1921, 1923, 1921, 1935, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 1951,
-    1921, 1923, 1921, 1927, 1921, 1923, 1921, 1935, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 1983,
-    1921, 1923, 1921, 1927, 1921, 1923, 1921, 1935, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 1951,
-    1921, 1923, 1921, 1927, 1921, 1923, 1921, 1935, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 255,
-    129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129,
-    135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131,
-    129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129,
-    131, 129, 135, 129, 131, 129, 511, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391,
-    385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385,
-    447, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385, 387,
-    385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 255, 129, 131, 129, 135, 129,
-    131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143,
-    129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129,
-    135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131,
-    129, 1023, 897, 899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903, 897, 899, 897, 927, 897,
-    899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903, 897, 899, 897, 959, 897, 899, 897, 903,
-    897, 899, 897, 911, 897, 899, 897, 903, 897, 899, 897, 927, 897, 899, 897, 903, 897, 899, 897,
-    911, 897, 899, 897, 903, 897, 899, 897, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131,
-    129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129,
-    131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159,
-    129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 511, 385, 387, 385,
-    391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385, 387, 385, 391, 385, 387,
-    385, 399, 385, 387, 385, 391, 385, 387, 385, 447, 385, 387, 385, 391, 385, 387, 385, 399, 385,
-    387, 385, 391, 385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391,
-    385, 387, 385, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129,
-    159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129, 131,
-    129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129,
-    131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 8191, 8065, 8067, 8065, 8071, 8065, 8067,
-    8065, 8079, 8065, 8067, 8065, 8071, 8065, 8067, 8065, 8095, 8065, 8067, 8065, 8071, 8065, 8067,
-    8065, 8079, 8065, 8067, 8065, 8071, 8065, 8067, 8065, 8127, 8065, 8067, 8065, 8071, 8065, 8067,
-    8065, 8079, 8065, 8067, 8065, 8071, 8065, 8067, 8065, 8095, 8065, 8067, 8065, 8071, 8065, 8067,
-    8065, 8079, 8065, 8067, 8065, 8071, 8065, 8067, 8065, 255, 129, 131, 129, 135, 129, 131, 129,
-    143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131,
-    129, 135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129,
-    131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 511,
-    385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385, 387, 385,
-    391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 447, 385, 387, 385, 391, 385, 387,
-    385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385, 399, 385,
-    387, 385, 391, 385, 387, 385, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135,
-    129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129,
-    191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131,
-    129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 1023, 897, 899, 897, 903, 897,
-    899, 897, 911, 897, 899, 897, 903, 897, 899, 897, 927, 897, 899, 897, 903, 897, 899, 897, 911,
-    897, 899, 897, 903, 897, 899, 897, 959, 897, 899, 897, 903, 897, 899, 897, 911, 897, 899, 897,
-    903, 897, 899, 897, 927, 897, 899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903, 897, 899,
-    897, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129,
-    131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129, 135,
-    129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129,
-    143, 129, 131, 129, 135, 129, 131, 129, 511, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387,
-    385, 391, 385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385,
-    387, 385, 447, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 415,
-    385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 255, 129, 131, 129,
-    135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131,
-    129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143, 129,
-    131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135,
-    129, 131, 129, 2047, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 1935, 1921, 1923, 1921, 1927,
-    1921, 1923, 1921, 1951, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 1935, 1921, 1923, 1921, 1927,
-    1921, 1923, 1921, 1983, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 1935, 1921, 1923, 1921, 1927,
-    1921, 1923, 1921, 1951, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 1935, 1921, 1923, 1921, 1927,
-    1921, 1923, 1921, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131,
-    129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129,
-    131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135,
-    129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 511, 385, 387, 385, 391, 385, 387, 385,
-    399, 385, 387, 385, 391, 385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387,
-    385, 391, 385, 387, 385, 447, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385,
-    387, 385, 415, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 255,
-    129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129,
-    135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131,
-    129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129,
-    131, 129, 135, 129, 131, 129, 1023, 897, 899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903,
-    897, 899, 897, 927, 897, 899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903, 897, 899, 897,
-    959, 897, 899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903, 897, 899, 897, 927, 897, 899,
-    897, 903, 897, 899, 897, 911, 897, 899, 897, 903, 897, 899, 897, 255, 129, 131, 129, 135, 129,
-    131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143,
-    129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129,
-    135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131,
-    129, 511, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385,
-    387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 447, 385, 387, 385, 391,
-    385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385,
-    399, 385, 387, 385, 391, 385, 387, 385, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131,
-    129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129,
-    131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159,
-    129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 4095, 3969, 3971,
-    3969, 3975, 3969, 3971, 3969, 3983, 3969, 3971, 3969, 3975, 3969, 3971, 3969, 3999, 3969, 3971,
-    3969, 3975, 3969, 3971, 3969, 3983, 3969, 3971, 3969, 3975, 3969, 3971, 3969, 4031, 3969, 3971,
-    3969, 3975, 3969, 3971, 3969, 3983, 3969, 3971, 3969, 3975, 3969, 3971, 3969, 3999, 3969, 3971,
-    3969, 3975, 3969, 3971, 3969, 3983, 3969, 3971, 3969, 3975, 3969, 3971, 3969, 255, 129, 131,
-    129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129,
-    131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143,
-    129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129,
-    135, 129, 131, 129, 511, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387,
-    385, 415, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 447, 385,
-    387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385, 387, 385, 391,
-    385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 255, 129, 131, 129, 135, 129, 131, 129,
-    143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131,
-    129, 135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129,
-    131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 1023,
-    897, 899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903, 897, 899, 897, 927, 897, 899, 897,
-    903, 897, 899, 897, 911, 897, 899, 897, 903, 897, 899, 897, 959, 897, 899, 897, 903, 897, 899,
-    897, 911, 897, 899, 897, 903, 897, 899, 897, 927, 897, 899, 897, 903, 897, 899, 897, 911, 897,
-    899, 897, 903, 897, 899, 897, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135,
-    129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129,
-    191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131,
-    129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 511, 385, 387, 385, 391, 385,
-    387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385, 399,
-    385, 387, 385, 391, 385, 387, 385, 447, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385,
-    391, 385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387,
-    385, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129,
-    131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129, 135,
-    129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129,
-    143, 129, 131, 129, 135, 129, 131, 129, 2047, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 1935,
-    1921, 1923, 1921, 1927, 1921, 1923, 1921, 1951, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 1935,
-    1921, 1923, 1921, 1927, 1921, 1923, 1921, 1983, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 1935,
-    1921, 1923, 1921, 1927, 1921, 1923, 1921, 1951, 1921, 1923, 1921, 1927, 1921, 1923, 1921, 1935,
-    1921, 1923, 1921, 1927, 1921, 1923, 1921, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129,
-    131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135,
-    129, 131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129,
-    159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 511, 385, 387,
-    385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385, 387, 385, 391, 385,
-    387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 447, 385, 387, 385, 391, 385, 387, 385, 399,
-    385, 387, 385, 391, 385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385,
-    391, 385, 387, 385, 255, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131,
-    129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129,
-    131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135,
-    129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 1023, 897, 899, 897, 903, 897, 899, 897,
-    911, 897, 899, 897, 903, 897, 899, 897, 927, 897, 899, 897, 903, 897, 899, 897, 911, 897, 899,
-    897, 903, 897, 899, 897, 959, 897, 899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903, 897,
-    899, 897, 927, 897, 899, 897, 903, 897, 899, 897, 911, 897, 899, 897, 903, 897, 899, 897, 255,
-    129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129,
-    135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131,
-    129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129,
-    131, 129, 135, 129, 131, 129, 511, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391,
-    385, 387, 385, 415, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385,
-    447, 385, 387, 385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 415, 385, 387,
-    385, 391, 385, 387, 385, 399, 385, 387, 385, 391, 385, 387, 385, 255, 129, 131, 129, 135, 129,
-    131, 129, 143, 129, 131, 129, 135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143,
-    129, 131, 129, 135, 129, 131, 129, 191, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129,
-    135, 129, 131, 129, 159, 129, 131, 129, 135, 129, 131, 129, 143, 129, 131, 129, 135, 129, 131,
-    129, 16383, 16259, 16263, 16259, 16271, 16259, 16263, 16259, 16287, 16259, 16263, 16259, 16271,
-    16259, 16263, 16259, 16319, 16259, 16263, 16259, 16271, 16259, 16263, 16259, 16287, 16259,
-    16263, 16259, 16271, 16259, 16263, 16259, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131,
-    135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143,
-    131, 135, 131, 511, 387, 391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387,
-    447, 387, 391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 255, 131, 135,
-    131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131,
-    135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 1023, 899, 903, 899, 911, 899, 903, 899, 927,
-    899, 903, 899, 911, 899, 903, 899, 959, 899, 903, 899, 911, 899, 903, 899, 927, 899, 903, 899,
-    911, 899, 903, 899, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135,
-    131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 511, 387,
-    391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 447, 387, 391, 387, 399,
-    387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 255, 131, 135, 131, 143, 131, 135, 131,
-    159, 131, 135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135,
-    131, 143, 131, 135, 131, 2047, 1923, 1927, 1923, 1935, 1923, 1927, 1923, 1951, 1923, 1927,
-    1923, 1935, 1923, 1927, 1923, 1983, 1923, 1927, 1923, 1935, 1923, 1927, 1923, 1951, 1923, 1927,
-    1923, 1935, 1923, 1927, 1923, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143,
-    131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131,
-    511, 387, 391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 447, 387, 391,
-    387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 255, 131, 135, 131, 143, 131,
-    135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159,
-    131, 135, 131, 143, 131, 135, 131, 1023, 899, 903, 899, 911, 899, 903, 899, 927, 899, 903, 899,
-    911, 899, 903, 899, 959, 899, 903, 899, 911, 899, 903, 899, 927, 899, 903, 899, 911, 899, 903,
-    899, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 191, 131,
-    135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 511, 387, 391, 387, 399,
-    387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 447, 387, 391, 387, 399, 387, 391, 387,
-    415, 387, 391, 387, 399, 387, 391, 387, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135,
-    131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131,
-    135, 131, 4095, 3971, 3975, 3971, 3983, 3971, 3975, 3971, 3999, 3971, 3975, 3971, 3983, 3971,
-    3975, 3971, 4031, 3971, 3975, 3971, 3983, 3971, 3975, 3971, 3999, 3971, 3975, 3971, 3983, 3971,
-    3975, 3971, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131,
-    191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 511, 387, 391,
-    387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 447, 387, 391, 387, 399, 387,
-    391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 255, 131, 135, 131, 143, 131, 135, 131, 159,
-    131, 135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131,
-    143, 131, 135, 131, 1023, 899, 903, 899, 911, 899, 903, 899, 927, 899, 903, 899, 911, 899, 903,
-    899, 959, 899, 903, 899, 911, 899, 903, 899, 927, 899, 903, 899, 911, 899, 903, 899, 255, 131,
-    135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143,
-    131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 511, 387, 391, 387, 399, 387, 391, 387,
-    415, 387, 391, 387, 399, 387, 391, 387, 447, 387, 391, 387, 399, 387, 391, 387, 415, 387, 391,
-    387, 399, 387, 391, 387, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131,
-    135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 2047,
-    1923, 1927, 1923, 1935, 1923, 1927, 1923, 1951, 1923, 1927, 1923, 1935, 1923, 1927, 1923, 1983,
-    1923, 1927, 1923, 1935, 1923, 1927, 1923, 1951, 1923, 1927, 1923, 1935, 1923, 1927, 1923, 255,
-    131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 191, 131, 135, 131,
-    143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 511, 387, 391, 387, 399, 387, 391,
-    387, 415, 387, 391, 387, 399, 387, 391, 387, 447, 387, 391, 387, 399, 387, 391, 387, 415, 387,
-    391, 387, 399, 387, 391, 387, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143,
-    131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131,
-    1023, 899, 903, 899, 911, 899, 903, 899, 927, 899, 903, 899, 911, 899, 903, 899, 959, 899, 903,
-    899, 911, 899, 903, 899, 927, 899, 903, 899, 911, 899, 903, 899, 255, 131, 135, 131, 143, 131,
-    135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159,
-    131, 135, 131, 143, 131, 135, 131, 511, 387, 391, 387, 399, 387, 391, 387, 415, 387, 391, 387,
-    399, 387, 391, 387, 447, 387, 391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391,
-    387, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 191, 131,
-    135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 8191, 8067, 8071, 8067,
-    8079, 8067, 8071, 8067, 8095, 8067, 8071, 8067, 8079, 8067, 8071, 8067, 8127, 8067, 8071, 8067,
-    8079, 8067, 8071, 8067, 8095, 8067, 8071, 8067, 8079, 8067, 8071, 8067, 255, 131, 135, 131,
-    143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131, 135,
-    131, 159, 131, 135, 131, 143, 131, 135, 131, 511, 387, 391, 387, 399, 387, 391, 387, 415, 387,
-    391, 387, 399, 387, 391, 387, 447, 387, 391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399,
-    387, 391, 387, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131,
-    191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 1023, 899, 903,
-    899, 911, 899, 903, 899, 927, 899, 903, 899, 911, 899, 903, 899, 959, 899, 903, 899, 911, 899,
-    903, 899, 927, 899, 903, 899, 911, 899, 903, 899, 255, 131, 135, 131, 143, 131, 135, 131, 159,
-    131, 135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131,
-    143, 131, 135, 131, 511, 387, 391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391,
-    387, 447, 387, 391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 255, 131,
-    135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143,
-    131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 2047, 1923, 1927, 1923, 1935, 1923,
-    1927, 1923, 1951, 1923, 1927, 1923, 1935, 1923, 1927, 1923, 1983, 1923, 1927, 1923, 1935, 1923,
-    1927, 1923, 1951, 1923, 1927, 1923, 1935, 1923, 1927, 1923, 255, 131, 135, 131, 143, 131, 135,
-    131, 159, 131, 135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131,
-    135, 131, 143, 131, 135, 131, 511, 387, 391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399,
-    387, 391, 387, 447, 387, 391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387,
-    255, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 191, 131, 135,
-    131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 1023, 899, 903, 899, 911, 899,
-    903, 899, 927, 899, 903, 899, 911, 899, 903, 899, 959, 899, 903, 899, 911, 899, 903, 899, 927,
-    899, 903, 899, 911, 899, 903, 899, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131,
-    143, 131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135,
-    131, 511, 387, 391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 447, 387,
-    391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 255, 131, 135, 131, 143,
-    131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131,
-    159, 131, 135, 131, 143, 131, 135, 131, 4095, 3971, 3975, 3971, 3983, 3971, 3975, 3971, 3999,
-    3971, 3975, 3971, 3983, 3971, 3975, 3971, 4031, 3971, 3975, 3971, 3983, 3971, 3975, 3971, 3999,
-    3971, 3975, 3971, 3983, 3971, 3975, 3971, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131,
-    135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143,
-    131, 135, 131, 511, 387, 391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387,
-    447, 387, 391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 255, 131, 135,
-    131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131,
-    135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 1023, 899, 903, 899, 911, 899, 903, 899, 927,
-    899, 903, 899, 911, 899, 903, 899, 959, 899, 903, 899, 911, 899, 903, 899, 927, 899, 903, 899,
-    911, 899, 903, 899, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135,
-    131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 511, 387,
-    391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 447, 387, 391, 387, 399,
-    387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 255, 131, 135, 131, 143, 131, 135, 131,
-    159, 131, 135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135,
-    131, 143, 131, 135, 131, 2047, 1923, 1927, 1923, 1935, 1923, 1927, 1923, 1951, 1923, 1927,
-    1923, 1935, 1923, 1927, 1923, 1983, 1923, 1927, 1923, 1935, 1923, 1927, 1923, 1951, 1923, 1927,
-    1923, 1935, 1923, 1927, 1923, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143,
-    131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131,
-    511, 387, 391, 387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 447, 387, 391,
-    387, 399, 387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 255, 131, 135, 131, 143, 131,
-    135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159,
-    131, 135, 131, 143, 131, 135, 131, 1023, 899, 903, 899, 911, 899, 903, 899, 927, 899, 903, 899,
-    911, 899, 903, 899, 959, 899, 903, 899, 911, 899, 903, 899, 927, 899, 903, 899, 911, 899, 903,
-    899, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 191, 131,
-    135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131, 135, 131, 511, 387, 391, 387, 399,
-    387, 391, 387, 415, 387, 391, 387, 399, 387, 391, 387, 447, 387, 391, 387, 399, 387, 391, 387,
-    415, 387, 391, 387, 399, 387, 391, 387, 255, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135,
-    131, 143, 131, 135, 131, 191, 131, 135, 131, 143, 131, 135, 131, 159, 131, 135, 131, 143, 131,
-    135, 131, 16383, 16382, 16263, 16262, 16271, 16270, 16263, 16262, 16287, 16286, 16263, 16262,
-    16271, 16270, 16263, 16262, 16319, 16318, 16263, 16262, 16271, 16270, 16263, 16262, 16287,
-    16286, 16263, 16262, 16271, 16270, 16263, 16262, 255, 254, 135, 134, 143, 142, 135, 134, 159,
-    158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134,
-    143, 142, 135, 134, 511, 510, 391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391,
-    390, 447, 446, 391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 255, 254,
-    135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134, 143,
-    142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 1023, 1022, 903, 902, 911, 910, 903,
-    902, 927, 926, 903, 902, 911, 910, 903, 902, 959, 958, 903, 902, 911, 910, 903, 902, 927, 926,
-    903, 902, 911, 910, 903, 902, 255, 254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143,
-    142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134,
-    511, 510, 391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 447, 446, 391,
-    390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 255, 254, 135, 134, 143, 142,
-    135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159,
-    158, 135, 134, 143, 142, 135, 134, 2047, 2046, 1927, 1926, 1935, 1934, 1927, 1926, 1951, 1950,
-    1927, 1926, 1935, 1934, 1927, 1926, 1983, 1982, 1927, 1926, 1935, 1934, 1927, 1926, 1951, 1950,
-    1927, 1926, 1935, 1934, 1927, 1926, 255, 254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134,
-    143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135,
-    134, 511, 510, 391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 447, 446,
-    391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 255, 254, 135, 134, 143,
-    142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134,
-    159, 158, 135, 134, 143, 142, 135, 134, 1023, 1022, 903, 902, 911, 910, 903, 902, 927, 926,
-    903, 902, 911, 910, 903, 902, 959, 958, 903, 902, 911, 910, 903, 902, 927, 926, 903, 902, 911,
-    910, 903, 902, 255, 254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134,
-    191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 511, 510, 391,
-    390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 447, 446, 391, 390, 399, 398,
-    391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 255, 254, 135, 134, 143, 142, 135, 134, 159,
-    158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134,
-    143, 142, 135, 134, 4095, 4094, 3975, 3974, 3983, 3982, 3975, 3974, 3999, 3998, 3975, 3974,
-    3983, 3982, 3975, 3974, 4031, 4030, 3975, 3974, 3983, 3982, 3975, 3974, 3999, 3998, 3975, 3974,
-    3983, 3982, 3975, 3974, 255, 254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142,
-    135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 511,
-    510, 391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 447, 446, 391, 390,
-    399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 255, 254, 135, 134, 143, 142, 135,
-    134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159, 158,
-    135, 134, 143, 142, 135, 134, 1023, 1022, 903, 902, 911, 910, 903, 902, 927, 926, 903, 902,
-    911, 910, 903, 902, 959, 958, 903, 902, 911, 910, 903, 902, 927, 926, 903, 902, 911, 910, 903,
-    902, 255, 254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190,
-    135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 511, 510, 391, 390, 399,
-    398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 447, 446, 391, 390, 399, 398, 391, 390,
-    415, 414, 391, 390, 399, 398, 391, 390, 255, 254, 135, 134, 143, 142, 135, 134, 159, 158, 135,
-    134, 143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142,
-    135, 134, 2047, 2046, 1927, 1926, 1935, 1934, 1927, 1926, 1951, 1950, 1927, 1926, 1935, 1934,
-    1927, 1926, 1983, 1982, 1927, 1926, 1935, 1934, 1927, 1926, 1951, 1950, 1927, 1926, 1935, 1934,
-    1927, 1926, 255, 254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134,
-    191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 511, 510, 391,
-    390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 447, 446, 391, 390, 399, 398,
-    391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 255, 254, 135, 134, 143, 142, 135, 134, 159,
-    158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134,
-    143, 142, 135, 134, 1023, 1022, 903, 902, 911, 910, 903, 902, 927, 926, 903, 902, 911, 910,
-    903, 902, 959, 958, 903, 902, 911, 910, 903, 902, 927, 926, 903, 902, 911, 910, 903, 902, 255,
-    254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134,
-    143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 511, 510, 391, 390, 399, 398, 391,
-    390, 415, 414, 391, 390, 399, 398, 391, 390, 447, 446, 391, 390, 399, 398, 391, 390, 415, 414,
-    391, 390, 399, 398, 391, 390, 255, 254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143,
-    142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134,
-    8191, 8190, 8071, 8070, 8079, 8078, 8071, 8070, 8095, 8094, 8071, 8070, 8079, 8078, 8071, 8070,
-    8127, 8126, 8071, 8070, 8079, 8078, 8071, 8070, 8095, 8094, 8071, 8070, 8079, 8078, 8071, 8070,
-    255, 254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190, 135,
-    134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 511, 510, 391, 390, 399, 398,
-    391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 447, 446, 391, 390, 399, 398, 391, 390, 415,
-    414, 391, 390, 399, 398, 391, 390, 255, 254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134,
-    143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135,
-    134, 1023, 1022, 903, 902, 911, 910, 903, 902, 927, 926, 903, 902, 911, 910, 903, 902, 959,
-    958, 903, 902, 911, 910, 903, 902, 927, 926, 903, 902, 911, 910, 903, 902, 255, 254, 135, 134,
-    143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135,
-    134, 159, 158, 135, 134, 143, 142, 135, 134, 511, 510, 391, 390, 399, 398, 391, 390, 415, 414,
-    391, 390, 399, 398, 391, 390, 447, 446, 391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399,
-    398, 391, 390, 255, 254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134,
-    191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 2047, 2046,
-    1927, 1926, 1935, 1934, 1927, 1926, 1951, 1950, 1927, 1926, 1935, 1934, 1927, 1926, 1983, 1982,
-    1927, 1926, 1935, 1934, 1927, 1926, 1951, 1950, 1927, 1926, 1935, 1934, 1927, 1926, 255, 254,
-    135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134, 143,
-    142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 511, 510, 391, 390, 399, 398, 391, 390,
-    415, 414, 391, 390, 399, 398, 391, 390, 447, 446, 391, 390, 399, 398, 391, 390, 415, 414, 391,
-    390, 399, 398, 391, 390, 255, 254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142,
-    135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 1023,
-    1022, 903, 902, 911, 910, 903, 902, 927, 926, 903, 902, 911, 910, 903, 902, 959, 958, 903, 902,
-    911, 910, 903, 902, 927, 926, 903, 902, 911, 910, 903, 902, 255, 254, 135, 134, 143, 142, 135,
-    134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159, 158,
-    135, 134, 143, 142, 135, 134, 511, 510, 391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399,
-    398, 391, 390, 447, 446, 391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390,
-    255, 254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190, 135,
-    134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 4095, 4094, 3975, 3974, 3983,
-    3982, 3975, 3974, 3999, 3998, 3975, 3974, 3983, 3982, 3975, 3974, 4031, 4030, 3975, 3974, 3983,
-    3982, 3975, 3974, 3999, 3998, 3975, 3974, 3983, 3982, 3975, 3974, 255, 254, 135, 134, 143, 142,
-    135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159,
-    158, 135, 134, 143, 142, 135, 134, 511, 510, 391, 390, 399, 398, 391, 390, 415, 414, 391, 390,
-    399, 398, 391, 390, 447, 446, 391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391,
-    390, 255, 254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190,
-    135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 1023, 1022, 903, 902,
-    911, 910, 903, 902, 927, 926, 903, 902, 911, 910, 903, 902, 959, 958, 903, 902, 911, 910, 903,
-    902, 927, 926, 903, 902, 911, 910, 903, 902, 255, 254, 135, 134, 143, 142, 135, 134, 159, 158,
-    135, 134, 143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143,
-    142, 135, 134, 511, 510, 391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390,
-    447, 446, 391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 255, 254, 135,
-    134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134, 143, 142,
-    135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 2047, 2046, 1927, 1926, 1935, 1934, 1927,
-    1926, 1951, 1950, 1927, 1926, 1935, 1934, 1927, 1926, 1983, 1982, 1927, 1926, 1935, 1934, 1927,
-    1926, 1951, 1950, 1927, 1926, 1935, 1934, 1927, 1926, 255, 254, 135, 134, 143, 142, 135, 134,
-    159, 158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135,
-    134, 143, 142, 135, 134, 511, 510, 391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398,
-    391, 390, 447, 446, 391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 255,
-    254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134,
-    143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 1023, 1022, 903, 902, 911, 910,
-    903, 902, 927, 926, 903, 902, 911, 910, 903, 902, 959, 958, 903, 902, 911, 910, 903, 902, 927,
-    926, 903, 902, 911, 910, 903, 902, 255, 254, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134,
-    143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134, 159, 158, 135, 134, 143, 142, 135,
-    134, 511, 510, 391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 447, 446,
-    391, 390, 399, 398, 391, 390, 415, 414, 391, 390, 399, 398, 391, 390, 255, 254, 135, 134, 143,
-    142, 135, 134, 159, 158, 135, 134, 143, 142, 135, 134, 191, 190, 135, 134, 143, 142, 135, 134,
-    159, 158, 135, 134, 143, 142, 135, 134, 16383, 16382, 16380, 16380, 16271, 16270, 16268, 16268,
-    16287, 16286, 16284, 16284, 16271, 16270, 16268, 16268, 16319, 16318, 16316, 16316, 16271,
-    16270, 16268, 16268, 16287, 16286, 16284, 16284, 16271, 16270, 16268, 16268, 255, 254, 252,
-    252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142,
-    140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 511, 510, 508, 508, 399, 398, 396, 396, 415,
-    414, 412, 412, 399, 398, 396, 396, 447, 446, 444, 444, 399, 398, 396, 396, 415, 414, 412, 412,
-    399, 398, 396, 396, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140,
-    140, 191, 190, 188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 1023,
-    1022, 1020, 1020, 911, 910, 908, 908, 927, 926, 924, 924, 911, 910, 908, 908, 959, 958, 956,
-    956, 911, 910, 908, 908, 927, 926, 924, 924, 911, 910, 908, 908, 255, 254, 252, 252, 143, 142,
-    140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142, 140, 140, 159,
-    158, 156, 156, 143, 142, 140, 140, 511, 510, 508, 508, 399, 398, 396, 396, 415, 414, 412, 412,
-    399, 398, 396, 396, 447, 446, 444, 444, 399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396,
-    396, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190,
-    188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 2047, 2046, 2044, 2044,
-    1935, 1934, 1932, 1932, 1951, 1950, 1948, 1948, 1935, 1934, 1932, 1932, 1983, 1982, 1980, 1980,
-    1935, 1934, 1932, 1932, 1951, 1950, 1948, 1948, 1935, 1934, 1932, 1932, 255, 254, 252, 252,
-    143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142, 140,
-    140, 159, 158, 156, 156, 143, 142, 140, 140, 511, 510, 508, 508, 399, 398, 396, 396, 415, 414,
-    412, 412, 399, 398, 396, 396, 447, 446, 444, 444, 399, 398, 396, 396, 415, 414, 412, 412, 399,
-    398, 396, 396, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140,
-    191, 190, 188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 1023, 1022,
-    1020, 1020, 911, 910, 908, 908, 927, 926, 924, 924, 911, 910, 908, 908, 959, 958, 956, 956,
-    911, 910, 908, 908, 927, 926, 924, 924, 911, 910, 908, 908, 255, 254, 252, 252, 143, 142, 140,
-    140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142, 140, 140, 159, 158,
-    156, 156, 143, 142, 140, 140, 511, 510, 508, 508, 399, 398, 396, 396, 415, 414, 412, 412, 399,
-    398, 396, 396, 447, 446, 444, 444, 399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396,
-    255, 254, 252, 252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188,
-    188, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 4095, 4094, 4092, 4092, 3983,
-    3982, 3980, 3980, 3999, 3998, 3996, 3996, 3983, 3982, 3980, 3980, 4031, 4030, 4028, 4028, 3983,
-    3982, 3980, 3980, 3999, 3998, 3996, 3996, 3983, 3982, 3980, 3980, 255, 254, 252, 252, 143, 142,
-    140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142, 140, 140, 159,
-    158, 156, 156, 143, 142, 140, 140, 511, 510, 508, 508, 399, 398, 396, 396, 415, 414, 412, 412,
-    399, 398, 396, 396, 447, 446, 444, 444, 399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396,
-    396, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190,
-    188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 1023, 1022, 1020, 1020,
-    911, 910, 908, 908, 927, 926, 924, 924, 911, 910, 908, 908, 959, 958, 956, 956, 911, 910, 908,
-    908, 927, 926, 924, 924, 911, 910, 908, 908, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158,
-    156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143,
-    142, 140, 140, 511, 510, 508, 508, 399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396,
-    447, 446, 444, 444, 399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396, 255, 254, 252,
-    252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142,
-    140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 2047, 2046, 2044, 2044, 1935, 1934, 1932,
-    1932, 1951, 1950, 1948, 1948, 1935, 1934, 1932, 1932, 1983, 1982, 1980, 1980, 1935, 1934, 1932,
-    1932, 1951, 1950, 1948, 1948, 1935, 1934, 1932, 1932, 255, 254, 252, 252, 143, 142, 140, 140,
-    159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142, 140, 140, 159, 158, 156,
-    156, 143, 142, 140, 140, 511, 510, 508, 508, 399, 398, 396, 396, 415, 414, 412, 412, 399, 398,
-    396, 396, 447, 446, 444, 444, 399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396, 255,
-    254, 252, 252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188, 188,
-    143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 1023, 1022, 1020, 1020, 911, 910,
-    908, 908, 927, 926, 924, 924, 911, 910, 908, 908, 959, 958, 956, 956, 911, 910, 908, 908, 927,
-    926, 924, 924, 911, 910, 908, 908, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158, 156, 156,
-    143, 142, 140, 140, 191, 190, 188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140,
-    140, 511, 510, 508, 508, 399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396, 447, 446,
-    444, 444, 399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396, 255, 254, 252, 252, 143,
-    142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142, 140, 140,
-    159, 158, 156, 156, 143, 142, 140, 140, 8191, 8190, 8188, 8188, 8079, 8078, 8076, 8076, 8095,
-    8094, 8092, 8092, 8079, 8078, 8076, 8076, 8127, 8126, 8124, 8124, 8079, 8078, 8076, 8076, 8095,
-    8094, 8092, 8092, 8079, 8078, 8076, 8076, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158,
-    156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143,
-    142, 140, 140, 511, 510, 508, 508, 399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396,
-    447, 446, 444, 444, 399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396, 255, 254, 252,
-    252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142,
-    140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 1023, 1022, 1020, 1020, 911, 910, 908, 908,
-    927, 926, 924, 924, 911, 910, 908, 908, 959, 958, 956, 956, 911, 910, 908, 908, 927, 926, 924,
-    924, 911, 910, 908, 908, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142,
-    140, 140, 191, 190, 188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 511,
-    510, 508, 508, 399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396, 447, 446, 444, 444,
-    399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396, 255, 254, 252, 252, 143, 142, 140,
-    140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142, 140, 140, 159, 158,
-    156, 156, 143, 142, 140, 140, 2047, 2046, 2044, 2044, 1935, 1934, 1932, 1932, 1951, 1950, 1948,
-    1948, 1935, 1934, 1932, 1932, 1983, 1982, 1980, 1980, 1935, 1934, 1932, 1932, 1951, 1950, 1948,
-    1948, 1935, 1934, 1932, 1932, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158, 156, 156, 143,
-    142, 140, 140, 191, 190, 188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140,
-    511, 510, 508, 508, 399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396, 447, 446, 444,
-    444, 399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396, 255, 254, 252, 252, 143, 142,
-    140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142, 140, 140, 159,
-    158, 156, 156, 143, 142, 140, 140, 1023, 1022, 1020, 1020, 911, 910, 908, 908, 927, 926, 924,
-    924, 911, 910, 908, 908, 959, 958, 956, 956, 911, 910, 908, 908, 927, 926, 924, 924, 911, 910,
-    908, 908, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191,
-    190, 188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 511, 510, 508, 508,
-    399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396, 447, 446, 444, 444, 399, 398, 396,
-    396, 415, 414, 412, 412, 399, 398, 396, 396, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158,
-    156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143,
-    142, 140, 140, 4095, 4094, 4092, 4092, 3983, 3982, 3980, 3980, 3999, 3998, 3996, 3996, 3983,
-    3982, 3980, 3980, 4031, 4030, 4028, 4028, 3983, 3982, 3980, 3980, 3999, 3998, 3996, 3996, 3983,
-    3982, 3980, 3980, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140,
-    140, 191, 190, 188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 511, 510,
-    508, 508, 399, 398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396, 447, 446, 444, 444, 399,
-    398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396, 255, 254, 252, 252, 143, 142, 140, 140,
-    159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142, 140, 140, 159, 158, 156,
-    156, 143, 142, 140, 140, 1023, 1022, 1020, 1020, 911, 910, 908, 908, 927, 926, 924, 924, 911,
-    910, 908, 908, 959, 958, 956, 956, 911, 910, 908, 908, 927, 926, 924, 924, 911, 910, 908, 908,
-    255, 254, 252, 252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188,
-    188, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 511, 510, 508, 508, 399, 398,
-    396, 396, 415, 414, 412, 412, 399, 398, 396, 396, 447, 446, 444, 444, 399, 398, 396, 396, 415,
-    414, 412, 412, 399, 398, 396, 396, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158, 156, 156,
-    143, 142, 140, 140, 191, 190, 188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140,
-    140, 2047, 2046, 2044, 2044, 1935, 1934, 1932, 1932, 1951, 1950, 1948, 1948, 1935, 1934, 1932,
-    1932, 1983, 1982, 1980, 1980, 1935, 1934, 1932, 1932, 1951, 1950, 1948, 1948, 1935, 1934, 1932,
-    1932, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190,
-    188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 511, 510, 508, 508, 399,
-    398, 396, 396, 415, 414, 412, 412, 399, 398, 396, 396, 447, 446, 444, 444, 399, 398, 396, 396,
-    415, 414, 412, 412, 399, 398, 396, 396, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158, 156,
-    156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142,
-    140, 140, 1023, 1022, 1020, 1020, 911, 910, 908, 908, 927, 926, 924, 924, 911, 910, 908, 908,
-    959, 958, 956, 956, 911, 910, 908, 908, 927, 926, 924, 924, 911, 910, 908, 908, 255, 254, 252,
-    252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 191, 190, 188, 188, 143, 142,
-    140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 511, 510, 508, 508, 399, 398, 396, 396, 415,
-    414, 412, 412, 399, 398, 396, 396, 447, 446, 444, 444, 399, 398, 396, 396, 415, 414, 412, 412,
-    399, 398, 396, 396, 255, 254, 252, 252, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140,
-    140, 191, 190, 188, 188, 143, 142, 140, 140, 159, 158, 156, 156, 143, 142, 140, 140, 16383,
-    16382, 16380, 16380, 16376, 16376, 16376, 16376, 16287, 16286, 16284, 16284, 16280, 16280,
-    16280, 16280, 16319, 16318, 16316, 16316, 16312, 16312, 16312, 16312, 16287, 16286, 16284,
-    16284, 16280, 16280, 16280, 16280, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156,
-    152, 152, 152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152,
-    152, 511, 510, 508, 508, 504, 504, 504, 504, 415, 414, 412, 412, 408, 408, 408, 408, 447, 446,
-    444, 444, 440, 440, 440, 440, 415, 414, 412, 412, 408, 408, 408, 408, 255, 254, 252, 252, 248,
-    248, 248, 248, 159, 158, 156, 156, 152, 152, 152, 152, 191, 190, 188, 188, 184, 184, 184, 184,
-    159, 158, 156, 156, 152, 152, 152, 152, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 927,
-    926, 924, 924, 920, 920, 920, 920, 959, 958, 956, 956, 952, 952, 952, 952, 927, 926, 924, 924,
-    920, 920, 920, 920, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152, 152,
-    152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 511, 510,
-    508, 508, 504, 504, 504, 504, 415, 414, 412, 412, 408, 408, 408, 408, 447, 446, 444, 444, 440,
-    440, 440, 440, 415, 414, 412, 412, 408, 408, 408, 408, 255, 254, 252, 252, 248, 248, 248, 248,
-    159, 158, 156, 156, 152, 152, 152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156,
-    156, 152, 152, 152, 152, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040, 1951, 1950, 1948,
-    1948, 1944, 1944, 1944, 1944, 1983, 1982, 1980, 1980, 1976, 1976, 1976, 1976, 1951, 1950, 1948,
-    1948, 1944, 1944, 1944, 1944, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152,
-    152, 152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152,
-    511, 510, 508, 508, 504, 504, 504, 504, 415, 414, 412, 412, 408, 408, 408, 408, 447, 446, 444,
-    444, 440, 440, 440, 440, 415, 414, 412, 412, 408, 408, 408, 408, 255, 254, 252, 252, 248, 248,
-    248, 248, 159, 158, 156, 156, 152, 152, 152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159,
-    158, 156, 156, 152, 152, 152, 152, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 927, 926,
-    924, 924, 920, 920, 920, 920, 959, 958, 956, 956, 952, 952, 952, 952, 927, 926, 924, 924, 920,
-    920, 920, 920, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152, 152, 152,
-    191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 511, 510, 508,
-    508, 504, 504, 504, 504, 415, 414, 412, 412, 408, 408, 408, 408, 447, 446, 444, 444, 440, 440,
-    440, 440, 415, 414, 412, 412, 408, 408, 408, 408, 255, 254, 252, 252, 248, 248, 248, 248, 159,
-    158, 156, 156, 152, 152, 152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156,
-    152, 152, 152, 152, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 3999, 3998, 3996, 3996,
-    3992, 3992, 3992, 3992, 4031, 4030, 4028, 4028, 4024, 4024, 4024, 4024, 3999, 3998, 3996, 3996,
-    3992, 3992, 3992, 3992, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152,
-    152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 511,
-    510, 508, 508, 504, 504, 504, 504, 415, 414, 412, 412, 408, 408, 408, 408, 447, 446, 444, 444,
-    440, 440, 440, 440, 415, 414, 412, 412, 408, 408, 408, 408, 255, 254, 252, 252, 248, 248, 248,
-    248, 159, 158, 156, 156, 152, 152, 152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158,
-    156, 156, 152, 152, 152, 152, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 927, 926, 924,
-    924, 920, 920, 920, 920, 959, 958, 956, 956, 952, 952, 952, 952, 927, 926, 924, 924, 920, 920,
-    920, 920, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152, 152, 152, 191,
-    190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 511, 510, 508, 508,
-    504, 504, 504, 504, 415, 414, 412, 412, 408, 408, 408, 408, 447, 446, 444, 444, 440, 440, 440,
-    440, 415, 414, 412, 412, 408, 408, 408, 408, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158,
-    156, 156, 152, 152, 152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152,
-    152, 152, 152, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040, 1951, 1950, 1948, 1948, 1944,
-    1944, 1944, 1944, 1983, 1982, 1980, 1980, 1976, 1976, 1976, 1976, 1951, 1950, 1948, 1948, 1944,
-    1944, 1944, 1944, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152, 152,
-    152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 511, 510,
-    508, 508, 504, 504, 504, 504, 415, 414, 412, 412, 408, 408, 408, 408, 447, 446, 444, 444, 440,
-    440, 440, 440, 415, 414, 412, 412, 408, 408, 408, 408, 255, 254, 252, 252, 248, 248, 248, 248,
-    159, 158, 156, 156, 152, 152, 152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156,
-    156, 152, 152, 152, 152, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 927, 926, 924, 924,
-    920, 920, 920, 920, 959, 958, 956, 956, 952, 952, 952, 952, 927, 926, 924, 924, 920, 920, 920,
-    920, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152, 152, 152, 191, 190,
-    188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 511, 510, 508, 508, 504,
-    504, 504, 504, 415, 414, 412, 412, 408, 408, 408, 408, 447, 446, 444, 444, 440, 440, 440, 440,
-    415, 414, 412, 412, 408, 408, 408, 408, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156,
-    156, 152, 152, 152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152,
-    152, 152, 8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 8095, 8094, 8092, 8092, 8088, 8088,
-    8088, 8088, 8127, 8126, 8124, 8124, 8120, 8120, 8120, 8120, 8095, 8094, 8092, 8092, 8088, 8088,
-    8088, 8088, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152, 152, 152,
-    191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 511, 510, 508,
-    508, 504, 504, 504, 504, 415, 414, 412, 412, 408, 408, 408, 408, 447, 446, 444, 444, 440, 440,
-    440, 440, 415, 414, 412, 412, 408, 408, 408, 408, 255, 254, 252, 252, 248, 248, 248, 248, 159,
-    158, 156, 156, 152, 152, 152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156,
-    152, 152, 152, 152, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 927, 926, 924, 924, 920,
-    920, 920, 920, 959, 958, 956, 956, 952, 952, 952, 952, 927, 926, 924, 924, 920, 920, 920, 920,
-    255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152, 152, 152, 191, 190, 188,
-    188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 511, 510, 508, 508, 504, 504,
-    504, 504, 415, 414, 412, 412, 408, 408, 408, 408, 447, 446, 444, 444, 440, 440, 440, 440, 415,
-    414, 412, 412, 408, 408, 408, 408, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156,
-    152, 152, 152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152,
-    152, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040, 1951, 1950, 1948, 1948, 1944, 1944, 1944,
-    1944, 1983, 1982, 1980, 1980, 1976, 1976, 1976, 1976, 1951, 1950, 1948, 1948, 1944, 1944, 1944,
-    1944, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152, 152, 152, 191, 190,
-    188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 511, 510, 508, 508, 504,
-    504, 504, 504, 415, 414, 412, 412, 408, 408, 408, 408, 447, 446, 444, 444, 440, 440, 440, 440,
-    415, 414, 412, 412, 408, 408, 408, 408, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156,
-    156, 152, 152, 152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152,
-    152, 152, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 927, 926, 924, 924, 920, 920, 920,
-    920, 959, 958, 956, 956, 952, 952, 952, 952, 927, 926, 924, 924, 920, 920, 920, 920, 255, 254,
-    252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152, 152, 152, 191, 190, 188, 188, 184,
-    184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 511, 510, 508, 508, 504, 504, 504, 504,
-    415, 414, 412, 412, 408, 408, 408, 408, 447, 446, 444, 444, 440, 440, 440, 440, 415, 414, 412,
-    412, 408, 408, 408, 408, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152,
-    152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 4095,
-    4094, 4092, 4092, 4088, 4088, 4088, 4088, 3999, 3998, 3996, 3996, 3992, 3992, 3992, 3992, 4031,
-    4030, 4028, 4028, 4024, 4024, 4024, 4024, 3999, 3998, 3996, 3996, 3992, 3992, 3992, 3992, 255,
-    254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152, 152, 152, 191, 190, 188, 188,
-    184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 511, 510, 508, 508, 504, 504, 504,
-    504, 415, 414, 412, 412, 408, 408, 408, 408, 447, 446, 444, 444, 440, 440, 440, 440, 415, 414,
-    412, 412, 408, 408, 408, 408, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152,
-    152, 152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152,
-    1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 927, 926, 924, 924, 920, 920, 920, 920, 959,
-    958, 956, 956, 952, 952, 952, 952, 927, 926, 924, 924, 920, 920, 920, 920, 255, 254, 252, 252,
-    248, 248, 248, 248, 159, 158, 156, 156, 152, 152, 152, 152, 191, 190, 188, 188, 184, 184, 184,
-    184, 159, 158, 156, 156, 152, 152, 152, 152, 511, 510, 508, 508, 504, 504, 504, 504, 415, 414,
-    412, 412, 408, 408, 408, 408, 447, 446, 444, 444, 440, 440, 440, 440, 415, 414, 412, 412, 408,
-    408, 408, 408, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152, 152, 152,
-    191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 2047, 2046,
-    2044, 2044, 2040, 2040, 2040, 2040, 1951, 1950, 1948, 1948, 1944, 1944, 1944, 1944, 1983, 1982,
-    1980, 1980, 1976, 1976, 1976, 1976, 1951, 1950, 1948, 1948, 1944, 1944, 1944, 1944, 255, 254,
-    252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152, 152, 152, 191, 190, 188, 188, 184,
-    184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 511, 510, 508, 508, 504, 504, 504, 504,
-    415, 414, 412, 412, 408, 408, 408, 408, 447, 446, 444, 444, 440, 440, 440, 440, 415, 414, 412,
-    412, 408, 408, 408, 408, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152,
-    152, 152, 191, 190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 1023,
-    1022, 1020, 1020, 1016, 1016, 1016, 1016, 927, 926, 924, 924, 920, 920, 920, 920, 959, 958,
-    956, 956, 952, 952, 952, 952, 927, 926, 924, 924, 920, 920, 920, 920, 255, 254, 252, 252, 248,
-    248, 248, 248, 159, 158, 156, 156, 152, 152, 152, 152, 191, 190, 188, 188, 184, 184, 184, 184,
-    159, 158, 156, 156, 152, 152, 152, 152, 511, 510, 508, 508, 504, 504, 504, 504, 415, 414, 412,
-    412, 408, 408, 408, 408, 447, 446, 444, 444, 440, 440, 440, 440, 415, 414, 412, 412, 408, 408,
-    408, 408, 255, 254, 252, 252, 248, 248, 248, 248, 159, 158, 156, 156, 152, 152, 152, 152, 191,
-    190, 188, 188, 184, 184, 184, 184, 159, 158, 156, 156, 152, 152, 152, 152, 16383, 16382, 16380,
-    16380, 16376, 16376, 16376, 16376, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16368,
-    16319, 16318, 16316, 16316, 16312, 16312, 16312, 16312, 16304, 16304, 16304, 16304, 16304,
-    16304, 16304, 16304, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240,
-    240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511, 510,
-    508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444, 440,
-    440, 440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248, 248,
-    240, 240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176,
-    176, 176, 176, 176, 176, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 959, 958, 956, 956, 952, 952, 952, 952, 944, 944, 944, 944, 944,
-    944, 944, 944, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240,
-    191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511, 510, 508,
-    508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444, 440, 440,
-    440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248, 248, 240,
-    240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176,
-    176, 176, 176, 176, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032, 2032,
-    2032, 2032, 2032, 2032, 1983, 1982, 1980, 1980, 1976, 1976, 1976, 1976, 1968, 1968, 1968, 1968,
-    1968, 1968, 1968, 1968, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240,
-    240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511,
-    510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444,
-    440, 440, 440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176,
-    176, 176, 176, 176, 176, 176, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 959, 958, 956, 956, 952, 952, 952, 952, 944, 944, 944, 944, 944,
-    944, 944, 944, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240,
-    191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511, 510, 508,
-    508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444, 440, 440,
-    440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248, 248, 240,
-    240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176,
-    176, 176, 176, 176, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4080, 4080, 4080, 4080,
-    4080, 4080, 4080, 4080, 4031, 4030, 4028, 4028, 4024, 4024, 4024, 4024, 4016, 4016, 4016, 4016,
-    4016, 4016, 4016, 4016, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240,
-    240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511,
-    510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444,
-    440, 440, 440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176,
-    176, 176, 176, 176, 176, 176, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 959, 958, 956, 956, 952, 952, 952, 952, 944, 944, 944, 944, 944,
-    944, 944, 944, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240,
-    191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511, 510, 508,
-    508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444, 440, 440,
-    440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248, 248, 240,
-    240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176,
-    176, 176, 176, 176, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032, 2032,
-    2032, 2032, 2032, 2032, 1983, 1982, 1980, 1980, 1976, 1976, 1976, 1976, 1968, 1968, 1968, 1968,
-    1968, 1968, 1968, 1968, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240,
-    240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511,
-    510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444,
-    440, 440, 440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176,
-    176, 176, 176, 176, 176, 176, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 959, 958, 956, 956, 952, 952, 952, 952, 944, 944, 944, 944, 944,
-    944, 944, 944, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240,
-    191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511, 510, 508,
-    508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444, 440, 440,
-    440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248, 248, 240,
-    240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176,
-    176, 176, 176, 176, 8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 8176, 8176, 8176, 8176,
-    8176, 8176, 8176, 8176, 8127, 8126, 8124, 8124, 8120, 8120, 8120, 8120, 8112, 8112, 8112, 8112,
-    8112, 8112, 8112, 8112, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240,
-    240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511,
-    510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444,
-    440, 440, 440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176,
-    176, 176, 176, 176, 176, 176, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 959, 958, 956, 956, 952, 952, 952, 952, 944, 944, 944, 944, 944,
-    944, 944, 944, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240,
-    191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511, 510, 508,
-    508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444, 440, 440,
-    440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248, 248, 240,
-    240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176,
-    176, 176, 176, 176, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032, 2032,
-    2032, 2032, 2032, 2032, 1983, 1982, 1980, 1980, 1976, 1976, 1976, 1976, 1968, 1968, 1968, 1968,
-    1968, 1968, 1968, 1968, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240,
-    240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511,
-    510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444,
-    440, 440, 440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176,
-    176, 176, 176, 176, 176, 176, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 959, 958, 956, 956, 952, 952, 952, 952, 944, 944, 944, 944, 944,
-    944, 944, 944, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240,
-    191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511, 510, 508,
-    508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444, 440, 440,
-    440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248, 248, 240,
-    240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176,
-    176, 176, 176, 176, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4080, 4080, 4080, 4080,
-    4080, 4080, 4080, 4080, 4031, 4030, 4028, 4028, 4024, 4024, 4024, 4024, 4016, 4016, 4016, 4016,
-    4016, 4016, 4016, 4016, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240,
-    240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511,
-    510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444,
-    440, 440, 440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176,
-    176, 176, 176, 176, 176, 176, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 959, 958, 956, 956, 952, 952, 952, 952, 944, 944, 944, 944, 944,
-    944, 944, 944, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240,
-    191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511, 510, 508,
-    508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444, 440, 440,
-    440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248, 248, 240,
-    240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176,
-    176, 176, 176, 176, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032, 2032,
-    2032, 2032, 2032, 2032, 1983, 1982, 1980, 1980, 1976, 1976, 1976, 1976, 1968, 1968, 1968, 1968,
-    1968, 1968, 1968, 1968, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240,
-    240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511,
-    510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444,
-    440, 440, 440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176,
-    176, 176, 176, 176, 176, 176, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 959, 958, 956, 956, 952, 952, 952, 952, 944, 944, 944, 944, 944,
-    944, 944, 944, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240,
-    191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176, 176, 176, 176, 176, 511, 510, 508,
-    508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 447, 446, 444, 444, 440, 440,
-    440, 440, 432, 432, 432, 432, 432, 432, 432, 432, 255, 254, 252, 252, 248, 248, 248, 248, 240,
-    240, 240, 240, 240, 240, 240, 240, 191, 190, 188, 188, 184, 184, 184, 184, 176, 176, 176, 176,
-    176, 176, 176, 176, 16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368,
-    16368, 16368, 16368, 16368, 16368, 16368, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 255, 254, 252, 252, 248, 248,
-    248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496,
-    496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 1023, 1022, 1020, 1020,
-    1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496,
-    496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 2047, 2046, 2044, 2044, 2040,
-    2040, 2040, 2040, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 255, 254, 252, 252, 248, 248,
-    248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496,
-    496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 1023, 1022, 1020, 1020,
-    1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496,
-    496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 4095, 4094, 4092, 4092, 4088,
-    4088, 4088, 4088, 4080, 4080, 4080, 4080, 4080, 4080, 4080, 4080, 4064, 4064, 4064, 4064, 4064,
-    4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 255, 254, 252, 252, 248, 248,
-    248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496,
-    496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 1023, 1022, 1020, 1020,
-    1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496,
-    496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 2047, 2046, 2044, 2044, 2040,
-    2040, 2040, 2040, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 255, 254, 252, 252, 248, 248,
-    248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496,
-    496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 1023, 1022, 1020, 1020,
-    1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496,
-    496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 8191, 8190, 8188, 8188, 8184,
-    8184, 8184, 8184, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8160, 8160, 8160, 8160, 8160,
-    8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 255, 254, 252, 252, 248, 248,
-    248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496,
-    496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 1023, 1022, 1020, 1020,
-    1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496,
-    496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 2047, 2046, 2044, 2044, 2040,
-    2040, 2040, 2040, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 255, 254, 252, 252, 248, 248,
-    248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496,
-    496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 1023, 1022, 1020, 1020,
-    1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496,
-    496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 4095, 4094, 4092, 4092, 4088,
-    4088, 4088, 4088, 4080, 4080, 4080, 4080, 4080, 4080, 4080, 4080, 4064, 4064, 4064, 4064, 4064,
-    4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 255, 254, 252, 252, 248, 248,
-    248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496,
-    496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 1023, 1022, 1020, 1020,
-    1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496,
-    496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 2047, 2046, 2044, 2044, 2040,
-    2040, 2040, 2040, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 255, 254, 252, 252, 248, 248,
-    248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496,
-    496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 1023, 1022, 1020, 1020,
-    1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496,
-    496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 16383, 16382, 16380, 16380,
-    16376, 16376, 16376, 16376, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16352,
-    16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16352, 16352, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 255, 254, 252, 252, 248, 248, 248, 248,
-    240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511,
-    510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240,
-    240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 1023, 1022, 1020, 1020, 1016,
-    1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240,
-    240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504,
-    496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255,
-    254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032,
-    2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240,
-    240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504,
-    504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008,
-    1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496,
-    496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255, 254, 252, 252, 248,
-    248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4080, 4080, 4080, 4080, 4080, 4080,
-    4080, 4080, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064,
-    4064, 4064, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504, 496,
-    496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255, 254,
-    252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 255, 254, 252,
-    252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496,
-    496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255, 254, 252, 252, 248, 248, 248, 248,
-    240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 2047,
-    2046, 2044, 2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 255,
-    254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496,
-    496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255, 254, 252, 252, 248, 248,
-    248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008,
-    1008, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 255, 254, 252, 252, 248, 248, 248, 248,
-    240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511,
-    510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240,
-    240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 8191, 8190, 8188, 8188, 8184,
-    8184, 8184, 8184, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8160, 8160, 8160, 8160, 8160,
-    8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8128, 8128, 8128, 8128, 8128,
-    8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128,
-    8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 255, 254, 252, 252, 248, 248,
-    248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240,
-    240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 1023, 1022, 1020,
-    1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240,
-    240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504,
-    504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040,
-    2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 255, 254, 252, 252, 248, 248, 248, 248, 240,
-    240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510,
-    508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240,
-    240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 1023, 1022, 1020, 1020, 1016, 1016,
-    1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504, 496,
-    496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255, 254,
-    252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4080, 4080, 4080,
-    4080, 4080, 4080, 4080, 4080, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064,
-    4064, 4064, 4064, 4064, 4064, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240,
-    240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504,
-    504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008,
-    1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496,
-    496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255, 254, 252, 252, 248,
-    248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032, 2032, 2032, 2032,
-    2032, 2032, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504, 496,
-    496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255, 254,
-    252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 255, 254, 252,
-    252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496,
-    496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255, 254, 252, 252, 248, 248, 248, 248,
-    240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    16383, 16131, 16135, 16131, 16143, 16131, 16135, 16131, 16159, 16131, 16135, 16131, 16143,
-    16131, 16135, 16131, 16191, 16131, 16135, 16131, 16143, 16131, 16135, 16131, 16159, 16131,
-    16135, 16131, 16143, 16131, 16135, 16131, 16255, 16131, 16135, 16131, 16143, 16131, 16135,
-    16131, 16159, 16131, 16135, 16131, 16143, 16131, 16135, 16131, 16191, 16131, 16135, 16131,
-    16143, 16131, 16135, 16131, 16159, 16131, 16135, 16131, 16143, 16131, 16135, 16131, 511, 259,
-    263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271,
-    259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 383, 259, 263, 259, 271, 259, 263, 259,
-    287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263,
-    259, 271, 259, 263, 259, 1023, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771,
-    775, 771, 831, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 895,
-    771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 831, 771, 775, 771,
-    783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 511, 259, 263, 259, 271, 259, 263,
-    259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259,
-    263, 259, 271, 259, 263, 259, 383, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271,
-    259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259,
-    2047, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 1823, 1795, 1799, 1795, 1807, 1795, 1799, 1795,
-    1855, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 1823, 1795, 1799, 1795, 1807, 1795, 1799, 1795,
-    1919, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 1823, 1795, 1799, 1795, 1807, 1795, 1799, 1795,
-    1855, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 1823, 1795, 1799, 1795, 1807, 1795, 1799, 1795,
-    511, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263,
-    259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 383, 259, 263, 259, 271, 259,
-    263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287,
-    259, 263, 259, 271, 259, 263, 259, 1023, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771,
-    783, 771, 775, 771, 831, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775,
-    771, 895, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 831, 771,
-    775, 771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 511, 259, 263, 259, 271,
-    259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259,
-    287, 259, 263, 259, 271, 259, 263, 259, 383, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263,
-    259, 271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259,
-    263, 259, 4095, 3843, 3847, 3843, 3855, 3843, 3847, 3843, 3871, 3843, 3847, 3843, 3855, 3843,
-    3847, 3843, 3903, 3843, 3847, 3843, 3855, 3843, 3847, 3843, 3871, 3843, 3847, 3843, 3855, 3843,
-    3847, 3843, 3967, 3843, 3847, 3843, 3855, 3843, 3847, 3843, 3871, 3843, 3847, 3843, 3855, 3843,
-    3847, 3843, 3903, 3843, 3847, 3843, 3855, 3843, 3847, 3843, 3871, 3843, 3847, 3843, 3855, 3843,
-    3847, 3843, 511, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259,
-    319, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 383, 259, 263,
-    259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271, 259,
-    263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 1023, 771, 775, 771, 783, 771, 775, 771, 799,
-    771, 775, 771, 783, 771, 775, 771, 831, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771,
-    783, 771, 775, 771, 895, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775,
-    771, 831, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 511, 259,
-    263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271,
-    259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 383, 259, 263, 259, 271, 259, 263, 259,
-    287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263,
-    259, 271, 259, 263, 259, 2047, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 1823, 1795, 1799,
-    1795, 1807, 1795, 1799, 1795, 1855, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 1823, 1795, 1799,
-    1795, 1807, 1795, 1799, 1795, 1919, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 1823, 1795, 1799,
-    1795, 1807, 1795, 1799, 1795, 1855, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 1823, 1795, 1799,
-    1795, 1807, 1795, 1799, 1795, 511, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271,
-    259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259,
-    383, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263,
-    259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 1023, 771, 775, 771, 783, 771,
-    775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 831, 771, 775, 771, 783, 771, 775, 771, 799,
-    771, 775, 771, 783, 771, 775, 771, 895, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771,
-    783, 771, 775, 771, 831, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775,
-    771, 511, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259,
-    263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 383, 259, 263, 259, 271,
-    259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259,
-    287, 259, 263, 259, 271, 259, 263, 259, 8191, 7939, 7943, 7939, 7951, 7939, 7943, 7939, 7967,
-    7939, 7943, 7939, 7951, 7939, 7943, 7939, 7999, 7939, 7943, 7939, 7951, 7939, 7943, 7939, 7967,
-    7939, 7943, 7939, 7951, 7939, 7943, 7939, 8063, 7939, 7943, 7939, 7951, 7939, 7943, 7939, 7967,
-    7939, 7943, 7939, 7951, 7939, 7943, 7939, 7999, 7939, 7943, 7939, 7951, 7939, 7943, 7939, 7967,
-    7939, 7943, 7939, 7951, 7939, 7943, 7939, 511, 259, 263, 259, 271, 259, 263, 259, 287, 259,
-    263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271,
-    259, 263, 259, 383, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259,
-    319, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 1023, 771, 775,
-    771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 831, 771, 775, 771, 783, 771,
-    775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 895, 771, 775, 771, 783, 771, 775, 771, 799,
-    771, 775, 771, 783, 771, 775, 771, 831, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771,
-    783, 771, 775, 771, 511, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263,
-    259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 383, 259,
-    263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271,
-    259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 2047, 1795, 1799, 1795, 1807, 1795,
-    1799, 1795, 1823, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 1855, 1795, 1799, 1795, 1807, 1795,
-    1799, 1795, 1823, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 1919, 1795, 1799, 1795, 1807, 1795,
-    1799, 1795, 1823, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 1855, 1795, 1799, 1795, 1807, 1795,
-    1799, 1795, 1823, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 511, 259, 263, 259, 271, 259, 263,
-    259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259,
-    263, 259, 271, 259, 263, 259, 383, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271,
-    259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259,
-    1023, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 831, 771, 775,
-    771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 895, 771, 775, 771, 783, 771,
-    775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 831, 771, 775, 771, 783, 771, 775, 771, 799,
-    771, 775, 771, 783, 771, 775, 771, 511, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259,
-    271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263,
-    259, 383, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259,
-    263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 4095, 3843, 3847, 3843,
-    3855, 3843, 3847, 3843, 3871, 3843, 3847, 3843, 3855, 3843, 3847, 3843, 3903, 3843, 3847, 3843,
-    3855, 3843, 3847, 3843, 3871, 3843, 3847, 3843, 3855, 3843, 3847, 3843, 3967, 3843, 3847, 3843,
-    3855, 3843, 3847, 3843, 3871, 3843, 3847, 3843, 3855, 3843, 3847, 3843, 3903, 3843, 3847, 3843,
-    3855, 3843, 3847, 3843, 3871, 3843, 3847, 3843, 3855, 3843, 3847, 3843, 511, 259, 263, 259,
-    271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263,
-    259, 287, 259, 263, 259, 271, 259, 263, 259, 383, 259, 263, 259, 271, 259, 263, 259, 287, 259,
-    263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271,
-    259, 263, 259, 1023, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775, 771,
-    831, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 895, 771, 775,
-    771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 831, 771, 775, 771, 783, 771,
-    775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 511, 259, 263, 259, 271, 259, 263, 259, 287,
-    259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259,
-    271, 259, 263, 259, 383, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263,
-    259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 2047,
-    1795, 1799, 1795, 1807, 1795, 1799, 1795, 1823, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 1855,
-    1795, 1799, 1795, 1807, 1795, 1799, 1795, 1823, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 1919,
-    1795, 1799, 1795, 1807, 1795, 1799, 1795, 1823, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 1855,
-    1795, 1799, 1795, 1807, 1795, 1799, 1795, 1823, 1795, 1799, 1795, 1807, 1795, 1799, 1795, 511,
-    259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259,
-    271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 383, 259, 263, 259, 271, 259, 263,
-    259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259,
-    263, 259, 271, 259, 263, 259, 1023, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771, 783,
-    771, 775, 771, 831, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775, 771,
-    895, 771, 775, 771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 831, 771, 775,
-    771, 783, 771, 775, 771, 799, 771, 775, 771, 783, 771, 775, 771, 511, 259, 263, 259, 271, 259,
-    263, 259, 287, 259, 263, 259, 271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287,
-    259, 263, 259, 271, 259, 263, 259, 383, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259,
-    271, 259, 263, 259, 319, 259, 263, 259, 271, 259, 263, 259, 287, 259, 263, 259, 271, 259, 263,
-    259, 16383, 16135, 16143, 16135, 16159, 16135, 16143, 16135, 16191, 16135, 16143, 16135, 16159,
-    16135, 16143, 16135, 16255, 16135, 16143, 16135, 16159, 16135, 16143, 16135, 16191, 16135,
-    16143, 16135, 16159, 16135, 16143, 16135, 511, 263, 271, 263, 287, 263, 271, 263, 319, 263,
-    271, 263, 287, 263, 271, 263, 383, 263, 271, 263, 287, 263, 271, 263, 319, 263, 271, 263, 287,
-    263, 271, 263, 1023, 775, 783, 775, 799, 775, 783, 775, 831, 775, 783, 775, 799, 775, 783, 775,
-    895, 775, 783, 775, 799, 775, 783, 775, 831, 775, 783, 775, 799, 775, 783, 775, 511, 263, 271,
-    263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 383, 263, 271, 263, 287, 263,
-    271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 2047, 1799, 1807, 1799, 1823, 1799, 1807,
-    1799, 1855, 1799, 1807, 1799, 1823, 1799, 1807, 1799, 1919, 1799, 1807, 1799, 1823, 1799, 1807,
-    1799, 1855, 1799, 1807, 1799, 1823, 1799, 1807, 1799, 511, 263, 271, 263, 287, 263, 271, 263,
-    319, 263, 271, 263, 287, 263, 271, 263, 383, 263, 271, 263, 287, 263, 271, 263, 319, 263, 271,
-    263, 287, 263, 271, 263, 1023, 775, 783, 775, 799, 775, 783, 775, 831, 775, 783, 775, 799, 775,
-    783, 775, 895, 775, 783, 775, 799, 775, 783, 775, 831, 775, 783, 775, 799, 775, 783, 775, 511,
-    263, 271, 263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 383, 263, 271, 263,
-    287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 4095, 3847, 3855, 3847, 3871, 3847,
-    3855, 3847, 3903, 3847, 3855, 3847, 3871, 3847, 3855, 3847, 3967, 3847, 3855, 3847, 3871, 3847,
-    3855, 3847, 3903, 3847, 3855, 3847, 3871, 3847, 3855, 3847, 511, 263, 271, 263, 287, 263, 271,
-    263, 319, 263, 271, 263, 287, 263, 271, 263, 383, 263, 271, 263, 287, 263, 271, 263, 319, 263,
-    271, 263, 287, 263, 271, 263, 1023, 775, 783, 775, 799, 775, 783, 775, 831, 775, 783, 775, 799,
-    775, 783, 775, 895, 775, 783, 775, 799, 775, 783, 775, 831, 775, 783, 775, 799, 775, 783, 775,
-    511, 263, 271, 263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 383, 263, 271,
-    263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 2047, 1799, 1807, 1799, 1823,
-    1799, 1807, 1799, 1855, 1799, 1807, 1799, 1823, 1799, 1807, 1799, 1919, 1799, 1807, 1799, 1823,
-    1799, 1807, 1799, 1855, 1799, 1807, 1799, 1823, 1799, 1807, 1799, 511, 263, 271, 263, 287, 263,
-    271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 383, 263, 271, 263, 287, 263, 271, 263, 319,
-    263, 271, 263, 287, 263, 271, 263, 1023, 775, 783, 775, 799, 775, 783, 775, 831, 775, 783, 775,
-    799, 775, 783, 775, 895, 775, 783, 775, 799, 775, 783, 775, 831, 775, 783, 775, 799, 775, 783,
-    775, 511, 263, 271, 263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 383, 263,
-    271, 263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 8191, 7943, 7951, 7943,
-    7967, 7943, 7951, 7943, 7999, 7943, 7951, 7943, 7967, 7943, 7951, 7943, 8063, 7943, 7951, 7943,
-    7967, 7943, 7951, 7943, 7999, 7943, 7951, 7943, 7967, 7943, 7951, 7943, 511, 263, 271, 263,
-    287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 383, 263, 271, 263, 287, 263, 271,
-    263, 319, 263, 271, 263, 287, 263, 271, 263, 1023, 775, 783, 775, 799, 775, 783, 775, 831, 775,
-    783, 775, 799, 775, 783, 775, 895, 775, 783, 775, 799, 775, 783, 775, 831, 775, 783, 775, 799,
-    775, 783, 775, 511, 263, 271, 263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263,
-    383, 263, 271, 263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 2047, 1799,
-    1807, 1799, 1823, 1799, 1807, 1799, 1855, 1799, 1807, 1799, 1823, 1799, 1807, 1799, 1919, 1799,
-    1807, 1799, 1823, 1799, 1807, 1799, 1855, 1799, 1807, 1799, 1823, 1799, 1807, 1799, 511, 263,
-    271, 263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 383, 263, 271, 263, 287,
-    263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 1023, 775, 783, 775, 799, 775, 783, 775,
-    831, 775, 783, 775, 799, 775, 783, 775, 895, 775, 783, 775, 799, 775, 783, 775, 831, 775, 783,
-    775, 799, 775, 783, 775, 511, 263, 271, 263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263,
-    271, 263, 383, 263, 271, 263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 4095,
-    3847, 3855, 3847, 3871, 3847, 3855, 3847, 3903, 3847, 3855, 3847, 3871, 3847, 3855, 3847, 3967,
-    3847, 3855, 3847, 3871, 3847, 3855, 3847, 3903, 3847, 3855, 3847, 3871, 3847, 3855, 3847, 511,
-    263, 271, 263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 383, 263, 271, 263,
-    287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 1023, 775, 783, 775, 799, 775, 783,
-    775, 831, 775, 783, 775, 799, 775, 783, 775, 895, 775, 783, 775, 799, 775, 783, 775, 831, 775,
-    783, 775, 799, 775, 783, 775, 511, 263, 271, 263, 287, 263, 271, 263, 319, 263, 271, 263, 287,
-    263, 271, 263, 383, 263, 271, 263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263,
-    2047, 1799, 1807, 1799, 1823, 1799, 1807, 1799, 1855, 1799, 1807, 1799, 1823, 1799, 1807, 1799,
-    1919, 1799, 1807, 1799, 1823, 1799, 1807, 1799, 1855, 1799, 1807, 1799, 1823, 1799, 1807, 1799,
-    511, 263, 271, 263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 383, 263, 271,
-    263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271, 263, 1023, 775, 783, 775, 799, 775,
-    783, 775, 831, 775, 783, 775, 799, 775, 783, 775, 895, 775, 783, 775, 799, 775, 783, 775, 831,
-    775, 783, 775, 799, 775, 783, 775, 511, 263, 271, 263, 287, 263, 271, 263, 319, 263, 271, 263,
-    287, 263, 271, 263, 383, 263, 271, 263, 287, 263, 271, 263, 319, 263, 271, 263, 287, 263, 271,
-    263, 16383, 16381, 16143, 16141, 16159, 16157, 16143, 16141, 16191, 16189, 16143, 16141, 16159,
-    16157, 16143, 16141, 16255, 16253, 16143, 16141, 16159, 16157, 16143, 16141, 16191, 16189,
-    16143, 16141, 16159, 16157, 16143, 16141, 511, 509, 271, 269, 287, 285, 271, 269, 319, 317,
-    271, 269, 287, 285, 271, 269, 383, 381, 271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287,
-    285, 271, 269, 1023, 1021, 783, 781, 799, 797, 783, 781, 831, 829, 783, 781, 799, 797, 783,
-    781, 895, 893, 783, 781, 799, 797, 783, 781, 831, 829, 783, 781, 799, 797, 783, 781, 511, 509,
-    271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287, 285, 271, 269, 383, 381, 271, 269, 287,
-    285, 271, 269, 319, 317, 271, 269, 287, 285, 271, 269, 2047, 2045, 1807, 1805, 1823, 1821,
-    1807, 1805, 1855, 1853, 1807, 1805, 1823, 1821, 1807, 1805, 1919, 1917, 1807, 1805, 1823, 1821,
-    1807, 1805, 1855, 1853, 1807, 1805, 1823, 1821, 1807, 1805, 511, 509, 271, 269, 287, 285, 271,
-    269, 319, 317, 271, 269, 287, 285, 271, 269, 383, 381, 271, 269, 287, 285, 271, 269, 319, 317,
-    271, 269, 287, 285, 271, 269, 1023, 1021, 783, 781, 799, 797, 783, 781, 831, 829, 783, 781,
-    799, 797, 783, 781, 895, 893, 783, 781, 799, 797, 783, 781, 831, 829, 783, 781, 799, 797, 783,
-    781, 511, 509, 271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287, 285, 271, 269, 383, 381,
-    271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287, 285, 271, 269, 4095, 4093, 3855, 3853,
-    3871, 3869, 3855, 3853, 3903, 3901, 3855, 3853, 3871, 3869, 3855, 3853, 3967, 3965, 3855, 3853,
-    3871, 3869, 3855, 3853, 3903, 3901, 3855, 3853, 3871, 3869, 3855, 3853, 511, 509, 271, 269,
-    287, 285, 271, 269, 319, 317, 271, 269, 287, 285, 271, 269, 383, 381, 271, 269, 287, 285, 271,
-    269, 319, 317, 271, 269, 287, 285, 271, 269, 1023, 1021, 783, 781, 799, 797, 783, 781, 831,
-    829, 783, 781, 799, 797, 783, 781, 895, 893, 783, 781, 799, 797, 783, 781, 831, 829, 783, 781,
-    799, 797, 783, 781, 511, 509, 271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287, 285, 271,
-    269, 383, 381, 271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287, 285, 271, 269, 2047,
-    2045, 1807, 1805, 1823, 1821, 1807, 1805, 1855, 1853, 1807, 1805, 1823, 1821, 1807, 1805, 1919,
-    1917, 1807, 1805, 1823, 1821, 1807, 1805, 1855, 1853, 1807, 1805, 1823, 1821, 1807, 1805, 511,
-    509, 271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287, 285, 271, 269, 383, 381, 271, 269,
-    287, 285, 271, 269, 319, 317, 271, 269, 287, 285, 271, 269, 1023, 1021, 783, 781, 799, 797,
-    783, 781, 831, 829, 783, 781, 799, 797, 783, 781, 895, 893, 783, 781, 799, 797, 783, 781, 831,
-    829, 783, 781, 799, 797, 783, 781, 511, 509, 271, 269, 287, 285, 271, 269, 319, 317, 271, 269,
-    287, 285, 271, 269, 383, 381, 271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287, 285, 271,
-    269, 8191, 8189, 7951, 7949, 7967, 7965, 7951, 7949, 7999, 7997, 7951, 7949, 7967, 7965, 7951,
-    7949, 8063, 8061, 7951, 7949, 7967, 7965, 7951, 7949, 7999, 7997, 7951, 7949, 7967, 7965, 7951,
-    7949, 511, 509, 271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287, 285, 271, 269, 383, 381,
-    271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287, 285, 271, 269, 1023, 1021, 783, 781,
-    799, 797, 783, 781, 831, 829, 783, 781, 799, 797, 783, 781, 895, 893, 783, 781, 799, 797, 783,
-    781, 831, 829, 783, 781, 799, 797, 783, 781, 511, 509, 271, 269, 287, 285, 271, 269, 319, 317,
-    271, 269, 287, 285, 271, 269, 383, 381, 271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287,
-    285, 271, 269, 2047, 2045, 1807, 1805, 1823, 1821, 1807, 1805, 1855, 1853, 1807, 1805, 1823,
-    1821, 1807, 1805, 1919, 1917, 1807, 1805, 1823, 1821, 1807, 1805, 1855, 1853, 1807, 1805, 1823,
-    1821, 1807, 1805, 511, 509, 271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287, 285, 271,
-    269, 383, 381, 271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287, 285, 271, 269, 1023,
-    1021, 783, 781, 799, 797, 783, 781, 831, 829, 783, 781, 799, 797, 783, 781, 895, 893, 783, 781,
-    799, 797, 783, 781, 831, 829, 783, 781, 799, 797, 783, 781, 511, 509, 271, 269, 287, 285, 271,
-    269, 319, 317, 271, 269, 287, 285, 271, 269, 383, 381, 271, 269, 287, 285, 271, 269, 319, 317,
-    271, 269, 287, 285, 271, 269, 4095, 4093, 3855, 3853, 3871, 3869, 3855, 3853, 3903, 3901, 3855,
-    3853, 3871, 3869, 3855, 3853, 3967, 3965, 3855, 3853, 3871, 3869, 3855, 3853, 3903, 3901, 3855,
-    3853, 3871, 3869, 3855, 3853, 511, 509, 271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287,
-    285, 271, 269, 383, 381, 271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287, 285, 271, 269,
-    1023, 1021, 783, 781, 799, 797, 783, 781, 831, 829, 783, 781, 799, 797, 783, 781, 895, 893,
-    783, 781, 799, 797, 783, 781, 831, 829, 783, 781, 799, 797, 783, 781, 511, 509, 271, 269, 287,
-    285, 271, 269, 319, 317, 271, 269, 287, 285, 271, 269, 383, 381, 271, 269, 287, 285, 271, 269,
-    319, 317, 271, 269, 287, 285, 271, 269, 2047, 2045, 1807, 1805, 1823, 1821, 1807, 1805, 1855,
-    1853, 1807, 1805, 1823, 1821, 1807, 1805, 1919, 1917, 1807, 1805, 1823, 1821, 1807, 1805, 1855,
-    1853, 1807, 1805, 1823, 1821, 1807, 1805, 511, 509, 271, 269, 287, 285, 271, 269, 319, 317,
-    271, 269, 287, 285, 271, 269, 383, 381, 271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287,
-    285, 271, 269, 1023, 1021, 783, 781, 799, 797, 783, 781, 831, 829, 783, 781, 799, 797, 783,
-    781, 895, 893, 783, 781, 799, 797, 783, 781, 831, 829, 783, 781, 799, 797, 783, 781, 511, 509,
-    271, 269, 287, 285, 271, 269, 319, 317, 271, 269, 287, 285, 271, 269, 383, 381, 271, 269, 287,
-    285, 271, 269, 319, 317, 271, 269, 287, 285, 271, 269, 16383, 16381, 16377, 16377, 16159,
-    16157, 16153, 16153, 16191, 16189, 16185, 16185, 16159, 16157, 16153, 16153, 16255, 16253,
-    16249, 16249, 16159, 16157, 16153, 16153, 16191, 16189, 16185, 16185, 16159, 16157, 16153,
-    16153, 511, 509, 505, 505, 287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281, 281, 383,
-    381, 377, 377, 287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281, 281, 1023, 1021, 1017,
-    1017, 799, 797, 793, 793, 831, 829, 825, 825, 799, 797, 793, 793, 895, 893, 889, 889, 799, 797,
-    793, 793, 831, 829, 825, 825, 799, 797, 793, 793, 511, 509, 505, 505, 287, 285, 281, 281, 319,
-    317, 313, 313, 287, 285, 281, 281, 383, 381, 377, 377, 287, 285, 281, 281, 319, 317, 313, 313,
-    287, 285, 281, 281, 2047, 2045, 2041, 2041, 1823, 1821, 1817, 1817, 1855, 1853, 1849, 1849,
-    1823, 1821, 1817, 1817, 1919, 1917, 1913, 1913, 1823, 1821, 1817, 1817, 1855, 1853, 1849, 1849,
-    1823, 1821, 1817, 1817, 511, 509, 505, 505, 287, 285, 281, 281, 319, 317, 313, 313, 287, 285,
-    281, 281, 383, 381, 377, 377, 287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281, 281, 1023,
-    1021, 1017, 1017, 799, 797, 793, 793, 831, 829, 825, 825, 799, 797, 793, 793, 895, 893, 889,
-    889, 799, 797, 793, 793, 831, 829, 825, 825, 799, 797, 793, 793, 511, 509, 505, 505, 287, 285,
-    281, 281, 319, 317, 313, 313, 287, 285, 281, 281, 383, 381, 377, 377, 287, 285, 281, 281, 319,
-    317, 313, 313, 287, 285, 281, 281, 4095, 4093, 4089, 4089, 3871, 3869, 3865, 3865, 3903, 3901,
-    3897, 3897, 3871, 3869, 3865, 3865, 3967, 3965, 3961, 3961, 3871, 3869, 3865, 3865, 3903, 3901,
-    3897, 3897, 3871, 3869, 3865, 3865, 511, 509, 505, 505, 287, 285, 281, 281, 319, 317, 313, 313,
-    287, 285, 281, 281, 383, 381, 377, 377, 287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281,
-    281, 1023, 1021, 1017, 1017, 799, 797, 793, 793, 831, 829, 825, 825, 799, 797, 793, 793, 895,
-    893, 889, 889, 799, 797, 793, 793, 831, 829, 825, 825, 799, 797, 793, 793, 511, 509, 505, 505,
-    287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281, 281, 383, 381, 377, 377, 287, 285, 281,
-    281, 319, 317, 313, 313, 287, 285, 281, 281, 2047, 2045, 2041, 2041, 1823, 1821, 1817, 1817,
-    1855, 1853, 1849, 1849, 1823, 1821, 1817, 1817, 1919, 1917, 1913, 1913, 1823, 1821, 1817, 1817,
-    1855, 1853, 1849, 1849, 1823, 1821, 1817, 1817, 511, 509, 505, 505, 287, 285, 281, 281, 319,
-    317, 313, 313, 287, 285, 281, 281, 383, 381, 377, 377, 287, 285, 281, 281, 319, 317, 313, 313,
-    287, 285, 281, 281, 1023, 1021, 1017, 1017, 799, 797, 793, 793, 831, 829, 825, 825, 799, 797,
-    793, 793, 895, 893, 889, 889, 799, 797, 793, 793, 831, 829, 825, 825, 799, 797, 793, 793, 511,
-    509, 505, 505, 287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281, 281, 383, 381, 377, 377,
-    287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281, 281, 8191, 8189, 8185, 8185, 7967, 7965,
-    7961, 7961, 7999, 7997, 7993, 7993, 7967, 7965, 7961, 7961, 8063, 8061, 8057, 8057, 7967, 7965,
-    7961, 7961, 7999, 7997, 7993, 7993, 7967, 7965, 7961, 7961, 511, 509, 505, 505, 287, 285, 281,
-    281, 319, 317, 313, 313, 287, 285, 281, 281, 383, 381, 377, 377, 287, 285, 281, 281, 319, 317,
-    313, 313, 287, 285, 281, 281, 1023, 1021, 1017, 1017, 799, 797, 793, 793, 831, 829, 825, 825,
-    799, 797, 793, 793, 895, 893, 889, 889, 799, 797, 793, 793, 831, 829, 825, 825, 799, 797, 793,
-    793, 511, 509, 505, 505, 287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281, 281, 383, 381,
-    377, 377, 287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281, 281, 2047, 2045, 2041, 2041,
-    1823, 1821, 1817, 1817, 1855, 1853, 1849, 1849, 1823, 1821, 1817, 1817, 1919, 1917, 1913, 1913,
-    1823, 1821, 1817, 1817, 1855, 1853, 1849, 1849, 1823, 1821, 1817, 1817, 511, 509, 505, 505,
-    287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281, 281, 383, 381, 377, 377, 287, 285, 281,
-    281, 319, 317, 313, 313, 287, 285, 281, 281, 1023, 1021, 1017, 1017, 799, 797, 793, 793, 831,
-    829, 825, 825, 799, 797, 793, 793, 895, 893, 889, 889, 799, 797, 793, 793, 831, 829, 825, 825,
-    799, 797, 793, 793, 511, 509, 505, 505, 287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281,
-    281, 383, 381, 377, 377, 287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281, 281, 4095,
-    4093, 4089, 4089, 3871, 3869, 3865, 3865, 3903, 3901, 3897, 3897, 3871, 3869, 3865, 3865, 3967,
-    3965, 3961, 3961, 3871, 3869, 3865, 3865, 3903, 3901, 3897, 3897, 3871, 3869, 3865, 3865, 511,
-    509, 505, 505, 287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281, 281, 383, 381, 377, 377,
-    287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281, 281, 1023, 1021, 1017, 1017, 799, 797,
-    793, 793, 831, 829, 825, 825, 799, 797, 793, 793, 895, 893, 889, 889, 799, 797, 793, 793, 831,
-    829, 825, 825, 799, 797, 793, 793, 511, 509, 505, 505, 287, 285, 281, 281, 319, 317, 313, 313,
-    287, 285, 281, 281, 383, 381, 377, 377, 287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281,
-    281, 2047, 2045, 2041, 2041, 1823, 1821, 1817, 1817, 1855, 1853, 1849, 1849, 1823, 1821, 1817,
-    1817, 1919, 1917, 1913, 1913, 1823, 1821, 1817, 1817, 1855, 1853, 1849, 1849, 1823, 1821, 1817,
-    1817, 511, 509, 505, 505, 287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281, 281, 383, 381,
-    377, 377, 287, 285, 281, 281, 319, 317, 313, 313, 287, 285, 281, 281, 1023, 1021, 1017, 1017,
-    799, 797, 793, 793, 831, 829, 825, 825, 799, 797, 793, 793, 895, 893, 889, 889, 799, 797, 793,
-    793, 831, 829, 825, 825, 799, 797, 793, 793, 511, 509, 505, 505, 287, 285, 281, 281, 319, 317,
-    313, 313, 287, 285, 281, 281, 383, 381, 377, 377, 287, 285, 281, 281, 319, 317, 313, 313, 287,
-    285, 281, 281, 16383, 16381, 16377, 16377, 16369, 16369, 16369, 16369, 16191, 16189, 16185,
-    16185, 16177, 16177, 16177, 16177, 16255, 16253, 16249, 16249, 16241, 16241, 16241, 16241,
-    16191, 16189, 16185, 16185, 16177, 16177, 16177, 16177, 511, 509, 505, 505, 497, 497, 497, 497,
-    319, 317, 313, 313, 305, 305, 305, 305, 383, 381, 377, 377, 369, 369, 369, 369, 319, 317, 313,
-    313, 305, 305, 305, 305, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 831, 829, 825, 825,
-    817, 817, 817, 817, 895, 893, 889, 889, 881, 881, 881, 881, 831, 829, 825, 825, 817, 817, 817,
-    817, 511, 509, 505, 505, 497, 497, 497, 497, 319, 317, 313, 313, 305, 305, 305, 305, 383, 381,
-    377, 377, 369, 369, 369, 369, 319, 317, 313, 313, 305, 305, 305, 305, 2047, 2045, 2041, 2041,
-    2033, 2033, 2033, 2033, 1855, 1853, 1849, 1849, 1841, 1841, 1841, 1841, 1919, 1917, 1913, 1913,
-    1905, 1905, 1905, 1905, 1855, 1853, 1849, 1849, 1841, 1841, 1841, 1841, 511, 509, 505, 505,
-    497, 497, 497, 497, 319, 317, 313, 313, 305, 305, 305, 305, 383, 381, 377, 377, 369, 369, 369,
-    369, 319, 317, 313, 313, 305, 305, 305, 305, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009,
-    831, 829, 825, 825, 817, 817, 817, 817, 895, 893, 889, 889, 881, 881, 881, 881, 831, 829, 825,
-    825, 817, 817, 817, 817, 511, 509, 505, 505, 497, 497, 497, 497, 319, 317, 313, 313, 305, 305,
-    305, 305, 383, 381, 377, 377, 369, 369, 369, 369, 319, 317, 313, 313, 305, 305, 305, 305, 4095,
-    4093, 4089, 4089, 4081, 4081, 4081, 4081, 3903, 3901, 3897, 3897, 3889, 3889, 3889, 3889, 3967,
-    3965, 3961, 3961, 3953, 3953, 3953, 3953, 3903, 3901, 3897, 3897, 3889, 3889, 3889, 3889, 511,
-    509, 505, 505, 497, 497, 497, 497, 319, 317, 313, 313, 305, 305, 305, 305, 383, 381, 377, 377,
-    369, 369, 369, 369, 319, 317, 313, 313, 305, 305, 305, 305, 1023, 1021, 1017, 1017, 1009, 1009,
-    1009, 1009, 831, 829, 825, 825, 817, 817, 817, 817, 895, 893, 889, 889, 881, 881, 881, 881,
-    831, 829, 825, 825, 817, 817, 817, 817, 511, 509, 505, 505, 497, 497, 497, 497, 319, 317, 313,
-    313, 305, 305, 305, 305, 383, 381, 377, 377, 369, 369, 369, 369, 319, 317, 313, 313, 305, 305,
-    305, 305, 2047, 2045, 2041, 2041, 2033, 2033, 2033, 2033, 1855, 1853, 1849, 1849, 1841, 1841,
-    1841, 1841, 1919, 1917, 1913, 1913, 1905, 1905, 1905, 1905, 1855, 1853, 1849, 1849, 1841, 1841,
-    1841, 1841, 511, 509, 505, 505, 497, 497, 497, 497, 319, 317, 313, 313, 305, 305, 305, 305,
-    383, 381, 377, 377, 369, 369, 369, 369, 319, 317, 313, 313, 305, 305, 305, 305, 1023, 1021,
-    1017, 1017, 1009, 1009, 1009, 1009, 831, 829, 825, 825, 817, 817, 817, 817, 895, 893, 889, 889,
-    881, 881, 881, 881, 831, 829, 825, 825, 817, 817, 817, 817, 511, 509, 505, 505, 497, 497, 497,
-    497, 319, 317, 313, 313, 305, 305, 305, 305, 383, 381, 377, 377, 369, 369, 369, 369, 319, 317,
-    313, 313, 305, 305, 305, 305, 8191, 8189, 8185, 8185, 8177, 8177, 8177, 8177, 7999, 7997, 7993,
-    7993, 7985, 7985, 7985, 7985, 8063, 8061, 8057, 8057, 8049, 8049, 8049, 8049, 7999, 7997, 7993,
-    7993, 7985, 7985, 7985, 7985, 511, 509, 505, 505, 497, 497, 497, 497, 319, 317, 313, 313, 305,
-    305, 305, 305, 383, 381, 377, 377, 369, 369, 369, 369, 319, 317, 313, 313, 305, 305, 305, 305,
-    1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 831, 829, 825, 825, 817, 817, 817, 817, 895,
-    893, 889, 889, 881, 881, 881, 881, 831, 829, 825, 825, 817, 817, 817, 817, 511, 509, 505, 505,
-    497, 497, 497, 497, 319, 317, 313, 313, 305, 305, 305, 305, 383, 381, 377, 377, 369, 369, 369,
-    369, 319, 317, 313, 313, 305, 305, 305, 305, 2047, 2045, 2041, 2041, 2033, 2033, 2033, 2033,
-    1855, 1853, 1849, 1849, 1841, 1841, 1841, 1841, 1919, 1917, 1913, 1913, 1905, 1905, 1905, 1905,
-    1855, 1853, 1849, 1849, 1841, 1841, 1841, 1841, 511, 509, 505, 505, 497, 497, 497, 497, 319,
-    317, 313, 313, 305, 305, 305, 305, 383, 381, 377, 377, 369, 369, 369, 369, 319, 317, 313, 313,
-    305, 305, 305, 305, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 831, 829, 825, 825, 817,
-    817, 817, 817, 895, 893, 889, 889, 881, 881, 881, 881, 831, 829, 825, 825, 817, 817, 817, 817,
-    511, 509, 505, 505, 497, 497, 497, 497, 319, 317, 313, 313, 305, 305, 305, 305, 383, 381, 377,
-    377, 369, 369, 369, 369, 319, 317, 313, 313, 305, 305, 305, 305, 4095, 4093, 4089, 4089, 4081,
-    4081, 4081, 4081, 3903, 3901, 3897, 3897, 3889, 3889, 3889, 3889, 3967, 3965, 3961, 3961, 3953,
-    3953, 3953, 3953, 3903, 3901, 3897, 3897, 3889, 3889, 3889, 3889, 511, 509, 505, 505, 497, 497,
-    497, 497, 319, 317, 313, 313, 305, 305, 305, 305, 383, 381, 377, 377, 369, 369, 369, 369, 319,
-    317, 313, 313, 305, 305, 305, 305, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 831, 829,
-    825, 825, 817, 817, 817, 817, 895, 893, 889, 889, 881, 881, 881, 881, 831, 829, 825, 825, 817,
-    817, 817, 817, 511, 509, 505, 505, 497, 497, 497, 497, 319, 317, 313, 313, 305, 305, 305, 305,
-    383, 381, 377, 377, 369, 369, 369, 369, 319, 317, 313, 313, 305, 305, 305, 305, 2047, 2045,
-    2041, 2041, 2033, 2033, 2033, 2033, 1855, 1853, 1849, 1849, 1841, 1841, 1841, 1841, 1919, 1917,
-    1913, 1913, 1905, 1905, 1905, 1905, 1855, 1853, 1849, 1849, 1841, 1841, 1841, 1841, 511, 509,
-    505, 505, 497, 497, 497, 497, 319, 317, 313, 313, 305, 305, 305, 305, 383, 381, 377, 377, 369,
-    369, 369, 369, 319, 317, 313, 313, 305, 305, 305, 305, 1023, 1021, 1017, 1017, 1009, 1009,
-    1009, 1009, 831, 829, 825, 825, 817, 817, 817, 817, 895, 893, 889, 889, 881, 881, 881, 881,
-    831, 829, 825, 825, 817, 817, 817, 817, 511, 509, 505, 505, 497, 497, 497, 497, 319, 317, 313,
-    313, 305, 305, 305, 305, 383, 381, 377, 377, 369, 369, 369, 369, 319, 317, 313, 313, 305, 305,
-    305, 305, 16383, 16381, 16377, 16377, 16369, 16369, 16369, 16369, 16353, 16353, 16353, 16353,
-    16353, 16353, 16353, 16353, 16255, 16253, 16249, 16249, 16241, 16241, 16241, 16241, 16225,
-    16225, 16225, 16225, 16225, 16225, 16225, 16225, 511, 509, 505, 505, 497, 497, 497, 497, 481,
-    481, 481, 481, 481, 481, 481, 481, 383, 381, 377, 377, 369, 369, 369, 369, 353, 353, 353, 353,
-    353, 353, 353, 353, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 993, 993, 993, 993, 993,
-    993, 993, 993, 895, 893, 889, 889, 881, 881, 881, 881, 865, 865, 865, 865, 865, 865, 865, 865,
-    511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 383, 381, 377,
-    377, 369, 369, 369, 369, 353, 353, 353, 353, 353, 353, 353, 353, 2047, 2045, 2041, 2041, 2033,
-    2033, 2033, 2033, 2017, 2017, 2017, 2017, 2017, 2017, 2017, 2017, 1919, 1917, 1913, 1913, 1905,
-    1905, 1905, 1905, 1889, 1889, 1889, 1889, 1889, 1889, 1889, 1889, 511, 509, 505, 505, 497, 497,
-    497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 383, 381, 377, 377, 369, 369, 369, 369, 353,
-    353, 353, 353, 353, 353, 353, 353, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 993, 993,
-    993, 993, 993, 993, 993, 993, 895, 893, 889, 889, 881, 881, 881, 881, 865, 865, 865, 865, 865,
-    865, 865, 865, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481,
-    383, 381, 377, 377, 369, 369, 369, 369, 353, 353, 353, 353, 353, 353, 353, 353, 4095, 4093,
-    4089, 4089, 4081, 4081, 4081, 4081, 4065, 4065, 4065, 4065, 4065, 4065, 4065, 4065, 3967, 3965,
-    3961, 3961, 3953, 3953, 3953, 3953, 3937, 3937, 3937, 3937, 3937, 3937, 3937, 3937, 511, 509,
-    505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 383, 381, 377, 377, 369,
-    369, 369, 369, 353, 353, 353, 353, 353, 353, 353, 353, 1023, 1021, 1017, 1017, 1009, 1009,
-    1009, 1009, 993, 993, 993, 993, 993, 993, 993, 993, 895, 893, 889, 889, 881, 881, 881, 881,
-    865, 865, 865, 865, 865, 865, 865, 865, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481,
-    481, 481, 481, 481, 481, 383, 381, 377, 377, 369, 369, 369, 369, 353, 353, 353, 353, 353, 353,
-    353, 353, 2047, 2045, 2041, 2041, 2033, 2033, 2033, 2033, 2017, 2017, 2017, 2017, 2017, 2017,
-    2017, 2017, 1919, 1917, 1913, 1913, 1905, 1905, 1905, 1905, 1889, 1889, 1889, 1889, 1889, 1889,
-    1889, 1889, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481,
-    383, 381, 377, 377, 369, 369, 369, 369, 353, 353, 353, 353, 353, 353, 353, 353, 1023, 1021,
-    1017, 1017, 1009, 1009, 1009, 1009, 993, 993, 993, 993, 993, 993, 993, 993, 895, 893, 889, 889,
-    881, 881, 881, 881, 865, 865, 865, 865, 865, 865, 865, 865, 511, 509, 505, 505, 497, 497, 497,
-    497, 481, 481, 481, 481, 481, 481, 481, 481, 383, 381, 377, 377, 369, 369, 369, 369, 353, 353,
-    353, 353, 353, 353, 353, 353, 8191, 8189, 8185, 8185, 8177, 8177, 8177, 8177, 8161, 8161, 8161,
-    8161, 8161, 8161, 8161, 8161, 8063, 8061, 8057, 8057, 8049, 8049, 8049, 8049, 8033, 8033, 8033,
-    8033, 8033, 8033, 8033, 8033, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481,
-    481, 481, 481, 383, 381, 377, 377, 369, 369, 369, 369, 353, 353, 353, 353, 353, 353, 353, 353,
-    1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 993, 993, 993, 993, 993, 993, 993, 993, 895,
-    893, 889, 889, 881, 881, 881, 881, 865, 865, 865, 865, 865, 865, 865, 865, 511, 509, 505, 505,
-    497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 383, 381, 377, 377, 369, 369, 369,
-    369, 353, 353, 353, 353, 353, 353, 353, 353, 2047, 2045, 2041, 2041, 2033, 2033, 2033, 2033,
-    2017, 2017, 2017, 2017, 2017, 2017, 2017, 2017, 1919, 1917, 1913, 1913, 1905, 1905, 1905, 1905,
-    1889, 1889, 1889, 1889, 1889, 1889, 1889, 1889, 511, 509, 505, 505, 497, 497, 497, 497, 481,
-    481, 481, 481, 481, 481, 481, 481, 383, 381, 377, 377, 369, 369, 369, 369, 353, 353, 353, 353,
-    353, 353, 353, 353, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 993, 993, 993, 993, 993,
-    993, 993, 993, 895, 893, 889, 889, 881, 881, 881, 881, 865, 865, 865, 865, 865, 865, 865, 865,
-    511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 383, 381, 377,
-    377, 369, 369, 369, 369, 353, 353, 353, 353, 353, 353, 353, 353, 4095, 4093, 4089, 4089, 4081,
-    4081, 4081, 4081, 4065, 4065, 4065, 4065, 4065, 4065, 4065, 4065, 3967, 3965, 3961, 3961, 3953,
-    3953, 3953, 3953, 3937, 3937, 3937, 3937, 3937, 3937, 3937, 3937, 511, 509, 505, 505, 497, 497,
-    497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 383, 381, 377, 377, 369, 369, 369, 369, 353,
-    353, 353, 353, 353, 353, 353, 353, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 993, 993,
-    993, 993, 993, 993, 993, 993, 895, 893, 889, 889, 881, 881, 881, 881, 865, 865, 865, 865, 865,
-    865, 865, 865, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481,
-    383, 381, 377, 377, 369, 369, 369, 369, 353, 353, 353, 353, 353, 353, 353, 353, 2047, 2045,
-    2041, 2041, 2033, 2033, 2033, 2033, 2017, 2017, 2017, 2017, 2017, 2017, 2017, 2017, 1919, 1917,
-    1913, 1913, 1905, 1905, 1905, 1905, 1889, 1889, 1889, 1889, 1889, 1889, 1889, 1889, 511, 509,
-    505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 383, 381, 377, 377, 369,
-    369, 369, 369, 353, 353, 353, 353, 353, 353, 353, 353, 1023, 1021, 1017, 1017, 1009, 1009,
-    1009, 1009, 993, 993, 993, 993, 993, 993, 993, 993, 895, 893, 889, 889, 881, 881, 881, 881,
-    865, 865, 865, 865, 865, 865, 865, 865, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481,
-    481, 481, 481, 481, 481, 383, 381, 377, 377, 369, 369, 369, 369, 353, 353, 353, 353, 353, 353,
-    353, 353, 16383, 16381, 16377, 16377, 16369, 16369, 16369, 16369, 16353, 16353, 16353, 16353,
-    16353, 16353, 16353, 16353, 16321, 16321, 16321, 16321, 16321, 16321, 16321, 16321, 16321,
-    16321, 16321, 16321, 16321, 16321, 16321, 16321, 511, 509, 505, 505, 497, 497, 497, 497, 481,
-    481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 993, 993, 993, 993, 993,
-    993, 993, 993, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961,
-    511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 2047, 2045, 2041, 2041, 2033,
-    2033, 2033, 2033, 2017, 2017, 2017, 2017, 2017, 2017, 2017, 2017, 1985, 1985, 1985, 1985, 1985,
-    1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 511, 509, 505, 505, 497, 497,
-    497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 449, 449, 449, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 993, 993,
-    993, 993, 993, 993, 993, 993, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961,
-    961, 961, 961, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 4095, 4093,
-    4089, 4089, 4081, 4081, 4081, 4081, 4065, 4065, 4065, 4065, 4065, 4065, 4065, 4065, 4033, 4033,
-    4033, 4033, 4033, 4033, 4033, 4033, 4033, 4033, 4033, 4033, 4033, 4033, 4033, 4033, 511, 509,
-    505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 1023, 1021, 1017, 1017, 1009, 1009,
-    1009, 1009, 993, 993, 993, 993, 993, 993, 993, 993, 961, 961, 961, 961, 961, 961, 961, 961,
-    961, 961, 961, 961, 961, 961, 961, 961, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481,
-    481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449,
-    449, 449, 2047, 2045, 2041, 2041, 2033, 2033, 2033, 2033, 2017, 2017, 2017, 2017, 2017, 2017,
-    2017, 2017, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985,
-    1985, 1985, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 1023, 1021,
-    1017, 1017, 1009, 1009, 1009, 1009, 993, 993, 993, 993, 993, 993, 993, 993, 961, 961, 961, 961,
-    961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 511, 509, 505, 505, 497, 497, 497,
-    497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 449, 449, 8191, 8189, 8185, 8185, 8177, 8177, 8177, 8177, 8161, 8161, 8161,
-    8161, 8161, 8161, 8161, 8161, 8129, 8129, 8129, 8129, 8129, 8129, 8129, 8129, 8129, 8129, 8129,
-    8129, 8129, 8129, 8129, 8129, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481,
-    481, 481, 481, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449,
-    1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 993, 993, 993, 993, 993, 993, 993, 993, 961,
-    961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 511, 509, 505, 505,
-    497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 2047, 2045, 2041, 2041, 2033, 2033, 2033, 2033,
-    2017, 2017, 2017, 2017, 2017, 2017, 2017, 2017, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985,
-    1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 511, 509, 505, 505, 497, 497, 497, 497, 481,
-    481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 993, 993, 993, 993, 993,
-    993, 993, 993, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961,
-    511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 4095, 4093, 4089, 4089, 4081,
-    4081, 4081, 4081, 4065, 4065, 4065, 4065, 4065, 4065, 4065, 4065, 4033, 4033, 4033, 4033, 4033,
-    4033, 4033, 4033, 4033, 4033, 4033, 4033, 4033, 4033, 4033, 4033, 511, 509, 505, 505, 497, 497,
-    497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 449, 449, 449, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 993, 993,
-    993, 993, 993, 993, 993, 993, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961,
-    961, 961, 961, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 2047, 2045,
-    2041, 2041, 2033, 2033, 2033, 2033, 2017, 2017, 2017, 2017, 2017, 2017, 2017, 2017, 1985, 1985,
-    1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 511, 509,
-    505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 1023, 1021, 1017, 1017, 1009, 1009,
-    1009, 1009, 993, 993, 993, 993, 993, 993, 993, 993, 961, 961, 961, 961, 961, 961, 961, 961,
-    961, 961, 961, 961, 961, 961, 961, 961, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481,
-    481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449,
-    449, 449, 16383, 16381, 16377, 16377, 16369, 16369, 16369, 16369, 16353, 16353, 16353, 16353,
-    16353, 16353, 16353, 16353, 16321, 16321, 16321, 16321, 16321, 16321, 16321, 16321, 16321,
-    16321, 16321, 16321, 16321, 16321, 16321, 16321, 16257, 16257, 16257, 16257, 16257, 16257,
-    16257, 16257, 16257, 16257, 16257, 16257, 16257, 16257, 16257, 16257, 16257, 16257, 16257,
-    16257, 16257, 16257, 16257, 16257, 16257, 16257, 16257, 16257, 16257, 16257, 16257, 16257, 511,
-    509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 993, 993, 993,
-    993, 993, 993, 993, 993, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961,
-    961, 961, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897,
-    897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 511, 509, 505, 505,
-    497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 2047, 2045, 2041, 2041, 2033, 2033, 2033, 2033, 2017, 2017, 2017, 2017, 2017,
-    2017, 2017, 2017, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985,
-    1985, 1985, 1985, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921,
-    1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921,
-    1921, 1921, 1921, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481,
-    481, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 1023, 1021, 1017, 1017, 1009, 1009,
-    1009, 1009, 993, 993, 993, 993, 993, 993, 993, 993, 961, 961, 961, 961, 961, 961, 961, 961,
-    961, 961, 961, 961, 961, 961, 961, 961, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897,
-    897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897,
-    897, 897, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 4095, 4093, 4089, 4089, 4081, 4081, 4081, 4081,
-    4065, 4065, 4065, 4065, 4065, 4065, 4065, 4065, 4033, 4033, 4033, 4033, 4033, 4033, 4033, 4033,
-    4033, 4033, 4033, 4033, 4033, 4033, 4033, 4033, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969,
-    3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969,
-    3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 511, 509, 505, 505, 497, 497, 497, 497, 481,
-    481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 1023,
-    1021, 1017, 1017, 1009, 1009, 1009, 1009, 993, 993, 993, 993, 993, 993, 993, 993, 961, 961,
-    961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 897, 897, 897, 897, 897,
-    897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897,
-    897, 897, 897, 897, 897, 897, 897, 897, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481,
-    481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449,
-    449, 449, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 2047, 2045, 2041,
-    2041, 2033, 2033, 2033, 2033, 2017, 2017, 2017, 2017, 2017, 2017, 2017, 2017, 1985, 1985, 1985,
-    1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1921, 1921, 1921,
-    1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921,
-    1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 511, 509, 505,
-    505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 993, 993, 993, 993, 993,
-    993, 993, 993, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961,
-    897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897,
-    897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 511, 509, 505, 505, 497, 497,
-    497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 449, 449, 449, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 8191, 8189, 8185, 8185, 8177, 8177, 8177, 8177, 8161, 8161, 8161, 8161, 8161, 8161, 8161,
-    8161, 8129, 8129, 8129, 8129, 8129, 8129, 8129, 8129, 8129, 8129, 8129, 8129, 8129, 8129, 8129,
-    8129, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065,
-    8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065, 8065,
-    8065, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 993,
-    993, 993, 993, 993, 993, 993, 993, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961,
-    961, 961, 961, 961, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897,
-    897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 511, 509,
-    505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 2047, 2045, 2041, 2041, 2033, 2033, 2033, 2033, 2017, 2017, 2017,
-    2017, 2017, 2017, 2017, 2017, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985,
-    1985, 1985, 1985, 1985, 1985, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921,
-    1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921,
-    1921, 1921, 1921, 1921, 1921, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481,
-    481, 481, 481, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 1023, 1021, 1017, 1017, 1009,
-    1009, 1009, 1009, 993, 993, 993, 993, 993, 993, 993, 993, 961, 961, 961, 961, 961, 961, 961,
-    961, 961, 961, 961, 961, 961, 961, 961, 961, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897,
-    897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897,
-    897, 897, 897, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 4095, 4093, 4089, 4089, 4081, 4081, 4081,
-    4081, 4065, 4065, 4065, 4065, 4065, 4065, 4065, 4065, 4033, 4033, 4033, 4033, 4033, 4033, 4033,
-    4033, 4033, 4033, 4033, 4033, 4033, 4033, 4033, 4033, 3969, 3969, 3969, 3969, 3969, 3969, 3969,
-    3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969,
-    3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 3969, 511, 509, 505, 505, 497, 497, 497, 497,
-    481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 449, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 1023,
-    1021, 1017, 1017, 1009, 1009, 1009, 1009, 993, 993, 993, 993, 993, 993, 993, 993, 961, 961,
-    961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 897, 897, 897, 897, 897,
-    897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897,
-    897, 897, 897, 897, 897, 897, 897, 897, 511, 509, 505, 505, 497, 497, 497, 497, 481, 481, 481,
-    481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 449,
-    449, 449, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 2047, 2045, 2041,
-    2041, 2033, 2033, 2033, 2033, 2017, 2017, 2017, 2017, 2017, 2017, 2017, 2017, 1985, 1985, 1985,
-    1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1985, 1921, 1921, 1921,
-    1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921,
-    1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 511, 509, 505,
-    505, 497, 497, 497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 449, 449, 449, 449, 449, 449, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 1023, 1021, 1017, 1017, 1009, 1009, 1009, 1009, 993, 993, 993, 993, 993,
-    993, 993, 993, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961, 961,
-    897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897,
-    897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 897, 511, 509, 505, 505, 497, 497,
-    497, 497, 481, 481, 481, 481, 481, 481, 481, 481, 449, 449, 449, 449, 449, 449, 449, 449, 449,
-    449, 449, 449, 449, 449, 449, 449, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385, 385,
-    385, 16383, 16382, 15879, 15878, 15887, 15886, 15879, 15878, 15903, 15902, 15879, 15878, 15887,
-    15886, 15879, 15878, 15935, 15934, 15879, 15878, 15887, 15886, 15879, 15878, 15903, 15902,
-    15879, 15878, 15887, 15886, 15879, 15878, 15999, 15998, 15879, 15878, 15887, 15886, 15879,
-    15878, 15903, 15902, 15879, 15878, 15887, 15886, 15879, 15878, 15935, 15934, 15879, 15878,
-    15887, 15886, 15879, 15878, 15903, 15902, 15879, 15878, 15887, 15886, 15879, 15878, 16127,
-    16126, 15879, 15878, 15887, 15886, 15879, 15878, 15903, 15902, 15879, 15878, 15887, 15886,
-    15879, 15878, 15935, 15934, 15879, 15878, 15887, 15886, 15879, 15878, 15903, 15902, 15879,
-    15878, 15887, 15886, 15879, 15878, 15999, 15998, 15879, 15878, 15887, 15886, 15879, 15878,
-    15903, 15902, 15879, 15878, 15887, 15886, 15879, 15878, 15935, 15934, 15879, 15878, 15887,
-    15886, 15879, 15878, 15903, 15902, 15879, 15878, 15887, 15886, 15879, 15878, 1023, 1022, 519,
-    518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526,
-    519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 639, 638, 519, 518, 527, 526, 519, 518, 543,
-    542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518,
-    527, 526, 519, 518, 767, 766, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519,
-    518, 575, 574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 639, 638,
-    519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527,
-    526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 2047, 2046, 1543, 1542, 1551, 1550,
-    1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1599, 1598, 1543, 1542, 1551, 1550,
-    1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1663, 1662, 1543, 1542, 1551, 1550,
-    1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1599, 1598, 1543, 1542, 1551, 1550,
-    1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1791, 1790, 1543, 1542, 1551, 1550,
-    1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1599, 1598, 1543, 1542, 1551, 1550,
-    1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1663, 1662, 1543, 1542, 1551, 1550,
-    1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1599, 1598, 1543, 1542, 1551, 1550,
-    1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1023, 1022, 519, 518, 527, 526,
-    519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518, 543,
-    542, 519, 518, 527, 526, 519, 518, 639, 638, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518,
-    527, 526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519,
-    518, 767, 766, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574,
-    519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 639, 638, 519, 518, 527,
-    526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518,
-    543, 542, 519, 518, 527, 526, 519, 518, 4095, 4094, 3591, 3590, 3599, 3598, 3591, 3590, 3615,
-    3614, 3591, 3590, 3599, 3598, 3591, 3590, 3647, 3646, 3591, 3590, 3599, 3598, 3591, 3590, 3615,
-    3614, 3591, 3590, 3599, 3598, 3591, 3590, 3711, 3710, 3591, 3590, 3599, 3598, 3591, 3590, 3615,
-    3614, 3591, 3590, 3599, 3598, 3591, 3590, 3647, 3646, 3591, 3590, 3599, 3598, 3591, 3590, 3615,
-    3614, 3591, 3590, 3599, 3598, 3591, 3590, 3839, 3838, 3591, 3590, 3599, 3598, 3591, 3590, 3615,
-    3614, 3591, 3590, 3599, 3598, 3591, 3590, 3647, 3646, 3591, 3590, 3599, 3598, 3591, 3590, 3615,
-    3614, 3591, 3590, 3599, 3598, 3591, 3590, 3711, 3710, 3591, 3590, 3599, 3598, 3591, 3590, 3615,
-    3614, 3591, 3590, 3599, 3598, 3591, 3590, 3647, 3646, 3591, 3590, 3599, 3598, 3591, 3590, 3615,
-    3614, 3591, 3590, 3599, 3598, 3591, 3590, 1023, 1022, 519, 518, 527, 526, 519, 518, 543, 542,
-    519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527,
-    526, 519, 518, 639, 638, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518,
-    575, 574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 767, 766, 519,
-    518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526,
-    519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 639, 638, 519, 518, 527, 526, 519, 518, 543,
-    542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518,
-    527, 526, 519, 518, 2047, 2046, 1543, 1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542,
-    1551, 1550, 1543, 1542, 1599, 1598, 1543, 1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542,
-    1551, 1550, 1543, 1542, 1663, 1662, 1543, 1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542,
-    1551, 1550, 1543, 1542, 1599, 1598, 1543, 1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542,
-    1551, 1550, 1543, 1542, 1791, 1790, 1543, 1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542,
-    1551, 1550, 1543, 1542, 1599, 1598, 1543, 1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542,
-    1551, 1550, 1543, 1542, 1663, 1662, 1543, 1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542,
-    1551, 1550, 1543, 1542, 1599, 1598, 1543, 1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542,
-    1551, 1550, 1543, 1542, 1023, 1022, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526,
-    519, 518, 575, 574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 639,
-    638, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518,
-    527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 767, 766, 519, 518, 527, 526, 519,
-    518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518, 543, 542,
-    519, 518, 527, 526, 519, 518, 639, 638, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527,
-    526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518,
-    8191, 8190, 7687, 7686, 7695, 7694, 7687, 7686, 7711, 7710, 7687, 7686, 7695, 7694, 7687, 7686,
-    7743, 7742, 7687, 7686, 7695, 7694, 7687, 7686, 7711, 7710, 7687, 7686, 7695, 7694, 7687, 7686,
-    7807, 7806, 7687, 7686, 7695, 7694, 7687, 7686, 7711, 7710, 7687, 7686, 7695, 7694, 7687, 7686,
-    7743, 7742, 7687, 7686, 7695, 7694, 7687, 7686, 7711, 7710, 7687, 7686, 7695, 7694, 7687, 7686,
-    7935, 7934, 7687, 7686, 7695, 7694, 7687, 7686, 7711, 7710, 7687, 7686, 7695, 7694, 7687, 7686,
-    7743, 7742, 7687, 7686, 7695, 7694, 7687, 7686, 7711, 7710, 7687, 7686, 7695, 7694, 7687, 7686,
-    7807, 7806, 7687, 7686, 7695, 7694, 7687, 7686, 7711, 7710, 7687, 7686, 7695, 7694, 7687, 7686,
-    7743, 7742, 7687, 7686, 7695, 7694, 7687, 7686, 7711, 7710, 7687, 7686, 7695, 7694, 7687, 7686,
-    1023, 1022, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574,
-    519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 639, 638, 519, 518, 527,
-    526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518,
-    543, 542, 519, 518, 527, 526, 519, 518, 767, 766, 519, 518, 527, 526, 519, 518, 543, 542, 519,
-    518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526,
-    519, 518, 639, 638, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575,
-    574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 2047, 2046, 1543,
-    1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1599, 1598, 1543,
-    1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1663, 1662, 1543,
-    1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1599, 1598, 1543,
-    1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1791, 1790, 1543,
-    1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1599, 1598, 1543,
-    1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1663, 1662, 1543,
-    1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1599, 1598, 1543,
-    1542, 1551, 1550, 1543, 1542, 1567, 1566, 1543, 1542, 1551, 1550, 1543, 1542, 1023, 1022, 519,
-    518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526,
-    519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 639, 638, 519, 518, 527, 526, 519, 518, 543,
-    542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518,
-    527, 526, 519, 518, 767, 766, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519,
-    518, 575, 574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 639, 638,
-    519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527,
-    526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 4095, 4094, 3591, 3590, 3599, 3598,
-    3591, 3590, 3615, 3614, 3591, 3590, 3599, 3598, 3591, 3590, 3647, 3646, 3591, 3590, 3599, 3598,
-    3591, 3590, 3615, 3614, 3591, 3590, 3599, 3598, 3591, 3590, 3711, 3710, 3591, 3590, 3599, 3598,
-    3591, 3590, 3615, 3614, 3591, 3590, 3599, 3598, 3591, 3590, 3647, 3646, 3591, 3590, 3599, 3598,
-    3591, 3590, 3615, 3614, 3591, 3590, 3599, 3598, 3591, 3590, 3839, 3838, 3591, 3590, 3599, 3598,
-    3591, 3590, 3615, 3614, 3591, 3590, 3599, 3598, 3591, 3590, 3647, 3646, 3591, 3590, 3599, 3598,
-    3591, 3590, 3615, 3614, 3591, 3590, 3599, 3598, 3591, 3590, 3711, 3710, 3591, 3590, 3599, 3598,
-    3591, 3590, 3615, 3614, 3591, 3590, 3599, 3598, 3591, 3590, 3647, 3646, 3591, 3590, 3599, 3598,
-    3591, 3590, 3615, 3614, 3591, 3590, 3599, 3598, 3591, 3590, 1023, 1022, 519, 518, 527, 526,
-    519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518, 543,
-    542, 519, 518, 527, 526, 519, 518, 639, 638, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518,
-    527, 526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519,
-    518, 767, 766, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574,
-    519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 639, 638, 519, 518, 527,
-    526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518,
-    543, 542, 519, 518, 527, 526, 519, 518, 2047, 2046, 1543, 1542, 1551, 1550, 1543, 1542, 1567,
-    1566, 1543, 1542, 1551, 1550, 1543, 1542, 1599, 1598, 1543, 1542, 1551, 1550, 1543, 1542, 1567,
-    1566, 1543, 1542, 1551, 1550, 1543, 1542, 1663, 1662, 1543, 1542, 1551, 1550, 1543, 1542, 1567,
-    1566, 1543, 1542, 1551, 1550, 1543, 1542, 1599, 1598, 1543, 1542, 1551, 1550, 1543, 1542, 1567,
-    1566, 1543, 1542, 1551, 1550, 1543, 1542, 1791, 1790, 1543, 1542, 1551, 1550, 1543, 1542, 1567,
-    1566, 1543, 1542, 1551, 1550, 1543, 1542, 1599, 1598, 1543, 1542, 1551, 1550, 1543, 1542, 1567,
-    1566, 1543, 1542, 1551, 1550, 1543, 1542, 1663, 1662, 1543, 1542, 1551, 1550, 1543, 1542, 1567,
-    1566, 1543, 1542, 1551, 1550, 1543, 1542, 1599, 1598, 1543, 1542, 1551, 1550, 1543, 1542, 1567,
-    1566, 1543, 1542, 1551, 1550, 1543, 1542, 1023, 1022, 519, 518, 527, 526, 519, 518, 543, 542,
-    519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527,
-    526, 519, 518, 639, 638, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518,
-    575, 574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 767, 766, 519,
-    518, 527, 526, 519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526,
-    519, 518, 543, 542, 519, 518, 527, 526, 519, 518, 639, 638, 519, 518, 527, 526, 519, 518, 543,
-    542, 519, 518, 527, 526, 519, 518, 575, 574, 519, 518, 527, 526, 519, 518, 543, 542, 519, 518,
-    527, 526, 519, 518, 16383, 16382, 15887, 15886, 15903, 15902, 15887, 15886, 15935, 15934,
-    15887, 15886, 15903, 15902, 15887, 15886, 15999, 15998, 15887, 15886, 15903, 15902, 15887,
-    15886, 15935, 15934, 15887, 15886, 15903, 15902, 15887, 15886, 16127, 16126, 15887, 15886,
-    15903, 15902, 15887, 15886, 15935, 15934, 15887, 15886, 15903, 15902, 15887, 15886, 15999,
-    15998, 15887, 15886, 15903, 15902, 15887, 15886, 15935, 15934, 15887, 15886, 15903, 15902,
-    15887, 15886, 1023, 1022, 527, 526, 543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527, 526,
-    639, 638, 527, 526, 543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 767, 766, 527,
-    526, 543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 639, 638, 527, 526, 543, 542,
-    527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 2047, 2046, 1551, 1550, 1567, 1566, 1551,
-    1550, 1599, 1598, 1551, 1550, 1567, 1566, 1551, 1550, 1663, 1662, 1551, 1550, 1567, 1566, 1551,
-    1550, 1599, 1598, 1551, 1550, 1567, 1566, 1551, 1550, 1791, 1790, 1551, 1550, 1567, 1566, 1551,
-    1550, 1599, 1598, 1551, 1550, 1567, 1566, 1551, 1550, 1663, 1662, 1551, 1550, 1567, 1566, 1551,
-    1550, 1599, 1598, 1551, 1550, 1567, 1566, 1551, 1550, 1023, 1022, 527, 526, 543, 542, 527, 526,
-    575, 574, 527, 526, 543, 542, 527, 526, 639, 638, 527, 526, 543, 542, 527, 526, 575, 574, 527,
-    526, 543, 542, 527, 526, 767, 766, 527, 526, 543, 542, 527, 526, 575, 574, 527, 526, 543, 542,
-    527, 526, 639, 638, 527, 526, 543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 4095,
-    4094, 3599, 3598, 3615, 3614, 3599, 3598, 3647, 3646, 3599, 3598, 3615, 3614, 3599, 3598, 3711,
-    3710, 3599, 3598, 3615, 3614, 3599, 3598, 3647, 3646, 3599, 3598, 3615, 3614, 3599, 3598, 3839,
-    3838, 3599, 3598, 3615, 3614, 3599, 3598, 3647, 3646, 3599, 3598, 3615, 3614, 3599, 3598, 3711,
-    3710, 3599, 3598, 3615, 3614, 3599, 3598, 3647, 3646, 3599, 3598, 3615, 3614, 3599, 3598, 1023,
-    1022, 527, 526, 543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 639, 638, 527, 526,
-    543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 767, 766, 527, 526, 543, 542, 527,
-    526, 575, 574, 527, 526, 543, 542, 527, 526, 639, 638, 527, 526, 543, 542, 527, 526, 575, 574,
-    527, 526, 543, 542, 527, 526, 2047, 2046, 1551, 1550, 1567, 1566, 1551, 1550, 1599, 1598, 1551,
-    1550, 1567, 1566, 1551, 1550, 1663, 1662, 1551, 1550, 1567, 1566, 1551, 1550, 1599, 1598, 1551,
-    1550, 1567, 1566, 1551, 1550, 1791, 1790, 1551, 1550, 1567, 1566, 1551, 1550, 1599, 1598, 1551,
-    1550, 1567, 1566, 1551, 1550, 1663, 1662, 1551, 1550, 1567, 1566, 1551, 1550, 1599, 1598, 1551,
-    1550, 1567, 1566, 1551, 1550, 1023, 1022, 527, 526, 543, 542, 527, 526, 575, 574, 527, 526,
-    543, 542, 527, 526, 639, 638, 527, 526, 543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527,
-    526, 767, 766, 527, 526, 543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 639, 638,
-    527, 526, 543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 8191, 8190, 7695, 7694,
-    7711, 7710, 7695, 7694, 7743, 7742, 7695, 7694, 7711, 7710, 7695, 7694, 7807, 7806, 7695, 7694,
-    7711, 7710, 7695, 7694, 7743, 7742, 7695, 7694, 7711, 7710, 7695, 7694, 7935, 7934, 7695, 7694,
-    7711, 7710, 7695, 7694, 7743, 7742, 7695, 7694, 7711, 7710, 7695, 7694, 7807, 7806, 7695, 7694,
-    7711, 7710, 7695, 7694, 7743, 7742, 7695, 7694, 7711, 7710, 7695, 7694, 1023, 1022, 527, 526,
-    543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 639, 638, 527, 526, 543, 542, 527,
-    526, 575, 574, 527, 526, 543, 542, 527, 526, 767, 766, 527, 526, 543, 542, 527, 526, 575, 574,
-    527, 526, 543, 542, 527, 526, 639, 638, 527, 526, 543, 542, 527, 526, 575, 574, 527, 526, 543,
-    542, 527, 526, 2047, 2046, 1551, 1550, 1567, 1566, 1551, 1550, 1599, 1598, 1551, 1550, 1567,
-    1566, 1551, 1550, 1663, 1662, 1551, 1550, 1567, 1566, 1551, 1550, 1599, 1598, 1551, 1550, 1567,
-    1566, 1551, 1550, 1791, 1790, 1551, 1550, 1567, 1566, 1551, 1550, 1599, 1598, 1551, 1550, 1567,
-    1566, 1551, 1550, 1663, 1662, 1551, 1550, 1567, 1566, 1551, 1550, 1599, 1598, 1551, 1550, 1567,
-    1566, 1551, 1550, 1023, 1022, 527, 526, 543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527,
-    526, 639, 638, 527, 526, 543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 767, 766,
-    527, 526, 543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 639, 638, 527, 526, 543,
-    542, 527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 4095, 4094, 3599, 3598, 3615, 3614,
-    3599, 3598, 3647, 3646, 3599, 3598, 3615, 3614, 3599, 3598, 3711, 3710, 3599, 3598, 3615, 3614,
-    3599, 3598, 3647, 3646, 3599, 3598, 3615, 3614, 3599, 3598, 3839, 3838, 3599, 3598, 3615, 3614,
-    3599, 3598, 3647, 3646, 3599, 3598, 3615, 3614, 3599, 3598, 3711, 3710, 3599, 3598, 3615, 3614,
-    3599, 3598, 3647, 3646, 3599, 3598, 3615, 3614, 3599, 3598, 1023, 1022, 527, 526, 543, 542,
-    527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 639, 638, 527, 526, 543, 542, 527, 526, 575,
-    574, 527, 526, 543, 542, 527, 526, 767, 766, 527, 526, 543, 542, 527, 526, 575, 574, 527, 526,
-    543, 542, 527, 526, 639, 638, 527, 526, 543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527,
-    526, 2047, 2046, 1551, 1550, 1567, 1566, 1551, 1550, 1599, 1598, 1551, 1550, 1567, 1566, 1551,
-    1550, 1663, 1662, 1551, 1550, 1567, 1566, 1551, 1550, 1599, 1598, 1551, 1550, 1567, 1566, 1551,
-    1550, 1791, 1790, 1551, 1550, 1567, 1566, 1551, 1550, 1599, 1598, 1551, 1550, 1567, 1566, 1551,
-    1550, 1663, 1662, 1551, 1550, 1567, 1566, 1551, 1550, 1599, 1598, 1551, 1550, 1567, 1566, 1551,
-    1550, 1023, 1022, 527, 526, 543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 639,
-    638, 527, 526, 543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 767, 766, 527, 526,
-    543, 542, 527, 526, 575, 574, 527, 526, 543, 542, 527, 526, 639, 638, 527, 526, 543, 542, 527,
-    526, 575, 574, 527, 526, 543, 542, 527, 526, 16383, 16382, 16379, 16378, 15903, 15902, 15899,
-    15898, 15935, 15934, 15931, 15930, 15903, 15902, 15899, 15898, 15999, 15998, 15995, 15994,
-    15903, 15902, 15899, 15898, 15935, 15934, 15931, 15930, 15903, 15902, 15899, 15898, 16127,
-    16126, 16123, 16122, 15903, 15902, 15899, 15898, 15935, 15934, 15931, 15930, 15903, 15902,
-    15899, 15898, 15999, 15998, 15995, 15994, 15903, 15902, 15899, 15898, 15935, 15934, 15931,
-    15930, 15903, 15902, 15899, 15898, 1023, 1022, 1019, 1018, 543, 542, 539, 538, 575, 574, 571,
-    570, 543, 542, 539, 538, 639, 638, 635, 634, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542,
-    539, 538, 767, 766, 763, 762, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538, 639,
-    638, 635, 634, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538, 2047, 2046, 2043,
-    2042, 1567, 1566, 1563, 1562, 1599, 1598, 1595, 1594, 1567, 1566, 1563, 1562, 1663, 1662, 1659,
-    1658, 1567, 1566, 1563, 1562, 1599, 1598, 1595, 1594, 1567, 1566, 1563, 1562, 1791, 1790, 1787,
-    1786, 1567, 1566, 1563, 1562, 1599, 1598, 1595, 1594, 1567, 1566, 1563, 1562, 1663, 1662, 1659,
-    1658, 1567, 1566, 1563, 1562, 1599, 1598, 1595, 1594, 1567, 1566, 1563, 1562, 1023, 1022, 1019,
-    1018, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538, 639, 638, 635, 634, 543, 542,
-    539, 538, 575, 574, 571, 570, 543, 542, 539, 538, 767, 766, 763, 762, 543, 542, 539, 538, 575,
-    574, 571, 570, 543, 542, 539, 538, 639, 638, 635, 634, 543, 542, 539, 538, 575, 574, 571, 570,
-    543, 542, 539, 538, 4095, 4094, 4091, 4090, 3615, 3614, 3611, 3610, 3647, 3646, 3643, 3642,
-    3615, 3614, 3611, 3610, 3711, 3710, 3707, 3706, 3615, 3614, 3611, 3610, 3647, 3646, 3643, 3642,
-    3615, 3614, 3611, 3610, 3839, 3838, 3835, 3834, 3615, 3614, 3611, 3610, 3647, 3646, 3643, 3642,
-    3615, 3614, 3611, 3610, 3711, 3710, 3707, 3706, 3615, 3614, 3611, 3610, 3647, 3646, 3643, 3642,
-    3615, 3614, 3611, 3610, 1023, 1022, 1019, 1018, 543, 542, 539, 538, 575, 574, 571, 570, 543,
-    542, 539, 538, 639, 638, 635, 634, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538,
-    767, 766, 763, 762, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538, 639, 638, 635,
-    634, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538, 2047, 2046, 2043, 2042, 1567,
-    1566, 1563, 1562, 1599, 1598, 1595, 1594, 1567, 1566, 1563, 1562, 1663, 1662, 1659, 1658, 1567,
-    1566, 1563, 1562, 1599, 1598, 1595, 1594, 1567, 1566, 1563, 1562, 1791, 1790, 1787, 1786, 1567,
-    1566, 1563, 1562, 1599, 1598, 1595, 1594, 1567, 1566, 1563, 1562, 1663, 1662, 1659, 1658, 1567,
-    1566, 1563, 1562, 1599, 1598, 1595, 1594, 1567, 1566, 1563, 1562, 1023, 1022, 1019, 1018, 543,
-    542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538, 639, 638, 635, 634, 543, 542, 539, 538,
-    575, 574, 571, 570, 543, 542, 539, 538, 767, 766, 763, 762, 543, 542, 539, 538, 575, 574, 571,
-    570, 543, 542, 539, 538, 639, 638, 635, 634, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542,
-    539, 538, 8191, 8190, 8187, 8186, 7711, 7710, 7707, 7706, 7743, 7742, 7739, 7738, 7711, 7710,
-    7707, 7706, 7807, 7806, 7803, 7802, 7711, 7710, 7707, 7706, 7743, 7742, 7739, 7738, 7711, 7710,
-    7707, 7706, 7935, 7934, 7931, 7930, 7711, 7710, 7707, 7706, 7743, 7742, 7739, 7738, 7711, 7710,
-    7707, 7706, 7807, 7806, 7803, 7802, 7711, 7710, 7707, 7706, 7743, 7742, 7739, 7738, 7711, 7710,
-    7707, 7706, 1023, 1022, 1019, 1018, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538,
-    639, 638, 635, 634, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538, 767, 766, 763,
-    762, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538, 639, 638, 635, 634, 543, 542,
-    539, 538, 575, 574, 571, 570, 543, 542, 539, 538, 2047, 2046, 2043, 2042, 1567, 1566, 1563,
-    1562, 1599, 1598, 1595, 1594, 1567, 1566, 1563, 1562, 1663, 1662, 1659, 1658, 1567, 1566, 1563,
-    1562, 1599, 1598, 1595, 1594, 1567, 1566, 1563, 1562, 1791, 1790, 1787, 1786, 1567, 1566, 1563,
-    1562, 1599, 1598, 1595, 1594, 1567, 1566, 1563, 1562, 1663, 1662, 1659, 1658, 1567, 1566, 1563,
-    1562, 1599, 1598, 1595, 1594, 1567, 1566, 1563, 1562, 1023, 1022, 1019, 1018, 543, 542, 539,
-    538, 575, 574, 571, 570, 543, 542, 539, 538, 639, 638, 635, 634, 543, 542, 539, 538, 575, 574,
-    571, 570, 543, 542, 539, 538, 767, 766, 763, 762, 543, 542, 539, 538, 575, 574, 571, 570, 543,
-    542, 539, 538, 639, 638, 635, 634, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538,
-    4095, 4094, 4091, 4090, 3615, 3614, 3611, 3610, 3647, 3646, 3643, 3642, 3615, 3614, 3611, 3610,
-    3711, 3710, 3707, 3706, 3615, 3614, 3611, 3610, 3647, 3646, 3643, 3642, 3615, 3614, 3611, 3610,
-    3839, 3838, 3835, 3834, 3615, 3614, 3611, 3610, 3647, 3646, 3643, 3642, 3615, 3614, 3611, 3610,
-    3711, 3710, 3707, 3706, 3615, 3614, 3611, 3610, 3647, 3646, 3643, 3642, 3615, 3614, 3611, 3610,
-    1023, 1022, 1019, 1018, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538, 639, 638,
-    635, 634, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538, 767, 766, 763, 762, 543,
-    542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538, 639, 638, 635, 634, 543, 542, 539, 538,
-    575, 574, 571, 570, 543, 542, 539, 538, 2047, 2046, 2043, 2042, 1567, 1566, 1563, 1562, 1599,
-    1598, 1595, 1594, 1567, 1566, 1563, 1562, 1663, 1662, 1659, 1658, 1567, 1566, 1563, 1562, 1599,
-    1598, 1595, 1594, 1567, 1566, 1563, 1562, 1791, 1790, 1787, 1786, 1567, 1566, 1563, 1562, 1599,
-    1598, 1595, 1594, 1567, 1566, 1563, 1562, 1663, 1662, 1659, 1658, 1567, 1566, 1563, 1562, 1599,
-    1598, 1595, 1594, 1567, 1566, 1563, 1562, 1023, 1022, 1019, 1018, 543, 542, 539, 538, 575, 574,
-    571, 570, 543, 542, 539, 538, 639, 638, 635, 634, 543, 542, 539, 538, 575, 574, 571, 570, 543,
-    542, 539, 538, 767, 766, 763, 762, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538,
-    639, 638, 635, 634, 543, 542, 539, 538, 575, 574, 571, 570, 543, 542, 539, 538, 16383, 16382,
-    16379, 16378, 16371, 16370, 16371, 16370, 15935, 15934, 15931, 15930, 15923, 15922, 15923,
-    15922, 15999, 15998, 15995, 15994, 15987, 15986, 15987, 15986, 15935, 15934, 15931, 15930,
-    15923, 15922, 15923, 15922, 16127, 16126, 16123, 16122, 16115, 16114, 16115, 16114, 15935,
-    15934, 15931, 15930, 15923, 15922, 15923, 15922, 15999, 15998, 15995, 15994, 15987, 15986,
-    15987, 15986, 15935, 15934, 15931, 15930, 15923, 15922, 15923, 15922, 1023, 1022, 1019, 1018,
-    1011, 1010, 1011, 1010, 575, 574, 571, 570, 563, 562, 563, 562, 639, 638, 635, 634, 627, 626,
-    627, 626, 575, 574, 571, 570, 563, 562, 563, 562, 767, 766, 763, 762, 755, 754, 755, 754, 575,
-    574, 571, 570, 563, 562, 563, 562, 639, 638, 635, 634, 627, 626, 627, 626, 575, 574, 571, 570,
-    563, 562, 563, 562, 2047, 2046, 2043, 2042, 2035, 2034, 2035, 2034, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1791, 1790, 1787, 1786, 1779, 1778, 1779, 1778, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 575, 574, 571, 570,
-    563, 562, 563, 562, 639, 638, 635, 634, 627, 626, 627, 626, 575, 574, 571, 570, 563, 562, 563,
-    562, 767, 766, 763, 762, 755, 754, 755, 754, 575, 574, 571, 570, 563, 562, 563, 562, 639, 638,
-    635, 634, 627, 626, 627, 626, 575, 574, 571, 570, 563, 562, 563, 562, 4095, 4094, 4091, 4090,
-    4083, 4082, 4083, 4082, 3647, 3646, 3643, 3642, 3635, 3634, 3635, 3634, 3711, 3710, 3707, 3706,
-    3699, 3698, 3699, 3698, 3647, 3646, 3643, 3642, 3635, 3634, 3635, 3634, 3839, 3838, 3835, 3834,
-    3827, 3826, 3827, 3826, 3647, 3646, 3643, 3642, 3635, 3634, 3635, 3634, 3711, 3710, 3707, 3706,
-    3699, 3698, 3699, 3698, 3647, 3646, 3643, 3642, 3635, 3634, 3635, 3634, 1023, 1022, 1019, 1018,
-    1011, 1010, 1011, 1010, 575, 574, 571, 570, 563, 562, 563, 562, 639, 638, 635, 634, 627, 626,
-    627, 626, 575, 574, 571, 570, 563, 562, 563, 562, 767, 766, 763, 762, 755, 754, 755, 754, 575,
-    574, 571, 570, 563, 562, 563, 562, 639, 638, 635, 634, 627, 626, 627, 626, 575, 574, 571, 570,
-    563, 562, 563, 562, 2047, 2046, 2043, 2042, 2035, 2034, 2035, 2034, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1791, 1790, 1787, 1786, 1779, 1778, 1779, 1778, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 575, 574, 571, 570,
-    563, 562, 563, 562, 639, 638, 635, 634, 627, 626, 627, 626, 575, 574, 571, 570, 563, 562, 563,
-    562, 767, 766, 763, 762, 755, 754, 755, 754, 575, 574, 571, 570, 563, 562, 563, 562, 639, 638,
-    635, 634, 627, 626, 627, 626, 575, 574, 571, 570, 563, 562, 563, 562, 8191, 8190, 8187, 8186,
-    8179, 8178, 8179, 8178, 7743, 7742, 7739, 7738, 7731, 7730, 7731, 7730, 7807, 7806, 7803, 7802,
-    7795, 7794, 7795, 7794, 7743, 7742, 7739, 7738, 7731, 7730, 7731, 7730, 7935, 7934, 7931, 7930,
-    7923, 7922, 7923, 7922, 7743, 7742, 7739, 7738, 7731, 7730, 7731, 7730, 7807, 7806, 7803, 7802,
-    7795, 7794, 7795, 7794, 7743, 7742, 7739, 7738, 7731, 7730, 7731, 7730, 1023, 1022, 1019, 1018,
-    1011, 1010, 1011, 1010, 575, 574, 571, 570, 563, 562, 563, 562, 639, 638, 635, 634, 627, 626,
-    627, 626, 575, 574, 571, 570, 563, 562, 563, 562, 767, 766, 763, 762, 755, 754, 755, 754, 575,
-    574, 571, 570, 563, 562, 563, 562, 639, 638, 635, 634, 627, 626, 627, 626, 575, 574, 571, 570,
-    563, 562, 563, 562, 2047, 2046, 2043, 2042, 2035, 2034, 2035, 2034, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1791, 1790, 1787, 1786, 1779, 1778, 1779, 1778, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 575, 574, 571, 570,
-    563, 562, 563, 562, 639, 638, 635, 634, 627, 626, 627, 626, 575, 574, 571, 570, 563, 562, 563,
-    562, 767, 766, 763, 762, 755, 754, 755, 754, 575, 574, 571, 570, 563, 562, 563, 562, 639, 638,
-    635, 634, 627, 626, 627, 626, 575, 574, 571, 570, 563, 562, 563, 562, 4095, 4094, 4091, 4090,
-    4083, 4082, 4083, 4082, 3647, 3646, 3643, 3642, 3635, 3634, 3635, 3634, 3711, 3710, 3707, 3706,
-    3699, 3698, 3699, 3698, 3647, 3646, 3643, 3642, 3635, 3634, 3635, 3634, 3839, 3838, 3835, 3834,
-    3827, 3826, 3827, 3826, 3647, 3646, 3643, 3642, 3635, 3634, 3635, 3634, 3711, 3710, 3707, 3706,
-    3699, 3698, 3699, 3698, 3647, 3646, 3643, 3642, 3635, 3634, 3635, 3634, 1023, 1022, 1019, 1018,
-    1011, 1010, 1011, 1010, 575, 574, 571, 570, 563, 562, 563, 562, 639, 638, 635, 634, 627, 626,
-    627, 626, 575, 574, 571, 570, 563, 562, 563, 562, 767, 766, 763, 762, 755, 754, 755, 754, 575,
-    574, 571, 570, 563, 562, 563, 562, 639, 638, 635, 634, 627, 626, 627, 626, 575, 574, 571, 570,
-    563, 562, 563, 562, 2047, 2046, 2043, 2042, 2035, 2034, 2035, 2034, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1791, 1790, 1787, 1786, 1779, 1778, 1779, 1778, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1599, 1598, 1595, 1594,
-    1587, 1586, 1587, 1586, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 575, 574, 571, 570,
-    563, 562, 563, 562, 639, 638, 635, 634, 627, 626, 627, 626, 575, 574, 571, 570, 563, 562, 563,
-    562, 767, 766, 763, 762, 755, 754, 755, 754, 575, 574, 571, 570, 563, 562, 563, 562, 639, 638,
-    635, 634, 627, 626, 627, 626, 575, 574, 571, 570, 563, 562, 563, 562, 16383, 16382, 16379,
-    16378, 16371, 16370, 16371, 16370, 16355, 16354, 16355, 16354, 16355, 16354, 16355, 16354,
-    15999, 15998, 15995, 15994, 15987, 15986, 15987, 15986, 15971, 15970, 15971, 15970, 15971,
-    15970, 15971, 15970, 16127, 16126, 16123, 16122, 16115, 16114, 16115, 16114, 16099, 16098,
-    16099, 16098, 16099, 16098, 16099, 16098, 15999, 15998, 15995, 15994, 15987, 15986, 15987,
-    15986, 15971, 15970, 15971, 15970, 15971, 15970, 15971, 15970, 1023, 1022, 1019, 1018, 1011,
-    1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 639, 638, 635, 634, 627, 626, 627,
-    626, 611, 610, 611, 610, 611, 610, 611, 610, 767, 766, 763, 762, 755, 754, 755, 754, 739, 738,
-    739, 738, 739, 738, 739, 738, 639, 638, 635, 634, 627, 626, 627, 626, 611, 610, 611, 610, 611,
-    610, 611, 610, 2047, 2046, 2043, 2042, 2035, 2034, 2035, 2034, 2019, 2018, 2019, 2018, 2019,
-    2018, 2019, 2018, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1635, 1634, 1635, 1634, 1635,
-    1634, 1635, 1634, 1791, 1790, 1787, 1786, 1779, 1778, 1779, 1778, 1763, 1762, 1763, 1762, 1763,
-    1762, 1763, 1762, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1635, 1634, 1635, 1634, 1635,
-    1634, 1635, 1634, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994,
-    995, 994, 639, 638, 635, 634, 627, 626, 627, 626, 611, 610, 611, 610, 611, 610, 611, 610, 767,
-    766, 763, 762, 755, 754, 755, 754, 739, 738, 739, 738, 739, 738, 739, 738, 639, 638, 635, 634,
-    627, 626, 627, 626, 611, 610, 611, 610, 611, 610, 611, 610, 4095, 4094, 4091, 4090, 4083, 4082,
-    4083, 4082, 4067, 4066, 4067, 4066, 4067, 4066, 4067, 4066, 3711, 3710, 3707, 3706, 3699, 3698,
-    3699, 3698, 3683, 3682, 3683, 3682, 3683, 3682, 3683, 3682, 3839, 3838, 3835, 3834, 3827, 3826,
-    3827, 3826, 3811, 3810, 3811, 3810, 3811, 3810, 3811, 3810, 3711, 3710, 3707, 3706, 3699, 3698,
-    3699, 3698, 3683, 3682, 3683, 3682, 3683, 3682, 3683, 3682, 1023, 1022, 1019, 1018, 1011, 1010,
-    1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 639, 638, 635, 634, 627, 626, 627, 626,
-    611, 610, 611, 610, 611, 610, 611, 610, 767, 766, 763, 762, 755, 754, 755, 754, 739, 738, 739,
-    738, 739, 738, 739, 738, 639, 638, 635, 634, 627, 626, 627, 626, 611, 610, 611, 610, 611, 610,
-    611, 610, 2047, 2046, 2043, 2042, 2035, 2034, 2035, 2034, 2019, 2018, 2019, 2018, 2019, 2018,
-    2019, 2018, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1635, 1634, 1635, 1634, 1635, 1634,
-    1635, 1634, 1791, 1790, 1787, 1786, 1779, 1778, 1779, 1778, 1763, 1762, 1763, 1762, 1763, 1762,
-    1763, 1762, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1635, 1634, 1635, 1634, 1635, 1634,
-    1635, 1634, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995,
-    994, 639, 638, 635, 634, 627, 626, 627, 626, 611, 610, 611, 610, 611, 610, 611, 610, 767, 766,
-    763, 762, 755, 754, 755, 754, 739, 738, 739, 738, 739, 738, 739, 738, 639, 638, 635, 634, 627,
-    626, 627, 626, 611, 610, 611, 610, 611, 610, 611, 610, 8191, 8190, 8187, 8186, 8179, 8178,
-    8179, 8178, 8163, 8162, 8163, 8162, 8163, 8162, 8163, 8162, 7807, 7806, 7803, 7802, 7795, 7794,
-    7795, 7794, 7779, 7778, 7779, 7778, 7779, 7778, 7779, 7778, 7935, 7934, 7931, 7930, 7923, 7922,
-    7923, 7922, 7907, 7906, 7907, 7906, 7907, 7906, 7907, 7906, 7807, 7806, 7803, 7802, 7795, 7794,
-    7795, 7794, 7779, 7778, 7779, 7778, 7779, 7778, 7779, 7778, 1023, 1022, 1019, 1018, 1011, 1010,
-    1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 639, 638, 635, 634, 627, 626, 627, 626,
-    611, 610, 611, 610, 611, 610, 611, 610, 767, 766, 763, 762, 755, 754, 755, 754, 739, 738, 739,
-    738, 739, 738, 739, 738, 639, 638, 635, 634, 627, 626, 627, 626, 611, 610, 611, 610, 611, 610,
-    611, 610, 2047, 2046, 2043, 2042, 2035, 2034, 2035, 2034, 2019, 2018, 2019, 2018, 2019, 2018,
-    2019, 2018, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1635, 1634, 1635, 1634, 1635, 1634,
-    1635, 1634, 1791, 1790, 1787, 1786, 1779, 1778, 1779, 1778, 1763, 1762, 1763, 1762, 1763, 1762,
-    1763, 1762, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1635, 1634, 1635, 1634, 1635, 1634,
-    1635, 1634, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995,
-    994, 639, 638, 635, 634, 627, 626, 627, 626, 611, 610, 611, 610, 611, 610, 611, 610, 767, 766,
-    763, 762, 755, 754, 755, 754, 739, 738, 739, 738, 739, 738, 739, 738, 639, 638, 635, 634, 627,
-    626, 627, 626, 611, 610, 611, 610, 611, 610, 611, 610, 4095, 4094, 4091, 4090, 4083, 4082,
-    4083, 4082, 4067, 4066, 4067, 4066, 4067, 4066, 4067, 4066, 3711, 3710, 3707, 3706, 3699, 3698,
-    3699, 3698, 3683, 3682, 3683, 3682, 3683, 3682, 3683, 3682, 3839, 3838, 3835, 3834, 3827, 3826,
-    3827, 3826, 3811, 3810, 3811, 3810, 3811, 3810, 3811, 3810, 3711, 3710, 3707, 3706, 3699, 3698,
-    3699, 3698, 3683, 3682, 3683, 3682, 3683, 3682, 3683, 3682, 1023, 1022, 1019, 1018, 1011, 1010,
-    1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 639, 638, 635, 634, 627, 626, 627, 626,
-    611, 610, 611, 610, 611, 610, 611, 610, 767, 766, 763, 762, 755, 754, 755, 754, 739, 738, 739,
-    738, 739, 738, 739, 738, 639, 638, 635, 634, 627, 626, 627, 626, 611, 610, 611, 610, 611, 610,
-    611, 610, 2047, 2046, 2043, 2042, 2035, 2034, 2035, 2034, 2019, 2018, 2019, 2018, 2019, 2018,
-    2019, 2018, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1635, 1634, 1635, 1634, 1635, 1634,
-    1635, 1634, 1791, 1790, 1787, 1786, 1779, 1778, 1779, 1778, 1763, 1762, 1763, 1762, 1763, 1762,
-    1763, 1762, 1663, 1662, 1659, 1658, 1651, 1650, 1651, 1650, 1635, 1634, 1635, 1634, 1635, 1634,
-    1635, 1634, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995,
-    994, 639, 638, 635, 634, 627, 626, 627, 626, 611, 610, 611, 610, 611, 610, 611, 610, 767, 766,
-    763, 762, 755, 754, 755, 754, 739, 738, 739, 738, 739, 738, 739, 738, 639, 638, 635, 634, 627,
-    626, 627, 626, 611, 610, 611, 610, 611, 610, 611, 610, 16383, 16382, 16379, 16378, 16371,
-    16370, 16371, 16370, 16355, 16354, 16355, 16354, 16355, 16354, 16355, 16354, 16323, 16322,
-    16323, 16322, 16323, 16322, 16323, 16322, 16323, 16322, 16323, 16322, 16323, 16322, 16323,
-    16322, 16127, 16126, 16123, 16122, 16115, 16114, 16115, 16114, 16099, 16098, 16099, 16098,
-    16099, 16098, 16099, 16098, 16067, 16066, 16067, 16066, 16067, 16066, 16067, 16066, 16067,
-    16066, 16067, 16066, 16067, 16066, 16067, 16066, 1023, 1022, 1019, 1018, 1011, 1010, 1011,
-    1010, 995, 994, 995, 994, 995, 994, 995, 994, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962,
-    963, 962, 963, 962, 963, 962, 767, 766, 763, 762, 755, 754, 755, 754, 739, 738, 739, 738, 739,
-    738, 739, 738, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706,
-    2047, 2046, 2043, 2042, 2035, 2034, 2035, 2034, 2019, 2018, 2019, 2018, 2019, 2018, 2019, 2018,
-    1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986,
-    1791, 1790, 1787, 1786, 1779, 1778, 1779, 1778, 1763, 1762, 1763, 1762, 1763, 1762, 1763, 1762,
-    1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730,
-    1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 963,
-    962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 767, 766, 763, 762,
-    755, 754, 755, 754, 739, 738, 739, 738, 739, 738, 739, 738, 707, 706, 707, 706, 707, 706, 707,
-    706, 707, 706, 707, 706, 707, 706, 707, 706, 4095, 4094, 4091, 4090, 4083, 4082, 4083, 4082,
-    4067, 4066, 4067, 4066, 4067, 4066, 4067, 4066, 4035, 4034, 4035, 4034, 4035, 4034, 4035, 4034,
-    4035, 4034, 4035, 4034, 4035, 4034, 4035, 4034, 3839, 3838, 3835, 3834, 3827, 3826, 3827, 3826,
-    3811, 3810, 3811, 3810, 3811, 3810, 3811, 3810, 3779, 3778, 3779, 3778, 3779, 3778, 3779, 3778,
-    3779, 3778, 3779, 3778, 3779, 3778, 3779, 3778, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010,
-    995, 994, 995, 994, 995, 994, 995, 994, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963,
-    962, 963, 962, 963, 962, 767, 766, 763, 762, 755, 754, 755, 754, 739, 738, 739, 738, 739, 738,
-    739, 738, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706, 2047,
-    2046, 2043, 2042, 2035, 2034, 2035, 2034, 2019, 2018, 2019, 2018, 2019, 2018, 2019, 2018, 1987,
-    1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1791,
-    1790, 1787, 1786, 1779, 1778, 1779, 1778, 1763, 1762, 1763, 1762, 1763, 1762, 1763, 1762, 1731,
-    1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1023,
-    1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 963, 962,
-    963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 767, 766, 763, 762, 755,
-    754, 755, 754, 739, 738, 739, 738, 739, 738, 739, 738, 707, 706, 707, 706, 707, 706, 707, 706,
-    707, 706, 707, 706, 707, 706, 707, 706, 8191, 8190, 8187, 8186, 8179, 8178, 8179, 8178, 8163,
-    8162, 8163, 8162, 8163, 8162, 8163, 8162, 8131, 8130, 8131, 8130, 8131, 8130, 8131, 8130, 8131,
-    8130, 8131, 8130, 8131, 8130, 8131, 8130, 7935, 7934, 7931, 7930, 7923, 7922, 7923, 7922, 7907,
-    7906, 7907, 7906, 7907, 7906, 7907, 7906, 7875, 7874, 7875, 7874, 7875, 7874, 7875, 7874, 7875,
-    7874, 7875, 7874, 7875, 7874, 7875, 7874, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 995,
-    994, 995, 994, 995, 994, 995, 994, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962,
-    963, 962, 963, 962, 767, 766, 763, 762, 755, 754, 755, 754, 739, 738, 739, 738, 739, 738, 739,
-    738, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706, 2047,
-    2046, 2043, 2042, 2035, 2034, 2035, 2034, 2019, 2018, 2019, 2018, 2019, 2018, 2019, 2018, 1987,
-    1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1791,
-    1790, 1787, 1786, 1779, 1778, 1779, 1778, 1763, 1762, 1763, 1762, 1763, 1762, 1763, 1762, 1731,
-    1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1023,
-    1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 963, 962,
-    963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 767, 766, 763, 762, 755,
-    754, 755, 754, 739, 738, 739, 738, 739, 738, 739, 738, 707, 706, 707, 706, 707, 706, 707, 706,
-    707, 706, 707, 706, 707, 706, 707, 706, 4095, 4094, 4091, 4090, 4083, 4082, 4083, 4082, 4067,
-    4066, 4067, 4066, 4067, 4066, 4067, 4066, 4035, 4034, 4035, 4034, 4035, 4034, 4035, 4034, 4035,
-    4034, 4035, 4034, 4035, 4034, 4035, 4034, 3839, 3838, 3835, 3834, 3827, 3826, 3827, 3826, 3811,
-    3810, 3811, 3810, 3811, 3810, 3811, 3810, 3779, 3778, 3779, 3778, 3779, 3778, 3779, 3778, 3779,
-    3778, 3779, 3778, 3779, 3778, 3779, 3778, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 995,
-    994, 995, 994, 995, 994, 995, 994, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962,
-    963, 962, 963, 962, 767, 766, 763, 762, 755, 754, 755, 754, 739, 738, 739, 738, 739, 738, 739,
-    738, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706, 707, 706, 2047,
-    2046, 2043, 2042, 2035, 2034, 2035, 2034, 2019, 2018, 2019, 2018, 2019, 2018, 2019, 2018, 1987,
-    1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1791,
-    1790, 1787, 1786, 1779, 1778, 1779, 1778, 1763, 1762, 1763, 1762, 1763, 1762, 1763, 1762, 1731,
-    1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1731, 1730, 1023,
-    1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 963, 962,
-    963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 767, 766, 763, 762, 755,
-    754, 755, 754, 739, 738, 739, 738, 739, 738, 739, 738, 707, 706, 707, 706, 707, 706, 707, 706,
-    707, 706, 707, 706, 707, 706, 707, 706, 16383, 16382, 16379, 16378, 16371, 16370, 16371, 16370,
-    16355, 16354, 16355, 16354, 16355, 16354, 16355, 16354, 16323, 16322, 16323, 16322, 16323,
-    16322, 16323, 16322, 16323, 16322, 16323, 16322, 16323, 16322, 16323, 16322, 16259, 16258,
-    16259, 16258, 16259, 16258, 16259, 16258, 16259, 16258, 16259, 16258, 16259, 16258, 16259,
-    16258, 16259, 16258, 16259, 16258, 16259, 16258, 16259, 16258, 16259, 16258, 16259, 16258,
-    16259, 16258, 16259, 16258, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994,
-    995, 994, 995, 994, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963,
-    962, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898,
-    899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 2047, 2046, 2043, 2042,
-    2035, 2034, 2035, 2034, 2019, 2018, 2019, 2018, 2019, 2018, 2019, 2018, 1987, 1986, 1987, 1986,
-    1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1923, 1922, 1923, 1922,
-    1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922,
-    1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1023, 1022, 1019, 1018,
-    1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 963, 962, 963, 962, 963, 962,
-    963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 899, 898, 899, 898, 899, 898, 899, 898, 899,
-    898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898,
-    899, 898, 899, 898, 4095, 4094, 4091, 4090, 4083, 4082, 4083, 4082, 4067, 4066, 4067, 4066,
-    4067, 4066, 4067, 4066, 4035, 4034, 4035, 4034, 4035, 4034, 4035, 4034, 4035, 4034, 4035, 4034,
-    4035, 4034, 4035, 4034, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970,
-    3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970,
-    3971, 3970, 3971, 3970, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994,
-    995, 994, 995, 994, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963,
-    962, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898,
-    899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 2047, 2046, 2043, 2042,
-    2035, 2034, 2035, 2034, 2019, 2018, 2019, 2018, 2019, 2018, 2019, 2018, 1987, 1986, 1987, 1986,
-    1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1923, 1922, 1923, 1922,
-    1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922,
-    1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1023, 1022, 1019, 1018,
-    1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 963, 962, 963, 962, 963, 962,
-    963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 899, 898, 899, 898, 899, 898, 899, 898, 899,
-    898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898,
-    899, 898, 899, 898, 8191, 8190, 8187, 8186, 8179, 8178, 8179, 8178, 8163, 8162, 8163, 8162,
-    8163, 8162, 8163, 8162, 8131, 8130, 8131, 8130, 8131, 8130, 8131, 8130, 8131, 8130, 8131, 8130,
-    8131, 8130, 8131, 8130, 8067, 8066, 8067, 8066, 8067, 8066, 8067, 8066, 8067, 8066, 8067, 8066,
-    8067, 8066, 8067, 8066, 8067, 8066, 8067, 8066, 8067, 8066, 8067, 8066, 8067, 8066, 8067, 8066,
-    8067, 8066, 8067, 8066, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994,
-    995, 994, 995, 994, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963,
-    962, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898,
-    899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 2047, 2046, 2043, 2042,
-    2035, 2034, 2035, 2034, 2019, 2018, 2019, 2018, 2019, 2018, 2019, 2018, 1987, 1986, 1987, 1986,
-    1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1923, 1922, 1923, 1922,
-    1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922,
-    1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1023, 1022, 1019, 1018,
-    1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 963, 962, 963, 962, 963, 962,
-    963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 899, 898, 899, 898, 899, 898, 899, 898, 899,
-    898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898,
-    899, 898, 899, 898, 4095, 4094, 4091, 4090, 4083, 4082, 4083, 4082, 4067, 4066, 4067, 4066,
-    4067, 4066, 4067, 4066, 4035, 4034, 4035, 4034, 4035, 4034, 4035, 4034, 4035, 4034, 4035, 4034,
-    4035, 4034, 4035, 4034, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970,
-    3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970,
-    3971, 3970, 3971, 3970, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994,
-    995, 994, 995, 994, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963,
-    962, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898,
-    899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 2047, 2046, 2043, 2042,
-    2035, 2034, 2035, 2034, 2019, 2018, 2019, 2018, 2019, 2018, 2019, 2018, 1987, 1986, 1987, 1986,
-    1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1923, 1922, 1923, 1922,
-    1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922,
-    1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1023, 1022, 1019, 1018,
-    1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 963, 962, 963, 962, 963, 962,
-    963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 899, 898, 899, 898, 899, 898, 899, 898, 899,
-    898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898,
-    899, 898, 899, 898, 16383, 16382, 16379, 16378, 16371, 16370, 16371, 16370, 16355, 16354,
-    16355, 16354, 16355, 16354, 16355, 16354, 16323, 16322, 16323, 16322, 16323, 16322, 16323,
-    16322, 16323, 16322, 16323, 16322, 16323, 16322, 16323, 16322, 16259, 16258, 16259, 16258,
-    16259, 16258, 16259, 16258, 16259, 16258, 16259, 16258, 16259, 16258, 16259, 16258, 16259,
-    16258, 16259, 16258, 16259, 16258, 16259, 16258, 16259, 16258, 16259, 16258, 16259, 16258,
-    16259, 16258, 16131, 16130, 16131, 16130, 16131, 16130, 16131, 16130, 16131, 16130, 16131,
-    16130, 16131, 16130, 16131, 16130, 16131, 16130, 16131, 16130, 16131, 16130, 16131, 16130,
-    16131, 16130, 16131, 16130, 16131, 16130, 16131, 16130, 16131, 16130, 16131, 16130, 16131,
-    16130, 16131, 16130, 16131, 16130, 16131, 16130, 16131, 16130, 16131, 16130, 16131, 16130,
-    16131, 16130, 16131, 16130, 16131, 16130, 16131, 16130, 16131, 16130, 16131, 16130, 16131,
-    16130, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994,
-    963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 899, 898, 899,
-    898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898,
-    899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 771, 770, 771, 770, 771, 770, 771, 770, 771,
-    770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770,
-    771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771,
-    770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 2047,
-    2046, 2043, 2042, 2035, 2034, 2035, 2034, 2019, 2018, 2019, 2018, 2019, 2018, 2019, 2018, 1987,
-    1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1923,
-    1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923,
-    1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1795,
-    1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795,
-    1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795,
-    1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795,
-    1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1023,
-    1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 963, 962,
-    963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 899, 898, 899, 898, 899,
-    898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898,
-    899, 898, 899, 898, 899, 898, 899, 898, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771,
-    770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770,
-    771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771,
-    770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 4095, 4094, 4091,
-    4090, 4083, 4082, 4083, 4082, 4067, 4066, 4067, 4066, 4067, 4066, 4067, 4066, 4035, 4034, 4035,
-    4034, 4035, 4034, 4035, 4034, 4035, 4034, 4035, 4034, 4035, 4034, 4035, 4034, 3971, 3970, 3971,
-    3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971,
-    3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3843, 3842, 3843,
-    3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843,
-    3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843,
-    3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843,
-    3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 1023, 1022, 1019,
-    1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 963, 962, 963, 962, 963,
-    962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 899, 898, 899, 898, 899, 898, 899, 898,
-    899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899,
-    898, 899, 898, 899, 898, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770,
-    771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771,
-    770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770,
-    771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 2047, 2046, 2043, 2042, 2035, 2034,
-    2035, 2034, 2019, 2018, 2019, 2018, 2019, 2018, 2019, 2018, 1987, 1986, 1987, 1986, 1987, 1986,
-    1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1923, 1922, 1923, 1922, 1923, 1922,
-    1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922,
-    1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1795, 1794, 1795, 1794, 1795, 1794,
-    1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794,
-    1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794,
-    1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794,
-    1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1023, 1022, 1019, 1018, 1011, 1010,
-    1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 963, 962, 963, 962, 963, 962, 963, 962,
-    963, 962, 963, 962, 963, 962, 963, 962, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899,
-    898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898,
-    899, 898, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771,
-    770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770,
-    771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771,
-    770, 771, 770, 771, 770, 771, 770, 771, 770, 8191, 8190, 8187, 8186, 8179, 8178, 8179, 8178,
-    8163, 8162, 8163, 8162, 8163, 8162, 8163, 8162, 8131, 8130, 8131, 8130, 8131, 8130, 8131, 8130,
-    8131, 8130, 8131, 8130, 8131, 8130, 8131, 8130, 8067, 8066, 8067, 8066, 8067, 8066, 8067, 8066,
-    8067, 8066, 8067, 8066, 8067, 8066, 8067, 8066, 8067, 8066, 8067, 8066, 8067, 8066, 8067, 8066,
-    8067, 8066, 8067, 8066, 8067, 8066, 8067, 8066, 7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938,
-    7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938,
-    7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938,
-    7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938,
-    7939, 7938, 7939, 7938, 7939, 7938, 7939, 7938, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010,
-    995, 994, 995, 994, 995, 994, 995, 994, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963,
-    962, 963, 962, 963, 962, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898,
-    899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 771,
-    770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770,
-    771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771,
-    770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770,
-    771, 770, 771, 770, 771, 770, 2047, 2046, 2043, 2042, 2035, 2034, 2035, 2034, 2019, 2018, 2019,
-    2018, 2019, 2018, 2019, 2018, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987,
-    1986, 1987, 1986, 1987, 1986, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923,
-    1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923,
-    1922, 1923, 1922, 1923, 1922, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795,
-    1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795,
-    1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795,
-    1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795,
-    1794, 1795, 1794, 1795, 1794, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995,
-    994, 995, 994, 995, 994, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962,
-    963, 962, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899,
-    898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 771, 770, 771, 770,
-    771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771,
-    770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770,
-    771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771,
-    770, 771, 770, 4095, 4094, 4091, 4090, 4083, 4082, 4083, 4082, 4067, 4066, 4067, 4066, 4067,
-    4066, 4067, 4066, 4035, 4034, 4035, 4034, 4035, 4034, 4035, 4034, 4035, 4034, 4035, 4034, 4035,
-    4034, 4035, 4034, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971,
-    3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971, 3970, 3971,
-    3970, 3971, 3970, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843,
-    3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843,
-    3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843,
-    3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843, 3842, 3843,
-    3842, 3843, 3842, 1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994,
-    995, 994, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 899,
-    898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898,
-    899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 771, 770, 771, 770, 771, 770, 771,
-    770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770,
-    771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771,
-    770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770,
-    2047, 2046, 2043, 2042, 2035, 2034, 2035, 2034, 2019, 2018, 2019, 2018, 2019, 2018, 2019, 2018,
-    1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986, 1987, 1986,
-    1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922,
-    1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922, 1923, 1922,
-    1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794,
-    1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794,
-    1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794,
-    1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794, 1795, 1794,
-    1023, 1022, 1019, 1018, 1011, 1010, 1011, 1010, 995, 994, 995, 994, 995, 994, 995, 994, 963,
-    962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 963, 962, 899, 898, 899, 898,
-    899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899, 898, 899,
-    898, 899, 898, 899, 898, 899, 898, 899, 898, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770,
-    771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771,
-    770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770,
-    771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 771, 770, 16383, 16382,
-    16380, 16380, 15375, 15374, 15372, 15372, 15391, 15390, 15388, 15388, 15375, 15374, 15372,
-    15372, 15423, 15422, 15420, 15420, 15375, 15374, 15372, 15372, 15391, 15390, 15388, 15388,
-    15375, 15374, 15372, 15372, 15487, 15486, 15484, 15484, 15375, 15374, 15372, 15372, 15391,
-    15390, 15388, 15388, 15375, 15374, 15372, 15372, 15423, 15422, 15420, 15420, 15375, 15374,
-    15372, 15372, 15391, 15390, 15388, 15388, 15375, 15374, 15372, 15372, 15615, 15614, 15612,
-    15612, 15375, 15374, 15372, 15372, 15391, 15390, 15388, 15388, 15375, 15374, 15372, 15372,
-    15423, 15422, 15420, 15420, 15375, 15374, 15372, 15372, 15391, 15390, 15388, 15388, 15375,
-    15374, 15372, 15372, 15487, 15486, 15484, 15484, 15375, 15374, 15372, 15372, 15391, 15390,
-    15388, 15388, 15375, 15374, 15372, 15372, 15423, 15422, 15420, 15420, 15375, 15374, 15372,
-    15372, 15391, 15390, 15388, 15388, 15375, 15374, 15372, 15372, 15871, 15870, 15868, 15868,
-    15375, 15374, 15372, 15372, 15391, 15390, 15388, 15388, 15375, 15374, 15372, 15372, 15423,
-    15422, 15420, 15420, 15375, 15374, 15372, 15372, 15391, 15390, 15388, 15388, 15375, 15374,
-    15372, 15372, 15487, 15486, 15484, 15484, 15375, 15374, 15372, 15372, 15391, 15390, 15388,
-    15388, 15375, 15374, 15372, 15372, 15423, 15422, 15420, 15420, 15375, 15374, 15372, 15372,
-    15391, 15390, 15388, 15388, 15375, 15374, 15372, 15372, 15615, 15614, 15612, 15612, 15375,
-    15374, 15372, 15372, 15391, 15390, 15388, 15388, 15375, 15374, 15372, 15372, 15423, 15422,
-    15420, 15420, 15375, 15374, 15372, 15372, 15391, 15390, 15388, 15388, 15375, 15374, 15372,
-    15372, 15487, 15486, 15484, 15484, 15375, 15374, 15372, 15372, 15391, 15390, 15388, 15388,
-    15375, 15374, 15372, 15372, 15423, 15422, 15420, 15420, 15375, 15374, 15372, 15372, 15391,
-    15390, 15388, 15388, 15375, 15374, 15372, 15372, 2047, 2046, 2044, 2044, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1279, 1278, 1276, 1276, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1535, 1534, 1532, 1532, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1279, 1278, 1276, 1276, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 4095, 4094, 4092, 4092, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3199, 3198, 3196, 3196, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3327, 3326, 3324, 3324, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3199, 3198, 3196, 3196, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3583, 3582, 3580, 3580, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3199, 3198, 3196, 3196, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3327, 3326, 3324, 3324, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3199, 3198, 3196, 3196, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 2047, 2046, 2044, 2044, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1279, 1278, 1276, 1276, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1535, 1534, 1532, 1532, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1279, 1278, 1276, 1276, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 8191, 8190, 8188, 8188, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 7231, 7230, 7228, 7228, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 7295, 7294, 7292, 7292, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 7231, 7230, 7228, 7228, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 7423, 7422, 7420, 7420, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 7231, 7230, 7228, 7228, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 7295, 7294, 7292, 7292, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 7231, 7230, 7228, 7228, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 7679, 7678, 7676, 7676, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 7231, 7230, 7228, 7228, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 7295, 7294, 7292, 7292, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 7231, 7230, 7228, 7228, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 7423, 7422, 7420, 7420, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 7231, 7230, 7228, 7228, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 7295, 7294, 7292, 7292, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 7231, 7230, 7228, 7228, 7183, 7182, 7180,
-    7180, 7199, 7198, 7196, 7196, 7183, 7182, 7180, 7180, 2047, 2046, 2044, 2044, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1279, 1278, 1276, 1276, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1535, 1534, 1532, 1532, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1279, 1278, 1276, 1276, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 4095, 4094, 4092, 4092, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3199, 3198, 3196, 3196, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3327, 3326, 3324, 3324, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3199, 3198, 3196, 3196, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3583, 3582, 3580, 3580, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3199, 3198, 3196, 3196, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3327, 3326, 3324, 3324, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3199, 3198, 3196, 3196, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 3135, 3134, 3132, 3132, 3087, 3086, 3084,
-    3084, 3103, 3102, 3100, 3100, 3087, 3086, 3084, 3084, 2047, 2046, 2044, 2044, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1279, 1278, 1276, 1276, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1535, 1534, 1532, 1532, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1279, 1278, 1276, 1276, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1151, 1150, 1148, 1148, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 1087, 1086, 1084, 1084, 1039, 1038, 1036,
-    1036, 1055, 1054, 1052, 1052, 1039, 1038, 1036, 1036, 16383, 16382, 16380, 16380, 15391, 15390,
-    15388, 15388, 15423, 15422, 15420, 15420, 15391, 15390, 15388, 15388, 15487, 15486, 15484,
-    15484, 15391, 15390, 15388, 15388, 15423, 15422, 15420, 15420, 15391, 15390, 15388, 15388,
-    15615, 15614, 15612, 15612, 15391, 15390, 15388, 15388, 15423, 15422, 15420, 15420, 15391,
-    15390, 15388, 15388, 15487, 15486, 15484, 15484, 15391, 15390, 15388, 15388, 15423, 15422,
-    15420, 15420, 15391, 15390, 15388, 15388, 15871, 15870, 15868, 15868, 15391, 15390, 15388,
-    15388, 15423, 15422, 15420, 15420, 15391, 15390, 15388, 15388, 15487, 15486, 15484, 15484,
-    15391, 15390, 15388, 15388, 15423, 15422, 15420, 15420, 15391, 15390, 15388, 15388, 15615,
-    15614, 15612, 15612, 15391, 15390, 15388, 15388, 15423, 15422, 15420, 15420, 15391, 15390,
-    15388, 15388, 15487, 15486, 15484, 15484, 15391, 15390, 15388, 15388, 15423, 15422, 15420,
-    15420, 15391, 15390, 15388, 15388, 2047, 2046, 2044, 2044, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1279, 1278, 1276, 1276, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1535, 1534, 1532, 1532, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1279, 1278, 1276, 1276, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 4095, 4094, 4092, 4092, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 3199, 3198, 3196, 3196, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 3327, 3326, 3324, 3324, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 3199, 3198, 3196, 3196, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 3583, 3582, 3580, 3580, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 3199, 3198, 3196, 3196, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 3327, 3326, 3324, 3324, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 3199, 3198, 3196, 3196, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 2047, 2046, 2044, 2044, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1279, 1278, 1276, 1276, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1535, 1534, 1532, 1532, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1279, 1278, 1276, 1276, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 8191, 8190, 8188, 8188, 7199, 7198, 7196, 7196, 7231, 7230,
-    7228, 7228, 7199, 7198, 7196, 7196, 7295, 7294, 7292, 7292, 7199, 7198, 7196, 7196, 7231, 7230,
-    7228, 7228, 7199, 7198, 7196, 7196, 7423, 7422, 7420, 7420, 7199, 7198, 7196, 7196, 7231, 7230,
-    7228, 7228, 7199, 7198, 7196, 7196, 7295, 7294, 7292, 7292, 7199, 7198, 7196, 7196, 7231, 7230,
-    7228, 7228, 7199, 7198, 7196, 7196, 7679, 7678, 7676, 7676, 7199, 7198, 7196, 7196, 7231, 7230,
-    7228, 7228, 7199, 7198, 7196, 7196, 7295, 7294, 7292, 7292, 7199, 7198, 7196, 7196, 7231, 7230,
-    7228, 7228, 7199, 7198, 7196, 7196, 7423, 7422, 7420, 7420, 7199, 7198, 7196, 7196, 7231, 7230,
-    7228, 7228, 7199, 7198, 7196, 7196, 7295, 7294, 7292, 7292, 7199, 7198, 7196, 7196, 7231, 7230,
-    7228, 7228, 7199, 7198, 7196, 7196, 2047, 2046, 2044, 2044, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1279, 1278, 1276, 1276, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1535, 1534, 1532, 1532, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1279, 1278, 1276, 1276, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 4095, 4094, 4092, 4092, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 3199, 3198, 3196, 3196, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 3327, 3326, 3324, 3324, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 3199, 3198, 3196, 3196, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 3583, 3582, 3580, 3580, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 3199, 3198, 3196, 3196, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 3327, 3326, 3324, 3324, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 3199, 3198, 3196, 3196, 3103, 3102, 3100, 3100, 3135, 3134,
-    3132, 3132, 3103, 3102, 3100, 3100, 2047, 2046, 2044, 2044, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1279, 1278, 1276, 1276, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1535, 1534, 1532, 1532, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1279, 1278, 1276, 1276, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 1151, 1150, 1148, 1148, 1055, 1054, 1052, 1052, 1087, 1086,
-    1084, 1084, 1055, 1054, 1052, 1052, 16383, 16382, 16380, 16380, 16375, 16374, 16372, 16372,
-    15423, 15422, 15420, 15420, 15415, 15414, 15412, 15412, 15487, 15486, 15484, 15484, 15479,
-    15478, 15476, 15476, 15423, 15422, 15420, 15420, 15415, 15414, 15412, 15412, 15615, 15614,
-    15612, 15612, 15607, 15606, 15604, 15604, 15423, 15422, 15420, 15420, 15415, 15414, 15412,
-    15412, 15487, 15486, 15484, 15484, 15479, 15478, 15476, 15476, 15423, 15422, 15420, 15420,
-    15415, 15414, 15412, 15412, 15871, 15870, 15868, 15868, 15863, 15862, 15860, 15860, 15423,
-    15422, 15420, 15420, 15415, 15414, 15412, 15412, 15487, 15486, 15484, 15484, 15479, 15478,
-    15476, 15476, 15423, 15422, 15420, 15420, 15415, 15414, 15412, 15412, 15615, 15614, 15612,
-    15612, 15607, 15606, 15604, 15604, 15423, 15422, 15420, 15420, 15415, 15414, 15412, 15412,
-    15487, 15486, 15484, 15484, 15479, 15478, 15476, 15476, 15423, 15422, 15420, 15420, 15415,
-    15414, 15412, 15412, 2047, 2046, 2044, 2044, 2039, 2038, 2036, 2036, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1535, 1534, 1532, 1532, 1527, 1526, 1524, 1524, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 4095, 4094, 4092, 4092, 4087, 4086, 4084, 4084, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 3327, 3326, 3324, 3324, 3319, 3318, 3316, 3316, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 3583, 3582, 3580, 3580, 3575, 3574, 3572, 3572, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 3327, 3326, 3324, 3324, 3319, 3318, 3316, 3316, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 2047, 2046, 2044, 2044, 2039, 2038, 2036, 2036, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1535, 1534, 1532, 1532, 1527, 1526, 1524, 1524, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 8191, 8190, 8188, 8188, 8183, 8182, 8180, 8180, 7231, 7230, 7228, 7228,
-    7223, 7222, 7220, 7220, 7295, 7294, 7292, 7292, 7287, 7286, 7284, 7284, 7231, 7230, 7228, 7228,
-    7223, 7222, 7220, 7220, 7423, 7422, 7420, 7420, 7415, 7414, 7412, 7412, 7231, 7230, 7228, 7228,
-    7223, 7222, 7220, 7220, 7295, 7294, 7292, 7292, 7287, 7286, 7284, 7284, 7231, 7230, 7228, 7228,
-    7223, 7222, 7220, 7220, 7679, 7678, 7676, 7676, 7671, 7670, 7668, 7668, 7231, 7230, 7228, 7228,
-    7223, 7222, 7220, 7220, 7295, 7294, 7292, 7292, 7287, 7286, 7284, 7284, 7231, 7230, 7228, 7228,
-    7223, 7222, 7220, 7220, 7423, 7422, 7420, 7420, 7415, 7414, 7412, 7412, 7231, 7230, 7228, 7228,
-    7223, 7222, 7220, 7220, 7295, 7294, 7292, 7292, 7287, 7286, 7284, 7284, 7231, 7230, 7228, 7228,
-    7223, 7222, 7220, 7220, 2047, 2046, 2044, 2044, 2039, 2038, 2036, 2036, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1535, 1534, 1532, 1532, 1527, 1526, 1524, 1524, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 4095, 4094, 4092, 4092, 4087, 4086, 4084, 4084, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 3327, 3326, 3324, 3324, 3319, 3318, 3316, 3316, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 3583, 3582, 3580, 3580, 3575, 3574, 3572, 3572, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 3327, 3326, 3324, 3324, 3319, 3318, 3316, 3316, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3135, 3134, 3132, 3132,
-    3127, 3126, 3124, 3124, 2047, 2046, 2044, 2044, 2039, 2038, 2036, 2036, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1535, 1534, 1532, 1532, 1527, 1526, 1524, 1524, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1087, 1086, 1084, 1084,
-    1079, 1078, 1076, 1076, 16383, 16382, 16380, 16380, 16375, 16374, 16372, 16372, 16359, 16358,
-    16356, 16356, 16359, 16358, 16356, 16356, 15487, 15486, 15484, 15484, 15479, 15478, 15476,
-    15476, 15463, 15462, 15460, 15460, 15463, 15462, 15460, 15460, 15615, 15614, 15612, 15612,
-    15607, 15606, 15604, 15604, 15591, 15590, 15588, 15588, 15591, 15590, 15588, 15588, 15487,
-    15486, 15484, 15484, 15479, 15478, 15476, 15476, 15463, 15462, 15460, 15460, 15463, 15462,
-    15460, 15460, 15871, 15870, 15868, 15868, 15863, 15862, 15860, 15860, 15847, 15846, 15844,
-    15844, 15847, 15846, 15844, 15844, 15487, 15486, 15484, 15484, 15479, 15478, 15476, 15476,
-    15463, 15462, 15460, 15460, 15463, 15462, 15460, 15460, 15615, 15614, 15612, 15612, 15607,
-    15606, 15604, 15604, 15591, 15590, 15588, 15588, 15591, 15590, 15588, 15588, 15487, 15486,
-    15484, 15484, 15479, 15478, 15476, 15476, 15463, 15462, 15460, 15460, 15463, 15462, 15460,
-    15460, 2047, 2046, 2044, 2044, 2039, 2038, 2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022,
-    2020, 2020, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254,
-    1252, 1252, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 1535, 1534, 1532, 1532, 1527, 1526, 1524, 1524, 1511, 1510, 1508, 1508, 1511, 1510,
-    1508, 1508, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254,
-    1252, 1252, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 4095, 4094, 4092, 4092, 4087, 4086, 4084, 4084, 4071, 4070, 4068, 4068, 4071, 4070,
-    4068, 4068, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3175, 3174, 3172, 3172, 3175, 3174,
-    3172, 3172, 3327, 3326, 3324, 3324, 3319, 3318, 3316, 3316, 3303, 3302, 3300, 3300, 3303, 3302,
-    3300, 3300, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3175, 3174, 3172, 3172, 3175, 3174,
-    3172, 3172, 3583, 3582, 3580, 3580, 3575, 3574, 3572, 3572, 3559, 3558, 3556, 3556, 3559, 3558,
-    3556, 3556, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3175, 3174, 3172, 3172, 3175, 3174,
-    3172, 3172, 3327, 3326, 3324, 3324, 3319, 3318, 3316, 3316, 3303, 3302, 3300, 3300, 3303, 3302,
-    3300, 3300, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3175, 3174, 3172, 3172, 3175, 3174,
-    3172, 3172, 2047, 2046, 2044, 2044, 2039, 2038, 2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022,
-    2020, 2020, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254,
-    1252, 1252, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 1535, 1534, 1532, 1532, 1527, 1526, 1524, 1524, 1511, 1510, 1508, 1508, 1511, 1510,
-    1508, 1508, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254,
-    1252, 1252, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 8191, 8190, 8188, 8188, 8183, 8182, 8180, 8180, 8167, 8166, 8164, 8164, 8167, 8166,
-    8164, 8164, 7295, 7294, 7292, 7292, 7287, 7286, 7284, 7284, 7271, 7270, 7268, 7268, 7271, 7270,
-    7268, 7268, 7423, 7422, 7420, 7420, 7415, 7414, 7412, 7412, 7399, 7398, 7396, 7396, 7399, 7398,
-    7396, 7396, 7295, 7294, 7292, 7292, 7287, 7286, 7284, 7284, 7271, 7270, 7268, 7268, 7271, 7270,
-    7268, 7268, 7679, 7678, 7676, 7676, 7671, 7670, 7668, 7668, 7655, 7654, 7652, 7652, 7655, 7654,
-    7652, 7652, 7295, 7294, 7292, 7292, 7287, 7286, 7284, 7284, 7271, 7270, 7268, 7268, 7271, 7270,
-    7268, 7268, 7423, 7422, 7420, 7420, 7415, 7414, 7412, 7412, 7399, 7398, 7396, 7396, 7399, 7398,
-    7396, 7396, 7295, 7294, 7292, 7292, 7287, 7286, 7284, 7284, 7271, 7270, 7268, 7268, 7271, 7270,
-    7268, 7268, 2047, 2046, 2044, 2044, 2039, 2038, 2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022,
-    2020, 2020, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254,
-    1252, 1252, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 1535, 1534, 1532, 1532, 1527, 1526, 1524, 1524, 1511, 1510, 1508, 1508, 1511, 1510,
-    1508, 1508, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254,
-    1252, 1252, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 4095, 4094, 4092, 4092, 4087, 4086, 4084, 4084, 4071, 4070, 4068, 4068, 4071, 4070,
-    4068, 4068, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3175, 3174, 3172, 3172, 3175, 3174,
-    3172, 3172, 3327, 3326, 3324, 3324, 3319, 3318, 3316, 3316, 3303, 3302, 3300, 3300, 3303, 3302,
-    3300, 3300, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3175, 3174, 3172, 3172, 3175, 3174,
-    3172, 3172, 3583, 3582, 3580, 3580, 3575, 3574, 3572, 3572, 3559, 3558, 3556, 3556, 3559, 3558,
-    3556, 3556, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3175, 3174, 3172, 3172, 3175, 3174,
-    3172, 3172, 3327, 3326, 3324, 3324, 3319, 3318, 3316, 3316, 3303, 3302, 3300, 3300, 3303, 3302,
-    3300, 3300, 3199, 3198, 3196, 3196, 3191, 3190, 3188, 3188, 3175, 3174, 3172, 3172, 3175, 3174,
-    3172, 3172, 2047, 2046, 2044, 2044, 2039, 2038, 2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022,
-    2020, 2020, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254,
-    1252, 1252, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 1535, 1534, 1532, 1532, 1527, 1526, 1524, 1524, 1511, 1510, 1508, 1508, 1511, 1510,
-    1508, 1508, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 1279, 1278, 1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254,
-    1252, 1252, 1151, 1150, 1148, 1148, 1143, 1142, 1140, 1140, 1127, 1126, 1124, 1124, 1127, 1126,
-    1124, 1124, 16383, 16382, 16380, 16380, 16375, 16374, 16372, 16372, 16359, 16358, 16356, 16356,
-    16359, 16358, 16356, 16356, 16327, 16326, 16324, 16324, 16327, 16326, 16324, 16324, 16327,
-    16326, 16324, 16324, 16327, 16326, 16324, 16324, 15615, 15614, 15612, 15612, 15607, 15606,
-    15604, 15604, 15591, 15590, 15588, 15588, 15591, 15590, 15588, 15588, 15559, 15558, 15556,
-    15556, 15559, 15558, 15556, 15556, 15559, 15558, 15556, 15556, 15559, 15558, 15556, 15556,
-    15871, 15870, 15868, 15868, 15863, 15862, 15860, 15860, 15847, 15846, 15844, 15844, 15847,
-    15846, 15844, 15844, 15815, 15814, 15812, 15812, 15815, 15814, 15812, 15812, 15815, 15814,
-    15812, 15812, 15815, 15814, 15812, 15812, 15615, 15614, 15612, 15612, 15607, 15606, 15604,
-    15604, 15591, 15590, 15588, 15588, 15591, 15590, 15588, 15588, 15559, 15558, 15556, 15556,
-    15559, 15558, 15556, 15556, 15559, 15558, 15556, 15556, 15559, 15558, 15556, 15556, 2047, 2046,
-    2044, 2044, 2039, 2038, 2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022, 2020, 2020, 1991, 1990,
-    1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1279, 1278,
-    1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254, 1252, 1252, 1223, 1222,
-    1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 1535, 1534,
-    1532, 1532, 1527, 1526, 1524, 1524, 1511, 1510, 1508, 1508, 1511, 1510, 1508, 1508, 1479, 1478,
-    1476, 1476, 1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1279, 1278,
-    1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254, 1252, 1252, 1223, 1222,
-    1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 4095, 4094,
-    4092, 4092, 4087, 4086, 4084, 4084, 4071, 4070, 4068, 4068, 4071, 4070, 4068, 4068, 4039, 4038,
-    4036, 4036, 4039, 4038, 4036, 4036, 4039, 4038, 4036, 4036, 4039, 4038, 4036, 4036, 3327, 3326,
-    3324, 3324, 3319, 3318, 3316, 3316, 3303, 3302, 3300, 3300, 3303, 3302, 3300, 3300, 3271, 3270,
-    3268, 3268, 3271, 3270, 3268, 3268, 3271, 3270, 3268, 3268, 3271, 3270, 3268, 3268, 3583, 3582,
-    3580, 3580, 3575, 3574, 3572, 3572, 3559, 3558, 3556, 3556, 3559, 3558, 3556, 3556, 3527, 3526,
-    3524, 3524, 3527, 3526, 3524, 3524, 3527, 3526, 3524, 3524, 3527, 3526, 3524, 3524, 3327, 3326,
-    3324, 3324, 3319, 3318, 3316, 3316, 3303, 3302, 3300, 3300, 3303, 3302, 3300, 3300, 3271, 3270,
-    3268, 3268, 3271, 3270, 3268, 3268, 3271, 3270, 3268, 3268, 3271, 3270, 3268, 3268, 2047, 2046,
-    2044, 2044, 2039, 2038, 2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022, 2020, 2020, 1991, 1990,
-    1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1279, 1278,
-    1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254, 1252, 1252, 1223, 1222,
-    1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 1535, 1534,
-    1532, 1532, 1527, 1526, 1524, 1524, 1511, 1510, 1508, 1508, 1511, 1510, 1508, 1508, 1479, 1478,
-    1476, 1476, 1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1279, 1278,
-    1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254, 1252, 1252, 1223, 1222,
-    1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 8191, 8190,
-    8188, 8188, 8183, 8182, 8180, 8180, 8167, 8166, 8164, 8164, 8167, 8166, 8164, 8164, 8135, 8134,
-    8132, 8132, 8135, 8134, 8132, 8132, 8135, 8134, 8132, 8132, 8135, 8134, 8132, 8132, 7423, 7422,
-    7420, 7420, 7415, 7414, 7412, 7412, 7399, 7398, 7396, 7396, 7399, 7398, 7396, 7396, 7367, 7366,
-    7364, 7364, 7367, 7366, 7364, 7364, 7367, 7366, 7364, 7364, 7367, 7366, 7364, 7364, 7679, 7678,
-    7676, 7676, 7671, 7670, 7668, 7668, 7655, 7654, 7652, 7652, 7655, 7654, 7652, 7652, 7623, 7622,
-    7620, 7620, 7623, 7622, 7620, 7620, 7623, 7622, 7620, 7620, 7623, 7622, 7620, 7620, 7423, 7422,
-    7420, 7420, 7415, 7414, 7412, 7412, 7399, 7398, 7396, 7396, 7399, 7398, 7396, 7396, 7367, 7366,
-    7364, 7364, 7367, 7366, 7364, 7364, 7367, 7366, 7364, 7364, 7367, 7366, 7364, 7364, 2047, 2046,
-    2044, 2044, 2039, 2038, 2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022, 2020, 2020, 1991, 1990,
-    1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1279, 1278,
-    1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254, 1252, 1252, 1223, 1222,
-    1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 1535, 1534,
-    1532, 1532, 1527, 1526, 1524, 1524, 1511, 1510, 1508, 1508, 1511, 1510, 1508, 1508, 1479, 1478,
-    1476, 1476, 1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1279, 1278,
-    1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254, 1252, 1252, 1223, 1222,
-    1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 4095, 4094,
-    4092, 4092, 4087, 4086, 4084, 4084, 4071, 4070, 4068, 4068, 4071, 4070, 4068, 4068, 4039, 4038,
-    4036, 4036, 4039, 4038, 4036, 4036, 4039, 4038, 4036, 4036, 4039, 4038, 4036, 4036, 3327, 3326,
-    3324, 3324, 3319, 3318, 3316, 3316, 3303, 3302, 3300, 3300, 3303, 3302, 3300, 3300, 3271, 3270,
-    3268, 3268, 3271, 3270, 3268, 3268, 3271, 3270, 3268, 3268, 3271, 3270, 3268, 3268, 3583, 3582,
-    3580, 3580, 3575, 3574, 3572, 3572, 3559, 3558, 3556, 3556, 3559, 3558, 3556, 3556, 3527, 3526,
-    3524, 3524, 3527, 3526, 3524, 3524, 3527, 3526, 3524, 3524, 3527, 3526, 3524, 3524, 3327, 3326,
-    3324, 3324, 3319, 3318, 3316, 3316, 3303, 3302, 3300, 3300, 3303, 3302, 3300, 3300, 3271, 3270,
-    3268, 3268, 3271, 3270, 3268, 3268, 3271, 3270, 3268, 3268, 3271, 3270, 3268, 3268, 2047, 2046,
-    2044, 2044, 2039, 2038, 2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022, 2020, 2020, 1991, 1990,
-    1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1279, 1278,
-    1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254, 1252, 1252, 1223, 1222,
-    1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 1535, 1534,
-    1532, 1532, 1527, 1526, 1524, 1524, 1511, 1510, 1508, 1508, 1511, 1510, 1508, 1508, 1479, 1478,
-    1476, 1476, 1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1279, 1278,
-    1276, 1276, 1271, 1270, 1268, 1268, 1255, 1254, 1252, 1252, 1255, 1254, 1252, 1252, 1223, 1222,
-    1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 1223, 1222, 1220, 1220, 16383,
-    16382, 16380, 16380, 16375, 16374, 16372, 16372, 16359, 16358, 16356, 16356, 16359, 16358,
-    16356, 16356, 16327, 16326, 16324, 16324, 16327, 16326, 16324, 16324, 16327, 16326, 16324,
-    16324, 16327, 16326, 16324, 16324, 16263, 16262, 16260, 16260, 16263, 16262, 16260, 16260,
-    16263, 16262, 16260, 16260, 16263, 16262, 16260, 16260, 16263, 16262, 16260, 16260, 16263,
-    16262, 16260, 16260, 16263, 16262, 16260, 16260, 16263, 16262, 16260, 16260, 15871, 15870,
-    15868, 15868, 15863, 15862, 15860, 15860, 15847, 15846, 15844, 15844, 15847, 15846, 15844,
-    15844, 15815, 15814, 15812, 15812, 15815, 15814, 15812, 15812, 15815, 15814, 15812, 15812,
-    15815, 15814, 15812, 15812, 15751, 15750, 15748, 15748, 15751, 15750, 15748, 15748, 15751,
-    15750, 15748, 15748, 15751, 15750, 15748, 15748, 15751, 15750, 15748, 15748, 15751, 15750,
-    15748, 15748, 15751, 15750, 15748, 15748, 15751, 15750, 15748, 15748, 2047, 2046, 2044, 2044,
-    2039, 2038, 2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022, 2020, 2020, 1991, 1990, 1988, 1988,
-    1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1927, 1926, 1924, 1924,
-    1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924,
-    1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1535, 1534, 1532, 1532,
-    1527, 1526, 1524, 1524, 1511, 1510, 1508, 1508, 1511, 1510, 1508, 1508, 1479, 1478, 1476, 1476,
-    1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1415, 1414, 1412, 1412,
-    1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412,
-    1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 4095, 4094, 4092, 4092,
-    4087, 4086, 4084, 4084, 4071, 4070, 4068, 4068, 4071, 4070, 4068, 4068, 4039, 4038, 4036, 4036,
-    4039, 4038, 4036, 4036, 4039, 4038, 4036, 4036, 4039, 4038, 4036, 4036, 3975, 3974, 3972, 3972,
-    3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972,
-    3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3583, 3582, 3580, 3580,
-    3575, 3574, 3572, 3572, 3559, 3558, 3556, 3556, 3559, 3558, 3556, 3556, 3527, 3526, 3524, 3524,
-    3527, 3526, 3524, 3524, 3527, 3526, 3524, 3524, 3527, 3526, 3524, 3524, 3463, 3462, 3460, 3460,
-    3463, 3462, 3460, 3460, 3463, 3462, 3460, 3460, 3463, 3462, 3460, 3460, 3463, 3462, 3460, 3460,
-    3463, 3462, 3460, 3460, 3463, 3462, 3460, 3460, 3463, 3462, 3460, 3460, 2047, 2046, 2044, 2044,
-    2039, 2038, 2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022, 2020, 2020, 1991, 1990, 1988, 1988,
-    1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1927, 1926, 1924, 1924,
-    1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924,
-    1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1535, 1534, 1532, 1532,
-    1527, 1526, 1524, 1524, 1511, 1510, 1508, 1508, 1511, 1510, 1508, 1508, 1479, 1478, 1476, 1476,
-    1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1415, 1414, 1412, 1412,
-    1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412,
-    1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 8191, 8190, 8188, 8188,
-    8183, 8182, 8180, 8180, 8167, 8166, 8164, 8164, 8167, 8166, 8164, 8164, 8135, 8134, 8132, 8132,
-    8135, 8134, 8132, 8132, 8135, 8134, 8132, 8132, 8135, 8134, 8132, 8132, 8071, 8070, 8068, 8068,
-    8071, 8070, 8068, 8068, 8071, 8070, 8068, 8068, 8071, 8070, 8068, 8068, 8071, 8070, 8068, 8068,
-    8071, 8070, 8068, 8068, 8071, 8070, 8068, 8068, 8071, 8070, 8068, 8068, 7679, 7678, 7676, 7676,
-    7671, 7670, 7668, 7668, 7655, 7654, 7652, 7652, 7655, 7654, 7652, 7652, 7623, 7622, 7620, 7620,
-    7623, 7622, 7620, 7620, 7623, 7622, 7620, 7620, 7623, 7622, 7620, 7620, 7559, 7558, 7556, 7556,
-    7559, 7558, 7556, 7556, 7559, 7558, 7556, 7556, 7559, 7558, 7556, 7556, 7559, 7558, 7556, 7556,
-    7559, 7558, 7556, 7556, 7559, 7558, 7556, 7556, 7559, 7558, 7556, 7556, 2047, 2046, 2044, 2044,
-    2039, 2038, 2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022, 2020, 2020, 1991, 1990, 1988, 1988,
-    1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1927, 1926, 1924, 1924,
-    1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924,
-    1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1535, 1534, 1532, 1532,
-    1527, 1526, 1524, 1524, 1511, 1510, 1508, 1508, 1511, 1510, 1508, 1508, 1479, 1478, 1476, 1476,
-    1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1415, 1414, 1412, 1412,
-    1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412,
-    1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 4095, 4094, 4092, 4092,
-    4087, 4086, 4084, 4084, 4071, 4070, 4068, 4068, 4071, 4070, 4068, 4068, 4039, 4038, 4036, 4036,
-    4039, 4038, 4036, 4036, 4039, 4038, 4036, 4036, 4039, 4038, 4036, 4036, 3975, 3974, 3972, 3972,
-    3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972,
-    3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3583, 3582, 3580, 3580,
-    3575, 3574, 3572, 3572, 3559, 3558, 3556, 3556, 3559, 3558, 3556, 3556, 3527, 3526, 3524, 3524,
-    3527, 3526, 3524, 3524, 3527, 3526, 3524, 3524, 3527, 3526, 3524, 3524, 3463, 3462, 3460, 3460,
-    3463, 3462, 3460, 3460, 3463, 3462, 3460, 3460, 3463, 3462, 3460, 3460, 3463, 3462, 3460, 3460,
-    3463, 3462, 3460, 3460, 3463, 3462, 3460, 3460, 3463, 3462, 3460, 3460, 2047, 2046, 2044, 2044,
-    2039, 2038, 2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022, 2020, 2020, 1991, 1990, 1988, 1988,
-    1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1927, 1926, 1924, 1924,
-    1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924,
-    1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1535, 1534, 1532, 1532,
-    1527, 1526, 1524, 1524, 1511, 1510, 1508, 1508, 1511, 1510, 1508, 1508, 1479, 1478, 1476, 1476,
-    1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1479, 1478, 1476, 1476, 1415, 1414, 1412, 1412,
-    1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412,
-    1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 1415, 1414, 1412, 1412, 16383, 16382, 16380,
-    16380, 16375, 16374, 16372, 16372, 16359, 16358, 16356, 16356, 16359, 16358, 16356, 16356,
-    16327, 16326, 16324, 16324, 16327, 16326, 16324, 16324, 16327, 16326, 16324, 16324, 16327,
-    16326, 16324, 16324, 16263, 16262, 16260, 16260, 16263, 16262, 16260, 16260, 16263, 16262,
-    16260, 16260, 16263, 16262, 16260, 16260, 16263, 16262, 16260, 16260, 16263, 16262, 16260,
-    16260, 16263, 16262, 16260, 16260, 16263, 16262, 16260, 16260, 16135, 16134, 16132, 16132,
-    16135, 16134, 16132, 16132, 16135, 16134, 16132, 16132, 16135, 16134, 16132, 16132, 16135,
-    16134, 16132, 16132, 16135, 16134, 16132, 16132, 16135, 16134, 16132, 16132, 16135, 16134,
-    16132, 16132, 16135, 16134, 16132, 16132, 16135, 16134, 16132, 16132, 16135, 16134, 16132,
-    16132, 16135, 16134, 16132, 16132, 16135, 16134, 16132, 16132, 16135, 16134, 16132, 16132,
-    16135, 16134, 16132, 16132, 16135, 16134, 16132, 16132, 2047, 2046, 2044, 2044, 2039, 2038,
-    2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022, 2020, 2020, 1991, 1990, 1988, 1988, 1991, 1990,
-    1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1927, 1926, 1924, 1924, 1927, 1926,
-    1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926,
-    1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 4095, 4094, 4092, 4092, 4087, 4086,
-    4084, 4084, 4071, 4070, 4068, 4068, 4071, 4070, 4068, 4068, 4039, 4038, 4036, 4036, 4039, 4038,
-    4036, 4036, 4039, 4038, 4036, 4036, 4039, 4038, 4036, 4036, 3975, 3974, 3972, 3972, 3975, 3974,
-    3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974,
-    3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3847, 3846, 3844, 3844, 3847, 3846,
-    3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846,
-    3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846,
-    3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846,
-    3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 2047, 2046, 2044, 2044, 2039, 2038,
-    2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022, 2020, 2020, 1991, 1990, 1988, 1988, 1991, 1990,
-    1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1927, 1926, 1924, 1924, 1927, 1926,
-    1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926,
-    1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 8191, 8190, 8188, 8188, 8183, 8182,
-    8180, 8180, 8167, 8166, 8164, 8164, 8167, 8166, 8164, 8164, 8135, 8134, 8132, 8132, 8135, 8134,
-    8132, 8132, 8135, 8134, 8132, 8132, 8135, 8134, 8132, 8132, 8071, 8070, 8068, 8068, 8071, 8070,
-    8068, 8068, 8071, 8070, 8068, 8068, 8071, 8070, 8068, 8068, 8071, 8070, 8068, 8068, 8071, 8070,
-    8068, 8068, 8071, 8070, 8068, 8068, 8071, 8070, 8068, 8068, 7943, 7942, 7940, 7940, 7943, 7942,
-    7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942,
-    7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942,
-    7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942,
-    7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940, 7940, 2047, 2046, 2044, 2044, 2039, 2038,
-    2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022, 2020, 2020, 1991, 1990, 1988, 1988, 1991, 1990,
-    1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1927, 1926, 1924, 1924, 1927, 1926,
-    1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926,
-    1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 4095, 4094, 4092, 4092, 4087, 4086,
-    4084, 4084, 4071, 4070, 4068, 4068, 4071, 4070, 4068, 4068, 4039, 4038, 4036, 4036, 4039, 4038,
-    4036, 4036, 4039, 4038, 4036, 4036, 4039, 4038, 4036, 4036, 3975, 3974, 3972, 3972, 3975, 3974,
-    3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974,
-    3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3847, 3846, 3844, 3844, 3847, 3846,
-    3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846,
-    3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846,
-    3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846,
-    3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 2047, 2046, 2044, 2044, 2039, 2038,
-    2036, 2036, 2023, 2022, 2020, 2020, 2023, 2022, 2020, 2020, 1991, 1990, 1988, 1988, 1991, 1990,
-    1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1927, 1926, 1924, 1924, 1927, 1926,
-    1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926,
-    1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798,
-    1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 16383, 16382, 16380, 16380, 16375,
-    16374, 16372, 16372, 16359, 16358, 16356, 16356, 16359, 16358, 16356, 16356, 16327, 16326,
-    16324, 16324, 16327, 16326, 16324, 16324, 16327, 16326, 16324, 16324, 16327, 16326, 16324,
-    16324, 16263, 16262, 16260, 16260, 16263, 16262, 16260, 16260, 16263, 16262, 16260, 16260,
-    16263, 16262, 16260, 16260, 16263, 16262, 16260, 16260, 16263, 16262, 16260, 16260, 16263,
-    16262, 16260, 16260, 16263, 16262, 16260, 16260, 16135, 16134, 16132, 16132, 16135, 16134,
-    16132, 16132, 16135, 16134, 16132, 16132, 16135, 16134, 16132, 16132, 16135, 16134, 16132,
-    16132, 16135, 16134, 16132, 16132, 16135, 16134, 16132, 16132, 16135, 16134, 16132, 16132,
-    16135, 16134, 16132, 16132, 16135, 16134, 16132, 16132, 16135, 16134, 16132, 16132, 16135,
-    16134, 16132, 16132, 16135, 16134, 16132, 16132, 16135, 16134, 16132, 16132, 16135, 16134,
-    16132, 16132, 16135, 16134, 16132, 16132, 15879, 15878, 15876, 15876, 15879, 15878, 15876,
-    15876, 15879, 15878, 15876, 15876, 15879, 15878, 15876, 15876, 15879, 15878, 15876, 15876,
-    15879, 15878, 15876, 15876, 15879, 15878, 15876, 15876, 15879, 15878, 15876, 15876, 15879,
-    15878, 15876, 15876, 15879, 15878, 15876, 15876, 15879, 15878, 15876, 15876, 15879, 15878,
-    15876, 15876, 15879, 15878, 15876, 15876, 15879, 15878, 15876, 15876, 15879, 15878, 15876,
-    15876, 15879, 15878, 15876, 15876, 15879, 15878, 15876, 15876, 15879, 15878, 15876, 15876,
-    15879, 15878, 15876, 15876, 15879, 15878, 15876, 15876, 15879, 15878, 15876, 15876, 15879,
-    15878, 15876, 15876, 15879, 15878, 15876, 15876, 15879, 15878, 15876, 15876, 15879, 15878,
-    15876, 15876, 15879, 15878, 15876, 15876, 15879, 15878, 15876, 15876, 15879, 15878, 15876,
-    15876, 15879, 15878, 15876, 15876, 15879, 15878, 15876, 15876, 15879, 15878, 15876, 15876,
-    15879, 15878, 15876, 15876, 2047, 2046, 2044, 2044, 2039, 2038, 2036, 2036, 2023, 2022, 2020,
-    2020, 2023, 2022, 2020, 2020, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988,
-    1988, 1991, 1990, 1988, 1988, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924,
-    1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924,
-    1924, 1927, 1926, 1924, 1924, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 4095, 4094, 4092, 4092, 4087, 4086, 4084, 4084, 4071, 4070, 4068,
-    4068, 4071, 4070, 4068, 4068, 4039, 4038, 4036, 4036, 4039, 4038, 4036, 4036, 4039, 4038, 4036,
-    4036, 4039, 4038, 4036, 4036, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972,
-    3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972,
-    3972, 3975, 3974, 3972, 3972, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844,
-    3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844,
-    3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844,
-    3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844,
-    3844, 3847, 3846, 3844, 3844, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 2047, 2046, 2044, 2044, 2039, 2038, 2036, 2036, 2023, 2022, 2020,
-    2020, 2023, 2022, 2020, 2020, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988,
-    1988, 1991, 1990, 1988, 1988, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924,
-    1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924,
-    1924, 1927, 1926, 1924, 1924, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 8191, 8190, 8188, 8188, 8183, 8182, 8180, 8180, 8167, 8166, 8164,
-    8164, 8167, 8166, 8164, 8164, 8135, 8134, 8132, 8132, 8135, 8134, 8132, 8132, 8135, 8134, 8132,
-    8132, 8135, 8134, 8132, 8132, 8071, 8070, 8068, 8068, 8071, 8070, 8068, 8068, 8071, 8070, 8068,
-    8068, 8071, 8070, 8068, 8068, 8071, 8070, 8068, 8068, 8071, 8070, 8068, 8068, 8071, 8070, 8068,
-    8068, 8071, 8070, 8068, 8068, 7943, 7942, 7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940,
-    7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940,
-    7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940,
-    7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940, 7940, 7943, 7942, 7940,
-    7940, 7943, 7942, 7940, 7940, 7687, 7686, 7684, 7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684,
-    7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684,
-    7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684,
-    7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684,
-    7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684,
-    7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684,
-    7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684,
-    7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684, 7684, 7687, 7686, 7684,
-    7684, 7687, 7686, 7684, 7684, 2047, 2046, 2044, 2044, 2039, 2038, 2036, 2036, 2023, 2022, 2020,
-    2020, 2023, 2022, 2020, 2020, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988,
-    1988, 1991, 1990, 1988, 1988, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924,
-    1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924,
-    1924, 1927, 1926, 1924, 1924, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 4095, 4094, 4092, 4092, 4087, 4086, 4084, 4084, 4071, 4070, 4068,
-    4068, 4071, 4070, 4068, 4068, 4039, 4038, 4036, 4036, 4039, 4038, 4036, 4036, 4039, 4038, 4036,
-    4036, 4039, 4038, 4036, 4036, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972,
-    3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972, 3972, 3975, 3974, 3972,
-    3972, 3975, 3974, 3972, 3972, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844,
-    3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844,
-    3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844,
-    3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844, 3844, 3847, 3846, 3844,
-    3844, 3847, 3846, 3844, 3844, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588, 3588, 3591, 3590, 3588,
-    3588, 3591, 3590, 3588, 3588, 2047, 2046, 2044, 2044, 2039, 2038, 2036, 2036, 2023, 2022, 2020,
-    2020, 2023, 2022, 2020, 2020, 1991, 1990, 1988, 1988, 1991, 1990, 1988, 1988, 1991, 1990, 1988,
-    1988, 1991, 1990, 1988, 1988, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924,
-    1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924, 1924, 1927, 1926, 1924,
-    1924, 1927, 1926, 1924, 1924, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796, 1796, 1799, 1798, 1796,
-    1796, 1799, 1798, 1796, 1796, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540, 1540, 1543, 1542, 1540,
-    1540, 1543, 1542, 1540, 1540, 16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376, 14367,
-    14366, 14364, 14364, 14360, 14360, 14360, 14360, 14399, 14398, 14396, 14396, 14392, 14392,
-    14392, 14392, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14463, 14462, 14460,
-    14460, 14456, 14456, 14456, 14456, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360,
-    14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14367, 14366, 14364, 14364, 14360,
-    14360, 14360, 14360, 14591, 14590, 14588, 14588, 14584, 14584, 14584, 14584, 14367, 14366,
-    14364, 14364, 14360, 14360, 14360, 14360, 14399, 14398, 14396, 14396, 14392, 14392, 14392,
-    14392, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14463, 14462, 14460, 14460,
-    14456, 14456, 14456, 14456, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14399,
-    14398, 14396, 14396, 14392, 14392, 14392, 14392, 14367, 14366, 14364, 14364, 14360, 14360,
-    14360, 14360, 14847, 14846, 14844, 14844, 14840, 14840, 14840, 14840, 14367, 14366, 14364,
-    14364, 14360, 14360, 14360, 14360, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392,
-    14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14463, 14462, 14460, 14460, 14456,
-    14456, 14456, 14456, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14399, 14398,
-    14396, 14396, 14392, 14392, 14392, 14392, 14367, 14366, 14364, 14364, 14360, 14360, 14360,
-    14360, 14591, 14590, 14588, 14588, 14584, 14584, 14584, 14584, 14367, 14366, 14364, 14364,
-    14360, 14360, 14360, 14360, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14367,
-    14366, 14364, 14364, 14360, 14360, 14360, 14360, 14463, 14462, 14460, 14460, 14456, 14456,
-    14456, 14456, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14399, 14398, 14396,
-    14396, 14392, 14392, 14392, 14392, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360,
-    15359, 15358, 15356, 15356, 15352, 15352, 15352, 15352, 14367, 14366, 14364, 14364, 14360,
-    14360, 14360, 14360, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14367, 14366,
-    14364, 14364, 14360, 14360, 14360, 14360, 14463, 14462, 14460, 14460, 14456, 14456, 14456,
-    14456, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14399, 14398, 14396, 14396,
-    14392, 14392, 14392, 14392, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14591,
-    14590, 14588, 14588, 14584, 14584, 14584, 14584, 14367, 14366, 14364, 14364, 14360, 14360,
-    14360, 14360, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14367, 14366, 14364,
-    14364, 14360, 14360, 14360, 14360, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456,
-    14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14399, 14398, 14396, 14396, 14392,
-    14392, 14392, 14392, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14847, 14846,
-    14844, 14844, 14840, 14840, 14840, 14840, 14367, 14366, 14364, 14364, 14360, 14360, 14360,
-    14360, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14367, 14366, 14364, 14364,
-    14360, 14360, 14360, 14360, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14367,
-    14366, 14364, 14364, 14360, 14360, 14360, 14360, 14399, 14398, 14396, 14396, 14392, 14392,
-    14392, 14392, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14591, 14590, 14588,
-    14588, 14584, 14584, 14584, 14584, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360,
-    14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14367, 14366, 14364, 14364, 14360,
-    14360, 14360, 14360, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14367, 14366,
-    14364, 14364, 14360, 14360, 14360, 14360, 14399, 14398, 14396, 14396, 14392, 14392, 14392,
-    14392, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 4095, 4094, 4092, 4092, 4088,
-    4088, 4088, 4088, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2303, 2302, 2300, 2300, 2296,
-    2296, 2296, 2296, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2559, 2558, 2556, 2556, 2552,
-    2552, 2552, 2552, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2303, 2302, 2300, 2300, 2296,
-    2296, 2296, 2296, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 3071, 3070, 3068, 3068, 3064,
-    3064, 3064, 3064, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2303, 2302, 2300, 2300, 2296,
-    2296, 2296, 2296, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2559, 2558, 2556, 2556, 2552,
-    2552, 2552, 2552, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2303, 2302, 2300, 2300, 2296,
-    2296, 2296, 2296, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 8191, 8190, 8188, 8188, 8184,
-    8184, 8184, 8184, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6271, 6270, 6268, 6268, 6264,
-    6264, 6264, 6264, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6399, 6398, 6396, 6396, 6392,
-    6392, 6392, 6392, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6271, 6270, 6268, 6268, 6264,
-    6264, 6264, 6264, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6655, 6654, 6652, 6652, 6648,
-    6648, 6648, 6648, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6271, 6270, 6268, 6268, 6264,
-    6264, 6264, 6264, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6399, 6398, 6396, 6396, 6392,
-    6392, 6392, 6392, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6271, 6270, 6268, 6268, 6264,
-    6264, 6264, 6264, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 7167, 7166, 7164, 7164, 7160,
-    7160, 7160, 7160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6271, 6270, 6268, 6268, 6264,
-    6264, 6264, 6264, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6399, 6398, 6396, 6396, 6392,
-    6392, 6392, 6392, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6271, 6270, 6268, 6268, 6264,
-    6264, 6264, 6264, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6655, 6654, 6652, 6652, 6648,
-    6648, 6648, 6648, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6271, 6270, 6268, 6268, 6264,
-    6264, 6264, 6264, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6399, 6398, 6396, 6396, 6392,
-    6392, 6392, 6392, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6271, 6270, 6268, 6268, 6264,
-    6264, 6264, 6264, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6207, 6206, 6204, 6204, 6200,
-    6200, 6200, 6200, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 4095, 4094, 4092, 4092, 4088,
-    4088, 4088, 4088, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2303, 2302, 2300, 2300, 2296,
-    2296, 2296, 2296, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2559, 2558, 2556, 2556, 2552,
-    2552, 2552, 2552, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2303, 2302, 2300, 2300, 2296,
-    2296, 2296, 2296, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 3071, 3070, 3068, 3068, 3064,
-    3064, 3064, 3064, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2303, 2302, 2300, 2300, 2296,
-    2296, 2296, 2296, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2559, 2558, 2556, 2556, 2552,
-    2552, 2552, 2552, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2303, 2302, 2300, 2300, 2296,
-    2296, 2296, 2296, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2175, 2174, 2172, 2172, 2168,
-    2168, 2168, 2168, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 2111, 2110, 2108, 2108, 2104,
-    2104, 2104, 2104, 2079, 2078, 2076, 2076, 2072, 2072, 2072, 2072, 16383, 16382, 16380, 16380,
-    16376, 16376, 16376, 16376, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14463,
-    14462, 14460, 14460, 14456, 14456, 14456, 14456, 14399, 14398, 14396, 14396, 14392, 14392,
-    14392, 14392, 14591, 14590, 14588, 14588, 14584, 14584, 14584, 14584, 14399, 14398, 14396,
-    14396, 14392, 14392, 14392, 14392, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456,
-    14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14847, 14846, 14844, 14844, 14840,
-    14840, 14840, 14840, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14463, 14462,
-    14460, 14460, 14456, 14456, 14456, 14456, 14399, 14398, 14396, 14396, 14392, 14392, 14392,
-    14392, 14591, 14590, 14588, 14588, 14584, 14584, 14584, 14584, 14399, 14398, 14396, 14396,
-    14392, 14392, 14392, 14392, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14399,
-    14398, 14396, 14396, 14392, 14392, 14392, 14392, 15359, 15358, 15356, 15356, 15352, 15352,
-    15352, 15352, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14463, 14462, 14460,
-    14460, 14456, 14456, 14456, 14456, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392,
-    14591, 14590, 14588, 14588, 14584, 14584, 14584, 14584, 14399, 14398, 14396, 14396, 14392,
-    14392, 14392, 14392, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14399, 14398,
-    14396, 14396, 14392, 14392, 14392, 14392, 14847, 14846, 14844, 14844, 14840, 14840, 14840,
-    14840, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14463, 14462, 14460, 14460,
-    14456, 14456, 14456, 14456, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14591,
-    14590, 14588, 14588, 14584, 14584, 14584, 14584, 14399, 14398, 14396, 14396, 14392, 14392,
-    14392, 14392, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14399, 14398, 14396,
-    14396, 14392, 14392, 14392, 14392, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2559, 2558, 2556, 2556, 2552, 2552, 2552, 2552, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 3071, 3070, 3068, 3068, 3064, 3064, 3064, 3064, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2559, 2558, 2556, 2556, 2552, 2552, 2552, 2552, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 6399, 6398, 6396, 6396, 6392, 6392, 6392, 6392, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 6655, 6654, 6652, 6652, 6648, 6648, 6648, 6648, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 6399, 6398, 6396, 6396, 6392, 6392, 6392, 6392, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 7167, 7166, 7164, 7164, 7160, 7160, 7160, 7160, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 6399, 6398, 6396, 6396, 6392, 6392, 6392, 6392, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 6655, 6654, 6652, 6652, 6648, 6648, 6648, 6648, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 6399, 6398, 6396, 6396, 6392, 6392, 6392, 6392, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6207, 6206,
-    6204, 6204, 6200, 6200, 6200, 6200, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2559, 2558, 2556, 2556, 2552, 2552, 2552, 2552, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 3071, 3070, 3068, 3068, 3064, 3064, 3064, 3064, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2559, 2558, 2556, 2556, 2552, 2552, 2552, 2552, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2111, 2110,
-    2108, 2108, 2104, 2104, 2104, 2104, 16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376,
-    16367, 16366, 16364, 16364, 16360, 16360, 16360, 16360, 14463, 14462, 14460, 14460, 14456,
-    14456, 14456, 14456, 14447, 14446, 14444, 14444, 14440, 14440, 14440, 14440, 14591, 14590,
-    14588, 14588, 14584, 14584, 14584, 14584, 14575, 14574, 14572, 14572, 14568, 14568, 14568,
-    14568, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14447, 14446, 14444, 14444,
-    14440, 14440, 14440, 14440, 14847, 14846, 14844, 14844, 14840, 14840, 14840, 14840, 14831,
-    14830, 14828, 14828, 14824, 14824, 14824, 14824, 14463, 14462, 14460, 14460, 14456, 14456,
-    14456, 14456, 14447, 14446, 14444, 14444, 14440, 14440, 14440, 14440, 14591, 14590, 14588,
-    14588, 14584, 14584, 14584, 14584, 14575, 14574, 14572, 14572, 14568, 14568, 14568, 14568,
-    14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14447, 14446, 14444, 14444, 14440,
-    14440, 14440, 14440, 15359, 15358, 15356, 15356, 15352, 15352, 15352, 15352, 15343, 15342,
-    15340, 15340, 15336, 15336, 15336, 15336, 14463, 14462, 14460, 14460, 14456, 14456, 14456,
-    14456, 14447, 14446, 14444, 14444, 14440, 14440, 14440, 14440, 14591, 14590, 14588, 14588,
-    14584, 14584, 14584, 14584, 14575, 14574, 14572, 14572, 14568, 14568, 14568, 14568, 14463,
-    14462, 14460, 14460, 14456, 14456, 14456, 14456, 14447, 14446, 14444, 14444, 14440, 14440,
-    14440, 14440, 14847, 14846, 14844, 14844, 14840, 14840, 14840, 14840, 14831, 14830, 14828,
-    14828, 14824, 14824, 14824, 14824, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456,
-    14447, 14446, 14444, 14444, 14440, 14440, 14440, 14440, 14591, 14590, 14588, 14588, 14584,
-    14584, 14584, 14584, 14575, 14574, 14572, 14572, 14568, 14568, 14568, 14568, 14463, 14462,
-    14460, 14460, 14456, 14456, 14456, 14456, 14447, 14446, 14444, 14444, 14440, 14440, 14440,
-    14440, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4079, 4078, 4076, 4076, 4072, 4072,
-    4072, 4072, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280,
-    2280, 2280, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 2559, 2558, 2556, 2556, 2552, 2552, 2552, 2552, 2543, 2542, 2540, 2540, 2536, 2536,
-    2536, 2536, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280,
-    2280, 2280, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 3071, 3070, 3068, 3068, 3064, 3064, 3064, 3064, 3055, 3054, 3052, 3052, 3048, 3048,
-    3048, 3048, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280,
-    2280, 2280, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 2559, 2558, 2556, 2556, 2552, 2552, 2552, 2552, 2543, 2542, 2540, 2540, 2536, 2536,
-    2536, 2536, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280,
-    2280, 2280, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 8175, 8174, 8172, 8172, 8168, 8168,
-    8168, 8168, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6255, 6254, 6252, 6252, 6248, 6248,
-    6248, 6248, 6399, 6398, 6396, 6396, 6392, 6392, 6392, 6392, 6383, 6382, 6380, 6380, 6376, 6376,
-    6376, 6376, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6255, 6254, 6252, 6252, 6248, 6248,
-    6248, 6248, 6655, 6654, 6652, 6652, 6648, 6648, 6648, 6648, 6639, 6638, 6636, 6636, 6632, 6632,
-    6632, 6632, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6255, 6254, 6252, 6252, 6248, 6248,
-    6248, 6248, 6399, 6398, 6396, 6396, 6392, 6392, 6392, 6392, 6383, 6382, 6380, 6380, 6376, 6376,
-    6376, 6376, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6255, 6254, 6252, 6252, 6248, 6248,
-    6248, 6248, 7167, 7166, 7164, 7164, 7160, 7160, 7160, 7160, 7151, 7150, 7148, 7148, 7144, 7144,
-    7144, 7144, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6255, 6254, 6252, 6252, 6248, 6248,
-    6248, 6248, 6399, 6398, 6396, 6396, 6392, 6392, 6392, 6392, 6383, 6382, 6380, 6380, 6376, 6376,
-    6376, 6376, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6255, 6254, 6252, 6252, 6248, 6248,
-    6248, 6248, 6655, 6654, 6652, 6652, 6648, 6648, 6648, 6648, 6639, 6638, 6636, 6636, 6632, 6632,
-    6632, 6632, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6255, 6254, 6252, 6252, 6248, 6248,
-    6248, 6248, 6399, 6398, 6396, 6396, 6392, 6392, 6392, 6392, 6383, 6382, 6380, 6380, 6376, 6376,
-    6376, 6376, 6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6255, 6254, 6252, 6252, 6248, 6248,
-    6248, 6248, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4079, 4078, 4076, 4076, 4072, 4072,
-    4072, 4072, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280,
-    2280, 2280, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 2559, 2558, 2556, 2556, 2552, 2552, 2552, 2552, 2543, 2542, 2540, 2540, 2536, 2536,
-    2536, 2536, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280,
-    2280, 2280, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 3071, 3070, 3068, 3068, 3064, 3064, 3064, 3064, 3055, 3054, 3052, 3052, 3048, 3048,
-    3048, 3048, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280,
-    2280, 2280, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 2559, 2558, 2556, 2556, 2552, 2552, 2552, 2552, 2543, 2542, 2540, 2540, 2536, 2536,
-    2536, 2536, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 2303, 2302, 2300, 2300, 2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280,
-    2280, 2280, 2175, 2174, 2172, 2172, 2168, 2168, 2168, 2168, 2159, 2158, 2156, 2156, 2152, 2152,
-    2152, 2152, 16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376, 16367, 16366, 16364, 16364,
-    16360, 16360, 16360, 16360, 16335, 16334, 16332, 16332, 16328, 16328, 16328, 16328, 16335,
-    16334, 16332, 16332, 16328, 16328, 16328, 16328, 14591, 14590, 14588, 14588, 14584, 14584,
-    14584, 14584, 14575, 14574, 14572, 14572, 14568, 14568, 14568, 14568, 14543, 14542, 14540,
-    14540, 14536, 14536, 14536, 14536, 14543, 14542, 14540, 14540, 14536, 14536, 14536, 14536,
-    14847, 14846, 14844, 14844, 14840, 14840, 14840, 14840, 14831, 14830, 14828, 14828, 14824,
-    14824, 14824, 14824, 14799, 14798, 14796, 14796, 14792, 14792, 14792, 14792, 14799, 14798,
-    14796, 14796, 14792, 14792, 14792, 14792, 14591, 14590, 14588, 14588, 14584, 14584, 14584,
-    14584, 14575, 14574, 14572, 14572, 14568, 14568, 14568, 14568, 14543, 14542, 14540, 14540,
-    14536, 14536, 14536, 14536, 14543, 14542, 14540, 14540, 14536, 14536, 14536, 14536, 15359,
-    15358, 15356, 15356, 15352, 15352, 15352, 15352, 15343, 15342, 15340, 15340, 15336, 15336,
-    15336, 15336, 15311, 15310, 15308, 15308, 15304, 15304, 15304, 15304, 15311, 15310, 15308,
-    15308, 15304, 15304, 15304, 15304, 14591, 14590, 14588, 14588, 14584, 14584, 14584, 14584,
-    14575, 14574, 14572, 14572, 14568, 14568, 14568, 14568, 14543, 14542, 14540, 14540, 14536,
-    14536, 14536, 14536, 14543, 14542, 14540, 14540, 14536, 14536, 14536, 14536, 14847, 14846,
-    14844, 14844, 14840, 14840, 14840, 14840, 14831, 14830, 14828, 14828, 14824, 14824, 14824,
-    14824, 14799, 14798, 14796, 14796, 14792, 14792, 14792, 14792, 14799, 14798, 14796, 14796,
-    14792, 14792, 14792, 14792, 14591, 14590, 14588, 14588, 14584, 14584, 14584, 14584, 14575,
-    14574, 14572, 14572, 14568, 14568, 14568, 14568, 14543, 14542, 14540, 14540, 14536, 14536,
-    14536, 14536, 14543, 14542, 14540, 14540, 14536, 14536, 14536, 14536, 4095, 4094, 4092, 4092,
-    4088, 4088, 4088, 4088, 4079, 4078, 4076, 4076, 4072, 4072, 4072, 4072, 4047, 4046, 4044, 4044,
-    4040, 4040, 4040, 4040, 4047, 4046, 4044, 4044, 4040, 4040, 4040, 4040, 2303, 2302, 2300, 2300,
-    2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280, 2280, 2280, 2255, 2254, 2252, 2252,
-    2248, 2248, 2248, 2248, 2255, 2254, 2252, 2252, 2248, 2248, 2248, 2248, 2559, 2558, 2556, 2556,
-    2552, 2552, 2552, 2552, 2543, 2542, 2540, 2540, 2536, 2536, 2536, 2536, 2511, 2510, 2508, 2508,
-    2504, 2504, 2504, 2504, 2511, 2510, 2508, 2508, 2504, 2504, 2504, 2504, 2303, 2302, 2300, 2300,
-    2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280, 2280, 2280, 2255, 2254, 2252, 2252,
-    2248, 2248, 2248, 2248, 2255, 2254, 2252, 2252, 2248, 2248, 2248, 2248, 3071, 3070, 3068, 3068,
-    3064, 3064, 3064, 3064, 3055, 3054, 3052, 3052, 3048, 3048, 3048, 3048, 3023, 3022, 3020, 3020,
-    3016, 3016, 3016, 3016, 3023, 3022, 3020, 3020, 3016, 3016, 3016, 3016, 2303, 2302, 2300, 2300,
-    2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280, 2280, 2280, 2255, 2254, 2252, 2252,
-    2248, 2248, 2248, 2248, 2255, 2254, 2252, 2252, 2248, 2248, 2248, 2248, 2559, 2558, 2556, 2556,
-    2552, 2552, 2552, 2552, 2543, 2542, 2540, 2540, 2536, 2536, 2536, 2536, 2511, 2510, 2508, 2508,
-    2504, 2504, 2504, 2504, 2511, 2510, 2508, 2508, 2504, 2504, 2504, 2504, 2303, 2302, 2300, 2300,
-    2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280, 2280, 2280, 2255, 2254, 2252, 2252,
-    2248, 2248, 2248, 2248, 2255, 2254, 2252, 2252, 2248, 2248, 2248, 2248, 8191, 8190, 8188, 8188,
-    8184, 8184, 8184, 8184, 8175, 8174, 8172, 8172, 8168, 8168, 8168, 8168, 8143, 8142, 8140, 8140,
-    8136, 8136, 8136, 8136, 8143, 8142, 8140, 8140, 8136, 8136, 8136, 8136, 6399, 6398, 6396, 6396,
-    6392, 6392, 6392, 6392, 6383, 6382, 6380, 6380, 6376, 6376, 6376, 6376, 6351, 6350, 6348, 6348,
-    6344, 6344, 6344, 6344, 6351, 6350, 6348, 6348, 6344, 6344, 6344, 6344, 6655, 6654, 6652, 6652,
-    6648, 6648, 6648, 6648, 6639, 6638, 6636, 6636, 6632, 6632, 6632, 6632, 6607, 6606, 6604, 6604,
-    6600, 6600, 6600, 6600, 6607, 6606, 6604, 6604, 6600, 6600, 6600, 6600, 6399, 6398, 6396, 6396,
-    6392, 6392, 6392, 6392, 6383, 6382, 6380, 6380, 6376, 6376, 6376, 6376, 6351, 6350, 6348, 6348,
-    6344, 6344, 6344, 6344, 6351, 6350, 6348, 6348, 6344, 6344, 6344, 6344, 7167, 7166, 7164, 7164,
-    7160, 7160, 7160, 7160, 7151, 7150, 7148, 7148, 7144, 7144, 7144, 7144, 7119, 7118, 7116, 7116,
-    7112, 7112, 7112, 7112, 7119, 7118, 7116, 7116, 7112, 7112, 7112, 7112, 6399, 6398, 6396, 6396,
-    6392, 6392, 6392, 6392, 6383, 6382, 6380, 6380, 6376, 6376, 6376, 6376, 6351, 6350, 6348, 6348,
-    6344, 6344, 6344, 6344, 6351, 6350, 6348, 6348, 6344, 6344, 6344, 6344, 6655, 6654, 6652, 6652,
-    6648, 6648, 6648, 6648, 6639, 6638, 6636, 6636, 6632, 6632, 6632, 6632, 6607, 6606, 6604, 6604,
-    6600, 6600, 6600, 6600, 6607, 6606, 6604, 6604, 6600, 6600, 6600, 6600, 6399, 6398, 6396, 6396,
-    6392, 6392, 6392, 6392, 6383, 6382, 6380, 6380, 6376, 6376, 6376, 6376, 6351, 6350, 6348, 6348,
-    6344, 6344, 6344, 6344, 6351, 6350, 6348, 6348, 6344, 6344, 6344, 6344, 4095, 4094, 4092, 4092,
-    4088, 4088, 4088, 4088, 4079, 4078, 4076, 4076, 4072, 4072, 4072, 4072, 4047, 4046, 4044, 4044,
-    4040, 4040, 4040, 4040, 4047, 4046, 4044, 4044, 4040, 4040, 4040, 4040, 2303, 2302, 2300, 2300,
-    2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280, 2280, 2280, 2255, 2254, 2252, 2252,
-    2248, 2248, 2248, 2248, 2255, 2254, 2252, 2252, 2248, 2248, 2248, 2248, 2559, 2558, 2556, 2556,
-    2552, 2552, 2552, 2552, 2543, 2542, 2540, 2540, 2536, 2536, 2536, 2536, 2511, 2510, 2508, 2508,
-    2504, 2504, 2504, 2504, 2511, 2510, 2508, 2508, 2504, 2504, 2504, 2504, 2303, 2302, 2300, 2300,
-    2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280, 2280, 2280, 2255, 2254, 2252, 2252,
-    2248, 2248, 2248, 2248, 2255, 2254, 2252, 2252, 2248, 2248, 2248, 2248, 3071, 3070, 3068, 3068,
-    3064, 3064, 3064, 3064, 3055, 3054, 3052, 3052, 3048, 3048, 3048, 3048, 3023, 3022, 3020, 3020,
-    3016, 3016, 3016, 3016, 3023, 3022, 3020, 3020, 3016, 3016, 3016, 3016, 2303, 2302, 2300, 2300,
-    2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280, 2280, 2280, 2255, 2254, 2252, 2252,
-    2248, 2248, 2248, 2248, 2255, 2254, 2252, 2252, 2248, 2248, 2248, 2248, 2559, 2558, 2556, 2556,
-    2552, 2552, 2552, 2552, 2543, 2542, 2540, 2540, 2536, 2536, 2536, 2536, 2511, 2510, 2508, 2508,
-    2504, 2504, 2504, 2504, 2511, 2510, 2508, 2508, 2504, 2504, 2504, 2504, 2303, 2302, 2300, 2300,
-    2296, 2296, 2296, 2296, 2287, 2286, 2284, 2284, 2280, 2280, 2280, 2280, 2255, 2254, 2252, 2252,
-    2248, 2248, 2248, 2248, 2255, 2254, 2252, 2252, 2248, 2248, 2248, 2248, 16383, 16382, 16380,
-    16380, 16376, 16376, 16376, 16376, 16367, 16366, 16364, 16364, 16360, 16360, 16360, 16360,
-    16335, 16334, 16332, 16332, 16328, 16328, 16328, 16328, 16335, 16334, 16332, 16332, 16328,
-    16328, 16328, 16328, 16271, 16270, 16268, 16268, 16264, 16264, 16264, 16264, 16271, 16270,
-    16268, 16268, 16264, 16264, 16264, 16264, 16271, 16270, 16268, 16268, 16264, 16264, 16264,
-    16264, 16271, 16270, 16268, 16268, 16264, 16264, 16264, 16264, 14847, 14846, 14844, 14844,
-    14840, 14840, 14840, 14840, 14831, 14830, 14828, 14828, 14824, 14824, 14824, 14824, 14799,
-    14798, 14796, 14796, 14792, 14792, 14792, 14792, 14799, 14798, 14796, 14796, 14792, 14792,
-    14792, 14792, 14735, 14734, 14732, 14732, 14728, 14728, 14728, 14728, 14735, 14734, 14732,
-    14732, 14728, 14728, 14728, 14728, 14735, 14734, 14732, 14732, 14728, 14728, 14728, 14728,
-    14735, 14734, 14732, 14732, 14728, 14728, 14728, 14728, 15359, 15358, 15356, 15356, 15352,
-    15352, 15352, 15352, 15343, 15342, 15340, 15340, 15336, 15336, 15336, 15336, 15311, 15310,
-    15308, 15308, 15304, 15304, 15304, 15304, 15311, 15310, 15308, 15308, 15304, 15304, 15304,
-    15304, 15247, 15246, 15244, 15244, 15240, 15240, 15240, 15240, 15247, 15246, 15244, 15244,
-    15240, 15240, 15240, 15240, 15247, 15246, 15244, 15244, 15240, 15240, 15240, 15240, 15247,
-    15246, 15244, 15244, 15240, 15240, 15240, 15240, 14847, 14846, 14844, 14844, 14840, 14840,
-    14840, 14840, 14831, 14830, 14828, 14828, 14824, 14824, 14824, 14824, 14799, 14798, 14796,
-    14796, 14792, 14792, 14792, 14792, 14799, 14798, 14796, 14796, 14792, 14792, 14792, 14792,
-    14735, 14734, 14732, 14732, 14728, 14728, 14728, 14728, 14735, 14734, 14732, 14732, 14728,
-    14728, 14728, 14728, 14735, 14734, 14732, 14732, 14728, 14728, 14728, 14728, 14735, 14734,
-    14732, 14732, 14728, 14728, 14728, 14728, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4079,
-    4078, 4076, 4076, 4072, 4072, 4072, 4072, 4047, 4046, 4044, 4044, 4040, 4040, 4040, 4040, 4047,
-    4046, 4044, 4044, 4040, 4040, 4040, 4040, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983,
-    3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983,
-    3982, 3980, 3980, 3976, 3976, 3976, 3976, 2559, 2558, 2556, 2556, 2552, 2552, 2552, 2552, 2543,
-    2542, 2540, 2540, 2536, 2536, 2536, 2536, 2511, 2510, 2508, 2508, 2504, 2504, 2504, 2504, 2511,
-    2510, 2508, 2508, 2504, 2504, 2504, 2504, 2447, 2446, 2444, 2444, 2440, 2440, 2440, 2440, 2447,
-    2446, 2444, 2444, 2440, 2440, 2440, 2440, 2447, 2446, 2444, 2444, 2440, 2440, 2440, 2440, 2447,
-    2446, 2444, 2444, 2440, 2440, 2440, 2440, 3071, 3070, 3068, 3068, 3064, 3064, 3064, 3064, 3055,
-    3054, 3052, 3052, 3048, 3048, 3048, 3048, 3023, 3022, 3020, 3020, 3016, 3016, 3016, 3016, 3023,
-    3022, 3020, 3020, 3016, 3016, 3016, 3016, 2959, 2958, 2956, 2956, 2952, 2952, 2952, 2952, 2959,
-    2958, 2956, 2956, 2952, 2952, 2952, 2952, 2959, 2958, 2956, 2956, 2952, 2952, 2952, 2952, 2959,
-    2958, 2956, 2956, 2952, 2952, 2952, 2952, 2559, 2558, 2556, 2556, 2552, 2552, 2552, 2552, 2543,
-    2542, 2540, 2540, 2536, 2536, 2536, 2536, 2511, 2510, 2508, 2508, 2504, 2504, 2504, 2504, 2511,
-    2510, 2508, 2508, 2504, 2504, 2504, 2504, 2447, 2446, 2444, 2444, 2440, 2440, 2440, 2440, 2447,
-    2446, 2444, 2444, 2440, 2440, 2440, 2440, 2447, 2446, 2444, 2444, 2440, 2440, 2440, 2440, 2447,
-    2446, 2444, 2444, 2440, 2440, 2440, 2440, 8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 8175,
-    8174, 8172, 8172, 8168, 8168, 8168, 8168, 8143, 8142, 8140, 8140, 8136, 8136, 8136, 8136, 8143,
-    8142, 8140, 8140, 8136, 8136, 8136, 8136, 8079, 8078, 8076, 8076, 8072, 8072, 8072, 8072, 8079,
-    8078, 8076, 8076, 8072, 8072, 8072, 8072, 8079, 8078, 8076, 8076, 8072, 8072, 8072, 8072, 8079,
-    8078, 8076, 8076, 8072, 8072, 8072, 8072, 6655, 6654, 6652, 6652, 6648, 6648, 6648, 6648, 6639,
-    6638, 6636, 6636, 6632, 6632, 6632, 6632, 6607, 6606, 6604, 6604, 6600, 6600, 6600, 6600, 6607,
-    6606, 6604, 6604, 6600, 6600, 6600, 6600, 6543, 6542, 6540, 6540, 6536, 6536, 6536, 6536, 6543,
-    6542, 6540, 6540, 6536, 6536, 6536, 6536, 6543, 6542, 6540, 6540, 6536, 6536, 6536, 6536, 6543,
-    6542, 6540, 6540, 6536, 6536, 6536, 6536, 7167, 7166, 7164, 7164, 7160, 7160, 7160, 7160, 7151,
-    7150, 7148, 7148, 7144, 7144, 7144, 7144, 7119, 7118, 7116, 7116, 7112, 7112, 7112, 7112, 7119,
-    7118, 7116, 7116, 7112, 7112, 7112, 7112, 7055, 7054, 7052, 7052, 7048, 7048, 7048, 7048, 7055,
-    7054, 7052, 7052, 7048, 7048, 7048, 7048, 7055, 7054, 7052, 7052, 7048, 7048, 7048, 7048, 7055,
-    7054, 7052, 7052, 7048, 7048, 7048, 7048, 6655, 6654, 6652, 6652, 6648, 6648, 6648, 6648, 6639,
-    6638, 6636, 6636, 6632, 6632, 6632, 6632, 6607, 6606, 6604, 6604, 6600, 6600, 6600, 6600, 6607,
-    6606, 6604, 6604, 6600, 6600, 6600, 6600, 6543, 6542, 6540, 6540, 6536, 6536, 6536, 6536, 6543,
-    6542, 6540, 6540, 6536, 6536, 6536, 6536, 6543, 6542, 6540, 6540, 6536, 6536, 6536, 6536, 6543,
-    6542, 6540, 6540, 6536, 6536, 6536, 6536, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4079,
-    4078, 4076, 4076, 4072, 4072, 4072, 4072, 4047, 4046, 4044, 4044, 4040, 4040, 4040, 4040, 4047,
-    4046, 4044, 4044, 4040, 4040, 4040, 4040, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983,
-    3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983,
-    3982, 3980, 3980, 3976, 3976, 3976, 3976, 2559, 2558, 2556, 2556, 2552, 2552, 2552, 2552, 2543,
-    2542, 2540, 2540, 2536, 2536, 2536, 2536, 2511, 2510, 2508, 2508, 2504, 2504, 2504, 2504, 2511,
-    2510, 2508, 2508, 2504, 2504, 2504, 2504, 2447, 2446, 2444, 2444, 2440, 2440, 2440, 2440, 2447,
-    2446, 2444, 2444, 2440, 2440, 2440, 2440, 2447, 2446, 2444, 2444, 2440, 2440, 2440, 2440, 2447,
-    2446, 2444, 2444, 2440, 2440, 2440, 2440, 3071, 3070, 3068, 3068, 3064, 3064, 3064, 3064, 3055,
-    3054, 3052, 3052, 3048, 3048, 3048, 3048, 3023, 3022, 3020, 3020, 3016, 3016, 3016, 3016, 3023,
-    3022, 3020, 3020, 3016, 3016, 3016, 3016, 2959, 2958, 2956, 2956, 2952, 2952, 2952, 2952, 2959,
-    2958, 2956, 2956, 2952, 2952, 2952, 2952, 2959, 2958, 2956, 2956, 2952, 2952, 2952, 2952, 2959,
-    2958, 2956, 2956, 2952, 2952, 2952, 2952, 2559, 2558, 2556, 2556, 2552, 2552, 2552, 2552, 2543,
-    2542, 2540, 2540, 2536, 2536, 2536, 2536, 2511, 2510, 2508, 2508, 2504, 2504, 2504, 2504, 2511,
-    2510, 2508, 2508, 2504, 2504, 2504, 2504, 2447, 2446, 2444, 2444, 2440, 2440, 2440, 2440, 2447,
-    2446, 2444, 2444, 2440, 2440, 2440, 2440, 2447, 2446, 2444, 2444, 2440, 2440, 2440, 2440, 2447,
-    2446, 2444, 2444, 2440, 2440, 2440, 2440, 16383, 16382, 16380, 16380, 16376, 16376, 16376,
-    16376, 16367, 16366, 16364, 16364, 16360, 16360, 16360, 16360, 16335, 16334, 16332, 16332,
-    16328, 16328, 16328, 16328, 16335, 16334, 16332, 16332, 16328, 16328, 16328, 16328, 16271,
-    16270, 16268, 16268, 16264, 16264, 16264, 16264, 16271, 16270, 16268, 16268, 16264, 16264,
-    16264, 16264, 16271, 16270, 16268, 16268, 16264, 16264, 16264, 16264, 16271, 16270, 16268,
-    16268, 16264, 16264, 16264, 16264, 16143, 16142, 16140, 16140, 16136, 16136, 16136, 16136,
-    16143, 16142, 16140, 16140, 16136, 16136, 16136, 16136, 16143, 16142, 16140, 16140, 16136,
-    16136, 16136, 16136, 16143, 16142, 16140, 16140, 16136, 16136, 16136, 16136, 16143, 16142,
-    16140, 16140, 16136, 16136, 16136, 16136, 16143, 16142, 16140, 16140, 16136, 16136, 16136,
-    16136, 16143, 16142, 16140, 16140, 16136, 16136, 16136, 16136, 16143, 16142, 16140, 16140,
-    16136, 16136, 16136, 16136, 15359, 15358, 15356, 15356, 15352, 15352, 15352, 15352, 15343,
-    15342, 15340, 15340, 15336, 15336, 15336, 15336, 15311, 15310, 15308, 15308, 15304, 15304,
-    15304, 15304, 15311, 15310, 15308, 15308, 15304, 15304, 15304, 15304, 15247, 15246, 15244,
-    15244, 15240, 15240, 15240, 15240, 15247, 15246, 15244, 15244, 15240, 15240, 15240, 15240,
-    15247, 15246, 15244, 15244, 15240, 15240, 15240, 15240, 15247, 15246, 15244, 15244, 15240,
-    15240, 15240, 15240, 15119, 15118, 15116, 15116, 15112, 15112, 15112, 15112, 15119, 15118,
-    15116, 15116, 15112, 15112, 15112, 15112, 15119, 15118, 15116, 15116, 15112, 15112, 15112,
-    15112, 15119, 15118, 15116, 15116, 15112, 15112, 15112, 15112, 15119, 15118, 15116, 15116,
-    15112, 15112, 15112, 15112, 15119, 15118, 15116, 15116, 15112, 15112, 15112, 15112, 15119,
-    15118, 15116, 15116, 15112, 15112, 15112, 15112, 15119, 15118, 15116, 15116, 15112, 15112,
-    15112, 15112, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4079, 4078, 4076, 4076, 4072,
-    4072, 4072, 4072, 4047, 4046, 4044, 4044, 4040, 4040, 4040, 4040, 4047, 4046, 4044, 4044, 4040,
-    4040, 4040, 4040, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980, 3976,
-    3976, 3976, 3976, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980, 3976,
-    3976, 3976, 3976, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848,
-    3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848,
-    3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848,
-    3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848,
-    3848, 3848, 3848, 3071, 3070, 3068, 3068, 3064, 3064, 3064, 3064, 3055, 3054, 3052, 3052, 3048,
-    3048, 3048, 3048, 3023, 3022, 3020, 3020, 3016, 3016, 3016, 3016, 3023, 3022, 3020, 3020, 3016,
-    3016, 3016, 3016, 2959, 2958, 2956, 2956, 2952, 2952, 2952, 2952, 2959, 2958, 2956, 2956, 2952,
-    2952, 2952, 2952, 2959, 2958, 2956, 2956, 2952, 2952, 2952, 2952, 2959, 2958, 2956, 2956, 2952,
-    2952, 2952, 2952, 2831, 2830, 2828, 2828, 2824, 2824, 2824, 2824, 2831, 2830, 2828, 2828, 2824,
-    2824, 2824, 2824, 2831, 2830, 2828, 2828, 2824, 2824, 2824, 2824, 2831, 2830, 2828, 2828, 2824,
-    2824, 2824, 2824, 2831, 2830, 2828, 2828, 2824, 2824, 2824, 2824, 2831, 2830, 2828, 2828, 2824,
-    2824, 2824, 2824, 2831, 2830, 2828, 2828, 2824, 2824, 2824, 2824, 2831, 2830, 2828, 2828, 2824,
-    2824, 2824, 2824, 8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 8175, 8174, 8172, 8172, 8168,
-    8168, 8168, 8168, 8143, 8142, 8140, 8140, 8136, 8136, 8136, 8136, 8143, 8142, 8140, 8140, 8136,
-    8136, 8136, 8136, 8079, 8078, 8076, 8076, 8072, 8072, 8072, 8072, 8079, 8078, 8076, 8076, 8072,
-    8072, 8072, 8072, 8079, 8078, 8076, 8076, 8072, 8072, 8072, 8072, 8079, 8078, 8076, 8076, 8072,
-    8072, 8072, 8072, 7951, 7950, 7948, 7948, 7944, 7944, 7944, 7944, 7951, 7950, 7948, 7948, 7944,
-    7944, 7944, 7944, 7951, 7950, 7948, 7948, 7944, 7944, 7944, 7944, 7951, 7950, 7948, 7948, 7944,
-    7944, 7944, 7944, 7951, 7950, 7948, 7948, 7944, 7944, 7944, 7944, 7951, 7950, 7948, 7948, 7944,
-    7944, 7944, 7944, 7951, 7950, 7948, 7948, 7944, 7944, 7944, 7944, 7951, 7950, 7948, 7948, 7944,
-    7944, 7944, 7944, 7167, 7166, 7164, 7164, 7160, 7160, 7160, 7160, 7151, 7150, 7148, 7148, 7144,
-    7144, 7144, 7144, 7119, 7118, 7116, 7116, 7112, 7112, 7112, 7112, 7119, 7118, 7116, 7116, 7112,
-    7112, 7112, 7112, 7055, 7054, 7052, 7052, 7048, 7048, 7048, 7048, 7055, 7054, 7052, 7052, 7048,
-    7048, 7048, 7048, 7055, 7054, 7052, 7052, 7048, 7048, 7048, 7048, 7055, 7054, 7052, 7052, 7048,
-    7048, 7048, 7048, 6927, 6926, 6924, 6924, 6920, 6920, 6920, 6920, 6927, 6926, 6924, 6924, 6920,
-    6920, 6920, 6920, 6927, 6926, 6924, 6924, 6920, 6920, 6920, 6920, 6927, 6926, 6924, 6924, 6920,
-    6920, 6920, 6920, 6927, 6926, 6924, 6924, 6920, 6920, 6920, 6920, 6927, 6926, 6924, 6924, 6920,
-    6920, 6920, 6920, 6927, 6926, 6924, 6924, 6920, 6920, 6920, 6920, 6927, 6926, 6924, 6924, 6920,
-    6920, 6920, 6920, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4079, 4078, 4076, 4076, 4072,
-    4072, 4072, 4072, 4047, 4046, 4044, 4044, 4040, 4040, 4040, 4040, 4047, 4046, 4044, 4044, 4040,
-    4040, 4040, 4040, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980, 3976,
-    3976, 3976, 3976, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980, 3976,
-    3976, 3976, 3976, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848,
-    3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848,
-    3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848,
-    3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848,
-    3848, 3848, 3848, 3071, 3070, 3068, 3068, 3064, 3064, 3064, 3064, 3055, 3054, 3052, 3052, 3048,
-    3048, 3048, 3048, 3023, 3022, 3020, 3020, 3016, 3016, 3016, 3016, 3023, 3022, 3020, 3020, 3016,
-    3016, 3016, 3016, 2959, 2958, 2956, 2956, 2952, 2952, 2952, 2952, 2959, 2958, 2956, 2956, 2952,
-    2952, 2952, 2952, 2959, 2958, 2956, 2956, 2952, 2952, 2952, 2952, 2959, 2958, 2956, 2956, 2952,
-    2952, 2952, 2952, 2831, 2830, 2828, 2828, 2824, 2824, 2824, 2824, 2831, 2830, 2828, 2828, 2824,
-    2824, 2824, 2824, 2831, 2830, 2828, 2828, 2824, 2824, 2824, 2824, 2831, 2830, 2828, 2828, 2824,
-    2824, 2824, 2824, 2831, 2830, 2828, 2828, 2824, 2824, 2824, 2824, 2831, 2830, 2828, 2828, 2824,
-    2824, 2824, 2824, 2831, 2830, 2828, 2828, 2824, 2824, 2824, 2824, 2831, 2830, 2828, 2828, 2824,
-    2824, 2824, 2824, 16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376, 16367, 16366, 16364,
-    16364, 16360, 16360, 16360, 16360, 16335, 16334, 16332, 16332, 16328, 16328, 16328, 16328,
-    16335, 16334, 16332, 16332, 16328, 16328, 16328, 16328, 16271, 16270, 16268, 16268, 16264,
-    16264, 16264, 16264, 16271, 16270, 16268, 16268, 16264, 16264, 16264, 16264, 16271, 16270,
-    16268, 16268, 16264, 16264, 16264, 16264, 16271, 16270, 16268, 16268, 16264, 16264, 16264,
-    16264, 16143, 16142, 16140, 16140, 16136, 16136, 16136, 16136, 16143, 16142, 16140, 16140,
-    16136, 16136, 16136, 16136, 16143, 16142, 16140, 16140, 16136, 16136, 16136, 16136, 16143,
-    16142, 16140, 16140, 16136, 16136, 16136, 16136, 16143, 16142, 16140, 16140, 16136, 16136,
-    16136, 16136, 16143, 16142, 16140, 16140, 16136, 16136, 16136, 16136, 16143, 16142, 16140,
-    16140, 16136, 16136, 16136, 16136, 16143, 16142, 16140, 16140, 16136, 16136, 16136, 16136,
-    15887, 15886, 15884, 15884, 15880, 15880, 15880, 15880, 15887, 15886, 15884, 15884, 15880,
-    15880, 15880, 15880, 15887, 15886, 15884, 15884, 15880, 15880, 15880, 15880, 15887, 15886,
-    15884, 15884, 15880, 15880, 15880, 15880, 15887, 15886, 15884, 15884, 15880, 15880, 15880,
-    15880, 15887, 15886, 15884, 15884, 15880, 15880, 15880, 15880, 15887, 15886, 15884, 15884,
-    15880, 15880, 15880, 15880, 15887, 15886, 15884, 15884, 15880, 15880, 15880, 15880, 15887,
-    15886, 15884, 15884, 15880, 15880, 15880, 15880, 15887, 15886, 15884, 15884, 15880, 15880,
-    15880, 15880, 15887, 15886, 15884, 15884, 15880, 15880, 15880, 15880, 15887, 15886, 15884,
-    15884, 15880, 15880, 15880, 15880, 15887, 15886, 15884, 15884, 15880, 15880, 15880, 15880,
-    15887, 15886, 15884, 15884, 15880, 15880, 15880, 15880, 15887, 15886, 15884, 15884, 15880,
-    15880, 15880, 15880, 15887, 15886, 15884, 15884, 15880, 15880, 15880, 15880, 4095, 4094, 4092,
-    4092, 4088, 4088, 4088, 4088, 4079, 4078, 4076, 4076, 4072, 4072, 4072, 4072, 4047, 4046, 4044,
-    4044, 4040, 4040, 4040, 4040, 4047, 4046, 4044, 4044, 4040, 4040, 4040, 4040, 3983, 3982, 3980,
-    3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980,
-    3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3855, 3854, 3852,
-    3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852,
-    3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852,
-    3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852,
-    3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 8191, 8190, 8188,
-    8188, 8184, 8184, 8184, 8184, 8175, 8174, 8172, 8172, 8168, 8168, 8168, 8168, 8143, 8142, 8140,
-    8140, 8136, 8136, 8136, 8136, 8143, 8142, 8140, 8140, 8136, 8136, 8136, 8136, 8079, 8078, 8076,
-    8076, 8072, 8072, 8072, 8072, 8079, 8078, 8076, 8076, 8072, 8072, 8072, 8072, 8079, 8078, 8076,
-    8076, 8072, 8072, 8072, 8072, 8079, 8078, 8076, 8076, 8072, 8072, 8072, 8072, 7951, 7950, 7948,
-    7948, 7944, 7944, 7944, 7944, 7951, 7950, 7948, 7948, 7944, 7944, 7944, 7944, 7951, 7950, 7948,
-    7948, 7944, 7944, 7944, 7944, 7951, 7950, 7948, 7948, 7944, 7944, 7944, 7944, 7951, 7950, 7948,
-    7948, 7944, 7944, 7944, 7944, 7951, 7950, 7948, 7948, 7944, 7944, 7944, 7944, 7951, 7950, 7948,
-    7948, 7944, 7944, 7944, 7944, 7951, 7950, 7948, 7948, 7944, 7944, 7944, 7944, 7695, 7694, 7692,
-    7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692,
-    7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692,
-    7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692,
-    7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692,
-    7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692,
-    7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692,
-    7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692,
-    7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 4095, 4094, 4092,
-    4092, 4088, 4088, 4088, 4088, 4079, 4078, 4076, 4076, 4072, 4072, 4072, 4072, 4047, 4046, 4044,
-    4044, 4040, 4040, 4040, 4040, 4047, 4046, 4044, 4044, 4040, 4040, 4040, 4040, 3983, 3982, 3980,
-    3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980,
-    3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3855, 3854, 3852,
-    3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852,
-    3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852,
-    3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852,
-    3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596,
-    3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 16383, 16382,
-    16380, 16380, 16376, 16376, 16376, 16376, 16367, 16366, 16364, 16364, 16360, 16360, 16360,
-    16360, 16335, 16334, 16332, 16332, 16328, 16328, 16328, 16328, 16335, 16334, 16332, 16332,
-    16328, 16328, 16328, 16328, 16271, 16270, 16268, 16268, 16264, 16264, 16264, 16264, 16271,
-    16270, 16268, 16268, 16264, 16264, 16264, 16264, 16271, 16270, 16268, 16268, 16264, 16264,
-    16264, 16264, 16271, 16270, 16268, 16268, 16264, 16264, 16264, 16264, 16143, 16142, 16140,
-    16140, 16136, 16136, 16136, 16136, 16143, 16142, 16140, 16140, 16136, 16136, 16136, 16136,
-    16143, 16142, 16140, 16140, 16136, 16136, 16136, 16136, 16143, 16142, 16140, 16140, 16136,
-    16136, 16136, 16136, 16143, 16142, 16140, 16140, 16136, 16136, 16136, 16136, 16143, 16142,
-    16140, 16140, 16136, 16136, 16136, 16136, 16143, 16142, 16140, 16140, 16136, 16136, 16136,
-    16136, 16143, 16142, 16140, 16140, 16136, 16136, 16136, 16136, 15887, 15886, 15884, 15884,
-    15880, 15880, 15880, 15880, 15887, 15886, 15884, 15884, 15880, 15880, 15880, 15880, 15887,
-    15886, 15884, 15884, 15880, 15880, 15880, 15880, 15887, 15886, 15884, 15884, 15880, 15880,
-    15880, 15880, 15887, 15886, 15884, 15884, 15880, 15880, 15880, 15880, 15887, 15886, 15884,
-    15884, 15880, 15880, 15880, 15880, 15887, 15886, 15884, 15884, 15880, 15880, 15880, 15880,
-    15887, 15886, 15884, 15884, 15880, 15880, 15880, 15880, 15887, 15886, 15884, 15884, 15880,
-    15880, 15880, 15880, 15887, 15886, 15884, 15884, 15880, 15880, 15880, 15880, 15887, 15886,
-    15884, 15884, 15880, 15880, 15880, 15880, 15887, 15886, 15884, 15884, 15880, 15880, 15880,
-    15880, 15887, 15886, 15884, 15884, 15880, 15880, 15880, 15880, 15887, 15886, 15884, 15884,
-    15880, 15880, 15880, 15880, 15887, 15886, 15884, 15884, 15880, 15880, 15880, 15880, 15887,
-    15886, 15884, 15884, 15880, 15880, 15880, 15880, 15375, 15374, 15372, 15372, 15368, 15368,
-    15368, 15368, 15375, 15374, 15372, 15372, 15368, 15368, 15368, 15368, 15375, 15374, 15372,
-    15372, 15368, 15368, 15368, 15368, 15375, 15374, 15372, 15372, 15368, 15368, 15368, 15368,
-    15375, 15374, 15372, 15372, 15368, 15368, 15368, 15368, 15375, 15374, 15372, 15372, 15368,
-    15368, 15368, 15368, 15375, 15374, 15372, 15372, 15368, 15368, 15368, 15368, 15375, 15374,
-    15372, 15372, 15368, 15368, 15368, 15368, 15375, 15374, 15372, 15372, 15368, 15368, 15368,
-    15368, 15375, 15374, 15372, 15372, 15368, 15368, 15368, 15368, 15375, 15374, 15372, 15372,
-    15368, 15368, 15368, 15368, 15375, 15374, 15372, 15372, 15368, 15368, 15368, 15368, 15375,
-    15374, 15372, 15372, 15368, 15368, 15368, 15368, 15375, 15374, 15372, 15372, 15368, 15368,
-    15368, 15368, 15375, 15374, 15372, 15372, 15368, 15368, 15368, 15368, 15375, 15374, 15372,
-    15372, 15368, 15368, 15368, 15368, 15375, 15374, 15372, 15372, 15368, 15368, 15368, 15368,
-    15375, 15374, 15372, 15372, 15368, 15368, 15368, 15368, 15375, 15374, 15372, 15372, 15368,
-    15368, 15368, 15368, 15375, 15374, 15372, 15372, 15368, 15368, 15368, 15368, 15375, 15374,
-    15372, 15372, 15368, 15368, 15368, 15368, 15375, 15374, 15372, 15372, 15368, 15368, 15368,
-    15368, 15375, 15374, 15372, 15372, 15368, 15368, 15368, 15368, 15375, 15374, 15372, 15372,
-    15368, 15368, 15368, 15368, 15375, 15374, 15372, 15372, 15368, 15368, 15368, 15368, 15375,
-    15374, 15372, 15372, 15368, 15368, 15368, 15368, 15375, 15374, 15372, 15372, 15368, 15368,
-    15368, 15368, 15375, 15374, 15372, 15372, 15368, 15368, 15368, 15368, 15375, 15374, 15372,
-    15372, 15368, 15368, 15368, 15368, 15375, 15374, 15372, 15372, 15368, 15368, 15368, 15368,
-    15375, 15374, 15372, 15372, 15368, 15368, 15368, 15368, 15375, 15374, 15372, 15372, 15368,
-    15368, 15368, 15368, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4079, 4078, 4076, 4076,
-    4072, 4072, 4072, 4072, 4047, 4046, 4044, 4044, 4040, 4040, 4040, 4040, 4047, 4046, 4044, 4044,
-    4040, 4040, 4040, 4040, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980,
-    3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980,
-    3976, 3976, 3976, 3976, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852,
-    3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852,
-    3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852,
-    3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852,
-    3848, 3848, 3848, 3848, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 8175, 8174, 8172, 8172,
-    8168, 8168, 8168, 8168, 8143, 8142, 8140, 8140, 8136, 8136, 8136, 8136, 8143, 8142, 8140, 8140,
-    8136, 8136, 8136, 8136, 8079, 8078, 8076, 8076, 8072, 8072, 8072, 8072, 8079, 8078, 8076, 8076,
-    8072, 8072, 8072, 8072, 8079, 8078, 8076, 8076, 8072, 8072, 8072, 8072, 8079, 8078, 8076, 8076,
-    8072, 8072, 8072, 8072, 7951, 7950, 7948, 7948, 7944, 7944, 7944, 7944, 7951, 7950, 7948, 7948,
-    7944, 7944, 7944, 7944, 7951, 7950, 7948, 7948, 7944, 7944, 7944, 7944, 7951, 7950, 7948, 7948,
-    7944, 7944, 7944, 7944, 7951, 7950, 7948, 7948, 7944, 7944, 7944, 7944, 7951, 7950, 7948, 7948,
-    7944, 7944, 7944, 7944, 7951, 7950, 7948, 7948, 7944, 7944, 7944, 7944, 7951, 7950, 7948, 7948,
-    7944, 7944, 7944, 7944, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692,
-    7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692,
-    7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692,
-    7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692,
-    7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692,
-    7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692,
-    7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692,
-    7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692, 7688, 7688, 7688, 7688, 7695, 7694, 7692, 7692,
-    7688, 7688, 7688, 7688, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180, 7176, 7176, 7176, 7176, 7183, 7182, 7180, 7180,
-    7176, 7176, 7176, 7176, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4079, 4078, 4076, 4076,
-    4072, 4072, 4072, 4072, 4047, 4046, 4044, 4044, 4040, 4040, 4040, 4040, 4047, 4046, 4044, 4044,
-    4040, 4040, 4040, 4040, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980,
-    3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980, 3976, 3976, 3976, 3976, 3983, 3982, 3980, 3980,
-    3976, 3976, 3976, 3976, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852,
-    3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852,
-    3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852,
-    3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852, 3848, 3848, 3848, 3848, 3855, 3854, 3852, 3852,
-    3848, 3848, 3848, 3848, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596, 3592, 3592, 3592, 3592, 3599, 3598, 3596, 3596,
-    3592, 3592, 3592, 3592, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084, 3080, 3080, 3080, 3080, 3087, 3086, 3084, 3084,
-    3080, 3080, 3080, 3080, 16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368,
-    16368, 16368, 16368, 16368, 16368, 16368, 12351, 12350, 12348, 12348, 12344, 12344, 12344,
-    12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12415, 12414, 12412, 12412,
-    12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12351,
-    12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336,
-    12336, 12336, 12543, 12542, 12540, 12540, 12536, 12536, 12536, 12536, 12528, 12528, 12528,
-    12528, 12528, 12528, 12528, 12528, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344,
-    12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12415, 12414, 12412, 12412, 12408,
-    12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12351, 12350,
-    12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336,
-    12336, 12799, 12798, 12796, 12796, 12792, 12792, 12792, 12792, 12784, 12784, 12784, 12784,
-    12784, 12784, 12784, 12784, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336,
-    12336, 12336, 12336, 12336, 12336, 12336, 12336, 12415, 12414, 12412, 12412, 12408, 12408,
-    12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12351, 12350, 12348,
-    12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336,
-    12543, 12542, 12540, 12540, 12536, 12536, 12536, 12536, 12528, 12528, 12528, 12528, 12528,
-    12528, 12528, 12528, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336,
-    12336, 12336, 12336, 12336, 12336, 12336, 12415, 12414, 12412, 12412, 12408, 12408, 12408,
-    12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12351, 12350, 12348, 12348,
-    12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 13311,
-    13310, 13308, 13308, 13304, 13304, 13304, 13304, 13296, 13296, 13296, 13296, 13296, 13296,
-    13296, 13296, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336,
-    12336, 12336, 12336, 12336, 12336, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408,
-    12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12351, 12350, 12348, 12348, 12344,
-    12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12543, 12542,
-    12540, 12540, 12536, 12536, 12536, 12536, 12528, 12528, 12528, 12528, 12528, 12528, 12528,
-    12528, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336,
-    12336, 12336, 12336, 12336, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400,
-    12400, 12400, 12400, 12400, 12400, 12400, 12400, 12351, 12350, 12348, 12348, 12344, 12344,
-    12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12799, 12798, 12796,
-    12796, 12792, 12792, 12792, 12792, 12784, 12784, 12784, 12784, 12784, 12784, 12784, 12784,
-    12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336,
-    12336, 12336, 12336, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400,
-    12400, 12400, 12400, 12400, 12400, 12400, 12351, 12350, 12348, 12348, 12344, 12344, 12344,
-    12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12543, 12542, 12540, 12540,
-    12536, 12536, 12536, 12536, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12351,
-    12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336,
-    12336, 12336, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400,
-    12400, 12400, 12400, 12400, 12400, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344,
-    12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 14335, 14334, 14332, 14332, 14328,
-    14328, 14328, 14328, 14320, 14320, 14320, 14320, 14320, 14320, 14320, 14320, 12351, 12350,
-    12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336,
-    12336, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400,
-    12400, 12400, 12400, 12400, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336,
-    12336, 12336, 12336, 12336, 12336, 12336, 12336, 12543, 12542, 12540, 12540, 12536, 12536,
-    12536, 12536, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12351, 12350, 12348,
-    12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336,
-    12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400,
-    12400, 12400, 12400, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336,
-    12336, 12336, 12336, 12336, 12336, 12336, 12799, 12798, 12796, 12796, 12792, 12792, 12792,
-    12792, 12784, 12784, 12784, 12784, 12784, 12784, 12784, 12784, 12351, 12350, 12348, 12348,
-    12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12415,
-    12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400,
-    12400, 12400, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336,
-    12336, 12336, 12336, 12336, 12336, 12543, 12542, 12540, 12540, 12536, 12536, 12536, 12536,
-    12528, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12351, 12350, 12348, 12348, 12344,
-    12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12415, 12414,
-    12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400,
-    12400, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336,
-    12336, 12336, 12336, 12336, 13311, 13310, 13308, 13308, 13304, 13304, 13304, 13304, 13296,
-    13296, 13296, 13296, 13296, 13296, 13296, 13296, 12351, 12350, 12348, 12348, 12344, 12344,
-    12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12415, 12414, 12412,
-    12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400,
-    12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336,
-    12336, 12336, 12336, 12543, 12542, 12540, 12540, 12536, 12536, 12536, 12536, 12528, 12528,
-    12528, 12528, 12528, 12528, 12528, 12528, 12351, 12350, 12348, 12348, 12344, 12344, 12344,
-    12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12415, 12414, 12412, 12412,
-    12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12351,
-    12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336,
-    12336, 12336, 12799, 12798, 12796, 12796, 12792, 12792, 12792, 12792, 12784, 12784, 12784,
-    12784, 12784, 12784, 12784, 12784, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344,
-    12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12415, 12414, 12412, 12412, 12408,
-    12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12351, 12350,
-    12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336,
-    12336, 12543, 12542, 12540, 12540, 12536, 12536, 12536, 12536, 12528, 12528, 12528, 12528,
-    12528, 12528, 12528, 12528, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336,
-    12336, 12336, 12336, 12336, 12336, 12336, 12336, 12415, 12414, 12412, 12412, 12408, 12408,
-    12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12351, 12350, 12348,
-    12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336,
-    8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8176,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4607, 4606, 4604, 4604, 4600, 4600, 4600, 4600, 4592, 4592, 4592, 4592, 4592, 4592, 4592, 4592,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    5119, 5118, 5116, 5116, 5112, 5112, 5112, 5112, 5104, 5104, 5104, 5104, 5104, 5104, 5104, 5104,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4607, 4606, 4604, 4604, 4600, 4600, 4600, 4600, 4592, 4592, 4592, 4592, 4592, 4592, 4592, 4592,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    6143, 6142, 6140, 6140, 6136, 6136, 6136, 6136, 6128, 6128, 6128, 6128, 6128, 6128, 6128, 6128,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4607, 4606, 4604, 4604, 4600, 4600, 4600, 4600, 4592, 4592, 4592, 4592, 4592, 4592, 4592, 4592,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    5119, 5118, 5116, 5116, 5112, 5112, 5112, 5112, 5104, 5104, 5104, 5104, 5104, 5104, 5104, 5104,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4607, 4606, 4604, 4604, 4600, 4600, 4600, 4600, 4592, 4592, 4592, 4592, 4592, 4592, 4592, 4592,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208, 4208, 4208, 4208, 4208, 4208, 4208,
-    4159, 4158, 4156, 4156, 4152, 4152, 4152, 4152, 4144, 4144, 4144, 4144, 4144, 4144, 4144, 4144,
-    16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368, 16368, 16368, 16368,
-    16368, 16368, 16368, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400,
-    12400, 12400, 12400, 12400, 12400, 12400, 12543, 12542, 12540, 12540, 12536, 12536, 12536,
-    12536, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12415, 12414, 12412, 12412,
-    12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12799,
-    12798, 12796, 12796, 12792, 12792, 12792, 12792, 12784, 12784, 12784, 12784, 12784, 12784,
-    12784, 12784, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400,
-    12400, 12400, 12400, 12400, 12400, 12543, 12542, 12540, 12540, 12536, 12536, 12536, 12536,
-    12528, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12415, 12414, 12412, 12412, 12408,
-    12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 13311, 13310,
-    13308, 13308, 13304, 13304, 13304, 13304, 13296, 13296, 13296, 13296, 13296, 13296, 13296,
-    13296, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400,
-    12400, 12400, 12400, 12400, 12543, 12542, 12540, 12540, 12536, 12536, 12536, 12536, 12528,
-    12528, 12528, 12528, 12528, 12528, 12528, 12528, 12415, 12414, 12412, 12412, 12408, 12408,
-    12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12799, 12798, 12796,
-    12796, 12792, 12792, 12792, 12792, 12784, 12784, 12784, 12784, 12784, 12784, 12784, 12784,
-    12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400,
-    12400, 12400, 12400, 12543, 12542, 12540, 12540, 12536, 12536, 12536, 12536, 12528, 12528,
-    12528, 12528, 12528, 12528, 12528, 12528, 12415, 12414, 12412, 12412, 12408, 12408, 12408,
-    12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 14335, 14334, 14332, 14332,
-    14328, 14328, 14328, 14328, 14320, 14320, 14320, 14320, 14320, 14320, 14320, 14320, 12415,
-    12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400,
-    12400, 12400, 12543, 12542, 12540, 12540, 12536, 12536, 12536, 12536, 12528, 12528, 12528,
-    12528, 12528, 12528, 12528, 12528, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408,
-    12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12799, 12798, 12796, 12796, 12792,
-    12792, 12792, 12792, 12784, 12784, 12784, 12784, 12784, 12784, 12784, 12784, 12415, 12414,
-    12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400,
-    12400, 12543, 12542, 12540, 12540, 12536, 12536, 12536, 12536, 12528, 12528, 12528, 12528,
-    12528, 12528, 12528, 12528, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400,
-    12400, 12400, 12400, 12400, 12400, 12400, 12400, 13311, 13310, 13308, 13308, 13304, 13304,
-    13304, 13304, 13296, 13296, 13296, 13296, 13296, 13296, 13296, 13296, 12415, 12414, 12412,
-    12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400,
-    12543, 12542, 12540, 12540, 12536, 12536, 12536, 12536, 12528, 12528, 12528, 12528, 12528,
-    12528, 12528, 12528, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400,
-    12400, 12400, 12400, 12400, 12400, 12400, 12799, 12798, 12796, 12796, 12792, 12792, 12792,
-    12792, 12784, 12784, 12784, 12784, 12784, 12784, 12784, 12784, 12415, 12414, 12412, 12412,
-    12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12543,
-    12542, 12540, 12540, 12536, 12536, 12536, 12536, 12528, 12528, 12528, 12528, 12528, 12528,
-    12528, 12528, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400,
-    12400, 12400, 12400, 12400, 12400, 8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 8176, 8176,
-    8176, 8176, 8176, 8176, 8176, 8176, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336,
-    4336, 4336, 4336, 4336, 4336, 4336, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 4607, 4606, 4604, 4604, 4600, 4600, 4600, 4600, 4592, 4592,
-    4592, 4592, 4592, 4592, 4592, 4592, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336,
-    4336, 4336, 4336, 4336, 4336, 4336, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 5119, 5118, 5116, 5116, 5112, 5112, 5112, 5112, 5104, 5104,
-    5104, 5104, 5104, 5104, 5104, 5104, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336,
-    4336, 4336, 4336, 4336, 4336, 4336, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 4607, 4606, 4604, 4604, 4600, 4600, 4600, 4600, 4592, 4592,
-    4592, 4592, 4592, 4592, 4592, 4592, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336,
-    4336, 4336, 4336, 4336, 4336, 4336, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 6143, 6142, 6140, 6140, 6136, 6136, 6136, 6136, 6128, 6128,
-    6128, 6128, 6128, 6128, 6128, 6128, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336,
-    4336, 4336, 4336, 4336, 4336, 4336, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 4607, 4606, 4604, 4604, 4600, 4600, 4600, 4600, 4592, 4592,
-    4592, 4592, 4592, 4592, 4592, 4592, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336,
-    4336, 4336, 4336, 4336, 4336, 4336, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 5119, 5118, 5116, 5116, 5112, 5112, 5112, 5112, 5104, 5104,
-    5104, 5104, 5104, 5104, 5104, 5104, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336,
-    4336, 4336, 4336, 4336, 4336, 4336, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 4607, 4606, 4604, 4604, 4600, 4600, 4600, 4600, 4592, 4592,
-    4592, 4592, 4592, 4592, 4592, 4592, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 4351, 4350, 4348, 4348, 4344, 4344, 4344, 4344, 4336, 4336,
-    4336, 4336, 4336, 4336, 4336, 4336, 4223, 4222, 4220, 4220, 4216, 4216, 4216, 4216, 4208, 4208,
-    4208, 4208, 4208, 4208, 4208, 4208, 16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376,
-    16368, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16351, 16350, 16348, 16348, 16344,
-    16344, 16344, 16344, 16336, 16336, 16336, 16336, 16336, 16336, 16336, 16336, 12543, 12542,
-    12540, 12540, 12536, 12536, 12536, 12536, 12528, 12528, 12528, 12528, 12528, 12528, 12528,
-    12528, 12511, 12510, 12508, 12508, 12504, 12504, 12504, 12504, 12496, 12496, 12496, 12496,
-    12496, 12496, 12496, 12496, 12799, 12798, 12796, 12796, 12792, 12792, 12792, 12792, 12784,
-    12784, 12784, 12784, 12784, 12784, 12784, 12784, 12767, 12766, 12764, 12764, 12760, 12760,
-    12760, 12760, 12752, 12752, 12752, 12752, 12752, 12752, 12752, 12752, 12543, 12542, 12540,
-    12540, 12536, 12536, 12536, 12536, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12528,
-    12511, 12510, 12508, 12508, 12504, 12504, 12504, 12504, 12496, 12496, 12496, 12496, 12496,
-    12496, 12496, 12496, 13311, 13310, 13308, 13308, 13304, 13304, 13304, 13304, 13296, 13296,
-    13296, 13296, 13296, 13296, 13296, 13296, 13279, 13278, 13276, 13276, 13272, 13272, 13272,
-    13272, 13264, 13264, 13264, 13264, 13264, 13264, 13264, 13264, 12543, 12542, 12540, 12540,
-    12536, 12536, 12536, 12536, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12511,
-    12510, 12508, 12508, 12504, 12504, 12504, 12504, 12496, 12496, 12496, 12496, 12496, 12496,
-    12496, 12496, 12799, 12798, 12796, 12796, 12792, 12792, 12792, 12792, 12784, 12784, 12784,
-    12784, 12784, 12784, 12784, 12784, 12767, 12766, 12764, 12764, 12760, 12760, 12760, 12760,
-    12752, 12752, 12752, 12752, 12752, 12752, 12752, 12752, 12543, 12542, 12540, 12540, 12536,
-    12536, 12536, 12536, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12511, 12510,
-    12508, 12508, 12504, 12504, 12504, 12504, 12496, 12496, 12496, 12496, 12496, 12496, 12496,
-    12496, 14335, 14334, 14332, 14332, 14328, 14328, 14328, 14328, 14320, 14320, 14320, 14320,
-    14320, 14320, 14320, 14320, 14303, 14302, 14300, 14300, 14296, 14296, 14296, 14296, 14288,
-    14288, 14288, 14288, 14288, 14288, 14288, 14288, 12543, 12542, 12540, 12540, 12536, 12536,
-    12536, 12536, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12511, 12510, 12508,
-    12508, 12504, 12504, 12504, 12504, 12496, 12496, 12496, 12496, 12496, 12496, 12496, 12496,
-    12799, 12798, 12796, 12796, 12792, 12792, 12792, 12792, 12784, 12784, 12784, 12784, 12784,
-    12784, 12784, 12784, 12767, 12766, 12764, 12764, 12760, 12760, 12760, 12760, 12752, 12752,
-    12752, 12752, 12752, 12752, 12752, 12752, 12543, 12542, 12540, 12540, 12536, 12536, 12536,
-    12536, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12511, 12510, 12508, 12508,
-    12504, 12504, 12504, 12504, 12496, 12496, 12496, 12496, 12496, 12496, 12496, 12496, 13311,
-    13310, 13308, 13308, 13304, 13304, 13304, 13304, 13296, 13296, 13296, 13296, 13296, 13296,
-    13296, 13296, 13279, 13278, 13276, 13276, 13272, 13272, 13272, 13272, 13264, 13264, 13264,
-    13264, 13264, 13264, 13264, 13264, 12543, 12542, 12540, 12540, 12536, 12536, 12536, 12536,
-    12528, 12528, 12528, 12528, 12528, 12528, 12528, 12528, 12511, 12510, 12508, 12508, 12504,
-    12504, 12504, 12504, 12496, 12496, 12496, 12496, 12496, 12496, 12496, 12496, 12799, 12798,
-    12796, 12796, 12792, 12792, 12792, 12792, 12784, 12784, 12784, 12784, 12784, 12784, 12784,
-    12784, 12767, 12766, 12764, 12764, 12760, 12760, 12760, 12760, 12752, 12752, 12752, 12752,
-    12752, 12752, 12752, 12752, 12543, 12542, 12540, 12540, 12536, 12536, 12536, 12536, 12528,
-    12528, 12528, 12528, 12528, 12528, 12528, 12528, 12511, 12510, 12508, 12508, 12504, 12504,
-    12504, 12504, 12496, 12496, 12496, 12496, 12496, 12496, 12496, 12496, 8191, 8190, 8188, 8188,
-    8184, 8184, 8184, 8184, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8159, 8158, 8156, 8156,
-    8152, 8152, 8152, 8152, 8144, 8144, 8144, 8144, 8144, 8144, 8144, 8144, 4351, 4350, 4348, 4348,
-    4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4319, 4318, 4316, 4316,
-    4312, 4312, 4312, 4312, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 4607, 4606, 4604, 4604,
-    4600, 4600, 4600, 4600, 4592, 4592, 4592, 4592, 4592, 4592, 4592, 4592, 4575, 4574, 4572, 4572,
-    4568, 4568, 4568, 4568, 4560, 4560, 4560, 4560, 4560, 4560, 4560, 4560, 4351, 4350, 4348, 4348,
-    4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4319, 4318, 4316, 4316,
-    4312, 4312, 4312, 4312, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 5119, 5118, 5116, 5116,
-    5112, 5112, 5112, 5112, 5104, 5104, 5104, 5104, 5104, 5104, 5104, 5104, 5087, 5086, 5084, 5084,
-    5080, 5080, 5080, 5080, 5072, 5072, 5072, 5072, 5072, 5072, 5072, 5072, 4351, 4350, 4348, 4348,
-    4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4319, 4318, 4316, 4316,
-    4312, 4312, 4312, 4312, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 4607, 4606, 4604, 4604,
-    4600, 4600, 4600, 4600, 4592, 4592, 4592, 4592, 4592, 4592, 4592, 4592, 4575, 4574, 4572, 4572,
-    4568, 4568, 4568, 4568, 4560, 4560, 4560, 4560, 4560, 4560, 4560, 4560, 4351, 4350, 4348, 4348,
-    4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4319, 4318, 4316, 4316,
-    4312, 4312, 4312, 4312, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 6143, 6142, 6140, 6140,
-    6136, 6136, 6136, 6136, 6128, 6128, 6128, 6128, 6128, 6128, 6128, 6128, 6111, 6110, 6108, 6108,
-    6104, 6104, 6104, 6104, 6096, 6096, 6096, 6096, 6096, 6096, 6096, 6096, 4351, 4350, 4348, 4348,
-    4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4319, 4318, 4316, 4316,
-    4312, 4312, 4312, 4312, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 4607, 4606, 4604, 4604,
-    4600, 4600, 4600, 4600, 4592, 4592, 4592, 4592, 4592, 4592, 4592, 4592, 4575, 4574, 4572, 4572,
-    4568, 4568, 4568, 4568, 4560, 4560, 4560, 4560, 4560, 4560, 4560, 4560, 4351, 4350, 4348, 4348,
-    4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4319, 4318, 4316, 4316,
-    4312, 4312, 4312, 4312, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 5119, 5118, 5116, 5116,
-    5112, 5112, 5112, 5112, 5104, 5104, 5104, 5104, 5104, 5104, 5104, 5104, 5087, 5086, 5084, 5084,
-    5080, 5080, 5080, 5080, 5072, 5072, 5072, 5072, 5072, 5072, 5072, 5072, 4351, 4350, 4348, 4348,
-    4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4319, 4318, 4316, 4316,
-    4312, 4312, 4312, 4312, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 4607, 4606, 4604, 4604,
-    4600, 4600, 4600, 4600, 4592, 4592, 4592, 4592, 4592, 4592, 4592, 4592, 4575, 4574, 4572, 4572,
-    4568, 4568, 4568, 4568, 4560, 4560, 4560, 4560, 4560, 4560, 4560, 4560, 4351, 4350, 4348, 4348,
-    4344, 4344, 4344, 4344, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4336, 4319, 4318, 4316, 4316,
-    4312, 4312, 4312, 4312, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 4304, 16383, 16382, 16380,
-    16380, 16376, 16376, 16376, 16376, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16368,
-    16351, 16350, 16348, 16348, 16344, 16344, 16344, 16344, 16336, 16336, 16336, 16336, 16336,
-    16336, 16336, 16336, 16287, 16286, 16284, 16284, 16280, 16280, 16280, 16280, 16272, 16272,
-    16272, 16272, 16272, 16272, 16272, 16272, 16287, 16286, 16284, 16284, 16280, 16280, 16280,
-    16280, 16272, 16272, 16272, 16272, 16272, 16272, 16272, 16272, 12799, 12798, 12796, 12796,
-    12792, 12792, 12792, 12792, 12784, 12784, 12784, 12784, 12784, 12784, 12784, 12784, 12767,
-    12766, 12764, 12764, 12760, 12760, 12760, 12760, 12752, 12752, 12752, 12752, 12752, 12752,
-    12752, 12752, 12703, 12702, 12700, 12700, 12696, 12696, 12696, 12696, 12688, 12688, 12688,
-    12688, 12688, 12688, 12688, 12688, 12703, 12702, 12700, 12700, 12696, 12696, 12696, 12696,
-    12688, 12688, 12688, 12688, 12688, 12688, 12688, 12688, 13311, 13310, 13308, 13308, 13304,
-    13304, 13304, 13304, 13296, 13296, 13296, 13296, 13296, 13296, 13296, 13296, 13279, 13278,
-    13276, 13276, 13272, 13272, 13272, 13272, 13264, 13264, 13264, 13264, 13264, 13264, 13264,
-    13264, 13215, 13214, 13212, 13212, 13208, 13208, 13208, 13208, 13200, 13200, 13200, 13200,
-    13200, 13200, 13200, 13200, 13215, 13214, 13212, 13212, 13208, 13208, 13208, 13208, 13200,
-    13200, 13200, 13200, 13200, 13200, 13200, 13200, 12799, 12798, 12796, 12796, 12792, 12792,
-    12792, 12792, 12784, 12784, 12784, 12784, 12784, 12784, 12784, 12784, 12767, 12766, 12764,
-    12764, 12760, 12760, 12760, 12760, 12752, 12752, 12752, 12752, 12752, 12752, 12752, 12752,
-    12703, 12702, 12700, 12700, 12696, 12696, 12696, 12696, 12688, 12688, 12688, 12688, 12688,
-    12688, 12688, 12688, 12703, 12702, 12700, 12700, 12696, 12696, 12696, 12696, 12688, 12688,
-    12688, 12688, 12688, 12688, 12688, 12688, 14335, 14334, 14332, 14332, 14328, 14328, 14328,
-    14328, 14320, 14320, 14320, 14320, 14320, 14320, 14320, 14320, 14303, 14302, 14300, 14300,
-    14296, 14296, 14296, 14296, 14288, 14288, 14288, 14288, 14288, 14288, 14288, 14288, 14239,
-    14238, 14236, 14236, 14232, 14232, 14232, 14232, 14224, 14224, 14224, 14224, 14224, 14224,
-    14224, 14224, 14239, 14238, 14236, 14236, 14232, 14232, 14232, 14232, 14224, 14224, 14224,
-    14224, 14224, 14224, 14224, 14224, 12799, 12798, 12796, 12796, 12792, 12792, 12792, 12792,
-    12784, 12784, 12784, 12784, 12784, 12784, 12784, 12784, 12767, 12766, 12764, 12764, 12760,
-    12760, 12760, 12760, 12752, 12752, 12752, 12752, 12752, 12752, 12752, 12752, 12703, 12702,
-    12700, 12700, 12696, 12696, 12696, 12696, 12688, 12688, 12688, 12688, 12688, 12688, 12688,
-    12688, 12703, 12702, 12700, 12700, 12696, 12696, 12696, 12696, 12688, 12688, 12688, 12688,
-    12688, 12688, 12688, 12688, 13311, 13310, 13308, 13308, 13304, 13304, 13304, 13304, 13296,
-    13296, 13296, 13296, 13296, 13296, 13296, 13296, 13279, 13278, 13276, 13276, 13272, 13272,
-    13272, 13272, 13264, 13264, 13264, 13264, 13264, 13264, 13264, 13264, 13215, 13214, 13212,
-    13212, 13208, 13208, 13208, 13208, 13200, 13200, 13200, 13200, 13200, 13200, 13200, 13200,
-    13215, 13214, 13212, 13212, 13208, 13208, 13208, 13208, 13200, 13200, 13200, 13200, 13200,
-    13200, 13200, 13200, 12799, 12798, 12796, 12796, 12792, 12792, 12792, 12792, 12784, 12784,
-    12784, 12784, 12784, 12784, 12784, 12784, 12767, 12766, 12764, 12764, 12760, 12760, 12760,
-    12760, 12752, 12752, 12752, 12752, 12752, 12752, 12752, 12752, 12703, 12702, 12700, 12700,
-    12696, 12696, 12696, 12696, 12688, 12688, 12688, 12688, 12688, 12688, 12688, 12688, 12703,
-    12702, 12700, 12700, 12696, 12696, 12696, 12696, 12688, 12688, 12688, 12688, 12688, 12688,
-    12688, 12688, 8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 8176, 8176, 8176, 8176, 8176,
-    8176, 8176, 8176, 8159, 8158, 8156, 8156, 8152, 8152, 8152, 8152, 8144, 8144, 8144, 8144, 8144,
-    8144, 8144, 8144, 8095, 8094, 8092, 8092, 8088, 8088, 8088, 8088, 8080, 8080, 8080, 8080, 8080,
-    8080, 8080, 8080, 8095, 8094, 8092, 8092, 8088, 8088, 8088, 8088, 8080, 8080, 8080, 8080, 8080,
-    8080, 8080, 8080, 4607, 4606, 4604, 4604, 4600, 4600, 4600, 4600, 4592, 4592, 4592, 4592, 4592,
-    4592, 4592, 4592, 4575, 4574, 4572, 4572, 4568, 4568, 4568, 4568, 4560, 4560, 4560, 4560, 4560,
-    4560, 4560, 4560, 4511, 4510, 4508, 4508, 4504, 4504, 4504, 4504, 4496, 4496, 4496, 4496, 4496,
-    4496, 4496, 4496, 4511, 4510, 4508, 4508, 4504, 4504, 4504, 4504, 4496, 4496, 4496, 4496, 4496,
-    4496, 4496, 4496, 5119, 5118, 5116, 5116, 5112, 5112, 5112, 5112, 5104, 5104, 5104, 5104, 5104,
-    5104, 5104, 5104, 5087, 5086, 5084, 5084, 5080, 5080, 5080, 5080, 5072, 5072, 5072, 5072, 5072,
-    5072, 5072, 5072, 5023, 5022, 5020, 5020, 5016, 5016, 5016, 5016, 5008, 5008, 5008, 5008, 5008,
-    5008, 5008, 5008, 5023, 5022, 5020, 5020, 5016, 5016, 5016, 5016, 5008, 5008, 5008, 5008, 5008,
-    5008, 5008, 5008, 4607, 4606, 4604, 4604, 4600, 4600, 4600, 4600, 4592, 4592, 4592, 4592, 4592,
-    4592, 4592, 4592, 4575, 4574, 4572, 4572, 4568, 4568, 4568, 4568, 4560, 4560, 4560, 4560, 4560,
-    4560, 4560, 4560, 4511, 4510, 4508, 4508, 4504, 4504, 4504, 4504, 4496, 4496, 4496, 4496, 4496,
-    4496, 4496, 4496, 4511, 4510, 4508, 4508, 4504, 4504, 4504, 4504, 4496, 4496, 4496, 4496, 4496,
-    4496, 4496, 4496, 6143, 6142, 6140, 6140, 6136, 6136, 6136, 6136, 6128, 6128, 6128, 6128, 6128,
-    6128, 6128, 6128, 6111, 6110, 6108, 6108, 6104, 6104, 6104, 6104, 6096, 6096, 6096, 6096, 6096,
-    6096, 6096, 6096, 6047, 6046, 6044, 6044, 6040, 6040, 6040, 6040, 6032, 6032, 6032, 6032, 6032,
-    6032, 6032, 6032, 6047, 6046, 6044, 6044, 6040, 6040, 6040, 6040, 6032, 6032, 6032, 6032, 6032,
-    6032, 6032, 6032, 4607, 4606, 4604, 4604, 4600, 4600, 4600, 4600, 4592, 4592, 4592, 4592, 4592,
-    4592, 4592, 4592, 4575, 4574, 4572, 4572, 4568, 4568, 4568, 4568, 4560, 4560, 4560, 4560, 4560,
-    4560, 4560, 4560, 4511, 4510, 4508, 4508, 4504, 4504, 4504, 4504, 4496, 4496, 4496, 4496, 4496,
-    4496, 4496, 4496, 4511, 4510, 4508, 4508, 4504, 4504, 4504, 4504, 4496, 4496, 4496, 4496, 4496,
-    4496, 4496, 4496, 5119, 5118, 5116, 5116, 5112, 5112, 5112, 5112, 5104, 5104, 5104, 5104, 5104,
-    5104, 5104, 5104, 5087, 5086, 5084, 5084, 5080, 5080, 5080, 5080, 5072, 5072, 5072, 5072, 5072,
-    5072, 5072, 5072, 5023, 5022, 5020, 5020, 5016, 5016, 5016, 5016, 5008, 5008, 5008, 5008, 5008,
-    5008, 5008, 5008, 5023, 5022, 5020, 5020, 5016, 5016, 5016, 5016, 5008, 5008, 5008, 5008, 5008,
-    5008, 5008, 5008, 4607, 4606, 4604, 4604, 4600, 4600, 4600, 4600, 4592, 4592, 4592, 4592, 4592,
-    4592, 4592, 4592, 4575, 4574, 4572, 4572, 4568, 4568, 4568, 4568, 4560, 4560, 4560, 4560, 4560,
-    4560, 4560, 4560, 4511, 4510, 4508, 4508, 4504, 4504, 4504, 4504, 4496, 4496, 4496, 4496, 4496,
-    4496, 4496, 4496, 4511, 4510, 4508, 4508, 4504, 4504, 4504, 4504, 4496, 4496, 4496, 4496, 4496,
-    4496, 4496, 4496, 16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368, 16368,
-    16368, 16368, 16368, 16368, 16368, 16351, 16350, 16348, 16348, 16344, 16344, 16344, 16344,
-    16336, 16336, 16336, 16336, 16336, 16336, 16336, 16336, 16287, 16286, 16284, 16284, 16280,
-    16280, 16280, 16280, 16272, 16272, 16272, 16272, 16272, 16272, 16272, 16272, 16287, 16286,
-    16284, 16284, 16280, 16280, 16280, 16280, 16272, 16272, 16272, 16272, 16272, 16272, 16272,
-    16272, 16159, 16158, 16156, 16156, 16152, 16152, 16152, 16152, 16144, 16144, 16144, 16144,
-    16144, 16144, 16144, 16144, 16159, 16158, 16156, 16156, 16152, 16152, 16152, 16152, 16144,
-    16144, 16144, 16144, 16144, 16144, 16144, 16144, 16159, 16158, 16156, 16156, 16152, 16152,
-    16152, 16152, 16144, 16144, 16144, 16144, 16144, 16144, 16144, 16144, 16159, 16158, 16156,
-    16156, 16152, 16152, 16152, 16152, 16144, 16144, 16144, 16144, 16144, 16144, 16144, 16144,
-    13311, 13310, 13308, 13308, 13304, 13304, 13304, 13304, 13296, 13296, 13296, 13296, 13296,
-    13296, 13296, 13296, 13279, 13278, 13276, 13276, 13272, 13272, 13272, 13272, 13264, 13264,
-    13264, 13264, 13264, 13264, 13264, 13264, 13215, 13214, 13212, 13212, 13208, 13208, 13208,
-    13208, 13200, 13200, 13200, 13200, 13200, 13200, 13200, 13200, 13215, 13214, 13212, 13212,
-    13208, 13208, 13208, 13208, 13200, 13200, 13200, 13200, 13200, 13200, 13200, 13200, 13087,
-    13086, 13084, 13084, 13080, 13080, 13080, 13080, 13072, 13072, 13072, 13072, 13072, 13072,
-    13072, 13072, 13087, 13086, 13084, 13084, 13080, 13080, 13080, 13080, 13072, 13072, 13072,
-    13072, 13072, 13072, 13072, 13072, 13087, 13086, 13084, 13084, 13080, 13080, 13080, 13080,
-    13072, 13072, 13072, 13072, 13072, 13072, 13072, 13072, 13087, 13086, 13084, 13084, 13080,
-    13080, 13080, 13080, 13072, 13072, 13072, 13072, 13072, 13072, 13072, 13072, 14335, 14334,
-    14332, 14332, 14328, 14328, 14328, 14328, 14320, 14320, 14320, 14320, 14320, 14320, 14320,
-    14320, 14303, 14302, 14300, 14300, 14296, 14296, 14296, 14296, 14288, 14288, 14288, 14288,
-    14288, 14288, 14288, 14288, 14239, 14238, 14236, 14236, 14232, 14232, 14232, 14232, 14224,
-    14224, 14224, 14224, 14224, 14224, 14224, 14224, 14239, 14238, 14236, 14236, 14232, 14232,
-    14232, 14232, 14224, 14224, 14224, 14224, 14224, 14224, 14224, 14224, 14111, 14110, 14108,
-    14108, 14104, 14104, 14104, 14104, 14096, 14096, 14096, 14096, 14096, 14096, 14096, 14096,
-    14111, 14110, 14108, 14108, 14104, 14104, 14104, 14104, 14096, 14096, 14096, 14096, 14096,
-    14096, 14096, 14096, 14111, 14110, 14108, 14108, 14104, 14104, 14104, 14104, 14096, 14096,
-    14096, 14096, 14096, 14096, 14096, 14096, 14111, 14110, 14108, 14108, 14104, 14104, 14104,
-    14104, 14096, 14096, 14096, 14096, 14096, 14096, 14096, 14096, 13311, 13310, 13308, 13308,
-    13304, 13304, 13304, 13304, 13296, 13296, 13296, 13296, 13296, 13296, 13296, 13296, 13279,
-    13278, 13276, 13276, 13272, 13272, 13272, 13272, 13264, 13264, 13264, 13264, 13264, 13264,
-    13264, 13264, 13215, 13214, 13212, 13212, 13208, 13208, 13208, 13208, 13200, 13200, 13200,
-    13200, 13200, 13200, 13200, 13200, 13215, 13214, 13212, 13212, 13208, 13208, 13208, 13208,
-    13200, 13200, 13200, 13200, 13200, 13200, 13200, 13200, 13087, 13086, 13084, 13084, 13080,
-    13080, 13080, 13080, 13072, 13072, 13072, 13072, 13072, 13072, 13072, 13072, 13087, 13086,
-    13084, 13084, 13080, 13080, 13080, 13080, 13072, 13072, 13072, 13072, 13072, 13072, 13072,
-    13072, 13087, 13086, 13084, 13084, 13080, 13080, 13080, 13080, 13072, 13072, 13072, 13072,
-    13072, 13072, 13072, 13072, 13087, 13086, 13084, 13084, 13080, 13080, 13080, 13080, 13072,
-    13072, 13072, 13072, 13072, 13072, 13072, 13072, 8191, 8190, 8188, 8188, 8184, 8184, 8184,
-    8184, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8159, 8158, 8156, 8156, 8152, 8152, 8152,
-    8152, 8144, 8144, 8144, 8144, 8144, 8144, 8144, 8144, 8095, 8094, 8092, 8092, 8088, 8088, 8088,
-    8088, 8080, 8080, 8080, 8080, 8080, 8080, 8080, 8080, 8095, 8094, 8092, 8092, 8088, 8088, 8088,
-    8088, 8080, 8080, 8080, 8080, 8080, 8080, 8080, 8080, 7967, 7966, 7964, 7964, 7960, 7960, 7960,
-    7960, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 7967, 7966, 7964, 7964, 7960, 7960, 7960,
-    7960, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 7967, 7966, 7964, 7964, 7960, 7960, 7960,
-    7960, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 7967, 7966, 7964, 7964, 7960, 7960, 7960,
-    7960, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 5119, 5118, 5116, 5116, 5112, 5112, 5112,
-    5112, 5104, 5104, 5104, 5104, 5104, 5104, 5104, 5104, 5087, 5086, 5084, 5084, 5080, 5080, 5080,
-    5080, 5072, 5072, 5072, 5072, 5072, 5072, 5072, 5072, 5023, 5022, 5020, 5020, 5016, 5016, 5016,
-    5016, 5008, 5008, 5008, 5008, 5008, 5008, 5008, 5008, 5023, 5022, 5020, 5020, 5016, 5016, 5016,
-    5016, 5008, 5008, 5008, 5008, 5008, 5008, 5008, 5008, 4895, 4894, 4892, 4892, 4888, 4888, 4888,
-    4888, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 4895, 4894, 4892, 4892, 4888, 4888, 4888,
-    4888, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 4895, 4894, 4892, 4892, 4888, 4888, 4888,
-    4888, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 4895, 4894, 4892, 4892, 4888, 4888, 4888,
-    4888, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 6143, 6142, 6140, 6140, 6136, 6136, 6136,
-    6136, 6128, 6128, 6128, 6128, 6128, 6128, 6128, 6128, 6111, 6110, 6108, 6108, 6104, 6104, 6104,
-    6104, 6096, 6096, 6096, 6096, 6096, 6096, 6096, 6096, 6047, 6046, 6044, 6044, 6040, 6040, 6040,
-    6040, 6032, 6032, 6032, 6032, 6032, 6032, 6032, 6032, 6047, 6046, 6044, 6044, 6040, 6040, 6040,
-    6040, 6032, 6032, 6032, 6032, 6032, 6032, 6032, 6032, 5919, 5918, 5916, 5916, 5912, 5912, 5912,
-    5912, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5919, 5918, 5916, 5916, 5912, 5912, 5912,
-    5912, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5919, 5918, 5916, 5916, 5912, 5912, 5912,
-    5912, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5919, 5918, 5916, 5916, 5912, 5912, 5912,
-    5912, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5119, 5118, 5116, 5116, 5112, 5112, 5112,
-    5112, 5104, 5104, 5104, 5104, 5104, 5104, 5104, 5104, 5087, 5086, 5084, 5084, 5080, 5080, 5080,
-    5080, 5072, 5072, 5072, 5072, 5072, 5072, 5072, 5072, 5023, 5022, 5020, 5020, 5016, 5016, 5016,
-    5016, 5008, 5008, 5008, 5008, 5008, 5008, 5008, 5008, 5023, 5022, 5020, 5020, 5016, 5016, 5016,
-    5016, 5008, 5008, 5008, 5008, 5008, 5008, 5008, 5008, 4895, 4894, 4892, 4892, 4888, 4888, 4888,
-    4888, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 4895, 4894, 4892, 4892, 4888, 4888, 4888,
-    4888, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 4895, 4894, 4892, 4892, 4888, 4888, 4888,
-    4888, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 4895, 4894, 4892, 4892, 4888, 4888, 4888,
-    4888, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 4880, 16383, 16382, 16380, 16380, 16376, 16376,
-    16376, 16376, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16351, 16350, 16348,
-    16348, 16344, 16344, 16344, 16344, 16336, 16336, 16336, 16336, 16336, 16336, 16336, 16336,
-    16287, 16286, 16284, 16284, 16280, 16280, 16280, 16280, 16272, 16272, 16272, 16272, 16272,
-    16272, 16272, 16272, 16287, 16286, 16284, 16284, 16280, 16280, 16280, 16280, 16272, 16272,
-    16272, 16272, 16272, 16272, 16272, 16272, 16159, 16158, 16156, 16156, 16152, 16152, 16152,
-    16152, 16144, 16144, 16144, 16144, 16144, 16144, 16144, 16144, 16159, 16158, 16156, 16156,
-    16152, 16152, 16152, 16152, 16144, 16144, 16144, 16144, 16144, 16144, 16144, 16144, 16159,
-    16158, 16156, 16156, 16152, 16152, 16152, 16152, 16144, 16144, 16144, 16144, 16144, 16144,
-    16144, 16144, 16159, 16158, 16156, 16156, 16152, 16152, 16152, 16152, 16144, 16144, 16144,
-    16144, 16144, 16144, 16144, 16144, 15903, 15902, 15900, 15900, 15896, 15896, 15896, 15896,
-    15888, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15903, 15902, 15900, 15900, 15896,
-    15896, 15896, 15896, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15903, 15902,
-    15900, 15900, 15896, 15896, 15896, 15896, 15888, 15888, 15888, 15888, 15888, 15888, 15888,
-    15888, 15903, 15902, 15900, 15900, 15896, 15896, 15896, 15896, 15888, 15888, 15888, 15888,
-    15888, 15888, 15888, 15888, 15903, 15902, 15900, 15900, 15896, 15896, 15896, 15896, 15888,
-    15888, 15888, 15888, 15888, 15888, 15888, 15888, 15903, 15902, 15900, 15900, 15896, 15896,
-    15896, 15896, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15903, 15902, 15900,
-    15900, 15896, 15896, 15896, 15896, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15888,
-    15903, 15902, 15900, 15900, 15896, 15896, 15896, 15896, 15888, 15888, 15888, 15888, 15888,
-    15888, 15888, 15888, 14335, 14334, 14332, 14332, 14328, 14328, 14328, 14328, 14320, 14320,
-    14320, 14320, 14320, 14320, 14320, 14320, 14303, 14302, 14300, 14300, 14296, 14296, 14296,
-    14296, 14288, 14288, 14288, 14288, 14288, 14288, 14288, 14288, 14239, 14238, 14236, 14236,
-    14232, 14232, 14232, 14232, 14224, 14224, 14224, 14224, 14224, 14224, 14224, 14224, 14239,
-    14238, 14236, 14236, 14232, 14232, 14232, 14232, 14224, 14224, 14224, 14224, 14224, 14224,
-    14224, 14224, 14111, 14110, 14108, 14108, 14104, 14104, 14104, 14104, 14096, 14096, 14096,
-    14096, 14096, 14096, 14096, 14096, 14111, 14110, 14108, 14108, 14104, 14104, 14104, 14104,
-    14096, 14096, 14096, 14096, 14096, 14096, 14096, 14096, 14111, 14110, 14108, 14108, 14104,
-    14104, 14104, 14104, 14096, 14096, 14096, 14096, 14096, 14096, 14096, 14096, 14111, 14110,
-    14108, 14108, 14104, 14104, 14104, 14104, 14096, 14096, 14096, 14096, 14096, 14096, 14096,
-    14096, 13855, 13854, 13852, 13852, 13848, 13848, 13848, 13848, 13840, 13840, 13840, 13840,
-    13840, 13840, 13840, 13840, 13855, 13854, 13852, 13852, 13848, 13848, 13848, 13848, 13840,
-    13840, 13840, 13840, 13840, 13840, 13840, 13840, 13855, 13854, 13852, 13852, 13848, 13848,
-    13848, 13848, 13840, 13840, 13840, 13840, 13840, 13840, 13840, 13840, 13855, 13854, 13852,
-    13852, 13848, 13848, 13848, 13848, 13840, 13840, 13840, 13840, 13840, 13840, 13840, 13840,
-    13855, 13854, 13852, 13852, 13848, 13848, 13848, 13848, 13840, 13840, 13840, 13840, 13840,
-    13840, 13840, 13840, 13855, 13854, 13852, 13852, 13848, 13848, 13848, 13848, 13840, 13840,
-    13840, 13840, 13840, 13840, 13840, 13840, 13855, 13854, 13852, 13852, 13848, 13848, 13848,
-    13848, 13840, 13840, 13840, 13840, 13840, 13840, 13840, 13840, 13855, 13854, 13852, 13852,
-    13848, 13848, 13848, 13848, 13840, 13840, 13840, 13840, 13840, 13840, 13840, 13840, 8191, 8190,
-    8188, 8188, 8184, 8184, 8184, 8184, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8159, 8158,
-    8156, 8156, 8152, 8152, 8152, 8152, 8144, 8144, 8144, 8144, 8144, 8144, 8144, 8144, 8095, 8094,
-    8092, 8092, 8088, 8088, 8088, 8088, 8080, 8080, 8080, 8080, 8080, 8080, 8080, 8080, 8095, 8094,
-    8092, 8092, 8088, 8088, 8088, 8088, 8080, 8080, 8080, 8080, 8080, 8080, 8080, 8080, 7967, 7966,
-    7964, 7964, 7960, 7960, 7960, 7960, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 7967, 7966,
-    7964, 7964, 7960, 7960, 7960, 7960, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 7967, 7966,
-    7964, 7964, 7960, 7960, 7960, 7960, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 7967, 7966,
-    7964, 7964, 7960, 7960, 7960, 7960, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 7952, 7711, 7710,
-    7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 7711, 7710,
-    7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 7711, 7710,
-    7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 7711, 7710,
-    7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 7711, 7710,
-    7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 7711, 7710,
-    7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 7711, 7710,
-    7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 7711, 7710,
-    7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 7696, 6143, 6142,
-    6140, 6140, 6136, 6136, 6136, 6136, 6128, 6128, 6128, 6128, 6128, 6128, 6128, 6128, 6111, 6110,
-    6108, 6108, 6104, 6104, 6104, 6104, 6096, 6096, 6096, 6096, 6096, 6096, 6096, 6096, 6047, 6046,
-    6044, 6044, 6040, 6040, 6040, 6040, 6032, 6032, 6032, 6032, 6032, 6032, 6032, 6032, 6047, 6046,
-    6044, 6044, 6040, 6040, 6040, 6040, 6032, 6032, 6032, 6032, 6032, 6032, 6032, 6032, 5919, 5918,
-    5916, 5916, 5912, 5912, 5912, 5912, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5919, 5918,
-    5916, 5916, 5912, 5912, 5912, 5912, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5919, 5918,
-    5916, 5916, 5912, 5912, 5912, 5912, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5919, 5918,
-    5916, 5916, 5912, 5912, 5912, 5912, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5904, 5663, 5662,
-    5660, 5660, 5656, 5656, 5656, 5656, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 5663, 5662,
-    5660, 5660, 5656, 5656, 5656, 5656, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 5663, 5662,
-    5660, 5660, 5656, 5656, 5656, 5656, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 5663, 5662,
-    5660, 5660, 5656, 5656, 5656, 5656, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 5663, 5662,
-    5660, 5660, 5656, 5656, 5656, 5656, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 5663, 5662,
-    5660, 5660, 5656, 5656, 5656, 5656, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 5663, 5662,
-    5660, 5660, 5656, 5656, 5656, 5656, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 5663, 5662,
-    5660, 5660, 5656, 5656, 5656, 5656, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 5648, 16383,
-    16382, 16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368, 16368, 16368, 16368, 16368,
-    16368, 16368, 16351, 16350, 16348, 16348, 16344, 16344, 16344, 16344, 16336, 16336, 16336,
-    16336, 16336, 16336, 16336, 16336, 16287, 16286, 16284, 16284, 16280, 16280, 16280, 16280,
-    16272, 16272, 16272, 16272, 16272, 16272, 16272, 16272, 16287, 16286, 16284, 16284, 16280,
-    16280, 16280, 16280, 16272, 16272, 16272, 16272, 16272, 16272, 16272, 16272, 16159, 16158,
-    16156, 16156, 16152, 16152, 16152, 16152, 16144, 16144, 16144, 16144, 16144, 16144, 16144,
-    16144, 16159, 16158, 16156, 16156, 16152, 16152, 16152, 16152, 16144, 16144, 16144, 16144,
-    16144, 16144, 16144, 16144, 16159, 16158, 16156, 16156, 16152, 16152, 16152, 16152, 16144,
-    16144, 16144, 16144, 16144, 16144, 16144, 16144, 16159, 16158, 16156, 16156, 16152, 16152,
-    16152, 16152, 16144, 16144, 16144, 16144, 16144, 16144, 16144, 16144, 15903, 15902, 15900,
-    15900, 15896, 15896, 15896, 15896, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15888,
-    15903, 15902, 15900, 15900, 15896, 15896, 15896, 15896, 15888, 15888, 15888, 15888, 15888,
-    15888, 15888, 15888, 15903, 15902, 15900, 15900, 15896, 15896, 15896, 15896, 15888, 15888,
-    15888, 15888, 15888, 15888, 15888, 15888, 15903, 15902, 15900, 15900, 15896, 15896, 15896,
-    15896, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15903, 15902, 15900, 15900,
-    15896, 15896, 15896, 15896, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15903,
-    15902, 15900, 15900, 15896, 15896, 15896, 15896, 15888, 15888, 15888, 15888, 15888, 15888,
-    15888, 15888, 15903, 15902, 15900, 15900, 15896, 15896, 15896, 15896, 15888, 15888, 15888,
-    15888, 15888, 15888, 15888, 15888, 15903, 15902, 15900, 15900, 15896, 15896, 15896, 15896,
-    15888, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15391, 15390, 15388, 15388, 15384,
-    15384, 15384, 15384, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15391, 15390,
-    15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376, 15376, 15376, 15376, 15376, 15376,
-    15376, 15391, 15390, 15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376, 15376, 15376,
-    15376, 15376, 15376, 15376, 15391, 15390, 15388, 15388, 15384, 15384, 15384, 15384, 15376,
-    15376, 15376, 15376, 15376, 15376, 15376, 15376, 15391, 15390, 15388, 15388, 15384, 15384,
-    15384, 15384, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15391, 15390, 15388,
-    15388, 15384, 15384, 15384, 15384, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15376,
-    15391, 15390, 15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376, 15376, 15376, 15376,
-    15376, 15376, 15376, 15391, 15390, 15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376,
-    15376, 15376, 15376, 15376, 15376, 15376, 15391, 15390, 15388, 15388, 15384, 15384, 15384,
-    15384, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15391, 15390, 15388, 15388,
-    15384, 15384, 15384, 15384, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15391,
-    15390, 15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376, 15376, 15376, 15376, 15376,
-    15376, 15376, 15391, 15390, 15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376, 15376,
-    15376, 15376, 15376, 15376, 15376, 15391, 15390, 15388, 15388, 15384, 15384, 15384, 15384,
-    15376, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15391, 15390, 15388, 15388, 15384,
-    15384, 15384, 15384, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15391, 15390,
-    15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376, 15376, 15376, 15376, 15376, 15376,
-    15376, 15391, 15390, 15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376, 15376, 15376,
-    15376, 15376, 15376, 15376, 8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 8176, 8176, 8176,
-    8176, 8176, 8176, 8176, 8176, 8159, 8158, 8156, 8156, 8152, 8152, 8152, 8152, 8144, 8144, 8144,
-    8144, 8144, 8144, 8144, 8144, 8095, 8094, 8092, 8092, 8088, 8088, 8088, 8088, 8080, 8080, 8080,
-    8080, 8080, 8080, 8080, 8080, 8095, 8094, 8092, 8092, 8088, 8088, 8088, 8088, 8080, 8080, 8080,
-    8080, 8080, 8080, 8080, 8080, 7967, 7966, 7964, 7964, 7960, 7960, 7960, 7960, 7952, 7952, 7952,
-    7952, 7952, 7952, 7952, 7952, 7967, 7966, 7964, 7964, 7960, 7960, 7960, 7960, 7952, 7952, 7952,
-    7952, 7952, 7952, 7952, 7952, 7967, 7966, 7964, 7964, 7960, 7960, 7960, 7960, 7952, 7952, 7952,
-    7952, 7952, 7952, 7952, 7952, 7967, 7966, 7964, 7964, 7960, 7960, 7960, 7960, 7952, 7952, 7952,
-    7952, 7952, 7952, 7952, 7952, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696,
-    7696, 7696, 7696, 7696, 7696, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696,
-    7696, 7696, 7696, 7696, 7696, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696,
-    7696, 7696, 7696, 7696, 7696, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696,
-    7696, 7696, 7696, 7696, 7696, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696,
-    7696, 7696, 7696, 7696, 7696, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696,
-    7696, 7696, 7696, 7696, 7696, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696,
-    7696, 7696, 7696, 7696, 7696, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696,
-    7696, 7696, 7696, 7696, 7696, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184,
-    7184, 7184, 7184, 7184, 7184, 16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376, 16368,
-    16368, 16368, 16368, 16368, 16368, 16368, 16368, 16351, 16350, 16348, 16348, 16344, 16344,
-    16344, 16344, 16336, 16336, 16336, 16336, 16336, 16336, 16336, 16336, 16287, 16286, 16284,
-    16284, 16280, 16280, 16280, 16280, 16272, 16272, 16272, 16272, 16272, 16272, 16272, 16272,
-    16287, 16286, 16284, 16284, 16280, 16280, 16280, 16280, 16272, 16272, 16272, 16272, 16272,
-    16272, 16272, 16272, 16159, 16158, 16156, 16156, 16152, 16152, 16152, 16152, 16144, 16144,
-    16144, 16144, 16144, 16144, 16144, 16144, 16159, 16158, 16156, 16156, 16152, 16152, 16152,
-    16152, 16144, 16144, 16144, 16144, 16144, 16144, 16144, 16144, 16159, 16158, 16156, 16156,
-    16152, 16152, 16152, 16152, 16144, 16144, 16144, 16144, 16144, 16144, 16144, 16144, 16159,
-    16158, 16156, 16156, 16152, 16152, 16152, 16152, 16144, 16144, 16144, 16144, 16144, 16144,
-    16144, 16144, 15903, 15902, 15900, 15900, 15896, 15896, 15896, 15896, 15888, 15888, 15888,
-    15888, 15888, 15888, 15888, 15888, 15903, 15902, 15900, 15900, 15896, 15896, 15896, 15896,
-    15888, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15903, 15902, 15900, 15900, 15896,
-    15896, 15896, 15896, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15903, 15902,
-    15900, 15900, 15896, 15896, 15896, 15896, 15888, 15888, 15888, 15888, 15888, 15888, 15888,
-    15888, 15903, 15902, 15900, 15900, 15896, 15896, 15896, 15896, 15888, 15888, 15888, 15888,
-    15888, 15888, 15888, 15888, 15903, 15902, 15900, 15900, 15896, 15896, 15896, 15896, 15888,
-    15888, 15888, 15888, 15888, 15888, 15888, 15888, 15903, 15902, 15900, 15900, 15896, 15896,
-    15896, 15896, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15903, 15902, 15900,
-    15900, 15896, 15896, 15896, 15896, 15888, 15888, 15888, 15888, 15888, 15888, 15888, 15888,
-    15391, 15390, 15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376, 15376, 15376, 15376,
-    15376, 15376, 15376, 15391, 15390, 15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376,
-    15376, 15376, 15376, 15376, 15376, 15376, 15391, 15390, 15388, 15388, 15384, 15384, 15384,
-    15384, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15391, 15390, 15388, 15388,
-    15384, 15384, 15384, 15384, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15391,
-    15390, 15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376, 15376, 15376, 15376, 15376,
-    15376, 15376, 15391, 15390, 15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376, 15376,
-    15376, 15376, 15376, 15376, 15376, 15391, 15390, 15388, 15388, 15384, 15384, 15384, 15384,
-    15376, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15391, 15390, 15388, 15388, 15384,
-    15384, 15384, 15384, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15391, 15390,
-    15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376, 15376, 15376, 15376, 15376, 15376,
-    15376, 15391, 15390, 15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376, 15376, 15376,
-    15376, 15376, 15376, 15376, 15391, 15390, 15388, 15388, 15384, 15384, 15384, 15384, 15376,
-    15376, 15376, 15376, 15376, 15376, 15376, 15376, 15391, 15390, 15388, 15388, 15384, 15384,
-    15384, 15384, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15391, 15390, 15388,
-    15388, 15384, 15384, 15384, 15384, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15376,
-    15391, 15390, 15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376, 15376, 15376, 15376,
-    15376, 15376, 15376, 15391, 15390, 15388, 15388, 15384, 15384, 15384, 15384, 15376, 15376,
-    15376, 15376, 15376, 15376, 15376, 15376, 15391, 15390, 15388, 15388, 15384, 15384, 15384,
-    15384, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 15376, 14367, 14366, 14364, 14364,
-    14360, 14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14367,
-    14366, 14364, 14364, 14360, 14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352,
-    14352, 14352, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14352, 14352, 14352,
-    14352, 14352, 14352, 14352, 14352, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360,
-    14352, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14367, 14366, 14364, 14364, 14360,
-    14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14367, 14366,
-    14364, 14364, 14360, 14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352, 14352,
-    14352, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14352, 14352, 14352, 14352,
-    14352, 14352, 14352, 14352, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14352,
-    14352, 14352, 14352, 14352, 14352, 14352, 14352, 14367, 14366, 14364, 14364, 14360, 14360,
-    14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14367, 14366, 14364,
-    14364, 14360, 14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14352,
-    14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352,
-    14352, 14352, 14352, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14352, 14352,
-    14352, 14352, 14352, 14352, 14352, 14352, 14367, 14366, 14364, 14364, 14360, 14360, 14360,
-    14360, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14367, 14366, 14364, 14364,
-    14360, 14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14367,
-    14366, 14364, 14364, 14360, 14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352,
-    14352, 14352, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14352, 14352, 14352,
-    14352, 14352, 14352, 14352, 14352, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360,
-    14352, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14367, 14366, 14364, 14364, 14360,
-    14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14367, 14366,
-    14364, 14364, 14360, 14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352, 14352,
-    14352, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14352, 14352, 14352, 14352,
-    14352, 14352, 14352, 14352, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14352,
-    14352, 14352, 14352, 14352, 14352, 14352, 14352, 14367, 14366, 14364, 14364, 14360, 14360,
-    14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14367, 14366, 14364,
-    14364, 14360, 14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14352,
-    14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352,
-    14352, 14352, 14352, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14352, 14352,
-    14352, 14352, 14352, 14352, 14352, 14352, 14367, 14366, 14364, 14364, 14360, 14360, 14360,
-    14360, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14367, 14366, 14364, 14364,
-    14360, 14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14367,
-    14366, 14364, 14364, 14360, 14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352,
-    14352, 14352, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360, 14352, 14352, 14352,
-    14352, 14352, 14352, 14352, 14352, 14367, 14366, 14364, 14364, 14360, 14360, 14360, 14360,
-    14352, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14367, 14366, 14364, 14364, 14360,
-    14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14352, 14367, 14366,
-    14364, 14364, 14360, 14360, 14360, 14360, 14352, 14352, 14352, 14352, 14352, 14352, 14352,
-    14352, 8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 8176, 8176, 8176, 8176, 8176, 8176,
-    8176, 8176, 8159, 8158, 8156, 8156, 8152, 8152, 8152, 8152, 8144, 8144, 8144, 8144, 8144, 8144,
-    8144, 8144, 8095, 8094, 8092, 8092, 8088, 8088, 8088, 8088, 8080, 8080, 8080, 8080, 8080, 8080,
-    8080, 8080, 8095, 8094, 8092, 8092, 8088, 8088, 8088, 8088, 8080, 8080, 8080, 8080, 8080, 8080,
-    8080, 8080, 7967, 7966, 7964, 7964, 7960, 7960, 7960, 7960, 7952, 7952, 7952, 7952, 7952, 7952,
-    7952, 7952, 7967, 7966, 7964, 7964, 7960, 7960, 7960, 7960, 7952, 7952, 7952, 7952, 7952, 7952,
-    7952, 7952, 7967, 7966, 7964, 7964, 7960, 7960, 7960, 7960, 7952, 7952, 7952, 7952, 7952, 7952,
-    7952, 7952, 7967, 7966, 7964, 7964, 7960, 7960, 7960, 7960, 7952, 7952, 7952, 7952, 7952, 7952,
-    7952, 7952, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696,
-    7696, 7696, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696,
-    7696, 7696, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696,
-    7696, 7696, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696,
-    7696, 7696, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696,
-    7696, 7696, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696,
-    7696, 7696, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696,
-    7696, 7696, 7711, 7710, 7708, 7708, 7704, 7704, 7704, 7704, 7696, 7696, 7696, 7696, 7696, 7696,
-    7696, 7696, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 7199, 7198, 7196, 7196, 7192, 7192, 7192, 7192, 7184, 7184, 7184, 7184, 7184, 7184,
-    7184, 7184, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 6175, 6174, 6172, 6172, 6168, 6168, 6168, 6168, 6160, 6160, 6160, 6160, 6160, 6160,
-    6160, 6160, 16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368, 16368, 16368,
-    16368, 16368, 16368, 16368, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16352, 16352, 16352, 16352, 16352, 16352, 16352, 8319, 8318, 8316, 8316, 8312, 8312, 8312,
-    8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8447, 8446, 8444, 8444, 8440, 8440, 8440,
-    8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8319, 8318, 8316, 8316, 8312, 8312, 8312,
-    8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8703, 8702, 8700, 8700, 8696, 8696, 8696,
-    8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8319, 8318, 8316, 8316, 8312, 8312, 8312,
-    8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8447, 8446, 8444, 8444, 8440, 8440, 8440,
-    8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8319, 8318, 8316, 8316, 8312, 8312, 8312,
-    8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 9215, 9214, 9212, 9212, 9208, 9208, 9208,
-    9208, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9184, 9184, 9184, 9184, 9184, 9184, 9184,
-    9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 8319, 8318, 8316, 8316, 8312, 8312, 8312,
-    8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8447, 8446, 8444, 8444, 8440, 8440, 8440,
-    8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8319, 8318, 8316, 8316, 8312, 8312, 8312,
-    8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8703, 8702, 8700, 8700, 8696, 8696, 8696,
-    8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8319, 8318, 8316, 8316, 8312, 8312, 8312,
-    8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8447, 8446, 8444, 8444, 8440, 8440, 8440,
-    8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8319, 8318, 8316, 8316, 8312, 8312, 8312,
-    8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 10239, 10238, 10236, 10236, 10232, 10232,
-    10232, 10232, 10224, 10224, 10224, 10224, 10224, 10224, 10224, 10224, 10208, 10208, 10208,
-    10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208,
-    8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8703, 8702, 8700, 8700, 8696, 8696, 8696, 8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672,
-    8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    9215, 9214, 9212, 9212, 9208, 9208, 9208, 9208, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9200,
-    9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184,
-    8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8703, 8702, 8700, 8700, 8696, 8696, 8696, 8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672,
-    8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    12287, 12286, 12284, 12284, 12280, 12280, 12280, 12280, 12272, 12272, 12272, 12272, 12272,
-    12272, 12272, 12272, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256,
-    12256, 12256, 12256, 12256, 12256, 12256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304,
-    8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432,
-    8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304,
-    8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8703, 8702, 8700, 8700, 8696, 8696, 8696, 8696, 8688,
-    8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304,
-    8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432,
-    8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304,
-    8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 9215, 9214, 9212, 9212, 9208, 9208, 9208, 9208, 9200,
-    9200, 9200, 9200, 9200, 9200, 9200, 9200, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184,
-    9184, 9184, 9184, 9184, 9184, 9184, 9184, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304,
-    8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432,
-    8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304,
-    8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8703, 8702, 8700, 8700, 8696, 8696, 8696, 8696, 8688,
-    8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304,
-    8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432,
-    8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304,
-    8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 10239, 10238, 10236, 10236, 10232, 10232, 10232,
-    10232, 10224, 10224, 10224, 10224, 10224, 10224, 10224, 10224, 10208, 10208, 10208, 10208,
-    10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 8319, 8318,
-    8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8447, 8446,
-    8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8319, 8318,
-    8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8703, 8702,
-    8700, 8700, 8696, 8696, 8696, 8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8319, 8318,
-    8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8447, 8446,
-    8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8319, 8318,
-    8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 9215, 9214,
-    9212, 9212, 9208, 9208, 9208, 9208, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9184, 9184,
-    9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 8319, 8318,
-    8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8447, 8446,
-    8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8319, 8318,
-    8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8703, 8702,
-    8700, 8700, 8696, 8696, 8696, 8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8319, 8318,
-    8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8447, 8446,
-    8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8319, 8318,
-    8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8304, 8288, 8288,
-    8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 16383,
-    16382, 16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368, 16368, 16368, 16368, 16368,
-    16368, 16368, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16352, 16352, 16352, 16352, 16352, 8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432,
-    8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8703, 8702, 8700, 8700, 8696, 8696, 8696, 8696, 8688, 8688,
-    8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432,
-    8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 9215, 9214, 9212, 9212, 9208, 9208, 9208, 9208, 9200, 9200,
-    9200, 9200, 9200, 9200, 9200, 9200, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184,
-    9184, 9184, 9184, 9184, 9184, 9184, 8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432,
-    8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8703, 8702, 8700, 8700, 8696, 8696, 8696, 8696, 8688, 8688,
-    8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432,
-    8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 10239, 10238, 10236, 10236, 10232, 10232, 10232, 10232,
-    10224, 10224, 10224, 10224, 10224, 10224, 10224, 10224, 10208, 10208, 10208, 10208, 10208,
-    10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 8447, 8446, 8444,
-    8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8703, 8702, 8700,
-    8700, 8696, 8696, 8696, 8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8447, 8446, 8444,
-    8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 9215, 9214, 9212,
-    9212, 9208, 9208, 9208, 9208, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9184, 9184, 9184,
-    9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 8447, 8446, 8444,
-    8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8703, 8702, 8700,
-    8700, 8696, 8696, 8696, 8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8447, 8446, 8444,
-    8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 12287, 12286,
-    12284, 12284, 12280, 12280, 12280, 12280, 12272, 12272, 12272, 12272, 12272, 12272, 12272,
-    12272, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256,
-    12256, 12256, 12256, 12256, 8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432,
-    8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8703, 8702, 8700, 8700, 8696, 8696, 8696, 8696, 8688, 8688, 8688,
-    8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432,
-    8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 9215, 9214, 9212, 9212, 9208, 9208, 9208, 9208, 9200, 9200, 9200,
-    9200, 9200, 9200, 9200, 9200, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184,
-    9184, 9184, 9184, 9184, 9184, 8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432,
-    8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8703, 8702, 8700, 8700, 8696, 8696, 8696, 8696, 8688, 8688, 8688,
-    8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8447, 8446, 8444, 8444, 8440, 8440, 8440, 8440, 8432, 8432, 8432,
-    8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 10239, 10238, 10236, 10236, 10232, 10232, 10232, 10232, 10224,
-    10224, 10224, 10224, 10224, 10224, 10224, 10224, 10208, 10208, 10208, 10208, 10208, 10208,
-    10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 8447, 8446, 8444, 8444,
-    8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8703, 8702, 8700, 8700,
-    8696, 8696, 8696, 8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8447, 8446, 8444, 8444,
-    8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 9215, 9214, 9212, 9212,
-    9208, 9208, 9208, 9208, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9184, 9184, 9184, 9184,
-    9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 8447, 8446, 8444, 8444,
-    8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8703, 8702, 8700, 8700,
-    8696, 8696, 8696, 8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8447, 8446, 8444, 8444,
-    8440, 8440, 8440, 8440, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8432, 8416, 8416, 8416, 8416,
-    8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 8416, 16383, 16382, 16380,
-    16380, 16376, 16376, 16376, 16376, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16368,
-    16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16352, 16352, 16352, 16319, 16318, 16316, 16316, 16312, 16312, 16312, 16312, 16304, 16304,
-    16304, 16304, 16304, 16304, 16304, 16304, 16288, 16288, 16288, 16288, 16288, 16288, 16288,
-    16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 8703, 8702, 8700, 8700, 8696,
-    8696, 8696, 8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8639, 8638, 8636, 8636, 8632,
-    8632, 8632, 8632, 8624, 8624, 8624, 8624, 8624, 8624, 8624, 8624, 8608, 8608, 8608, 8608, 8608,
-    8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 9215, 9214, 9212, 9212, 9208,
-    9208, 9208, 9208, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9184, 9184, 9184, 9184, 9184,
-    9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9151, 9150, 9148, 9148, 9144,
-    9144, 9144, 9144, 9136, 9136, 9136, 9136, 9136, 9136, 9136, 9136, 9120, 9120, 9120, 9120, 9120,
-    9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 8703, 8702, 8700, 8700, 8696,
-    8696, 8696, 8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8639, 8638, 8636, 8636, 8632,
-    8632, 8632, 8632, 8624, 8624, 8624, 8624, 8624, 8624, 8624, 8624, 8608, 8608, 8608, 8608, 8608,
-    8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 10239, 10238, 10236, 10236,
-    10232, 10232, 10232, 10232, 10224, 10224, 10224, 10224, 10224, 10224, 10224, 10224, 10208,
-    10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208,
-    10208, 10208, 10175, 10174, 10172, 10172, 10168, 10168, 10168, 10168, 10160, 10160, 10160,
-    10160, 10160, 10160, 10160, 10160, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144,
-    10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 8703, 8702, 8700, 8700, 8696, 8696,
-    8696, 8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8639, 8638, 8636, 8636, 8632, 8632,
-    8632, 8632, 8624, 8624, 8624, 8624, 8624, 8624, 8624, 8624, 8608, 8608, 8608, 8608, 8608, 8608,
-    8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 9215, 9214, 9212, 9212, 9208, 9208,
-    9208, 9208, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9184, 9184, 9184, 9184, 9184, 9184,
-    9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9151, 9150, 9148, 9148, 9144, 9144,
-    9144, 9144, 9136, 9136, 9136, 9136, 9136, 9136, 9136, 9136, 9120, 9120, 9120, 9120, 9120, 9120,
-    9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 8703, 8702, 8700, 8700, 8696, 8696,
-    8696, 8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8639, 8638, 8636, 8636, 8632, 8632,
-    8632, 8632, 8624, 8624, 8624, 8624, 8624, 8624, 8624, 8624, 8608, 8608, 8608, 8608, 8608, 8608,
-    8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 12287, 12286, 12284, 12284, 12280,
-    12280, 12280, 12280, 12272, 12272, 12272, 12272, 12272, 12272, 12272, 12272, 12256, 12256,
-    12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256,
-    12256, 12223, 12222, 12220, 12220, 12216, 12216, 12216, 12216, 12208, 12208, 12208, 12208,
-    12208, 12208, 12208, 12208, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192,
-    12192, 12192, 12192, 12192, 12192, 12192, 12192, 8703, 8702, 8700, 8700, 8696, 8696, 8696,
-    8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8639, 8638, 8636, 8636, 8632, 8632, 8632,
-    8632, 8624, 8624, 8624, 8624, 8624, 8624, 8624, 8624, 8608, 8608, 8608, 8608, 8608, 8608, 8608,
-    8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 9215, 9214, 9212, 9212, 9208, 9208, 9208,
-    9208, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9184, 9184, 9184, 9184, 9184, 9184, 9184,
-    9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9151, 9150, 9148, 9148, 9144, 9144, 9144,
-    9144, 9136, 9136, 9136, 9136, 9136, 9136, 9136, 9136, 9120, 9120, 9120, 9120, 9120, 9120, 9120,
-    9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 8703, 8702, 8700, 8700, 8696, 8696, 8696,
-    8696, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8639, 8638, 8636, 8636, 8632, 8632, 8632,
-    8632, 8624, 8624, 8624, 8624, 8624, 8624, 8624, 8624, 8608, 8608, 8608, 8608, 8608, 8608, 8608,
-    8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 10239, 10238, 10236, 10236, 10232, 10232,
-    10232, 10232, 10224, 10224, 10224, 10224, 10224, 10224, 10224, 10224, 10208, 10208, 10208,
-    10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208,
-    10175, 10174, 10172, 10172, 10168, 10168, 10168, 10168, 10160, 10160, 10160, 10160, 10160,
-    10160, 10160, 10160, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144,
-    10144, 10144, 10144, 10144, 10144, 10144, 8703, 8702, 8700, 8700, 8696, 8696, 8696, 8696, 8688,
-    8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8639, 8638, 8636, 8636, 8632, 8632, 8632, 8632, 8624,
-    8624, 8624, 8624, 8624, 8624, 8624, 8624, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608,
-    8608, 8608, 8608, 8608, 8608, 8608, 8608, 9215, 9214, 9212, 9212, 9208, 9208, 9208, 9208, 9200,
-    9200, 9200, 9200, 9200, 9200, 9200, 9200, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184,
-    9184, 9184, 9184, 9184, 9184, 9184, 9184, 9151, 9150, 9148, 9148, 9144, 9144, 9144, 9144, 9136,
-    9136, 9136, 9136, 9136, 9136, 9136, 9136, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120,
-    9120, 9120, 9120, 9120, 9120, 9120, 9120, 8703, 8702, 8700, 8700, 8696, 8696, 8696, 8696, 8688,
-    8688, 8688, 8688, 8688, 8688, 8688, 8688, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672, 8672,
-    8672, 8672, 8672, 8672, 8672, 8672, 8672, 8639, 8638, 8636, 8636, 8632, 8632, 8632, 8632, 8624,
-    8624, 8624, 8624, 8624, 8624, 8624, 8624, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608, 8608,
-    8608, 8608, 8608, 8608, 8608, 8608, 8608, 16383, 16382, 16380, 16380, 16376, 16376, 16376,
-    16376, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16352, 16352, 16352, 16352,
-    16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16319,
-    16318, 16316, 16316, 16312, 16312, 16312, 16312, 16304, 16304, 16304, 16304, 16304, 16304,
-    16304, 16304, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288,
-    16288, 16288, 16288, 16288, 16288, 16191, 16190, 16188, 16188, 16184, 16184, 16184, 16184,
-    16176, 16176, 16176, 16176, 16176, 16176, 16176, 16176, 16160, 16160, 16160, 16160, 16160,
-    16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16191, 16190,
-    16188, 16188, 16184, 16184, 16184, 16184, 16176, 16176, 16176, 16176, 16176, 16176, 16176,
-    16176, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160,
-    16160, 16160, 16160, 16160, 9215, 9214, 9212, 9212, 9208, 9208, 9208, 9208, 9200, 9200, 9200,
-    9200, 9200, 9200, 9200, 9200, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184,
-    9184, 9184, 9184, 9184, 9184, 9151, 9150, 9148, 9148, 9144, 9144, 9144, 9144, 9136, 9136, 9136,
-    9136, 9136, 9136, 9136, 9136, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120,
-    9120, 9120, 9120, 9120, 9120, 9023, 9022, 9020, 9020, 9016, 9016, 9016, 9016, 9008, 9008, 9008,
-    9008, 9008, 9008, 9008, 9008, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992,
-    8992, 8992, 8992, 8992, 8992, 9023, 9022, 9020, 9020, 9016, 9016, 9016, 9016, 9008, 9008, 9008,
-    9008, 9008, 9008, 9008, 9008, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992,
-    8992, 8992, 8992, 8992, 8992, 10239, 10238, 10236, 10236, 10232, 10232, 10232, 10232, 10224,
-    10224, 10224, 10224, 10224, 10224, 10224, 10224, 10208, 10208, 10208, 10208, 10208, 10208,
-    10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10175, 10174, 10172,
-    10172, 10168, 10168, 10168, 10168, 10160, 10160, 10160, 10160, 10160, 10160, 10160, 10160,
-    10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144,
-    10144, 10144, 10144, 10047, 10046, 10044, 10044, 10040, 10040, 10040, 10040, 10032, 10032,
-    10032, 10032, 10032, 10032, 10032, 10032, 10016, 10016, 10016, 10016, 10016, 10016, 10016,
-    10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10047, 10046, 10044, 10044,
-    10040, 10040, 10040, 10040, 10032, 10032, 10032, 10032, 10032, 10032, 10032, 10032, 10016,
-    10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016,
-    10016, 10016, 9215, 9214, 9212, 9212, 9208, 9208, 9208, 9208, 9200, 9200, 9200, 9200, 9200,
-    9200, 9200, 9200, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184,
-    9184, 9184, 9184, 9151, 9150, 9148, 9148, 9144, 9144, 9144, 9144, 9136, 9136, 9136, 9136, 9136,
-    9136, 9136, 9136, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120,
-    9120, 9120, 9120, 9023, 9022, 9020, 9020, 9016, 9016, 9016, 9016, 9008, 9008, 9008, 9008, 9008,
-    9008, 9008, 9008, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992,
-    8992, 8992, 8992, 9023, 9022, 9020, 9020, 9016, 9016, 9016, 9016, 9008, 9008, 9008, 9008, 9008,
-    9008, 9008, 9008, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992,
-    8992, 8992, 8992, 12287, 12286, 12284, 12284, 12280, 12280, 12280, 12280, 12272, 12272, 12272,
-    12272, 12272, 12272, 12272, 12272, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256,
-    12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12223, 12222, 12220, 12220, 12216,
-    12216, 12216, 12216, 12208, 12208, 12208, 12208, 12208, 12208, 12208, 12208, 12192, 12192,
-    12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192,
-    12192, 12095, 12094, 12092, 12092, 12088, 12088, 12088, 12088, 12080, 12080, 12080, 12080,
-    12080, 12080, 12080, 12080, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064,
-    12064, 12064, 12064, 12064, 12064, 12064, 12064, 12095, 12094, 12092, 12092, 12088, 12088,
-    12088, 12088, 12080, 12080, 12080, 12080, 12080, 12080, 12080, 12080, 12064, 12064, 12064,
-    12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064,
-    9215, 9214, 9212, 9212, 9208, 9208, 9208, 9208, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9200,
-    9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184,
-    9151, 9150, 9148, 9148, 9144, 9144, 9144, 9144, 9136, 9136, 9136, 9136, 9136, 9136, 9136, 9136,
-    9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120,
-    9023, 9022, 9020, 9020, 9016, 9016, 9016, 9016, 9008, 9008, 9008, 9008, 9008, 9008, 9008, 9008,
-    8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992,
-    9023, 9022, 9020, 9020, 9016, 9016, 9016, 9016, 9008, 9008, 9008, 9008, 9008, 9008, 9008, 9008,
-    8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992,
-    10239, 10238, 10236, 10236, 10232, 10232, 10232, 10232, 10224, 10224, 10224, 10224, 10224,
-    10224, 10224, 10224, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208,
-    10208, 10208, 10208, 10208, 10208, 10208, 10175, 10174, 10172, 10172, 10168, 10168, 10168,
-    10168, 10160, 10160, 10160, 10160, 10160, 10160, 10160, 10160, 10144, 10144, 10144, 10144,
-    10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10047,
-    10046, 10044, 10044, 10040, 10040, 10040, 10040, 10032, 10032, 10032, 10032, 10032, 10032,
-    10032, 10032, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016,
-    10016, 10016, 10016, 10016, 10016, 10047, 10046, 10044, 10044, 10040, 10040, 10040, 10040,
-    10032, 10032, 10032, 10032, 10032, 10032, 10032, 10032, 10016, 10016, 10016, 10016, 10016,
-    10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 9215, 9214, 9212,
-    9212, 9208, 9208, 9208, 9208, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9200, 9184, 9184, 9184,
-    9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9184, 9151, 9150, 9148,
-    9148, 9144, 9144, 9144, 9144, 9136, 9136, 9136, 9136, 9136, 9136, 9136, 9136, 9120, 9120, 9120,
-    9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9120, 9023, 9022, 9020,
-    9020, 9016, 9016, 9016, 9016, 9008, 9008, 9008, 9008, 9008, 9008, 9008, 9008, 8992, 8992, 8992,
-    8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 9023, 9022, 9020,
-    9020, 9016, 9016, 9016, 9016, 9008, 9008, 9008, 9008, 9008, 9008, 9008, 9008, 8992, 8992, 8992,
-    8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 8992, 16383, 16382,
-    16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368, 16368, 16368, 16368, 16368, 16368,
-    16368, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16352, 16352, 16352, 16352, 16319, 16318, 16316, 16316, 16312, 16312, 16312, 16312, 16304,
-    16304, 16304, 16304, 16304, 16304, 16304, 16304, 16288, 16288, 16288, 16288, 16288, 16288,
-    16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16191, 16190, 16188,
-    16188, 16184, 16184, 16184, 16184, 16176, 16176, 16176, 16176, 16176, 16176, 16176, 16176,
-    16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160,
-    16160, 16160, 16160, 16191, 16190, 16188, 16188, 16184, 16184, 16184, 16184, 16176, 16176,
-    16176, 16176, 16176, 16176, 16176, 16176, 16160, 16160, 16160, 16160, 16160, 16160, 16160,
-    16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 15935, 15934, 15932, 15932,
-    15928, 15928, 15928, 15928, 15920, 15920, 15920, 15920, 15920, 15920, 15920, 15920, 15904,
-    15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904,
-    15904, 15904, 15935, 15934, 15932, 15932, 15928, 15928, 15928, 15928, 15920, 15920, 15920,
-    15920, 15920, 15920, 15920, 15920, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904,
-    15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15935, 15934, 15932, 15932, 15928,
-    15928, 15928, 15928, 15920, 15920, 15920, 15920, 15920, 15920, 15920, 15920, 15904, 15904,
-    15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904,
-    15904, 15935, 15934, 15932, 15932, 15928, 15928, 15928, 15928, 15920, 15920, 15920, 15920,
-    15920, 15920, 15920, 15920, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904,
-    15904, 15904, 15904, 15904, 15904, 15904, 15904, 10239, 10238, 10236, 10236, 10232, 10232,
-    10232, 10232, 10224, 10224, 10224, 10224, 10224, 10224, 10224, 10224, 10208, 10208, 10208,
-    10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208,
-    10175, 10174, 10172, 10172, 10168, 10168, 10168, 10168, 10160, 10160, 10160, 10160, 10160,
-    10160, 10160, 10160, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144,
-    10144, 10144, 10144, 10144, 10144, 10144, 10047, 10046, 10044, 10044, 10040, 10040, 10040,
-    10040, 10032, 10032, 10032, 10032, 10032, 10032, 10032, 10032, 10016, 10016, 10016, 10016,
-    10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10047,
-    10046, 10044, 10044, 10040, 10040, 10040, 10040, 10032, 10032, 10032, 10032, 10032, 10032,
-    10032, 10032, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016,
-    10016, 10016, 10016, 10016, 10016, 9791, 9790, 9788, 9788, 9784, 9784, 9784, 9784, 9776, 9776,
-    9776, 9776, 9776, 9776, 9776, 9776, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760,
-    9760, 9760, 9760, 9760, 9760, 9760, 9791, 9790, 9788, 9788, 9784, 9784, 9784, 9784, 9776, 9776,
-    9776, 9776, 9776, 9776, 9776, 9776, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760,
-    9760, 9760, 9760, 9760, 9760, 9760, 9791, 9790, 9788, 9788, 9784, 9784, 9784, 9784, 9776, 9776,
-    9776, 9776, 9776, 9776, 9776, 9776, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760,
-    9760, 9760, 9760, 9760, 9760, 9760, 9791, 9790, 9788, 9788, 9784, 9784, 9784, 9784, 9776, 9776,
-    9776, 9776, 9776, 9776, 9776, 9776, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760,
-    9760, 9760, 9760, 9760, 9760, 9760, 12287, 12286, 12284, 12284, 12280, 12280, 12280, 12280,
-    12272, 12272, 12272, 12272, 12272, 12272, 12272, 12272, 12256, 12256, 12256, 12256, 12256,
-    12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12223, 12222,
-    12220, 12220, 12216, 12216, 12216, 12216, 12208, 12208, 12208, 12208, 12208, 12208, 12208,
-    12208, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192,
-    12192, 12192, 12192, 12192, 12095, 12094, 12092, 12092, 12088, 12088, 12088, 12088, 12080,
-    12080, 12080, 12080, 12080, 12080, 12080, 12080, 12064, 12064, 12064, 12064, 12064, 12064,
-    12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12095, 12094, 12092,
-    12092, 12088, 12088, 12088, 12088, 12080, 12080, 12080, 12080, 12080, 12080, 12080, 12080,
-    12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064,
-    12064, 12064, 12064, 11839, 11838, 11836, 11836, 11832, 11832, 11832, 11832, 11824, 11824,
-    11824, 11824, 11824, 11824, 11824, 11824, 11808, 11808, 11808, 11808, 11808, 11808, 11808,
-    11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11839, 11838, 11836, 11836,
-    11832, 11832, 11832, 11832, 11824, 11824, 11824, 11824, 11824, 11824, 11824, 11824, 11808,
-    11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808,
-    11808, 11808, 11839, 11838, 11836, 11836, 11832, 11832, 11832, 11832, 11824, 11824, 11824,
-    11824, 11824, 11824, 11824, 11824, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808,
-    11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11839, 11838, 11836, 11836, 11832,
-    11832, 11832, 11832, 11824, 11824, 11824, 11824, 11824, 11824, 11824, 11824, 11808, 11808,
-    11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808,
-    11808, 10239, 10238, 10236, 10236, 10232, 10232, 10232, 10232, 10224, 10224, 10224, 10224,
-    10224, 10224, 10224, 10224, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208, 10208,
-    10208, 10208, 10208, 10208, 10208, 10208, 10208, 10175, 10174, 10172, 10172, 10168, 10168,
-    10168, 10168, 10160, 10160, 10160, 10160, 10160, 10160, 10160, 10160, 10144, 10144, 10144,
-    10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144, 10144,
-    10047, 10046, 10044, 10044, 10040, 10040, 10040, 10040, 10032, 10032, 10032, 10032, 10032,
-    10032, 10032, 10032, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016,
-    10016, 10016, 10016, 10016, 10016, 10016, 10047, 10046, 10044, 10044, 10040, 10040, 10040,
-    10040, 10032, 10032, 10032, 10032, 10032, 10032, 10032, 10032, 10016, 10016, 10016, 10016,
-    10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 10016, 9791, 9790,
-    9788, 9788, 9784, 9784, 9784, 9784, 9776, 9776, 9776, 9776, 9776, 9776, 9776, 9776, 9760, 9760,
-    9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9791, 9790,
-    9788, 9788, 9784, 9784, 9784, 9784, 9776, 9776, 9776, 9776, 9776, 9776, 9776, 9776, 9760, 9760,
-    9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9791, 9790,
-    9788, 9788, 9784, 9784, 9784, 9784, 9776, 9776, 9776, 9776, 9776, 9776, 9776, 9776, 9760, 9760,
-    9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9791, 9790,
-    9788, 9788, 9784, 9784, 9784, 9784, 9776, 9776, 9776, 9776, 9776, 9776, 9776, 9776, 9760, 9760,
-    9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 9760, 16383,
-    16382, 16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368, 16368, 16368, 16368, 16368,
-    16368, 16368, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16352, 16352, 16352, 16352, 16352, 16319, 16318, 16316, 16316, 16312, 16312, 16312, 16312,
-    16304, 16304, 16304, 16304, 16304, 16304, 16304, 16304, 16288, 16288, 16288, 16288, 16288,
-    16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16191, 16190,
-    16188, 16188, 16184, 16184, 16184, 16184, 16176, 16176, 16176, 16176, 16176, 16176, 16176,
-    16176, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160,
-    16160, 16160, 16160, 16160, 16191, 16190, 16188, 16188, 16184, 16184, 16184, 16184, 16176,
-    16176, 16176, 16176, 16176, 16176, 16176, 16176, 16160, 16160, 16160, 16160, 16160, 16160,
-    16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 15935, 15934, 15932,
-    15932, 15928, 15928, 15928, 15928, 15920, 15920, 15920, 15920, 15920, 15920, 15920, 15920,
-    15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904,
-    15904, 15904, 15904, 15935, 15934, 15932, 15932, 15928, 15928, 15928, 15928, 15920, 15920,
-    15920, 15920, 15920, 15920, 15920, 15920, 15904, 15904, 15904, 15904, 15904, 15904, 15904,
-    15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15935, 15934, 15932, 15932,
-    15928, 15928, 15928, 15928, 15920, 15920, 15920, 15920, 15920, 15920, 15920, 15920, 15904,
-    15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904,
-    15904, 15904, 15935, 15934, 15932, 15932, 15928, 15928, 15928, 15928, 15920, 15920, 15920,
-    15920, 15920, 15920, 15920, 15920, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904,
-    15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15423, 15422, 15420, 15420, 15416,
-    15416, 15416, 15416, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15423, 15422, 15420, 15420, 15416, 15416, 15416, 15416, 15408, 15408, 15408, 15408,
-    15408, 15408, 15408, 15408, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15423, 15422, 15420, 15420, 15416, 15416,
-    15416, 15416, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15423, 15422, 15420, 15420, 15416, 15416, 15416, 15416, 15408, 15408, 15408, 15408, 15408,
-    15408, 15408, 15408, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15423, 15422, 15420, 15420, 15416, 15416, 15416,
-    15416, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15423,
-    15422, 15420, 15420, 15416, 15416, 15416, 15416, 15408, 15408, 15408, 15408, 15408, 15408,
-    15408, 15408, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15423, 15422, 15420, 15420, 15416, 15416, 15416, 15416,
-    15408, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15423, 15422,
-    15420, 15420, 15416, 15416, 15416, 15416, 15408, 15408, 15408, 15408, 15408, 15408, 15408,
-    15408, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 12287, 12286, 12284, 12284, 12280, 12280, 12280, 12280, 12272,
-    12272, 12272, 12272, 12272, 12272, 12272, 12272, 12256, 12256, 12256, 12256, 12256, 12256,
-    12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12256, 12223, 12222, 12220,
-    12220, 12216, 12216, 12216, 12216, 12208, 12208, 12208, 12208, 12208, 12208, 12208, 12208,
-    12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192, 12192,
-    12192, 12192, 12192, 12095, 12094, 12092, 12092, 12088, 12088, 12088, 12088, 12080, 12080,
-    12080, 12080, 12080, 12080, 12080, 12080, 12064, 12064, 12064, 12064, 12064, 12064, 12064,
-    12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12095, 12094, 12092, 12092,
-    12088, 12088, 12088, 12088, 12080, 12080, 12080, 12080, 12080, 12080, 12080, 12080, 12064,
-    12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064, 12064,
-    12064, 12064, 11839, 11838, 11836, 11836, 11832, 11832, 11832, 11832, 11824, 11824, 11824,
-    11824, 11824, 11824, 11824, 11824, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808,
-    11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11839, 11838, 11836, 11836, 11832,
-    11832, 11832, 11832, 11824, 11824, 11824, 11824, 11824, 11824, 11824, 11824, 11808, 11808,
-    11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808,
-    11808, 11839, 11838, 11836, 11836, 11832, 11832, 11832, 11832, 11824, 11824, 11824, 11824,
-    11824, 11824, 11824, 11824, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808,
-    11808, 11808, 11808, 11808, 11808, 11808, 11808, 11839, 11838, 11836, 11836, 11832, 11832,
-    11832, 11832, 11824, 11824, 11824, 11824, 11824, 11824, 11824, 11824, 11808, 11808, 11808,
-    11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808, 11808,
-    11327, 11326, 11324, 11324, 11320, 11320, 11320, 11320, 11312, 11312, 11312, 11312, 11312,
-    11312, 11312, 11312, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296,
-    11296, 11296, 11296, 11296, 11296, 11296, 11327, 11326, 11324, 11324, 11320, 11320, 11320,
-    11320, 11312, 11312, 11312, 11312, 11312, 11312, 11312, 11312, 11296, 11296, 11296, 11296,
-    11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11327,
-    11326, 11324, 11324, 11320, 11320, 11320, 11320, 11312, 11312, 11312, 11312, 11312, 11312,
-    11312, 11312, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296,
-    11296, 11296, 11296, 11296, 11296, 11327, 11326, 11324, 11324, 11320, 11320, 11320, 11320,
-    11312, 11312, 11312, 11312, 11312, 11312, 11312, 11312, 11296, 11296, 11296, 11296, 11296,
-    11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11327, 11326,
-    11324, 11324, 11320, 11320, 11320, 11320, 11312, 11312, 11312, 11312, 11312, 11312, 11312,
-    11312, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296,
-    11296, 11296, 11296, 11296, 11327, 11326, 11324, 11324, 11320, 11320, 11320, 11320, 11312,
-    11312, 11312, 11312, 11312, 11312, 11312, 11312, 11296, 11296, 11296, 11296, 11296, 11296,
-    11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11327, 11326, 11324,
-    11324, 11320, 11320, 11320, 11320, 11312, 11312, 11312, 11312, 11312, 11312, 11312, 11312,
-    11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296,
-    11296, 11296, 11296, 11327, 11326, 11324, 11324, 11320, 11320, 11320, 11320, 11312, 11312,
-    11312, 11312, 11312, 11312, 11312, 11312, 11296, 11296, 11296, 11296, 11296, 11296, 11296,
-    11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 11296, 16383, 16382, 16380, 16380,
-    16376, 16376, 16376, 16376, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16352,
-    16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16352, 16352, 16319, 16318, 16316, 16316, 16312, 16312, 16312, 16312, 16304, 16304, 16304,
-    16304, 16304, 16304, 16304, 16304, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288,
-    16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16191, 16190, 16188, 16188, 16184,
-    16184, 16184, 16184, 16176, 16176, 16176, 16176, 16176, 16176, 16176, 16176, 16160, 16160,
-    16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160,
-    16160, 16191, 16190, 16188, 16188, 16184, 16184, 16184, 16184, 16176, 16176, 16176, 16176,
-    16176, 16176, 16176, 16176, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160,
-    16160, 16160, 16160, 16160, 16160, 16160, 16160, 15935, 15934, 15932, 15932, 15928, 15928,
-    15928, 15928, 15920, 15920, 15920, 15920, 15920, 15920, 15920, 15920, 15904, 15904, 15904,
-    15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904,
-    15935, 15934, 15932, 15932, 15928, 15928, 15928, 15928, 15920, 15920, 15920, 15920, 15920,
-    15920, 15920, 15920, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904,
-    15904, 15904, 15904, 15904, 15904, 15904, 15935, 15934, 15932, 15932, 15928, 15928, 15928,
-    15928, 15920, 15920, 15920, 15920, 15920, 15920, 15920, 15920, 15904, 15904, 15904, 15904,
-    15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15935,
-    15934, 15932, 15932, 15928, 15928, 15928, 15928, 15920, 15920, 15920, 15920, 15920, 15920,
-    15920, 15920, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904,
-    15904, 15904, 15904, 15904, 15904, 15423, 15422, 15420, 15420, 15416, 15416, 15416, 15416,
-    15408, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15423, 15422,
-    15420, 15420, 15416, 15416, 15416, 15416, 15408, 15408, 15408, 15408, 15408, 15408, 15408,
-    15408, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15423, 15422, 15420, 15420, 15416, 15416, 15416, 15416, 15408,
-    15408, 15408, 15408, 15408, 15408, 15408, 15408, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15423, 15422, 15420,
-    15420, 15416, 15416, 15416, 15416, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15408,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15423, 15422, 15420, 15420, 15416, 15416, 15416, 15416, 15408, 15408,
-    15408, 15408, 15408, 15408, 15408, 15408, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15423, 15422, 15420, 15420,
-    15416, 15416, 15416, 15416, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15423, 15422, 15420, 15420, 15416, 15416, 15416, 15416, 15408, 15408, 15408,
-    15408, 15408, 15408, 15408, 15408, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15423, 15422, 15420, 15420, 15416,
-    15416, 15416, 15416, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384,
-    14384, 14384, 14384, 14384, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14399, 14398, 14396, 14396, 14392, 14392,
-    14392, 14392, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384, 14384,
-    14384, 14384, 14384, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14399, 14398, 14396, 14396, 14392, 14392, 14392,
-    14392, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14399,
-    14398, 14396, 14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384, 14384, 14384,
-    14384, 14384, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392,
-    14384, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14399, 14398,
-    14396, 14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384, 14384, 14384, 14384,
-    14384, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14384,
-    14384, 14384, 14384, 14384, 14384, 14384, 14384, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14399, 14398, 14396,
-    14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14384,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14384, 14384,
-    14384, 14384, 14384, 14384, 14384, 14384, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14399, 14398, 14396, 14396,
-    14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384,
-    14384, 14384, 14384, 14384, 14384, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14399, 14398, 14396, 14396, 14392,
-    14392, 14392, 14392, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384,
-    14384, 14384, 14384, 14384, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14399, 14398, 14396, 14396, 14392, 14392,
-    14392, 14392, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384, 14384,
-    14384, 14384, 14384, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 16383, 16382, 16380, 16380, 16376, 16376, 16376,
-    16376, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16352, 16352, 16352, 16352,
-    16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16319,
-    16318, 16316, 16316, 16312, 16312, 16312, 16312, 16304, 16304, 16304, 16304, 16304, 16304,
-    16304, 16304, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288, 16288,
-    16288, 16288, 16288, 16288, 16288, 16191, 16190, 16188, 16188, 16184, 16184, 16184, 16184,
-    16176, 16176, 16176, 16176, 16176, 16176, 16176, 16176, 16160, 16160, 16160, 16160, 16160,
-    16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16191, 16190,
-    16188, 16188, 16184, 16184, 16184, 16184, 16176, 16176, 16176, 16176, 16176, 16176, 16176,
-    16176, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160, 16160,
-    16160, 16160, 16160, 16160, 15935, 15934, 15932, 15932, 15928, 15928, 15928, 15928, 15920,
-    15920, 15920, 15920, 15920, 15920, 15920, 15920, 15904, 15904, 15904, 15904, 15904, 15904,
-    15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15935, 15934, 15932,
-    15932, 15928, 15928, 15928, 15928, 15920, 15920, 15920, 15920, 15920, 15920, 15920, 15920,
-    15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904,
-    15904, 15904, 15904, 15935, 15934, 15932, 15932, 15928, 15928, 15928, 15928, 15920, 15920,
-    15920, 15920, 15920, 15920, 15920, 15920, 15904, 15904, 15904, 15904, 15904, 15904, 15904,
-    15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15935, 15934, 15932, 15932,
-    15928, 15928, 15928, 15928, 15920, 15920, 15920, 15920, 15920, 15920, 15920, 15920, 15904,
-    15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904, 15904,
-    15904, 15904, 15423, 15422, 15420, 15420, 15416, 15416, 15416, 15416, 15408, 15408, 15408,
-    15408, 15408, 15408, 15408, 15408, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15423, 15422, 15420, 15420, 15416,
-    15416, 15416, 15416, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15423, 15422, 15420, 15420, 15416, 15416, 15416, 15416, 15408, 15408, 15408, 15408,
-    15408, 15408, 15408, 15408, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15423, 15422, 15420, 15420, 15416, 15416,
-    15416, 15416, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15423, 15422, 15420, 15420, 15416, 15416, 15416, 15416, 15408, 15408, 15408, 15408, 15408,
-    15408, 15408, 15408, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15423, 15422, 15420, 15420, 15416, 15416, 15416,
-    15416, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15423,
-    15422, 15420, 15420, 15416, 15416, 15416, 15416, 15408, 15408, 15408, 15408, 15408, 15408,
-    15408, 15408, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15423, 15422, 15420, 15420, 15416, 15416, 15416, 15416,
-    15408, 15408, 15408, 15408, 15408, 15408, 15408, 15408, 15392, 15392, 15392, 15392, 15392,
-    15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 15392, 14399, 14398,
-    14396, 14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384, 14384, 14384, 14384,
-    14384, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14384,
-    14384, 14384, 14384, 14384, 14384, 14384, 14384, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14399, 14398, 14396,
-    14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14384,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14384, 14384,
-    14384, 14384, 14384, 14384, 14384, 14384, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14399, 14398, 14396, 14396,
-    14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384,
-    14384, 14384, 14384, 14384, 14384, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14399, 14398, 14396, 14396, 14392,
-    14392, 14392, 14392, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384,
-    14384, 14384, 14384, 14384, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14399, 14398, 14396, 14396, 14392, 14392,
-    14392, 14392, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384, 14384,
-    14384, 14384, 14384, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14399, 14398, 14396, 14396, 14392, 14392, 14392,
-    14392, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14399,
-    14398, 14396, 14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384, 14384, 14384,
-    14384, 14384, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392,
-    14384, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14399, 14398,
-    14396, 14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384, 14384, 14384, 14384,
-    14384, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14399, 14398, 14396, 14396, 14392, 14392, 14392, 14392, 14384,
-    14384, 14384, 14384, 14384, 14384, 14384, 14384, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14399, 14398, 14396,
-    14396, 14392, 14392, 14392, 14392, 14384, 14384, 14384, 14384, 14384, 14384, 14384, 14384,
-    14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368, 14368,
-    14368, 14368, 14368, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336,
-    12336, 12336, 12336, 12336, 12336, 12336, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12351, 12350, 12348, 12348,
-    12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336,
-    12336, 12336, 12336, 12336, 12336, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12351, 12350, 12348, 12348, 12344,
-    12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336,
-    12336, 12336, 12336, 12336, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12351, 12350, 12348, 12348, 12344, 12344,
-    12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336,
-    12336, 12336, 12336, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12351, 12350, 12348, 12348, 12344, 12344, 12344,
-    12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12351,
-    12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336,
-    12336, 12336, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344,
-    12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12351, 12350,
-    12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336,
-    12336, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336,
-    12336, 12336, 12336, 12336, 12336, 12336, 12336, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12351, 12350, 12348,
-    12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336,
-    12336, 12336, 12336, 12336, 12336, 12336, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12351, 12350, 12348, 12348,
-    12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336,
-    12336, 12336, 12336, 12336, 12336, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12351, 12350, 12348, 12348, 12344,
-    12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336,
-    12336, 12336, 12336, 12336, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12351, 12350, 12348, 12348, 12344, 12344,
-    12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336,
-    12336, 12336, 12336, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12351, 12350, 12348, 12348, 12344, 12344, 12344,
-    12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12351,
-    12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336,
-    12336, 12336, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344,
-    12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12351, 12350,
-    12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336,
-    12336, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336,
-    12336, 12336, 12336, 12336, 12336, 12336, 12336, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12351, 12350, 12348,
-    12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336,
-    12336, 12336, 12336, 12336, 12336, 12336, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12351, 12350, 12348, 12348,
-    12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336,
-    12336, 12336, 12336, 12336, 12336, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12351, 12350, 12348, 12348, 12344,
-    12344, 12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12351, 12350, 12348, 12348, 12344, 12344, 12344, 12344, 12336, 12336, 12336, 12336,
-    12336, 12336, 12336, 12336, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12351, 12350, 12348, 12348, 12344, 12344,
-    12344, 12344, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12336, 12320, 12320, 12320,
-    12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320, 12320,
-    16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368, 16368, 16368, 16368,
-    16368, 16368, 16368, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16352, 16352, 16352, 16352, 16352, 16352, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 255, 254,
-    252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496,
-    496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 255, 254, 252, 252, 248, 248, 248, 248, 240,
-    240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510,
-    508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240,
-    240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 2047, 2046, 2044, 2044, 2040, 2040,
-    2040, 2040, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 255, 254, 252, 252, 248, 248, 248,
-    248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240,
-    240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 1023, 1022, 1020, 1020,
-    1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240,
-    240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504,
-    504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4080,
-    4080, 4080, 4080, 4080, 4080, 4080, 4080, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064,
-    4064, 4064, 4064, 4064, 4064, 4064, 4064, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240,
-    240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510, 508,
-    508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240,
-    240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 1023, 1022, 1020, 1020, 1016, 1016,
-    1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504, 496,
-    496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255, 254,
-    252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032,
-    2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240,
-    240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504,
-    504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008,
-    1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496,
-    496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255, 254, 252, 252, 248,
-    248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 8176, 8176, 8176, 8176, 8176, 8176,
-    8176, 8176, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160,
-    8160, 8160, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128,
-    8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128,
-    8128, 8128, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504, 496,
-    496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255, 254,
-    252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 255, 254, 252,
-    252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496,
-    496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255, 254, 252, 252, 248, 248, 248, 248,
-    240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 2047,
-    2046, 2044, 2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 255,
-    254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496,
-    496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255, 254, 252, 252, 248, 248,
-    248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008,
-    1008, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 255, 254, 252, 252, 248, 248, 248, 248,
-    240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511,
-    510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240,
-    240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 4095, 4094, 4092, 4092, 4088,
-    4088, 4088, 4088, 4080, 4080, 4080, 4080, 4080, 4080, 4080, 4080, 4064, 4064, 4064, 4064, 4064,
-    4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 255, 254, 252, 252, 248, 248,
-    248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240,
-    240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 1023, 1022, 1020,
-    1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240,
-    240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504,
-    504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040,
-    2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 255, 254, 252, 252, 248, 248, 248, 248, 240,
-    240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510,
-    508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240,
-    240, 240, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 1023, 1022, 1020, 1020, 1016, 1016,
-    1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 255, 254, 252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 511, 510, 508, 508, 504, 504, 504, 504, 496,
-    496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 255, 254,
-    252, 252, 248, 248, 248, 248, 240, 240, 240, 240, 240, 240, 240, 240, 224, 224, 224, 224, 224,
-    224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368,
-    16368, 16368, 16368, 16368, 16368, 16368, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 1023, 1022, 1020, 1020, 1016, 1016, 1016,
-    1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040,
-    2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 511, 510, 508, 508, 504, 504, 504, 504, 496,
-    496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 1023,
-    1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496,
-    496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 4095, 4094,
-    4092, 4092, 4088, 4088, 4088, 4088, 4080, 4080, 4080, 4080, 4080, 4080, 4080, 4080, 4064, 4064,
-    4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 511, 510,
-    508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 511, 510, 508,
-    508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032, 2032,
-    2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496,
-    496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 1023, 1022, 1020, 1020, 1016, 1016,
-    1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 8191, 8190, 8188, 8188, 8184, 8184, 8184,
-    8184, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8160, 8160, 8160, 8160, 8160, 8160, 8160,
-    8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8128, 8128, 8128, 8128, 8128, 8128, 8128,
-    8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128,
-    8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 511, 510, 508, 508, 504, 504, 504, 504,
-    496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 1023,
-    1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496,
-    496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 2047, 2046,
-    2044, 2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 511, 510,
-    508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 511, 510, 508,
-    508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4080, 4080, 4080, 4080,
-    4080, 4080, 4080, 4080, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064,
-    4064, 4064, 4064, 4064, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496,
-    496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 1023, 1022, 1020, 1020, 1016, 1016,
-    1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496, 496, 496, 496, 496, 496, 496,
-    480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 2047, 2046, 2044, 2044, 2040, 2040, 2040,
-    2040, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 511, 510, 508, 508, 504, 504, 504, 504,
-    496, 496, 496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 1023,
-    1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 511, 510, 508, 508, 504, 504, 504, 504, 496, 496,
-    496, 496, 496, 496, 496, 496, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480, 480,
-    480, 480, 480, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448,
-    448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 448, 16383, 16382,
-    16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368, 16368, 16368, 16368, 16368, 16368,
-    16368, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16352, 16352, 16352, 16352, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16255, 16254, 16252,
-    16252, 16248, 16248, 16248, 16248, 16240, 16240, 16240, 16240, 16240, 16240, 16240, 16240,
-    16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224,
-    16224, 16224, 16224, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192,
-    16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192,
-    16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 1023, 1022, 1020, 1020, 1016,
-    1016, 1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 895, 894, 892, 892, 888, 888, 888, 888, 880, 880, 880, 880, 880, 880, 880,
-    880, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 832, 832,
-    832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832,
-    832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 2047, 2046, 2044, 2044, 2040, 2040,
-    2040, 2040, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1919, 1918, 1916, 1916, 1912, 1912,
-    1912, 1912, 1904, 1904, 1904, 1904, 1904, 1904, 1904, 1904, 1888, 1888, 1888, 1888, 1888, 1888,
-    1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1856, 1856, 1856, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1023, 1022, 1020, 1020, 1016, 1016,
-    1016, 1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 895, 894, 892, 892, 888, 888, 888, 888, 880, 880, 880, 880, 880, 880, 880, 880,
-    864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 832, 832, 832,
-    832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832,
-    832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 4095, 4094, 4092, 4092, 4088, 4088, 4088,
-    4088, 4080, 4080, 4080, 4080, 4080, 4080, 4080, 4080, 4064, 4064, 4064, 4064, 4064, 4064, 4064,
-    4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 3967, 3966, 3964, 3964, 3960, 3960, 3960,
-    3960, 3952, 3952, 3952, 3952, 3952, 3952, 3952, 3952, 3936, 3936, 3936, 3936, 3936, 3936, 3936,
-    3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3904, 3904, 3904, 3904, 3904, 3904, 3904,
-    3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904,
-    3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 1023, 1022, 1020, 1020, 1016, 1016, 1016,
-    1016, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 895, 894, 892, 892, 888, 888, 888, 888, 880, 880, 880, 880, 880, 880, 880, 880, 864,
-    864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 832, 832, 832, 832,
-    832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832,
-    832, 832, 832, 832, 832, 832, 832, 832, 832, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040,
-    2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1919, 1918, 1916, 1916, 1912, 1912, 1912, 1912,
-    1904, 1904, 1904, 1904, 1904, 1904, 1904, 1904, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888,
-    1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016,
-    1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 895, 894, 892, 892, 888, 888, 888, 888, 880, 880, 880, 880, 880, 880, 880, 880, 864, 864,
-    864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 832, 832, 832, 832, 832,
-    832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832,
-    832, 832, 832, 832, 832, 832, 832, 832, 8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 8176,
-    8176, 8176, 8176, 8176, 8176, 8176, 8176, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160,
-    8160, 8160, 8160, 8160, 8160, 8160, 8160, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128,
-    8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128,
-    8128, 8128, 8128, 8128, 8128, 8128, 8128, 8063, 8062, 8060, 8060, 8056, 8056, 8056, 8056, 8048,
-    8048, 8048, 8048, 8048, 8048, 8048, 8048, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032,
-    8032, 8032, 8032, 8032, 8032, 8032, 8032, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000,
-    8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000,
-    8000, 8000, 8000, 8000, 8000, 8000, 8000, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008,
-    1008, 1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    895, 894, 892, 892, 888, 888, 888, 888, 880, 880, 880, 880, 880, 880, 880, 880, 864, 864, 864,
-    864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 832, 832, 832, 832, 832, 832,
-    832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832,
-    832, 832, 832, 832, 832, 832, 832, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040, 2032, 2032,
-    2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1919, 1918, 1916, 1916, 1912, 1912, 1912, 1912, 1904, 1904,
-    1904, 1904, 1904, 1904, 1904, 1904, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888,
-    1888, 1888, 1888, 1888, 1888, 1888, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1856, 1856, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 895, 894,
-    892, 892, 888, 888, 888, 888, 880, 880, 880, 880, 880, 880, 880, 880, 864, 864, 864, 864, 864,
-    864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 832, 832, 832, 832, 832, 832, 832, 832,
-    832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832,
-    832, 832, 832, 832, 832, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4080, 4080, 4080,
-    4080, 4080, 4080, 4080, 4080, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064,
-    4064, 4064, 4064, 4064, 4064, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 3967, 3966, 3964, 3964, 3960, 3960, 3960, 3960, 3952, 3952, 3952,
-    3952, 3952, 3952, 3952, 3952, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936,
-    3936, 3936, 3936, 3936, 3936, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904,
-    3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904,
-    3904, 3904, 3904, 3904, 3904, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 895, 894, 892,
-    892, 888, 888, 888, 888, 880, 880, 880, 880, 880, 880, 880, 880, 864, 864, 864, 864, 864, 864,
-    864, 864, 864, 864, 864, 864, 864, 864, 864, 864, 832, 832, 832, 832, 832, 832, 832, 832, 832,
-    832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832,
-    832, 832, 832, 832, 2047, 2046, 2044, 2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032, 2032,
-    2032, 2032, 2032, 2032, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1919, 1918, 1916, 1916, 1912, 1912, 1912, 1912, 1904, 1904, 1904, 1904,
-    1904, 1904, 1904, 1904, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888,
-    1888, 1888, 1888, 1888, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1023, 1022, 1020, 1020, 1016, 1016, 1016, 1016, 1008, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992,
-    992, 992, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960,
-    960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 960, 895, 894, 892, 892,
-    888, 888, 888, 888, 880, 880, 880, 880, 880, 880, 880, 880, 864, 864, 864, 864, 864, 864, 864,
-    864, 864, 864, 864, 864, 864, 864, 864, 864, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832,
-    832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832, 832,
-    832, 832, 832, 16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368, 16368,
-    16368, 16368, 16368, 16368, 16368, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16255, 16254, 16252, 16252, 16248, 16248, 16248, 16248, 16240, 16240, 16240, 16240,
-    16240, 16240, 16240, 16240, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224,
-    16224, 16224, 16224, 16224, 16224, 16224, 16224, 16192, 16192, 16192, 16192, 16192, 16192,
-    16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192,
-    16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192,
-    15999, 15998, 15996, 15996, 15992, 15992, 15992, 15992, 15984, 15984, 15984, 15984, 15984,
-    15984, 15984, 15984, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968,
-    15968, 15968, 15968, 15968, 15968, 15968, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15999,
-    15998, 15996, 15996, 15992, 15992, 15992, 15992, 15984, 15984, 15984, 15984, 15984, 15984,
-    15984, 15984, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968,
-    15968, 15968, 15968, 15968, 15968, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 2047, 2046, 2044,
-    2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1919, 1918, 1916,
-    1916, 1912, 1912, 1912, 1912, 1904, 1904, 1904, 1904, 1904, 1904, 1904, 1904, 1888, 1888, 1888,
-    1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1663, 1662, 1660,
-    1660, 1656, 1656, 1656, 1656, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1632, 1632, 1632,
-    1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1663, 1662, 1660,
-    1660, 1656, 1656, 1656, 1656, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1632, 1632, 1632,
-    1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 4095, 4094, 4092,
-    4092, 4088, 4088, 4088, 4088, 4080, 4080, 4080, 4080, 4080, 4080, 4080, 4080, 4064, 4064, 4064,
-    4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 3967, 3966, 3964,
-    3964, 3960, 3960, 3960, 3960, 3952, 3952, 3952, 3952, 3952, 3952, 3952, 3952, 3936, 3936, 3936,
-    3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3904, 3904, 3904,
-    3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904,
-    3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3711, 3710, 3708,
-    3708, 3704, 3704, 3704, 3704, 3696, 3696, 3696, 3696, 3696, 3696, 3696, 3696, 3680, 3680, 3680,
-    3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3711, 3710, 3708,
-    3708, 3704, 3704, 3704, 3704, 3696, 3696, 3696, 3696, 3696, 3696, 3696, 3696, 3680, 3680, 3680,
-    3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 2047, 2046, 2044,
-    2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1919, 1918, 1916,
-    1916, 1912, 1912, 1912, 1912, 1904, 1904, 1904, 1904, 1904, 1904, 1904, 1904, 1888, 1888, 1888,
-    1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1663, 1662, 1660,
-    1660, 1656, 1656, 1656, 1656, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1632, 1632, 1632,
-    1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1663, 1662, 1660,
-    1660, 1656, 1656, 1656, 1656, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1632, 1632, 1632,
-    1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 8191, 8190, 8188,
-    8188, 8184, 8184, 8184, 8184, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8160, 8160, 8160,
-    8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8128, 8128, 8128,
-    8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128,
-    8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8063, 8062, 8060,
-    8060, 8056, 8056, 8056, 8056, 8048, 8048, 8048, 8048, 8048, 8048, 8048, 8048, 8032, 8032, 8032,
-    8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8000, 8000, 8000,
-    8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000,
-    8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 7807, 7806, 7804,
-    7804, 7800, 7800, 7800, 7800, 7792, 7792, 7792, 7792, 7792, 7792, 7792, 7792, 7776, 7776, 7776,
-    7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7744, 7744, 7744,
-    7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744,
-    7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7807, 7806, 7804,
-    7804, 7800, 7800, 7800, 7800, 7792, 7792, 7792, 7792, 7792, 7792, 7792, 7792, 7776, 7776, 7776,
-    7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7744, 7744, 7744,
-    7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744,
-    7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 2047, 2046, 2044,
-    2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1919, 1918, 1916,
-    1916, 1912, 1912, 1912, 1912, 1904, 1904, 1904, 1904, 1904, 1904, 1904, 1904, 1888, 1888, 1888,
-    1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1663, 1662, 1660,
-    1660, 1656, 1656, 1656, 1656, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1632, 1632, 1632,
-    1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1663, 1662, 1660,
-    1660, 1656, 1656, 1656, 1656, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1632, 1632, 1632,
-    1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 4095, 4094, 4092,
-    4092, 4088, 4088, 4088, 4088, 4080, 4080, 4080, 4080, 4080, 4080, 4080, 4080, 4064, 4064, 4064,
-    4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 3967, 3966, 3964,
-    3964, 3960, 3960, 3960, 3960, 3952, 3952, 3952, 3952, 3952, 3952, 3952, 3952, 3936, 3936, 3936,
-    3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3904, 3904, 3904,
-    3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904,
-    3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3711, 3710, 3708,
-    3708, 3704, 3704, 3704, 3704, 3696, 3696, 3696, 3696, 3696, 3696, 3696, 3696, 3680, 3680, 3680,
-    3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3711, 3710, 3708,
-    3708, 3704, 3704, 3704, 3704, 3696, 3696, 3696, 3696, 3696, 3696, 3696, 3696, 3680, 3680, 3680,
-    3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 2047, 2046, 2044,
-    2044, 2040, 2040, 2040, 2040, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2032, 2016, 2016, 2016,
-    2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1919, 1918, 1916,
-    1916, 1912, 1912, 1912, 1912, 1904, 1904, 1904, 1904, 1904, 1904, 1904, 1904, 1888, 1888, 1888,
-    1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1888, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856,
-    1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1663, 1662, 1660,
-    1660, 1656, 1656, 1656, 1656, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1632, 1632, 1632,
-    1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1663, 1662, 1660,
-    1660, 1656, 1656, 1656, 1656, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1648, 1632, 1632, 1632,
-    1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1632, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600,
-    1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 1600, 16383, 16382,
-    16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368, 16368, 16368, 16368, 16368, 16368,
-    16368, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16352, 16352, 16352, 16352, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16255, 16254, 16252,
-    16252, 16248, 16248, 16248, 16248, 16240, 16240, 16240, 16240, 16240, 16240, 16240, 16240,
-    16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224,
-    16224, 16224, 16224, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192,
-    16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192,
-    16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 15999, 15998, 15996, 15996,
-    15992, 15992, 15992, 15992, 15984, 15984, 15984, 15984, 15984, 15984, 15984, 15984, 15968,
-    15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968,
-    15968, 15968, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15999, 15998, 15996, 15996, 15992,
-    15992, 15992, 15992, 15984, 15984, 15984, 15984, 15984, 15984, 15984, 15984, 15968, 15968,
-    15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968,
-    15968, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15487, 15486, 15484, 15484, 15480, 15480,
-    15480, 15480, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15456, 15456, 15456,
-    15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15487, 15486, 15484, 15484, 15480, 15480, 15480,
-    15480, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15456, 15456, 15456, 15456,
-    15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15487, 15486, 15484, 15484, 15480, 15480, 15480, 15480,
-    15472, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15456, 15456, 15456, 15456, 15456,
-    15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15487, 15486, 15484, 15484, 15480, 15480, 15480, 15480, 15472,
-    15472, 15472, 15472, 15472, 15472, 15472, 15472, 15456, 15456, 15456, 15456, 15456, 15456,
-    15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4080, 4080, 4080, 4080,
-    4080, 4080, 4080, 4080, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064,
-    4064, 4064, 4064, 4064, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 3967, 3966, 3964, 3964, 3960, 3960, 3960, 3960, 3952, 3952, 3952, 3952,
-    3952, 3952, 3952, 3952, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936,
-    3936, 3936, 3936, 3936, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904,
-    3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904,
-    3904, 3904, 3904, 3904, 3711, 3710, 3708, 3708, 3704, 3704, 3704, 3704, 3696, 3696, 3696, 3696,
-    3696, 3696, 3696, 3696, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680,
-    3680, 3680, 3680, 3680, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3711, 3710, 3708, 3708, 3704, 3704, 3704, 3704, 3696, 3696, 3696, 3696,
-    3696, 3696, 3696, 3696, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680,
-    3680, 3680, 3680, 3680, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3199, 3198, 3196, 3196, 3192, 3192, 3192, 3192, 3184, 3184, 3184, 3184,
-    3184, 3184, 3184, 3184, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168,
-    3168, 3168, 3168, 3168, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 3199, 3198, 3196, 3196, 3192, 3192, 3192, 3192, 3184, 3184, 3184, 3184,
-    3184, 3184, 3184, 3184, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168,
-    3168, 3168, 3168, 3168, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 3199, 3198, 3196, 3196, 3192, 3192, 3192, 3192, 3184, 3184, 3184, 3184,
-    3184, 3184, 3184, 3184, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168,
-    3168, 3168, 3168, 3168, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 3199, 3198, 3196, 3196, 3192, 3192, 3192, 3192, 3184, 3184, 3184, 3184,
-    3184, 3184, 3184, 3184, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168,
-    3168, 3168, 3168, 3168, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 8176, 8176, 8176, 8176,
-    8176, 8176, 8176, 8176, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160,
-    8160, 8160, 8160, 8160, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128,
-    8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128,
-    8128, 8128, 8128, 8128, 8063, 8062, 8060, 8060, 8056, 8056, 8056, 8056, 8048, 8048, 8048, 8048,
-    8048, 8048, 8048, 8048, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032,
-    8032, 8032, 8032, 8032, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000,
-    8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000,
-    8000, 8000, 8000, 8000, 7807, 7806, 7804, 7804, 7800, 7800, 7800, 7800, 7792, 7792, 7792, 7792,
-    7792, 7792, 7792, 7792, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776,
-    7776, 7776, 7776, 7776, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744,
-    7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744,
-    7744, 7744, 7744, 7744, 7807, 7806, 7804, 7804, 7800, 7800, 7800, 7800, 7792, 7792, 7792, 7792,
-    7792, 7792, 7792, 7792, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776,
-    7776, 7776, 7776, 7776, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744,
-    7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744,
-    7744, 7744, 7744, 7744, 7295, 7294, 7292, 7292, 7288, 7288, 7288, 7288, 7280, 7280, 7280, 7280,
-    7280, 7280, 7280, 7280, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264,
-    7264, 7264, 7264, 7264, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    7232, 7232, 7232, 7232, 7295, 7294, 7292, 7292, 7288, 7288, 7288, 7288, 7280, 7280, 7280, 7280,
-    7280, 7280, 7280, 7280, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264,
-    7264, 7264, 7264, 7264, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    7232, 7232, 7232, 7232, 7295, 7294, 7292, 7292, 7288, 7288, 7288, 7288, 7280, 7280, 7280, 7280,
-    7280, 7280, 7280, 7280, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264,
-    7264, 7264, 7264, 7264, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    7232, 7232, 7232, 7232, 7295, 7294, 7292, 7292, 7288, 7288, 7288, 7288, 7280, 7280, 7280, 7280,
-    7280, 7280, 7280, 7280, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264,
-    7264, 7264, 7264, 7264, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    7232, 7232, 7232, 7232, 4095, 4094, 4092, 4092, 4088, 4088, 4088, 4088, 4080, 4080, 4080, 4080,
-    4080, 4080, 4080, 4080, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064, 4064,
-    4064, 4064, 4064, 4064, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032, 4032,
-    4032, 4032, 4032, 4032, 3967, 3966, 3964, 3964, 3960, 3960, 3960, 3960, 3952, 3952, 3952, 3952,
-    3952, 3952, 3952, 3952, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936, 3936,
-    3936, 3936, 3936, 3936, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904,
-    3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904, 3904,
-    3904, 3904, 3904, 3904, 3711, 3710, 3708, 3708, 3704, 3704, 3704, 3704, 3696, 3696, 3696, 3696,
-    3696, 3696, 3696, 3696, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680,
-    3680, 3680, 3680, 3680, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3711, 3710, 3708, 3708, 3704, 3704, 3704, 3704, 3696, 3696, 3696, 3696,
-    3696, 3696, 3696, 3696, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680, 3680,
-    3680, 3680, 3680, 3680, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648, 3648,
-    3648, 3648, 3648, 3648, 3199, 3198, 3196, 3196, 3192, 3192, 3192, 3192, 3184, 3184, 3184, 3184,
-    3184, 3184, 3184, 3184, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168,
-    3168, 3168, 3168, 3168, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 3199, 3198, 3196, 3196, 3192, 3192, 3192, 3192, 3184, 3184, 3184, 3184,
-    3184, 3184, 3184, 3184, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168,
-    3168, 3168, 3168, 3168, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 3199, 3198, 3196, 3196, 3192, 3192, 3192, 3192, 3184, 3184, 3184, 3184,
-    3184, 3184, 3184, 3184, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168,
-    3168, 3168, 3168, 3168, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 3199, 3198, 3196, 3196, 3192, 3192, 3192, 3192, 3184, 3184, 3184, 3184,
-    3184, 3184, 3184, 3184, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168, 3168,
-    3168, 3168, 3168, 3168, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136, 3136,
-    3136, 3136, 3136, 3136, 16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368,
-    16368, 16368, 16368, 16368, 16368, 16368, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16255, 16254, 16252, 16252, 16248, 16248, 16248, 16248, 16240, 16240, 16240,
-    16240, 16240, 16240, 16240, 16240, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224,
-    16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16192, 16192, 16192, 16192, 16192,
-    16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192,
-    16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192,
-    16192, 15999, 15998, 15996, 15996, 15992, 15992, 15992, 15992, 15984, 15984, 15984, 15984,
-    15984, 15984, 15984, 15984, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968,
-    15968, 15968, 15968, 15968, 15968, 15968, 15968, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15999, 15998, 15996, 15996, 15992, 15992, 15992, 15992, 15984, 15984, 15984, 15984, 15984,
-    15984, 15984, 15984, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968,
-    15968, 15968, 15968, 15968, 15968, 15968, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15487,
-    15486, 15484, 15484, 15480, 15480, 15480, 15480, 15472, 15472, 15472, 15472, 15472, 15472,
-    15472, 15472, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456,
-    15456, 15456, 15456, 15456, 15456, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15487, 15486,
-    15484, 15484, 15480, 15480, 15480, 15480, 15472, 15472, 15472, 15472, 15472, 15472, 15472,
-    15472, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456,
-    15456, 15456, 15456, 15456, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15487, 15486, 15484,
-    15484, 15480, 15480, 15480, 15480, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15472,
-    15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456,
-    15456, 15456, 15456, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15487, 15486, 15484, 15484,
-    15480, 15480, 15480, 15480, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15456,
-    15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456,
-    15456, 15456, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 14463, 14462, 14460, 14460, 14456,
-    14456, 14456, 14456, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14463, 14462, 14460, 14460, 14456, 14456,
-    14456, 14456, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14463, 14462, 14460, 14460, 14456, 14456, 14456,
-    14456, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456,
-    14448, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14448,
-    14448, 14448, 14448, 14448, 14448, 14448, 14448, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14448, 14448,
-    14448, 14448, 14448, 14448, 14448, 14448, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14448, 14448, 14448,
-    14448, 14448, 14448, 14448, 14448, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14448, 14448, 14448, 14448,
-    14448, 14448, 14448, 14448, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    8191, 8190, 8188, 8188, 8184, 8184, 8184, 8184, 8176, 8176, 8176, 8176, 8176, 8176, 8176, 8176,
-    8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160, 8160,
-    8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128,
-    8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128, 8128,
-    8063, 8062, 8060, 8060, 8056, 8056, 8056, 8056, 8048, 8048, 8048, 8048, 8048, 8048, 8048, 8048,
-    8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032, 8032,
-    8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000,
-    8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000, 8000,
-    7807, 7806, 7804, 7804, 7800, 7800, 7800, 7800, 7792, 7792, 7792, 7792, 7792, 7792, 7792, 7792,
-    7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776,
-    7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744,
-    7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744,
-    7807, 7806, 7804, 7804, 7800, 7800, 7800, 7800, 7792, 7792, 7792, 7792, 7792, 7792, 7792, 7792,
-    7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776, 7776,
-    7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744,
-    7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744, 7744,
-    7295, 7294, 7292, 7292, 7288, 7288, 7288, 7288, 7280, 7280, 7280, 7280, 7280, 7280, 7280, 7280,
-    7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264,
-    7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    7295, 7294, 7292, 7292, 7288, 7288, 7288, 7288, 7280, 7280, 7280, 7280, 7280, 7280, 7280, 7280,
-    7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264,
-    7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    7295, 7294, 7292, 7292, 7288, 7288, 7288, 7288, 7280, 7280, 7280, 7280, 7280, 7280, 7280, 7280,
-    7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264,
-    7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    7295, 7294, 7292, 7292, 7288, 7288, 7288, 7288, 7280, 7280, 7280, 7280, 7280, 7280, 7280, 7280,
-    7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264, 7264,
-    7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232, 7232,
-    6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6256, 6256, 6256, 6256, 6256, 6256, 6256, 6256,
-    6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6256, 6256, 6256, 6256, 6256, 6256, 6256, 6256,
-    6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6256, 6256, 6256, 6256, 6256, 6256, 6256, 6256,
-    6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6256, 6256, 6256, 6256, 6256, 6256, 6256, 6256,
-    6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6256, 6256, 6256, 6256, 6256, 6256, 6256, 6256,
-    6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6256, 6256, 6256, 6256, 6256, 6256, 6256, 6256,
-    6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6256, 6256, 6256, 6256, 6256, 6256, 6256, 6256,
-    6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    6271, 6270, 6268, 6268, 6264, 6264, 6264, 6264, 6256, 6256, 6256, 6256, 6256, 6256, 6256, 6256,
-    6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240, 6240,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208, 6208,
-    16383, 16382, 16380, 16380, 16376, 16376, 16376, 16376, 16368, 16368, 16368, 16368, 16368,
-    16368, 16368, 16368, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16352, 16352, 16352, 16352, 16352, 16352, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16255,
-    16254, 16252, 16252, 16248, 16248, 16248, 16248, 16240, 16240, 16240, 16240, 16240, 16240,
-    16240, 16240, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224,
-    16224, 16224, 16224, 16224, 16224, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192,
-    16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192,
-    16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 15999, 15998,
-    15996, 15996, 15992, 15992, 15992, 15992, 15984, 15984, 15984, 15984, 15984, 15984, 15984,
-    15984, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968,
-    15968, 15968, 15968, 15968, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15999, 15998, 15996,
-    15996, 15992, 15992, 15992, 15992, 15984, 15984, 15984, 15984, 15984, 15984, 15984, 15984,
-    15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968,
-    15968, 15968, 15968, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15487, 15486, 15484, 15484,
-    15480, 15480, 15480, 15480, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15456,
-    15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456,
-    15456, 15456, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15487, 15486, 15484, 15484, 15480,
-    15480, 15480, 15480, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15456, 15456,
-    15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456,
-    15456, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15487, 15486, 15484, 15484, 15480, 15480,
-    15480, 15480, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15456, 15456, 15456,
-    15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15487, 15486, 15484, 15484, 15480, 15480, 15480,
-    15480, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15472, 15456, 15456, 15456, 15456,
-    15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456,
-    14448, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14448,
-    14448, 14448, 14448, 14448, 14448, 14448, 14448, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14448, 14448,
-    14448, 14448, 14448, 14448, 14448, 14448, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14448, 14448, 14448,
-    14448, 14448, 14448, 14448, 14448, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14448, 14448, 14448, 14448,
-    14448, 14448, 14448, 14448, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456, 14448, 14448, 14448, 14448, 14448,
-    14448, 14448, 14448, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14463,
-    14462, 14460, 14460, 14456, 14456, 14456, 14456, 14448, 14448, 14448, 14448, 14448, 14448,
-    14448, 14448, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14463, 14462,
-    14460, 14460, 14456, 14456, 14456, 14456, 14448, 14448, 14448, 14448, 14448, 14448, 14448,
-    14448, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 12415, 12414, 12412,
-    12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12415, 12414, 12412, 12412,
-    12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12415, 12414, 12412, 12412, 12408,
-    12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12415, 12414, 12412, 12412, 12408, 12408,
-    12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12415, 12414, 12412, 12412, 12408, 12408, 12408,
-    12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408,
-    12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400,
-    12400, 12400, 12400, 12400, 12400, 12400, 12400, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400,
-    12400, 12400, 12400, 12400, 12400, 12400, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400,
-    12400, 12400, 12400, 12400, 12400, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400,
-    12400, 12400, 12400, 12400, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400,
-    12400, 12400, 12400, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12415,
-    12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400,
-    12400, 12400, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12415, 12414,
-    12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400,
-    12400, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12415, 12414, 12412,
-    12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12415, 12414, 12412, 12412,
-    12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12415, 12414, 12412, 12412, 12408,
-    12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 16383, 16382, 16380, 16380, 16376, 16376,
-    16376, 16376, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16368, 16352, 16352, 16352,
-    16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352, 16352,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320, 16320,
-    16320, 16320, 16320, 16320, 16320, 16320, 16255, 16254, 16252, 16252, 16248, 16248, 16248,
-    16248, 16240, 16240, 16240, 16240, 16240, 16240, 16240, 16240, 16224, 16224, 16224, 16224,
-    16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16224, 16192,
-    16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192,
-    16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192, 16192,
-    16192, 16192, 16192, 16192, 16192, 15999, 15998, 15996, 15996, 15992, 15992, 15992, 15992,
-    15984, 15984, 15984, 15984, 15984, 15984, 15984, 15984, 15968, 15968, 15968, 15968, 15968,
-    15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15999, 15998, 15996, 15996, 15992, 15992, 15992, 15992, 15984,
-    15984, 15984, 15984, 15984, 15984, 15984, 15984, 15968, 15968, 15968, 15968, 15968, 15968,
-    15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15968, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936, 15936,
-    15936, 15936, 15936, 15487, 15486, 15484, 15484, 15480, 15480, 15480, 15480, 15472, 15472,
-    15472, 15472, 15472, 15472, 15472, 15472, 15456, 15456, 15456, 15456, 15456, 15456, 15456,
-    15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15487, 15486, 15484, 15484, 15480, 15480, 15480, 15480, 15472, 15472, 15472,
-    15472, 15472, 15472, 15472, 15472, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456,
-    15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15487, 15486, 15484, 15484, 15480, 15480, 15480, 15480, 15472, 15472, 15472, 15472,
-    15472, 15472, 15472, 15472, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456,
-    15456, 15456, 15456, 15456, 15456, 15456, 15456, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15487, 15486, 15484, 15484, 15480, 15480, 15480, 15480, 15472, 15472, 15472, 15472, 15472,
-    15472, 15472, 15472, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456, 15456,
-    15456, 15456, 15456, 15456, 15456, 15456, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424,
-    15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 15424, 14463,
-    14462, 14460, 14460, 14456, 14456, 14456, 14456, 14448, 14448, 14448, 14448, 14448, 14448,
-    14448, 14448, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14463, 14462,
-    14460, 14460, 14456, 14456, 14456, 14456, 14448, 14448, 14448, 14448, 14448, 14448, 14448,
-    14448, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14463, 14462, 14460,
-    14460, 14456, 14456, 14456, 14456, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14448,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14463, 14462, 14460, 14460,
-    14456, 14456, 14456, 14456, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14463, 14462, 14460, 14460, 14456,
-    14456, 14456, 14456, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14432, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14463, 14462, 14460, 14460, 14456, 14456,
-    14456, 14456, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14463, 14462, 14460, 14460, 14456, 14456, 14456,
-    14456, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14463, 14462, 14460, 14460, 14456, 14456, 14456, 14456,
-    14448, 14448, 14448, 14448, 14448, 14448, 14448, 14448, 14432, 14432, 14432, 14432, 14432,
-    14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14432, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400, 14400,
-    14400, 14400, 14400, 14400, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400,
-    12400, 12400, 12400, 12400, 12400, 12400, 12400, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400,
-    12400, 12400, 12400, 12400, 12400, 12400, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400,
-    12400, 12400, 12400, 12400, 12400, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400,
-    12400, 12400, 12400, 12400, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400,
-    12400, 12400, 12400, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12415,
-    12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400,
-    12400, 12400, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12415, 12414,
-    12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400,
-    12400, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12415, 12414, 12412,
-    12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12415, 12414, 12412, 12412,
-    12408, 12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12415, 12414, 12412, 12412, 12408,
-    12408, 12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12415, 12414, 12412, 12412, 12408, 12408,
-    12408, 12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12415, 12414, 12412, 12412, 12408, 12408, 12408,
-    12408, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408,
-    12400, 12400, 12400, 12400, 12400, 12400, 12400, 12400, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400,
-    12400, 12400, 12400, 12400, 12400, 12400, 12400, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400,
-    12400, 12400, 12400, 12400, 12400, 12400, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12415, 12414, 12412, 12412, 12408, 12408, 12408, 12408, 12400, 12400, 12400,
-    12400, 12400, 12400, 12400, 12400, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384,
-    12384, 12384, 12384, 12384, 12384, 12384, 12384, 12384, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352, 12352,
-    12352, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8319, 8318, 8316, 8316, 8312, 8312, 8312, 8312, 8304, 8304, 8304, 8304, 8304, 8304,
-    8304, 8304, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288, 8288,
-    8288, 8288, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256, 8256,
-    8256, 8256,
-];
+// Generated by build.rs
+pub static SHARED_ATTACK_INDICES: [u16; SHARED_TABLE_SIZE] =
+    include!(concat!(env!("OUT_DIR"), "/pext_attacks.rs"));
@@ -1,18 +1,18 @@
 // PEXT/PDEP bitboards https://www.chessprogramming.org/BMI2#PEXT.2FPDEP_Bitboards
+// Functions are compiled with BMI2 enabled so callers must check the CPU supports it
 
 use crate::bb::*;
 use crate::square::Square;
 
 mod consts;
 
-#[cfg(test)]
-mod generation;
-
 use self::consts::*;
-use std::arch::x86_64::{_pdep_u64, _pext_u64};
+use core::arch::x86_64::{_pdep_u64, _pext_u64};
 
+#[inline]
+#[target_feature(enable = "bmi2")]
 pub fn bishop_attacks_from_sq(from: Square, occupied: BB) -> BB {
-    return unsafe {
+    unsafe {
         let offset = BISHOP_OFFSETS.get_unchecked(from.to_usize());
         let outer_mask = from.bishop_rays();
 
@@ -22,11 +22,13 @@ pub fn bishop_attacks_from_sq(from: Square, occupied: BB) -> BB {
         let attack_indexes = *SHARED_ATTACK_INDICES.get_unchecked(idx);
 
         BB(_pdep_u64(attack_indexes as u64, outer_mask.0))
-    };
+    }
 }
 
+#[inline]
+#[target_feature(enable = "bmi2")]
 pub fn rook_attacks_from_sq(from: Square, occupied: BB) -> BB {
-    return unsafe {
+    unsafe {
         let offset = ROOK_OFFSETS.get_unchecked(from.to_usize());
         let outer_mask = from.rook_rays();
 
@@ -36,10 +38,11 @@ pub fn rook_attacks_from_sq(from: Square, occupied: BB) -> BB {
         let attack_indexes = *SHARED_ATTACK_INDICES.get_unchecked(idx);
 
         BB(_pdep_u64(attack_indexes as u64, outer_mask.0))
-    };
+    }
 }
 
 #[allow(dead_code)]
+#[target_feature(enable = "bmi2")]
 pub fn rook_attacks(from: BB, occupied: BB) -> BB {
     let mut attacks = EMPTY;
     for (sq, _) in from.iter() {
@@ -49,10 +52,11 @@ pub fn rook_attacks(from: BB, occupied: BB) -> BB {
 }
 
 #[allow(dead_code)]
+#[target_feature(enable = "bmi2")]
 pub fn bishop_attacks(from: BB, occupied: BB) -> BB {
     let mut attacks = EMPTY;
     for (sq, _) in from.iter() {
-        attacks |= rook_attacks_from_sq(sq, occupied);
+        attacks |= bishop_attacks_from_sq(sq, occupied);
     }
     attacks
 }
@@ -61,35 +65,22 @@ pub fn bishop_attacks(from: BB, occupied: BB) -> BB {
 mod test {
     use super::super::testing::*;
     use super::*;
-    use ::test;
 
     #[test]
     fn t_rook_attacks() {
-        test_rook_attacks_from_sq(rook_attacks_from_sq);
+        if !is_x86_feature_detected!("bmi2") {
+            return;
+        }
+        test_rook_attacks_from_sq(|from, occupied| unsafe { rook_attacks_from_sq(from, occupied) });
     }
 
     #[test]
     fn t_bishop_attacks() {
-        test_bishop_attacks_from_sq(bishop_attacks_from_sq);
-    }
-
-    #[bench]
-    fn bench_multiple_rook_attacks(b: &mut test::Bencher) {
-        bench_attacks_from_bb(b, rook_attacks);
-    }
-
-    #[bench]
-    fn bench_multiple_bishop_attacks(b: &mut test::Bencher) {
-        bench_attacks_from_bb(b, bishop_attacks);
-    }
-
-    #[bench]
-    fn bench_rook_attacks_from_sq(b: &mut test::Bencher) {
-        bench_attacks_from_sq(b, rook_attacks_from_sq);
-    }
-
-    #[bench]
-    fn bench_bishop_attacks_from_sq(b: &mut test::Bencher) {
-        bench_attacks_from_sq(b, bishop_attacks_from_sq);
+        if !is_x86_feature_detected!("bmi2") {
+            return;
+        }
+        test_bishop_attacks_from_sq(|from, occupied| unsafe {
+            bishop_attacks_from_sq(from, occupied)
+        });
     }
 }
@@ -6,6 +6,8 @@ use crate::square::Square;
 
 #[cfg(target_feature = "sse3")]
 use crate::dbb::*;
+#[cfg(target_feature = "avx2")]
+use crate::wbb::*;
 
 /// calculates the bitboard of pinned pieces
 #[allow(dead_code)]
@@ -14,6 +16,31 @@ pub fn pinned_pieces(king: BB, empty: BB, enemy_diag: BB, enemy_non_diag: BB) ->
         | non_diag_pinned_pieces(king, empty, enemy_non_diag)
 }
 
+/// calculates squares attacked by diagonal and non-diagonal sliders together
+#[cfg(not(target_feature = "avx2"))]
+pub fn slider_attacks(diag: BB, non_diag: BB, occupied: BB) -> BB {
+    bishop_attacks(diag, occupied) | rook_attacks(non_diag, occupied)
+}
+
+#[cfg(all(target_feature = "avx2", not(target_feature = "avx512f")))]
+pub fn slider_attacks(diag: BB, non_diag: BB, occupied: BB) -> BB {
+    let empty = QBB::splat(!occupied);
+
+    (QBB::splat(non_diag).attacks(empty, &ORTHOGONAL) | QBB::splat(diag).attacks(empty, &DIAGONAL))
+        .reduce_or()
+}
+
+#[cfg(target_feature = "avx512f")]
+pub fn slider_attacks(diag: BB, non_diag: BB, occupied: BB) -> BB {
+    let sliders = OBB::new([
+        non_diag, diag, non_diag, diag, non_diag, diag, non_diag, diag,
+    ]);
+
+    sliders
+        .attacks(OBB::splat(!occupied), &ALL_DIRECTIONS)
+        .reduce_or()
+}
+
 #[cfg(not(target_feature = "sse3"))]
 fn diag_pinned_pieces(king: BB, empty: BB, enemy_bishops: BB) -> BB {
     let north_west = king.north_west_attacks(empty) & enemy_bishops.south_east_attacks(empty);
@@ -232,7 +259,6 @@ pub fn bishop_attacks(from: BB, occupied: BB) -> BB {
 mod test {
     use super::super::testing::*;
     use super::*;
-    use ::test;
 
     #[test]
     fn t_bishop_attacks() {
@@ -244,23 +270,35 @@ mod test {
         test_rook_attacks_from_bb(rook_attacks);
     }
 
-    #[bench]
-    fn bench_multiple_rook_attacks(b: &mut test::Bencher) {
-        bench_attacks_from_bb(b, rook_attacks);
-    }
-
-    #[bench]
-    fn bench_multiple_bishop_attacks(b: &mut test::Bencher) {
-        bench_attacks_from_bb(b, bishop_attacks);
-    }
-
-    #[bench]
-    fn bench_rook_attacks_from_sq(b: &mut test::Bencher) {
-        bench_attacks_from_sq(b, rook_attacks_from_sq);
+    #[test]
+    fn t_slider_attacks() {
+        for _ in 0..1000 {
+            let (diag, non_diag) = (BB::random(0.05), BB::random(0.05));
+            let occupied = BB::random(0.3) | diag | non_diag;
+            assert_eq!(
+                slider_attacks(diag, non_diag, occupied),
+                bishop_attacks(diag, occupied) | rook_attacks(non_diag, occupied)
+            );
+        }
     }
 
-    #[bench]
-    fn bench_bishop_attacks_from_sq(b: &mut test::Bencher) {
-        bench_attacks_from_sq(b, bishop_attacks_from_sq);
+    #[test]
+    fn t_pinned_pieces() {
+        for _ in 0..1000 {
+            let king = BB::new(Square::random());
+            let (diag, non_diag) = (BB::random(0.05) & !king, BB::random(0.05) & !king);
+            let empty = !(BB::random(0.2) | king | diag | non_diag);
+
+            // scalar fills in every direction
+            let expected = (king.north_attacks(empty) & non_diag.south_attacks(empty))
+                | (king.south_attacks(empty) & non_diag.north_attacks(empty))
+                | (king.east_attacks(empty) & non_diag.west_attacks(empty))
+                | (king.west_attacks(empty) & non_diag.east_attacks(empty))
+                | (king.north_east_attacks(empty) & diag.south_west_attacks(empty))
+                | (king.north_west_attacks(empty) & diag.south_east_attacks(empty))
+                | (king.south_east_attacks(empty) & diag.north_west_attacks(empty))
+                | (king.south_west_attacks(empty) & diag.north_east_attacks(empty));
+            assert_eq!(pinned_pieces(king, empty, diag, non_diag), expected);
+        }
     }
 }
@@ -0,0 +1,137 @@
+// Shared with build.rs, which generates the attack table for these magic numbers
+
+// Fixed shift magic numbers and offsets from the original generated table. Offsets overlap the
+// tables of every square within MAGIC_TABLE_SIZE entries
+pub const BISHOP_MAGIC_NUMBERS: [(u64, u32); 64] = [
+    (0x404040404040, 33104),
+    (0xa060401007fc, 4094),
+    (0x401020200000, 24764),
+    (0x806004000000, 13882),
+    (0x440200000000, 23090),
+    (0x80100800000, 32640),
+    (0x104104004000, 11558),
+    (0x20020820080, 32912),
+    (0x40100202004, 13674),
+    (0x20080200802, 6109),
+    (0x10040080200, 26494),
+    (0x8060040000, 17919),
+    (0x4402000000, 25757),
+    (0x21c100b200, 17338),
+    (0x400410080, 16983),
+    (0x3f7f05fffc0, 16659),
+    (0x4228040808010, 13610),
+    (0x200040404040, 2224),
+    (0x400080808080, 60405),
+    (0x200200801000, 7983),
+    (0x240080840000, 17),
+    (0x18000c03fff8, 34321),
+    (0xa5840208020, 33216),
+    (0x58408404010, 17127),
+    (0x2022000408020, 6397),
+    (0x402000408080, 22169),
+    (0x804000810100, 42727),
+    (0x100403c0403ff, 155),
+    (0x78402a8802000, 8601),
+    (0x101000804400, 21101),
+    (0x80800104100, 29885),
+    (0x400480101008, 29340),
+    (0x1010102004040, 19785),
+    (0x808090402020, 12258),
+    (0x7fefe08810010, 50451),
+    (0x3ff0f833fc080, 1712),
+    (0x7fe08019003042, 78475),
+    (0x202040008040, 7855),
+    (0x1004008381008, 13642),
+    (0x802003700808, 8156),
+    (0x208200400080, 4348),
+    (0x104100200040, 28794),
+    (0x3ffdf7f833fc0, 22578),
+    (0x8840450020, 50315),
+    (0x20040100100, 85452),
+    (0x7fffdd80140028, 32816),
+    (0x202020200040, 13930),
+    (0x1004010039004, 17967),
+    (0x40041008000, 33200),
+    (0x3ffefe0c02200, 32456),
+    (0x1010806000, 7762),
+    (0x8403000, 7794),
+    (0x100202000, 22761),
+    (0x40100200800, 14918),
+    (0x404040404000, 11620),
+    (0x6020601803f4, 15925),
+    (0x3ffdfdfc28048, 32528),
+    (0x820820020, 12196),
+    (0x10108060, 32720),
+    (0x84030, 26781),
+    (0x1002020, 19817),
+    (0x40408020, 24732),
+    (0x4040404040, 25468),
+    (0x404040404040, 10186),
+];
+
+pub const ROOK_MAGIC_NUMBERS: [(u64, u32); 64] = [
+    (0x280077ffebfffe, 41305),
+    (0x2004010201097fff, 14326),
+    (0x10020010053fff, 24477),
+    (0x30002ff71ffffa, 8223),
+    (0x7fd00441ffffd003, 49795),
+    (0x4001d9e03ffff7, 60546),
+    (0x4000888847ffff, 28543),
+    (0x6800fbff75fffd, 79282),
+    (0x28010113ffff, 6457),
+    (0x20040201fcffff, 4125),
+    (0x7fe80042ffffe8, 81021),
+    (0x1800217fffe8, 42341),
+    (0x1800073fffe8, 14139),
+    (0x7fe8009effffe8, 19465),
+    (0x1800602fffe8, 9514),
+    (0x30002fffffa0, 71090),
+    (0x300018010bffff, 75419),
+    (0x3000c0085fffb, 33476),
+    (0x4000802010008, 27117),
+    (0x2002004002002, 85964),
+    (0x2002020010002, 54915),
+    (0x1002020008001, 36544),
+    (0x4040008001, 71854),
+    (0x802000200040, 37996),
+    (0x40200010080010, 30398),
+    (0x80010040010, 55939),
+    (0x4010008020008, 53891),
+    (0x40020200200, 56963),
+    (0x10020020020, 77451),
+    (0x10020200080, 12319),
+    (0x8020200040, 88500),
+    (0x200020004081, 51405),
+    (0xfffd1800300030, 72878),
+    (0x7fff7fbfd40020, 676),
+    (0x3fffbd00180018, 83122),
+    (0x1fffde80180018, 22206),
+    (0xfffe0bfe80018, 75186),
+    (0x1000080202001, 681),
+    (0x3fffbff980180, 36453),
+    (0x1fffdff9000e0, 20369),
+    (0xfffeebfeffd800, 1981),
+    (0x7ffff7ffc01400, 13343),
+    (0x408104200204, 10650),
+    (0x1ffff01fc03000, 57987),
+    (0xfffe7f8bfe800, 26302),
+    (0x8001002020, 58357),
+    (0x3fff85fffa804, 40546),
+    (0x1fffd75ffa802, 0),
+    (0xffffec00280028, 14967),
+    (0x7fff75ff7fbfd8, 80361),
+    (0x3fff863fbf7fd8, 40905),
+    (0x1fffbfdfd7ffd8, 58347),
+    (0xffff810280028, 20381),
+    (0x7ffd7f7feffd8, 81868),
+    (0x3fffc0c480048, 59381),
+    (0x1ffffafd7ffd8, 84404),
+    (0xffffe4ffdfa3ba, 45811),
+    (0x7fffef7ff3d3da, 62898),
+    (0x3fffbfdfeff7fa, 45796),
+    (0x1fffeff7fbfc22, 66994),
+    (0x20408001001, 67204),
+    (0x7fffeffff77fd, 32448),
+    (0x3ffffbf7dfeec, 62946),
+    (0x1ffff9dffa333, 17005),
+];
@@ -1,27 +1,21 @@
-// magic-lookup sliding piece attacks
-// https://chessprogramming.wikispaces.com/BMI2#PEXTBitboards
+// magic-lookup sliding piece attacks with fixed shifts and overlapping tables
+// https://www.chessprogramming.org/Magic_Bitboards#Fancy
 
 use crate::bb::*;
 use crate::square::Square;
 
 mod consts;
-
-#[derive(Copy, Clone)]
-pub struct Magic {
-    magic_number: u64,
-    mask: BB,
-    offset: u32,
-}
+mod magic_numbers;
 
 pub fn bishop_attacks_from_sq(from: Square, occupied: BB) -> BB {
     let magic = unsafe { *consts::BISHOP_MAGICS.get_unchecked(from.to_usize()) };
     let mult = (occupied & magic.mask)
         .to_u64()
         .wrapping_mul(magic.magic_number);
-    let index = (mult >> 55) as usize;
+    let index = (mult >> magic.shift) as usize;
     let offset = index + (magic.offset as usize);
 
-    unsafe { *consts::SHARED_ATTACKS.get_unchecked(offset) }
+    BB(unsafe { *consts::SHARED_ATTACKS.get_unchecked(offset) })
 }
 
 pub fn rook_attacks_from_sq(from: Square, occupied: BB) -> BB {
@@ -29,10 +23,10 @@ pub fn rook_attacks_from_sq(from: Square, occupied: BB) -> BB {
     let mult = (occupied & magic.mask)
         .to_u64()
         .wrapping_mul(magic.magic_number);
-    let index = (mult >> 52) as usize;
+    let index = (mult >> magic.shift) as usize;
     let offset = index + (magic.offset as usize);
 
-    unsafe { *consts::SHARED_ATTACKS.get_unchecked(offset) }
+    BB(unsafe { *consts::SHARED_ATTACKS.get_unchecked(offset) })
 }
 
 #[allow(dead_code)]
@@ -48,7 +42,7 @@ pub fn rook_attacks(from: BB, occupied: BB) -> BB {
 pub fn bishop_attacks(from: BB, occupied: BB) -> BB {
     let mut attacks = EMPTY;
     for (sq, _) in from.iter() {
-        attacks |= rook_attacks_from_sq(sq, occupied);
+        attacks |= bishop_attacks_from_sq(sq, occupied);
     }
     attacks
 }
@@ -57,7 +51,6 @@ pub fn bishop_attacks(from: BB, occupied: BB) -> BB {
 mod test {
     use super::super::testing::*;
     use super::*;
-    use ::test;
 
     #[test]
     fn t_rook_attacks() {
@@ -69,23 +62,25 @@ mod test {
         test_bishop_attacks_from_sq(bishop_attacks_from_sq);
     }
 
-    #[bench]
-    fn bench_multiple_rook_attacks(b: &mut test::Bencher) {
-        bench_attacks_from_bb(b, rook_attacks);
-    }
-
-    #[bench]
-    fn bench_multiple_bishop_attacks(b: &mut test::Bencher) {
-        bench_attacks_from_bb(b, bishop_attacks);
-    }
-
-    #[bench]
-    fn bench_rook_attacks_from_sq(b: &mut test::Bencher) {
-        bench_attacks_from_sq(b, rook_attacks_from_sq);
-    }
-
-    #[bench]
-    fn bench_bishop_attacks_from_sq(b: &mut test::Bencher) {
-        bench_attacks_from_sq(b, bishop_attacks_from_sq);
+    #[test]
+    fn t_table_size() {
+        let mut highest = 0;
+        for magics in [&consts::BISHOP_MAGICS, &consts::ROOK_MAGICS] {
+            for magic in magics.iter() {
+                let mask = magic.mask.to_u64();
+                let mut occupied = 0u64;
+                loop {
+                    let index = (occupied.wrapping_mul(magic.magic_number) >> magic.shift) as usize;
+                    highest = highest.max(magic.offset as usize + index);
+                    occupied = occupied.wrapping_sub(mask) & mask;
+                    if occupied == 0 {
+                        break;
+                    }
+                }
+            }
+        }
+
+        assert!(highest < consts::SHARED_ATTACKS.len());
+        assert!(consts::SHARED_ATTACKS.len() <= 89524);
     }
 }
@@ -26,6 +26,24 @@ pub fn bishop_attacks_from_sq(from: Square, occupied: BB) -> BB {
     diag_attacks | antidiag_attacks
 }
 
+#[allow(dead_code)]
+pub fn rook_attacks(from: BB, occupied: BB) -> BB {
+    let mut attacks = EMPTY;
+    for (sq, _) in from.iter() {
+        attacks |= rook_attacks_from_sq(sq, occupied);
+    }
+    attacks
+}
+
+#[allow(dead_code)]
+pub fn bishop_attacks(from: BB, occupied: BB) -> BB {
+    let mut attacks = EMPTY;
+    for (sq, _) in from.iter() {
+        attacks |= bishop_attacks_from_sq(sq, occupied);
+    }
+    attacks
+}
+
 fn line_attacks(occupied: BB, mask: LineMask) -> BB {
     let lower = mask.lower & occupied;
     let upper = mask.upper & occupied;
@@ -40,7 +58,6 @@ fn line_attacks(occupied: BB, mask: LineMask) -> BB {
 mod test {
     use super::super::testing::*;
     use super::*;
-    use ::test;
 
     #[test]
     fn t_rook_attacks() {
@@ -51,16 +68,6 @@ mod test {
     fn t_bishop_attacks() {
         test_bishop_attacks_from_sq(bishop_attacks_from_sq);
     }
-
-    #[bench]
-    fn bench_rook_attacks_from_sq(b: &mut test::Bencher) {
-        bench_attacks_from_sq(b, rook_attacks_from_sq);
-    }
-
-    #[bench]
-    fn bench_bishop_attacks_from_sq(b: &mut test::Bencher) {
-        bench_attacks_from_sq(b, bishop_attacks_from_sq);
-    }
 }
 
 pub const BISHOP_LINE_MASKS: [[LineMask; 2]; 64] = [
@@ -0,0 +1,288 @@
+// Sliding piece lookup tables and a magic number finder. Small tables are built by const
+// functions, the ~100k entry magic, PEXT and black magic attack tables are generated by build.rs
+// as const evaluation of them takes about a minute
+// https://www.chessprogramming.org/Magic_Bitboards#Fancy
+
+use crate::bb::BB;
+#[cfg(any(feature = "std", test))]
+use crate::square::Square;
+#[cfg(any(feature = "std", test))]
+use alloc::vec;
+#[cfg(any(feature = "std", test))]
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", test))]
+use rand::Rng;
+
+pub use super::geometry::*;
+
+/// Squares strictly between two squares on the same line, otherwise empty
+pub const fn squares_between_table() -> [[BB; 64]; 64] {
+    let mut table = [[BB(0); 64]; 64];
+    let mut a = 0;
+    while a < 64 {
+        let mut b = 0;
+        while b < 64 {
+            let mut diagonal = 0;
+            while diagonal < 2 {
+                let diagonal_bool = diagonal == 1;
+                if slider_attacks(a, 0, diagonal_bool) & (1u64 << b) != 0 {
+                    table[a][b] = BB(slider_attacks(a, 1u64 << b, diagonal_bool)
+                        & slider_attacks(b, 1u64 << a, diagonal_bool));
+                }
+                diagonal += 1;
+            }
+            b += 1;
+        }
+        a += 1;
+    }
+    table
+}
+
+/// Full line (edge to edge) through two squares on the same line, otherwise empty
+pub const fn lines_along_table() -> [[BB; 64]; 64] {
+    let mut table = [[BB(0); 64]; 64];
+    let mut a = 0;
+    while a < 64 {
+        let mut b = 0;
+        while b < 64 {
+            let mut diagonal = 0;
+            while diagonal < 2 {
+                let diagonal_bool = diagonal == 1;
+                if slider_attacks(a, 0, diagonal_bool) & (1u64 << b) != 0 {
+                    table[a][b] = BB((slider_attacks(a, 0, diagonal_bool)
+                        & slider_attacks(b, 0, diagonal_bool))
+                        | (1u64 << a)
+                        | (1u64 << b));
+                }
+                diagonal += 1;
+            }
+            b += 1;
+        }
+        a += 1;
+    }
+    table
+}
+
+/// Magic for one square: attacks are at
+/// table[offset + ((occupied & mask) * magic_number) >> shift]. Tables of different squares
+/// overlap where they agree
+#[derive(Copy, Clone)]
+pub struct Magic {
+    pub magic_number: u64,
+    pub mask: BB,
+    pub shift: u32,
+    pub offset: u32,
+}
+
+/// Magics for each square from (magic number, offset) pairs
+pub const fn magics(magic_numbers: &[(u64, u32); 64], diagonal: bool) -> [Magic; 64] {
+    let mut magics = [Magic {
+        magic_number: 0,
+        mask: BB(0),
+        shift: 0,
+        offset: 0,
+    }; 64];
+
+    let bits = if diagonal {
+        MAGIC_BISHOP_BITS
+    } else {
+        MAGIC_ROOK_BITS
+    };
+    let mut sq = 0;
+    while sq < 64 {
+        let (magic_number, offset) = magic_numbers[sq];
+        magics[sq] = Magic {
+            magic_number,
+            mask: BB(relevant_mask(sq, diagonal)),
+            shift: 64 - bits,
+            offset,
+        };
+        sq += 1;
+    }
+    magics
+}
+
+/// PEXT lookup for one square: attacks are pdep(table[base_offset + pext(occupied, inner_mask)],
+/// rays)
+#[derive(Copy, Clone)]
+pub struct Offset {
+    pub inner_mask: BB,
+    pub base_offset: u32,
+}
+
+/// Offsets for each square into a shared table starting at base_offset
+pub const fn pext_offsets(diagonal: bool, base_offset: usize) -> [Offset; 64] {
+    let mut offsets = [Offset {
+        inner_mask: BB(0),
+        base_offset: 0,
+    }; 64];
+
+    let mut offset = base_offset;
+    let mut sq = 0;
+    while sq < 64 {
+        let mask = relevant_mask(sq, diagonal);
+        offsets[sq] = Offset {
+            inner_mask: BB(mask),
+            base_offset: offset as u32,
+        };
+        offset += 1 << mask.count_ones();
+        sq += 1;
+    }
+    offsets
+}
+
+/// Searches for a magic number mapping every relevant occupancy of a square to a bits wide index
+/// without destructive collisions. bits must be at least the number of relevant squares
+#[cfg(any(feature = "std", test))]
+#[allow(dead_code)]
+pub fn find_magic<R: Rng>(sq: Square, diagonal: bool, bits: u32, rng: &mut R) -> u64 {
+    let sq = sq.to_usize();
+    let mask = relevant_mask(sq, diagonal);
+    assert!(bits >= mask.count_ones());
+
+    let cases = (0..1u64 << mask.count_ones())
+        .map(|idx| {
+            let occupied = pdep(idx, mask);
+            (occupied, slider_attacks(sq, occupied, diagonal))
+        })
+        .collect::<Vec<(u64, u64)>>();
+
+    let mut used = vec![0u64; 1 << bits];
+
+    loop {
+        // magics with few bits set are more likely to work
+        let magic_number = rng.next_u64() & rng.next_u64() & rng.next_u64();
+        if (mask.wrapping_mul(magic_number) >> 56).count_ones() < 6 {
+            continue;
+        }
+
+        used.iter_mut().for_each(|attacks| *attacks = 0);
+
+        let valid = cases.iter().all(|&(occupied, attacks)| {
+            let idx = (occupied.wrapping_mul(magic_number) >> (64 - bits)) as usize;
+            if used[idx] == 0 {
+                used[idx] = attacks;
+            }
+            used[idx] == attacks
+        });
+
+        if valid {
+            return magic_number;
+        }
+    }
+}
+
+/// Black magic for one square: attacks are at
+/// table[offset + ((occupied | !mask) * magic_number) >> (64 - bits)] where bits is fixed for
+/// each piece type. Tables of different squares overlap where they agree
+/// https://www.chessprogramming.org/Magic_Bitboards#Black_Magic_Bitboards
+#[derive(Copy, Clone)]
+pub struct BlackMagic {
+    pub magic_number: u64,
+    pub not_mask: BB,
+    pub offset: u32,
+}
+
+/// Black magics for each square from (magic number, offset) pairs
+pub const fn black_magics(magic_numbers: &[(u64, u32); 64], diagonal: bool) -> [BlackMagic; 64] {
+    let mut magics = [BlackMagic {
+        magic_number: 0,
+        not_mask: BB(0),
+        offset: 0,
+    }; 64];
+
+    let mut sq = 0;
+    while sq < 64 {
+        let (magic_number, offset) = magic_numbers[sq];
+        magics[sq] = BlackMagic {
+            magic_number,
+            not_mask: BB(!relevant_mask(sq, diagonal)),
+            offset,
+        };
+        sq += 1;
+    }
+    magics
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::ray_naive;
+    use super::*;
+    use crate::bb::*;
+    use crate::square::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    #[test]
+    fn test_slider_attacks() {
+        for i in 0..64 {
+            let sq = Square(i as SquareInternal);
+            for _ in 0..100 {
+                let occupied = BB::random(0.3);
+                assert_eq!(
+                    BB(slider_attacks(i, occupied.0, false)),
+                    ray_naive::rook_attacks_from_sq(sq, occupied)
+                );
+                assert_eq!(
+                    BB(slider_attacks(i, occupied.0, true)),
+                    ray_naive::bishop_attacks_from_sq(sq, occupied)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_table_sizes() {
+        let sizes = |diagonal| {
+            (0..64)
+                .map(|sq| 1usize << relevant_mask(sq, diagonal).count_ones())
+                .sum::<usize>()
+        };
+        assert_eq!(sizes(true), BISHOP_TABLE_SIZE);
+        assert_eq!(sizes(false), ROOK_TABLE_SIZE);
+    }
+
+    #[test]
+    fn test_pext_pdep() {
+        let mask = 0b1011_0100;
+        assert_eq!(pext(0b1001_0100, mask), 0b1011);
+        assert_eq!(pdep(0b1011, mask), 0b1001_0100);
+    }
+
+    #[test]
+    fn test_squares_between_and_lines_along() {
+        let between = squares_between_table();
+        assert_eq!(
+            between[A1.to_usize()][D4.to_usize()],
+            BB::new(B2) | BB::new(C3)
+        );
+        assert_eq!(between[A1.to_usize()][B3.to_usize()], EMPTY);
+
+        let lines = lines_along_table();
+        assert_eq!(lines[A1.to_usize()][A3.to_usize()], FILE_A);
+        assert_eq!(lines[A1.to_usize()][B3.to_usize()], EMPTY);
+    }
+
+    #[test]
+    fn test_find_magic() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for &sq in [A1, D4, H8].iter() {
+            for &(diagonal, bits) in [(false, MAGIC_ROOK_BITS), (true, MAGIC_BISHOP_BITS)].iter() {
+                let mut magic_numbers = [(0u64, 0u32); 64];
+                magic_numbers[sq.to_usize()].0 = find_magic(sq, diagonal, bits, &mut rng);
+
+                // only checks the square with a magic number
+                let magics = magics(&magic_numbers, diagonal);
+                let magic = magics[sq.to_usize()];
+                let mut used = vec![0u64; 1 << (64 - magic.shift)];
+                let mask = magic.mask.0;
+                for idx in 0..(1u64 << mask.count_ones()) {
+                    let occupied = pdep(idx, mask);
+                    let attacks = slider_attacks(sq.to_usize(), occupied, diagonal);
+                    let i = (occupied.wrapping_mul(magic.magic_number) >> magic.shift) as usize;
+                    assert!(used[i] == 0 || used[i] == attacks);
+                    used[i] = attacks;
+                }
+            }
+        }
+    }
+}
@@ -3,7 +3,6 @@
 use super::ray_naive::{bishop_attacks_from_sq, rook_attacks_from_sq};
 use crate::bb::*;
 use crate::square::*;
-use ::test;
 
 const TEST_CASE_BOARD_DENSITY: f64 = 0.3;
 
@@ -43,53 +42,6 @@ pub fn test_rook_attacks_from_sq<F: Fn(Square, BB) -> BB>(generator: F) {
     }
 }
 
-pub fn bench_attacks_from_sq<F: Fn(Square, BB) -> BB>(b: &mut test::Bencher, generator: F) {
-    let cases = random_occupancies_from_sq(640, TEST_CASE_BOARD_DENSITY);
-
-    b.iter(|| -> BB {
-        let mut ret = EMPTY;
-        for &(sq, occupied) in cases.iter() {
-            ret ^= generator(sq, occupied);
-        }
-        ret
-    });
-}
-
-pub fn bench_attacks_from_bb<F: Fn(BB, BB) -> BB>(b: &mut test::Bencher, generator: F) {
-    let cases = random_occupancies_from_bb(640, TEST_CASE_BOARD_DENSITY);
-    b.iter(|| -> BB {
-        let mut ret = EMPTY;
-
-        for &(from, occupied) in cases.iter() {
-            ret ^= generator(from, occupied);
-        }
-
-        ret
-    });
-}
-
-fn random_occupancies_from_bb(size: isize, density: f64) -> Vec<(BB, BB)> {
-    let mut ret = Vec::new();
-    for _ in 0..size {
-        let sq1 = Square::random();
-        let sq2 = Square::random();
-        let from = BB::new(sq1) | BB::new(sq2);
-        let occupied = BB::random(density) | from;
-        ret.push((from, occupied));
-    }
-    ret
-}
-
-fn random_occupancies_from_sq(size: usize, density: f64) -> Vec<(Square, BB)> {
-    let mut ret = Vec::new();
-    for i in 0..size {
-        let from = Square((i % 64) as SquareInternal);
-        let occupied = BB::random(density) | BB::new(from);
-        ret.push((from, occupied));
-    }
-    ret
-}
-
 fn generate_test_cases_from_sq<F: Fn(Square, BB) -> BB>(
     generator: F,
     density: f64,
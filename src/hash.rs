@@ -3,21 +3,29 @@ use crate::piece::*;
 use crate::position::State;
 use crate::side::*;
 use crate::square::{Square, SquareInternal};
+use alloc::boxed::Box;
+#[cfg(any(feature = "std", test))]
 use rand::{RngCore, SeedableRng, rngs::StdRng};
 
 /// Zobrist represents a set of keys for Zobrist hashing
+///
+/// The default keys rotate a single key per piece by the square index to keep the key
+/// set small. Polyglot compatible keys (see POLYGLOT_ZOBRIST_HASH) use a different key for
+/// every piece-square combination, so keys are stored per piece-square for both.
 #[derive(Debug)]
 pub struct Zobrist {
-    pieces: [u64; 12], // rotated based on square
+    pieces: [[u64; 64]; 12],
     castling_rights: [u64; 16],
     en_passant_file: [u64; 8],
     castles: [[u64; 2]; 2], // pre-computed based on piece-square changes
     stm: u64,
+    stm_side: Side,           // stm key is included when this side is to move
+    capturable_ep_only: bool, // en-passant is only hashed when the capture is possible
 }
 
 /// Default zobrist_keys generated with seed = 1
-pub static DEFAULT_ZOBRISH_HASH: Zobrist = Zobrist {
-    pieces: [
+pub static DEFAULT_ZOBRISH_HASH: Zobrist = Zobrist::from_tables(
+    rotated_piece_keys([
         16257666806172921645,
         12079090740189436754,
         11577349684372483860,
@@ -30,9 +38,8 @@ pub static DEFAULT_ZOBRISH_HASH: Zobrist = Zobrist {
         4820222721347483354,
         9222096121752829227,
         10997107696558716930,
-    ],
-
-    castling_rights: [
+    ]),
+    [
         5901611952838449075,
         16928860654864062033,
         3006969943347880664,
@@ -50,8 +57,7 @@ pub static DEFAULT_ZOBRISH_HASH: Zobrist = Zobrist {
         15580394547059712216,
         4575347809368850956,
     ],
-
-    en_passant_file: [
+    [
         3348961424409688254,
         2427135436213657123,
         2898060206792371384,
@@ -61,60 +67,109 @@ pub static DEFAULT_ZOBRISH_HASH: Zobrist = Zobrist {
         9554333785051357809,
         12082317543310182802,
     ],
-
-    castles: [[10993434298710260570, 16775444492128222288], [
-        13399088802984349794,
-        13139464958289927509,
-    ]],
-
-    stm: 5703255076737973876,
-};
+    5703255076737973876,
+    BLACK,
+);
+
+/// Expands one key per piece to a key per piece-square by rotating the key by the square index
+const fn rotated_piece_keys(keys: [u64; 12]) -> [[u64; 64]; 12] {
+    let mut pieces = [[0u64; 64]; 12];
+    let mut pc = 0;
+    while pc < 12 {
+        let mut sq = 0;
+        while sq < 64 {
+            pieces[pc][sq] = keys[pc].rotate_left(sq as u32);
+            sq += 1;
+        }
+        pc += 1;
+    }
+    pieces
+}
 
 impl Zobrist {
-    #[allow(dead_code)]
-    fn new(seed: [u8; 32]) -> Zobrist {
+    /// Generates a random set of keys from a seed
+    #[cfg(any(feature = "std", test))]
+    pub fn new(seed: [u8; 32]) -> Zobrist {
         let mut rng: StdRng = SeedableRng::from_seed(seed);
 
-        let mut keys = Zobrist {
-            pieces: [0; 12],
-            castling_rights: [0; 16],
-            castles: [[0; 2]; 2],
-            en_passant_file: [0; 8],
-            stm: 0,
-        };
-
-        for pc in 0..12 {
-            keys.pieces[pc] = rng.next_u64();
+        let mut piece_keys = [0u64; 12];
+        for key in piece_keys.iter_mut() {
+            *key = rng.next_u64();
         }
 
-        for cr in 0..16 {
-            keys.castling_rights[cr] = rng.next_u64();
+        let mut castling_rights = [0u64; 16];
+        for key in castling_rights.iter_mut() {
+            *key = rng.next_u64();
         }
 
-        for ep in 0..8 {
-            keys.en_passant_file[ep] = rng.next_u64();
+        let mut en_passant_file = [0u64; 8];
+        for key in en_passant_file.iter_mut() {
+            *key = rng.next_u64();
         }
 
-        keys.stm = rng.next_u64();
+        let stm = rng.next_u64();
 
-        // pre-compute castles
-        for &side in &[WHITE, BLACK] {
-            for &castle in &[QUEEN_SIDE, KING_SIDE] {
-                let mut hash = 0u64;
-
-                let (king_from, king_to) = castle_king_squares(side, castle);
-                hash ^= keys.piece_square(KING.pc(side), king_from);
-                hash ^= keys.piece_square(KING.pc(side), king_to);
-
-                let (rook_from, rook_to) = castle_rook_squares(side, castle);
-                hash ^= keys.piece_square(ROOK.pc(side), rook_to);
-                hash ^= keys.piece_square(ROOK.pc(side), rook_from);
+        Zobrist::from_tables(
+            rotated_piece_keys(piece_keys),
+            castling_rights,
+            en_passant_file,
+            stm,
+            BLACK,
+        )
+    }
 
-                keys.castles[castle.to_usize()][side.to_usize()] = hash;
+    /// Creates a set of keys from tables of keys. The stm key is included in the hash
+    /// when stm_side is to move
+    pub const fn from_tables(
+        pieces: [[u64; 64]; 12],
+        castling_rights: [u64; 16],
+        en_passant_file: [u64; 8],
+        stm: u64,
+        stm_side: Side,
+    ) -> Zobrist {
+        // pre-compute castles
+        let mut castles = [[0u64; 2]; 2];
+        let king_index = [KING.pc(WHITE), KING.pc(BLACK)];
+        let rook_index = [ROOK.pc(WHITE), ROOK.pc(BLACK)];
+
+        let mut side = 0;
+        while side < 2 {
+            let mut castle = 0;
+            while castle < 2 {
+                let (king_from, king_to) = castle_king_squares(Side(side), Castle(castle));
+                let (rook_from, rook_to) = castle_rook_squares(Side(side), Castle(castle));
+                let king = &pieces[king_index[side].to_usize()];
+                let rook = &pieces[rook_index[side].to_usize()];
+
+                castles[castle][side] =
+                    king[king_from.0] ^ king[king_to.0] ^ rook[rook_from.0] ^ rook[rook_to.0];
+
+                castle += 1;
             }
+            side += 1;
         }
 
-        keys
+        Zobrist {
+            pieces,
+            castling_rights,
+            en_passant_file,
+            castles,
+            stm,
+            stm_side,
+            capturable_ep_only: false,
+        }
+    }
+
+    /// Hashes the en-passant file only if a pawn of the side to move is in position to make the
+    /// capture, like Polyglot, instead of whenever there is an en-passant square
+    pub const fn with_capturable_ep_only(mut self) -> Zobrist {
+        self.capturable_ep_only = true;
+        self
+    }
+
+    /// Moves the keys to the heap and leaks them so they can be shared by positions
+    pub fn into_static(self) -> &'static Zobrist {
+        Box::leak(Box::new(self))
     }
 
     /// Generates the hash of the entire position
@@ -127,18 +182,101 @@ impl Zobrist {
 
         hash ^= self.castling_rights[state.castling_rights.to_usize()];
 
-        hash ^= self.ep_hash(state.ep_square);
+        hash ^= if self.capturable_ep_only {
+            self.ep(grid, state)
+        } else {
+            self.ep_file(state.ep_square)
+        };
+
+        if state.stm == self.stm_side {
+            hash ^= self.stm;
+        }
 
         hash
     }
 
-    /// Generates the difference between two states
+    /// Generates the hash of the pawns in the position, used for pawn structure caches
+    pub fn pawns(&self, grid: &[Piece; 64]) -> u64 {
+        let mut hash = 0u64;
+
+        for (idx, &pc) in grid
+            .iter()
+            .enumerate()
+            .filter(|&(_, &pc)| pc.is_some() && pc.kind() == PAWN)
+        {
+            hash ^= self.piece_square(pc, Square::new(idx as SquareInternal));
+        }
+
+        hash
+    }
+
+    /// Generates the hash of the number of each piece in the position, used for material caches
+    pub fn material(&self, grid: &[Piece; 64]) -> u64 {
+        let mut counts = [0usize; 12];
+        let mut hash = 0u64;
+
+        for &pc in grid.iter().filter(|pc| pc.is_some()) {
+            hash ^= self.material_count(pc, counts[pc.to_usize()]);
+            counts[pc.to_usize()] += 1;
+        }
+
+        hash
+    }
+
+    /// Generates the material hash of the nth piece of a kind, reusing the piece-square keys
+    /// with the count as the square
+    pub fn material_count(&self, piece: Piece, n: usize) -> u64 {
+        debug_assert!(n < 64);
+        self.piece_square(piece, Square(n))
+    }
+
+    /// Generates the difference between the castling rights, en-passant square and side to move
+    /// of two states. Keys which only hash capturable en-passant squares leave en-passant out,
+    /// since it depends on pawn positions (see `ep`)
     pub fn state(&self, before: &State, after: &State) -> u64 {
-        self.castling_rights[before.castling_rights.to_usize()]
+        let mut hash = self.castling_rights[before.castling_rights.to_usize()]
             ^ self.castling_rights[after.castling_rights.to_usize()]
-            ^ self.ep_hash(before.ep_square)
-            ^ self.ep_hash(after.ep_square)
-            ^ self.stm
+            ^ self.stm;
+
+        if !self.capturable_ep_only {
+            hash ^= self.ep_file(before.ep_square) ^ self.ep_file(after.ep_square);
+        }
+
+        hash
+    }
+
+    /// Generates the en-passant hash for keys which only hash capturable en-passant squares
+    /// (see `with_capturable_ep_only`): the en-passant file if a pawn of the side to move is in
+    /// position to make the capture. Zero for other keys, whose en-passant hash is part of
+    /// `state`
+    pub fn ep(&self, grid: &[Piece; 64], state: &State) -> u64 {
+        if !self.capturable_ep_only {
+            return 0;
+        }
+
+        let ep_square = match state.ep_square {
+            Some(sq) => sq,
+            None => return 0,
+        };
+
+        let pushed = ep_square.change_row(if state.stm == WHITE { 4 } else { 3 });
+        let capturer = PAWN.pc(state.stm);
+        let col = ep_square.col();
+
+        if (col > 0 && grid[pushed.to_usize() - 1] == capturer)
+            || (col < 7 && grid[pushed.to_usize() + 1] == capturer)
+        {
+            self.ep_file(state.ep_square)
+        } else {
+            0
+        }
+    }
+
+    fn ep_file(&self, ep_square: Option<Square>) -> u64 {
+        match ep_square {
+            Some(sq) => unsafe { *self.en_passant_file.get_unchecked(sq.col()) },
+            None => 0,
+        }
     }
 
     pub fn castle(&self, castle: Castle, stm: Side) -> u64 {
@@ -158,17 +296,12 @@ impl Zobrist {
         self.piece_square(pc_from, from) ^ self.piece_square(pc_to, to)
     }
 
-    fn piece_square(&self, piece: Piece, square: Square) -> u64 {
-        return unsafe {
-            (*self.pieces.get_unchecked(piece.to_usize())).rotate_left(square.to_u32())
-        };
-    }
-
-    fn ep_hash(&self, ep_square: Option<Square>) -> u64 {
-        if let Some(sq) = ep_square {
-            unsafe { *self.en_passant_file.get_unchecked(sq.col() as usize) }
-        } else {
-            0
+    pub fn piece_square(&self, piece: Piece, square: Square) -> u64 {
+        unsafe {
+            *self
+                .pieces
+                .get_unchecked(piece.to_usize())
+                .get_unchecked(square.to_usize())
         }
     }
 }
@@ -176,6 +309,8 @@ impl Zobrist {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::mv::Move;
+    use crate::position::Position;
     use crate::square::*;
 
     #[test]
@@ -198,4 +333,54 @@ mod test {
 
         assert_eq!(actual_key, expected_key);
     }
+
+    #[test]
+    fn test_ep_hashing() {
+        // the en-passant square on d6 can't be captured
+        let with_ep = "4k3/8/8/3p4/8/8/8/4K3 w - d6 0 1";
+        let without_ep = "4k3/8/8/3p4/8/8/8/4K3 w - - 0 1";
+        let key = |fen, hash| Position::from_fen_with_hash(fen, hash).unwrap().hash_key();
+        let capturable_only = Zobrist::new([7; 32])
+            .with_capturable_ep_only()
+            .into_static();
+
+        assert_ne!(
+            key(with_ep, &DEFAULT_ZOBRISH_HASH),
+            key(without_ep, &DEFAULT_ZOBRISH_HASH)
+        );
+        assert_eq!(
+            key(with_ep, capturable_only),
+            key(without_ep, capturable_only)
+        );
+
+        // incremental keys match keys from scratch after a double push in both modes
+        for hash in [&DEFAULT_ZOBRISH_HASH, capturable_only] {
+            let mut position =
+                Position::from_fen_with_hash("4k3/8/8/8/2p5/8/1P1P4/4K3 w - - 0 1", hash).unwrap();
+            position.make(Move::new_push(D2, D4));
+            assert_eq!(position.state().ep_square, Some(D3));
+            assert_eq!(
+                position.hash_key(),
+                hash.position(position.grid(), position.state())
+            );
+            position.make(Move::new_push(E8, E7));
+            assert_eq!(
+                position.hash_key(),
+                hash.position(position.grid(), position.state())
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_castle() {
+        let keys = &DEFAULT_ZOBRISH_HASH;
+
+        let mut expected_key = 0u64;
+        expected_key ^= keys.piece_square(BLACK_KING, E8);
+        expected_key ^= keys.piece_square(BLACK_KING, G8);
+        expected_key ^= keys.piece_square(BLACK_ROOK, H8);
+        expected_key ^= keys.piece_square(BLACK_ROOK, F8);
+
+        assert_eq!(keys.castle(KING_SIDE, BLACK), expected_key);
+    }
 }
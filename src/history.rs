@@ -0,0 +1,473 @@
+// Move ordering heuristics for search, see
+// https://www.chessprogramming.org/History_Heuristic
+// https://www.chessprogramming.org/Killer_Heuristic
+// https://www.chessprogramming.org/Countermove_Heuristic
+use crate::mv::{Move, NULL_MOVE};
+use crate::mv_list::{DefaultMoveScorer, MoveScorer};
+use crate::piece::*;
+use crate::side::Side;
+use crate::square::Square;
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// History scores stay within -MAX_HISTORY..=MAX_HISTORY
+pub const MAX_HISTORY: i16 = 16384;
+
+/// Default number of plies Killers has slots for
+pub const MAX_PLY: usize = 128;
+
+/// Bonus for a quiet move that caused a cutoff at depth. Use the negated bonus as a malus for
+/// the quiet moves searched before it
+pub fn history_bonus(depth: u32) -> i16 {
+    // the bonus reaches its cap at depth 9, clamping first keeps deeper depths from overflowing
+    let depth = depth.min(9);
+    (depth * depth * 16 + depth * 32).min(1536) as i16
+}
+
+// Moves entry towards +-MAX_HISTORY by bonus, scaled down the closer it already is so scores
+// saturate instead of overflowing, and old results fade as new ones arrive
+fn apply_gravity(entry: &mut i16, bonus: i16) {
+    let bonus = (bonus as i32).clamp(-(MAX_HISTORY as i32), MAX_HISTORY as i32);
+    let value = *entry as i32;
+    *entry = (value + bonus - value * bonus.abs() / MAX_HISTORY as i32) as i16;
+}
+
+/// Butterfly history, scores indexed by [side][from][to]
+#[derive(Clone)]
+pub struct ButterflyHistory {
+    table: Box<[[[i16; 64]; 64]; 2]>,
+}
+
+impl Default for ButterflyHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ButterflyHistory {
+    pub fn new() -> ButterflyHistory {
+        ButterflyHistory {
+            table: Box::new([[[0; 64]; 64]; 2]),
+        }
+    }
+
+    pub fn get(&self, side: Side, mv: Move) -> i16 {
+        self.table[side.to_usize()][mv.from().to_usize()][mv.to().to_usize()]
+    }
+
+    pub fn update(&mut self, side: Side, mv: Move, bonus: i16) {
+        apply_gravity(
+            &mut self.table[side.to_usize()][mv.from().to_usize()][mv.to().to_usize()],
+            bonus,
+        );
+    }
+
+    /// Halves every score, eg between searches so old results count less
+    pub fn age(&mut self) {
+        self.table
+            .iter_mut()
+            .flatten()
+            .flatten()
+            .for_each(|entry| *entry /= 2);
+    }
+
+    pub fn clear(&mut self) {
+        *self.table = [[[0; 64]; 64]; 2];
+    }
+}
+
+/// Scores indexed by [piece][to]. Pieces include their side so no separate side index is needed
+#[derive(Clone)]
+pub struct PieceToHistory {
+    table: [[i16; 64]; 12],
+}
+
+impl Default for PieceToHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PieceToHistory {
+    pub fn new() -> PieceToHistory {
+        PieceToHistory {
+            table: [[0; 64]; 12],
+        }
+    }
+
+    pub fn get(&self, piece: Piece, to: Square) -> i16 {
+        self.table[piece.to_usize()][to.to_usize()]
+    }
+
+    pub fn update(&mut self, piece: Piece, to: Square, bonus: i16) {
+        apply_gravity(&mut self.table[piece.to_usize()][to.to_usize()], bonus);
+    }
+
+    /// Halves every score
+    pub fn age(&mut self) {
+        self.table
+            .iter_mut()
+            .flatten()
+            .for_each(|entry| *entry /= 2);
+    }
+
+    pub fn clear(&mut self) {
+        self.table = [[0; 64]; 12];
+    }
+}
+
+/// Continuation history, a PieceToHistory for each [piece][to] of a previous move. Index it
+/// with the move one ply back for counter-move history or two plies back for follow-up history
+#[derive(Clone)]
+pub struct ContinuationHistory {
+    tables: Vec<PieceToHistory>,
+}
+
+impl Default for ContinuationHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContinuationHistory {
+    pub fn new() -> ContinuationHistory {
+        ContinuationHistory {
+            tables: vec![PieceToHistory::new(); 12 * 64],
+        }
+    }
+
+    /// Scores of moves following prev_piece moving to prev_to
+    pub fn get(&self, prev_piece: Piece, prev_to: Square) -> &PieceToHistory {
+        &self.tables[Self::index(prev_piece, prev_to)]
+    }
+
+    pub fn get_mut(&mut self, prev_piece: Piece, prev_to: Square) -> &mut PieceToHistory {
+        &mut self.tables[Self::index(prev_piece, prev_to)]
+    }
+
+    /// Halves every score
+    pub fn age(&mut self) {
+        self.tables.iter_mut().for_each(PieceToHistory::age);
+    }
+
+    pub fn clear(&mut self) {
+        self.tables.iter_mut().for_each(PieceToHistory::clear);
+    }
+
+    fn index(piece: Piece, to: Square) -> usize {
+        piece.to_usize() * 64 + to.to_usize()
+    }
+}
+
+/// Two killer move slots per ply, quiet moves that recently caused a cutoff at the same ply
+#[derive(Clone)]
+pub struct Killers {
+    slots: Vec<[Move; 2]>,
+}
+
+impl Default for Killers {
+    fn default() -> Self {
+        Self::new(MAX_PLY)
+    }
+}
+
+impl Killers {
+    pub fn new(max_ply: usize) -> Killers {
+        Killers {
+            slots: vec![[NULL_MOVE; 2]; max_ply],
+        }
+    }
+
+    /// Killers at ply, most recent first. Empty slots hold NULL_MOVE
+    pub fn get(&self, ply: usize) -> [Move; 2] {
+        self.slots.get(ply).copied().unwrap_or([NULL_MOVE; 2])
+    }
+
+    pub fn is_killer(&self, ply: usize, mv: Move) -> bool {
+        self.get(ply).contains(&mv)
+    }
+
+    /// Stores mv as the most recent killer at ply, keeping the previous one in the second slot
+    pub fn store(&mut self, ply: usize, mv: Move) {
+        if let Some(slot) = self.slots.get_mut(ply)
+            && slot[0] != mv
+        {
+            slot[1] = slot[0];
+            slot[0] = mv;
+        }
+    }
+
+    /// Empties the slots for ply, eg for the children of a node before searching them
+    pub fn clear_ply(&mut self, ply: usize) {
+        if let Some(slot) = self.slots.get_mut(ply) {
+            *slot = [NULL_MOVE; 2];
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.slots
+            .iter_mut()
+            .for_each(|slot| *slot = [NULL_MOVE; 2]);
+    }
+}
+
+/// The quiet move that last refuted each previous move, indexed by the previous move's [piece][to]
+#[derive(Clone)]
+pub struct CounterMoves {
+    table: [[Move; 64]; 12],
+}
+
+impl Default for CounterMoves {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CounterMoves {
+    pub fn new() -> CounterMoves {
+        CounterMoves {
+            table: [[NULL_MOVE; 64]; 12],
+        }
+    }
+
+    /// Counter to prev_piece moving to prev_to, or NULL_MOVE if there is none
+    pub fn get(&self, prev_piece: Piece, prev_to: Square) -> Move {
+        self.table[prev_piece.to_usize()][prev_to.to_usize()]
+    }
+
+    pub fn store(&mut self, prev_piece: Piece, prev_to: Square, mv: Move) {
+        self.table[prev_piece.to_usize()][prev_to.to_usize()] = mv;
+    }
+
+    pub fn clear(&mut self) {
+        self.table = [[NULL_MOVE; 64]; 12];
+    }
+}
+
+/// HistoryScorer is a MoveScorer for SortedMoveAdder that orders quiet moves by killers,
+/// counter-move and history. Captures and promotions keep DefaultMoveScorer's order
+///
+/// Move ordering priorities:
+/// 20025..20150: captures and queen or knight promotions (DefaultMoveScorer score + 20000)
+/// 19000: first killer
+/// 18999: second killer
+/// 18000: counter move
+/// -16384..16384: other quiet moves by butterfly plus continuation history, halved
+/// -20250..-20100: rook and bishop promotions (DefaultMoveScorer score - 20000)
+pub struct HistoryScorer<'a> {
+    stm: Side,
+    butterfly: &'a ButterflyHistory,
+    continuation: Option<&'a PieceToHistory>,
+    killers: [Move; 2],
+    counter: Move,
+}
+
+impl<'a> HistoryScorer<'a> {
+    const NOISY_BASE: i16 = 20000;
+    const KILLER_SCORE: i16 = 19000;
+    const COUNTER_SCORE: i16 = 18000;
+
+    pub fn new(stm: Side, butterfly: &'a ButterflyHistory) -> HistoryScorer<'a> {
+        HistoryScorer {
+            stm,
+            butterfly,
+            continuation: None,
+            killers: [NULL_MOVE; 2],
+            counter: NULL_MOVE,
+        }
+    }
+
+    /// Adds the continuation history for the previous move, eg from ContinuationHistory::get
+    pub fn with_continuation(mut self, continuation: &'a PieceToHistory) -> HistoryScorer<'a> {
+        self.continuation = Some(continuation);
+        self
+    }
+
+    /// Adds the killers for the current ply, eg from Killers::get
+    pub fn with_killers(mut self, killers: [Move; 2]) -> HistoryScorer<'a> {
+        self.killers = killers;
+        self
+    }
+
+    /// Adds the counter to the previous move, eg from CounterMoves::get
+    pub fn with_counter(mut self, counter: Move) -> HistoryScorer<'a> {
+        self.counter = counter;
+        self
+    }
+
+    fn quiet(&self, mv: Move, mover_kind: Kind) -> i16 {
+        if mv == self.killers[0] {
+            return Self::KILLER_SCORE;
+        }
+        if mv == self.killers[1] {
+            return Self::KILLER_SCORE - 1;
+        }
+        if mv == self.counter {
+            return Self::COUNTER_SCORE;
+        }
+
+        let mut score = self.butterfly.get(self.stm, mv) as i32;
+        if let Some(continuation) = self.continuation {
+            score += continuation.get(mover_kind.pc(self.stm), mv.to()) as i32;
+        }
+        (score / 2) as i16
+    }
+
+    fn noisy(score: i16) -> i16 {
+        if score < 0 {
+            score - Self::NOISY_BASE
+        } else {
+            score + Self::NOISY_BASE
+        }
+    }
+}
+
+impl<'a> MoveScorer for HistoryScorer<'a> {
+    fn capture(&self, mv: Move, mover_kind: Kind, captured_kind: Kind) -> i16 {
+        Self::noisy(DefaultMoveScorer.capture(mv, mover_kind, captured_kind))
+    }
+
+    fn non_capture(&self, mv: Move, mover_kind: Kind, _relative_to_sq: Square) -> i16 {
+        self.quiet(mv, mover_kind)
+    }
+
+    fn pawn_push(&self, mv: Move, _relative_to_sq: Square) -> i16 {
+        self.quiet(mv, PAWN)
+    }
+
+    fn promotion(&self, mv: Move, promotion_kind: Kind) -> i16 {
+        Self::noisy(DefaultMoveScorer.promotion(mv, promotion_kind))
+    }
+
+    fn capture_promotion(&self, mv: Move, captured_kind: Kind, promotion_kind: Kind) -> i16 {
+        Self::noisy(DefaultMoveScorer.capture_promotion(mv, captured_kind, promotion_kind))
+    }
+
+    fn castle(&self, mv: Move) -> i16 {
+        if self.killers.contains(&mv) || mv == self.counter {
+            return self.quiet(mv, KING);
+        }
+        DefaultMoveScorer.castle(mv)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generation::*;
+    use crate::mv_list::{PieceSquareTable, SortedMoveAdder, SortedMoveHeap};
+    use crate::position::*;
+    use crate::side::{BLACK, WHITE};
+    use crate::square::*;
+
+    #[test]
+    fn test_history_bonus() {
+        assert_eq!(history_bonus(0), 0);
+        assert_eq!(history_bonus(1), 48);
+        assert_eq!(history_bonus(8), 1280);
+        assert_eq!(history_bonus(9), 1536);
+        assert_eq!(history_bonus(20_000), 1536);
+        assert_eq!(history_bonus(u32::MAX), 1536);
+    }
+
+    #[test]
+    fn test_gravity_saturates() {
+        let mut history = ButterflyHistory::new();
+        let mv = Move::new_push(G1, F3);
+
+        for _ in 0..1000 {
+            history.update(WHITE, mv, history_bonus(20));
+        }
+        assert!(history.get(WHITE, mv) <= MAX_HISTORY);
+        assert!(history.get(WHITE, mv) > MAX_HISTORY - 1536);
+        assert_eq!(history.get(BLACK, mv), 0);
+
+        for _ in 0..1000 {
+            history.update(WHITE, mv, -history_bonus(20));
+        }
+        assert!(history.get(WHITE, mv) >= -MAX_HISTORY);
+        assert!(history.get(WHITE, mv) < 0);
+
+        history.age();
+        assert!(history.get(WHITE, mv) >= -MAX_HISTORY / 2);
+        history.clear();
+        assert_eq!(history.get(WHITE, mv), 0);
+    }
+
+    #[test]
+    fn test_killers() {
+        let mut killers = Killers::new(4);
+        let first = Move::new_push(G1, F3);
+        let second = Move::new_push(B1, C3);
+
+        killers.store(2, first);
+        killers.store(2, second);
+        killers.store(2, second);
+        assert_eq!(killers.get(2), [second, first]);
+        assert!(killers.is_killer(2, first));
+        assert!(!killers.is_killer(1, first));
+
+        // plies past the end are ignored
+        killers.store(10, first);
+        assert_eq!(killers.get(10), [NULL_MOVE; 2]);
+
+        killers.clear_ply(2);
+        assert_eq!(killers.get(2), [NULL_MOVE; 2]);
+    }
+
+    #[test]
+    fn test_counter_and_continuation() {
+        let mut counters = CounterMoves::new();
+        let mut continuation = ContinuationHistory::new();
+        let reply = Move::new_push(G8, F6);
+
+        counters.store(WHITE_PAWN, E4, reply);
+        assert_eq!(counters.get(WHITE_PAWN, E4), reply);
+        assert_eq!(counters.get(WHITE_PAWN, D4), NULL_MOVE);
+
+        continuation
+            .get_mut(WHITE_PAWN, E4)
+            .update(BLACK_KNIGHT, F6, 500);
+        assert_eq!(continuation.get(WHITE_PAWN, E4).get(BLACK_KNIGHT, F6), 500);
+        assert_eq!(continuation.get(WHITE_PAWN, D4).get(BLACK_KNIGHT, F6), 0);
+    }
+
+    #[test]
+    fn test_history_scorer_ordering() {
+        let position =
+            &Position::from_fen("rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2")
+                .unwrap();
+        let piece_square_table = PieceSquareTable::new([[100i16; 64]; 6]);
+        let capture = Move::new_capture(E4, D5);
+        let killer = Move::new_push(B1, C3);
+        let counter = Move::new_push(F1, B5);
+        let best_quiet = Move::new_push(G1, F3);
+
+        let mut butterfly = ButterflyHistory::new();
+        butterfly.update(WHITE, best_quiet, history_bonus(8));
+        butterfly.update(WHITE, Move::new_push(A2, A3), -history_bonus(8));
+
+        let scorer = HistoryScorer::new(WHITE, &butterfly)
+            .with_killers([killer, NULL_MOVE])
+            .with_counter(counter);
+        let mut heap = SortedMoveHeap::new(64);
+        let mut list = SortedMoveAdder::with_scorer(
+            &piece_square_table,
+            position.grid(),
+            WHITE,
+            &mut heap,
+            scorer,
+        );
+        legal_moves(position, &mut list);
+
+        let sorted = heap
+            .into_sorted_vec()
+            .iter()
+            .map(|move_score| move_score.mv())
+            .collect::<Vec<Move>>();
+
+        assert_eq!(sorted[..4], [capture, killer, counter, best_quiet]);
+        assert_eq!(*sorted.last().unwrap(), Move::new_push(A2, A3));
+    }
+}
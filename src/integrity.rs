@@ -4,6 +4,8 @@ use crate::piece::*;
 use crate::position::Position;
 use crate::side::*;
 use crate::square::*;
+use alloc::format;
+use alloc::string::{String, ToString};
 
 /*
     CHECKS:
@@ -14,64 +14,127 @@
 //! Ways to store moves:
 //!
 //! MoveVec: Wraps a vector of generated moves, useful if you need to access the actual moves being generated
+//! MoveArray: Like MoveVec but stores moves in a fixed-capacity array on the stack, avoiding a heap allocation per move list
 //! MoveCounter: Counts moves of each kind (captures, castles, promotions etc). Useful if you are making a perft function or need statistics about moves for a position, but don't care about the actual moves
 //! SortedMoveAdder + SortedMoveHeap: Stores genarated moves in a sorted binary heap, which are efficiently ordered as they are inserted based on a heuristic scoring and piece-square table that you provide. Use this if you want the moves to have a reasonably good initial ordering so moves that are checked first are more likely to lead to eg alpha-beta cutoffs and reduce the search tree size.
+//! SortedMoveAdder::with_scorer: Orders moves with your own MoveScorer, eg HistoryScorer which uses history, killer and counter-move tables
+//! SortedMoveAdder + ScoredMoveList: Same ordering as SortedMoveHeap but stored in a flat array and picked best-first lazily, so a search that cuts off early skips sorting the rest and can move eg the hash move to the front
 
-#![feature(test)]
-#![feature(portable_simd)]
-#![feature(binary_heap_into_iter_sorted)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "nightly", feature(portable_simd))]
+
+extern crate alloc;
 
 pub mod bb;
 mod board;
 mod cache;
 mod castle;
 mod castling_rights;
+mod eval;
+mod game_result;
 mod generation;
 mod hash;
+mod history;
 mod integrity;
 mod mv;
 mod mv_list;
+mod nnue;
 mod perft;
 mod piece;
+#[cfg(feature = "std")]
+mod playout;
+#[cfg(feature = "std")]
+mod polyglot;
 mod position;
+#[cfg(feature = "std")]
+mod random_position;
 mod side;
 mod square;
+#[cfg(feature = "std")]
+mod training_data;
 mod util;
 
-#[cfg(target_feature = "sse3")]
 mod dbb;
+mod wbb;
 
+#[cfg(feature = "std")]
 extern crate num_cpus;
+#[cfg(feature = "std")]
 extern crate rand;
+#[cfg(feature = "std")]
 extern crate threadpool;
 
-#[cfg(test)]
-extern crate test;
 #[cfg(test)]
 extern crate unindent;
 
 pub use crate::bb::BB;
 pub use crate::castle::{Castle, KING_SIDE, QUEEN_SIDE};
 pub use crate::castling_rights::{BLACK_KS, BLACK_QS, CastlingRights, WHITE_KS, WHITE_QS};
+pub use crate::eval::{
+    BACKWARD_PAWN_OFFSET, BISHOP_PAIR_OFFSET, DOUBLED_PAWN_OFFSET, EvalParams,
+    ISOLATED_PAWN_OFFSET, KING_ZONE_ATTACK_OFFSET, MATERIAL_OFFSET, MOBILITY_KINDS,
+    MOBILITY_OFFSET, NUM_PARAMS, PASSED_PAWN_OFFSET, PSQT_OFFSET, evaluate,
+};
+#[cfg(feature = "std")]
+pub use crate::eval::{TexelTuner, TexelTunerOptions};
+pub use crate::game_result::GameResult;
+#[cfg(target_arch = "x86_64")]
+pub use crate::generation::slider::Bmi2;
+pub use crate::generation::slider::{
+    BlackMagic, DefaultSliders, Hyperbola, KoggeStone, Magic, Naive, SliderAttacks, SliderBackend,
+    Subtract, slider_backend,
+};
+#[cfg(feature = "std")]
+pub use crate::generation::slider::{
+    find_magic, slider_attacks_differential, slider_differential_all_backends,
+    slider_perft_differential,
+};
 pub use crate::generation::{
-    MoveGenPreprocessing, legal_moves, legal_moves_with_preprocessing, loud_legal_moves,
-    loud_legal_moves_with_preprocessing, movegen_preprocessing,
+    MoveGenPreprocessing, legal_moves, legal_moves_with_preprocessing,
+    legal_moves_with_preprocessing_and_sliders, legal_moves_with_sliders, loud_legal_moves,
+    loud_legal_moves_with_preprocessing, loud_legal_moves_with_preprocessing_and_sliders,
+    loud_legal_moves_with_sliders, movegen_preprocessing,
+};
+pub use crate::hash::{DEFAULT_ZOBRISH_HASH, Zobrist};
+pub use crate::history::{
+    ButterflyHistory, ContinuationHistory, CounterMoves, HistoryScorer, Killers, MAX_HISTORY,
+    MAX_PLY, PieceToHistory, history_bonus,
 };
 pub use crate::mv::{KING_SIDE_CASTLE, Move, MoveScore, NULL_MOVE, QUEEN_SIDE_CASTLE};
 pub use crate::mv_list::{
-    MoveAdder, MoveCounter, MoveVec, PieceSquareTable, SortedMoveAdder, SortedMoveHeap,
-    SortedMoveHeapItem,
+    DefaultMoveScorer, MAX_MOVES, MAX_PHASE, MoveAdder, MoveArray, MoveArrayIntoIter, MoveCounter,
+    MoveScorer, MoveVec, PieceSquareTable, ScoredMoveList, ScoredMoves, SortedMoveAdder,
+    SortedMoveHeap, SortedMoveHeapItem, TaperedPieceSquareTable, TaperedScore,
+};
+#[cfg(feature = "nightly")]
+pub use crate::nnue::SimdOps;
+pub use crate::nnue::{
+    Accumulator, AccumulatorOps, DefaultOps, FeatureDelta, FeatureSet, FeatureTransformer,
+    FeatureUpdate, HalfKA, HalfKP, MAX_FEATURE_CHANGES, ScalarOps,
 };
 pub use crate::piece::*;
-pub use crate::position::{Position, STARTING_POSITION_FEN, State};
+#[cfg(feature = "std")]
+pub use crate::playout::{MoveSelection, Playout, PlayoutOptions, Termination, playout};
+#[cfg(feature = "std")]
+pub use crate::polyglot::{
+    BookBuilder, BookBuilderOptions, BookEntry, POLYGLOT_ZOBRIST_HASH, polyglot_key, polyglot_move,
+};
+pub use crate::position::{POSITION_FIXED_BYTES, Position, STARTING_POSITION_FEN, State};
+#[cfg(feature = "std")]
+pub use crate::random_position::{RandomPositionGenerator, RandomPositionOptions};
 pub use crate::side::{BLACK, Side, WHITE};
 pub use crate::square::*;
+#[cfg(feature = "std")]
+pub use crate::training_data::{
+    BinpackReader, BinpackWriter, PlainReader, PlainWriter, TrainingDataEntry,
+};
 pub use board::Board;
 pub use perft::perft;
 pub use perft::perft_detailed;
+pub use perft::perft_with_sliders;
 
-#[cfg(target_feature = "sse3")]
 pub use crate::dbb::*;
+pub use crate::wbb::*;
 
 #[test]
 fn basic_functionality() {
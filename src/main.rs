@@ -1,6 +1,6 @@
-#![feature(test)]
-#![feature(portable_simd)]
-#![feature(binary_heap_into_iter_sorted)]
+#![cfg_attr(feature = "nightly", feature(portable_simd))]
+
+extern crate alloc;
 
 pub mod bb;
 mod board;
@@ -19,15 +19,15 @@ mod side;
 mod square;
 mod util;
 
-#[cfg(target_feature = "sse3")]
+#[allow(dead_code)]
 mod dbb;
+#[allow(dead_code)]
+mod wbb;
 
 extern crate num_cpus;
 extern crate rand;
 extern crate threadpool;
 
-#[cfg(test)]
-extern crate test;
 #[cfg(test)]
 extern crate unindent;
 
@@ -1,7 +1,10 @@
-use crate::castle::{Castle, KING_SIDE, QUEEN_SIDE};
+use crate::castle::{Castle, KING_SIDE, QUEEN_SIDE, castle_king_squares};
 use crate::piece::*;
+use crate::side::Side;
 use crate::square::{Square, SquareInternal};
-use std::fmt;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
 
 /*
     FLAGS:
@@ -145,6 +148,23 @@ impl Move {
             upper: to.to_u8() | EP_CAPTURE_FLAG,
         }
     }
+
+    /// Convert to UCI notation eg "e2e4", "e7e8q" or "e1g1" for castles. The side to move is
+    /// needed for castles
+    pub fn to_uci(self, stm: Side) -> String {
+        if self.is_castle() {
+            let (from, to) = castle_king_squares(stm, self.castle());
+            return format!("{}{}", from, to);
+        }
+
+        let mut s = format!("{}{}", self.from(), self.to());
+
+        if self.is_promotion() {
+            s.push(self.promote_to().to_char().to_ascii_lowercase());
+        }
+
+        s
+    }
 }
 
 impl fmt::Display for Move {
@@ -219,7 +239,20 @@ impl fmt::Debug for MoveScore {
 mod test {
     use super::*;
     use crate::square::*;
-    use std::mem;
+    use core::mem;
+
+    #[test]
+    fn test_to_uci() {
+        use crate::side::{BLACK, WHITE};
+
+        assert_eq!(Move::new_push(E2, E4).to_uci(WHITE), "e2e4");
+        assert_eq!(
+            Move::new_capture_promotion(B2, A1, KNIGHT).to_uci(BLACK),
+            "b2a1n"
+        );
+        assert_eq!(Move::new_castle(KING_SIDE).to_uci(WHITE), "e1g1");
+        assert_eq!(Move::new_castle(QUEEN_SIDE).to_uci(BLACK), "e8c8");
+    }
 
     #[test]
     fn test_packed() {
@@ -2,22 +2,37 @@ use crate::bb::BB;
 use crate::castle::Castle;
 use crate::square::Square;
 
+mod move_scorer;
+mod mv_array;
 mod mv_counter;
 mod mv_vec;
 mod piece_square_table;
+mod scored_move_list;
 mod sorted_move_adder;
 
+#[allow(unused_imports)]
+pub use self::move_scorer::{DefaultMoveScorer, MoveScorer};
+#[allow(unused_imports)]
+pub use self::mv_array::{MAX_MOVES, MoveArray, MoveArrayIntoIter};
 pub use self::mv_counter::MoveCounter;
 pub use self::mv_vec::MoveVec;
-pub use self::piece_square_table::PieceSquareTable;
 #[allow(unused_imports)]
-pub use self::sorted_move_adder::{SortedMoveAdder, SortedMoveHeap, SortedMoveHeapItem};
+pub use self::piece_square_table::{
+    MAX_PHASE, PieceSquareTable, TaperedPieceSquareTable, TaperedScore,
+};
+#[allow(unused_imports)]
+pub use self::scored_move_list::ScoredMoveList;
+#[allow(unused_imports)]
+pub use self::sorted_move_adder::{
+    ScoredMoves, SortedMoveAdder, SortedMoveHeap, SortedMoveHeapItem,
+};
 
 /// MoveAdder represents a way to collect moves from move generation functions
 /// Implementations:
 ///   MoveCounter (count moves only)
 ///   MoveVec (adds move to Vec)
-///   SortedMoveAdder (adds moves along with piece-square-scores to a sorted binary heap)
+///   MoveArray (adds move to a fixed-capacity array on the stack)
+///   SortedMoveAdder (adds moves along with piece-square-scores to a sorted binary heap or a ScoredMoveList)
 pub trait MoveAdder {
     fn add_captures(&mut self, from: Square, targets: BB);
     fn add_non_captures(&mut self, from: Square, targets: BB);
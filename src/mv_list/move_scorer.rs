@@ -0,0 +1,113 @@
+use crate::mv::Move;
+use crate::piece::*;
+use crate::square::Square;
+
+/// MoveScorer gives the ordering score SortedMoveAdder sorts each move by. Higher scores are
+/// tried first
+///
+/// Every method has a default implementing the ordering described on SortedMoveAdder, so an
+/// engine only overrides the ones it wants to change, eg scoring quiet moves with history and
+/// killer tables or captures with SEE. Scorers that need the position or search state can hold
+/// references to them
+pub trait MoveScorer {
+    /// Capture (including en-passant) of a captured_kind piece by a mover_kind piece
+    fn capture(&self, _mv: Move, mover_kind: Kind, captured_kind: Kind) -> i16 {
+        debug_assert_ne!(captured_kind, KING);
+        100i16 + mvv_score(captured_kind) - mvv_score(mover_kind)
+    }
+
+    /// Non-capture by a piece other than a pawn. relative_to_sq is the destination from the
+    /// mover's side
+    fn non_capture(&self, _mv: Move, _mover_kind: Kind, relative_to_sq: Square) -> i16 {
+        let row = relative_to_sq.row();
+        // no bonus for moves to rank 1
+        if row == 0 {
+            return 0i16;
+        }
+        // +2 bonus compared to pawn moves
+        2i16 + (row as i16)
+    }
+
+    /// Pawn push other than a promotion. relative_to_sq is the destination from the mover's side
+    fn pawn_push(&self, _mv: Move, relative_to_sq: Square) -> i16 {
+        let row = relative_to_sq.row();
+
+        if row > 4 {
+            // bonus for moves to rank 6, 7, 8 (=row 5, 6, 7)
+            return row as i16 + 5i16;
+        }
+
+        row as i16
+    }
+
+    /// Promotion to promotion_kind without a capture
+    fn promotion(&self, _mv: Move, promotion_kind: Kind) -> i16 {
+        promotion_score(promotion_kind)
+    }
+
+    /// Promotion to promotion_kind capturing a captured_kind piece
+    fn capture_promotion(&self, _mv: Move, _captured_kind: Kind, promotion_kind: Kind) -> i16 {
+        100i16 + promotion_score(promotion_kind)
+    }
+
+    fn castle(&self, _mv: Move) -> i16 {
+        20i16
+    }
+}
+
+/// DefaultMoveScorer orders moves by MVV-LVA and destination rank as described on
+/// SortedMoveAdder
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DefaultMoveScorer;
+
+impl MoveScorer for DefaultMoveScorer {}
+
+// Most-valuable-victim / least-valuable-aggresor piece scores
+fn mvv_score(kind: Kind) -> i16 {
+    const MVV_SCORE: [i16; 6] = [
+        3, // Bishop
+        5, // Queen
+        4, // Rook
+        2, // Knight
+        0, // Pawn
+        1, // King
+    ];
+    MVV_SCORE[kind.to_usize()]
+}
+
+// reason: calculating rook and bishop promotions almost never gives
+// an advantage over queen and knight promotions
+fn promotion_score(kind: Kind) -> i16 {
+    if kind == QUEEN {
+        50i16
+    } else if kind == KNIGHT {
+        25i16
+    } else if kind == ROOK {
+        -200i16
+    } else {
+        -250i16
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mv::NULL_MOVE;
+    use crate::square::*;
+
+    #[test]
+    fn test_default_scores() {
+        let scorer = DefaultMoveScorer;
+
+        assert_eq!(scorer.capture(NULL_MOVE, PAWN, QUEEN), 105);
+        assert_eq!(scorer.capture(NULL_MOVE, QUEEN, PAWN), 95);
+        assert_eq!(scorer.non_capture(NULL_MOVE, KNIGHT, A1), 0);
+        assert_eq!(scorer.non_capture(NULL_MOVE, KNIGHT, C3), 4);
+        assert_eq!(scorer.pawn_push(NULL_MOVE, C4), 3);
+        assert_eq!(scorer.pawn_push(NULL_MOVE, C7), 11);
+        assert_eq!(scorer.promotion(NULL_MOVE, QUEEN), 50);
+        assert_eq!(scorer.promotion(NULL_MOVE, BISHOP), -250);
+        assert_eq!(scorer.capture_promotion(NULL_MOVE, ROOK, KNIGHT), 125);
+        assert_eq!(scorer.castle(NULL_MOVE), 20);
+    }
+}
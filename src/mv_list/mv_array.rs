@@ -0,0 +1,308 @@
+use crate::bb::{BB, END_ROWS};
+use crate::castle::Castle;
+use crate::mv::{Move, NULL_MOVE};
+use crate::mv_list::MoveAdder;
+use crate::piece::*;
+use crate::square::{Square, SquareInternal};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Capacity of MoveArray. No legal position has more than 218 moves
+pub const MAX_MOVES: usize = 256;
+
+/// MoveArray implements MoveAdder and collects moves in a fixed-capacity array on the stack.
+/// Use it instead of MoveVec to avoid a heap allocation per generated move list
+#[derive(Clone)]
+pub struct MoveArray {
+    moves: [Move; MAX_MOVES],
+    len: usize,
+}
+
+impl fmt::Display for MoveArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.iter()
+                .map(|mv: &Move| mv.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+impl fmt::Debug for MoveArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl MoveAdder for MoveArray {
+    fn add_captures(&mut self, from: Square, targets: BB) {
+        self.insert_moves(from, targets, Move::new_capture);
+    }
+
+    fn add_non_captures(&mut self, from: Square, targets: BB) {
+        self.insert_moves(from, targets, Move::new_push);
+    }
+
+    fn add_castle(&mut self, castle: Castle) {
+        self.push(Move::new_castle(castle));
+    }
+
+    fn add_pawn_ep_capture(&mut self, from: Square, to: Square) {
+        self.push(Move::new_ep_capture(from, to));
+    }
+
+    fn add_pawn_pushes(&mut self, shift: usize, targets: BB) {
+        self.insert_promos_by_shift(shift, targets & END_ROWS, Move::new_promotion);
+        self.insert_moves_by_shift(shift, targets & !END_ROWS, Move::new_push);
+    }
+
+    fn add_pawn_captures(&mut self, shift: usize, targets: BB) {
+        self.insert_promos_by_shift(shift, targets & END_ROWS, Move::new_capture_promotion);
+        self.insert_moves_by_shift(shift, targets & !END_ROWS, Move::new_capture);
+    }
+}
+
+impl Default for MoveArray {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> IntoIterator for &'a MoveArray {
+    type Item = &'a Move;
+    type IntoIter = core::slice::Iter<'a, Move>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for MoveArray {
+    type Item = Move;
+    type IntoIter = MoveArrayIntoIter;
+
+    fn into_iter(self) -> MoveArrayIntoIter {
+        MoveArrayIntoIter {
+            array: self,
+            index: 0,
+        }
+    }
+}
+
+/// Iterates over the moves of a MoveArray by value
+pub struct MoveArrayIntoIter {
+    array: MoveArray,
+    index: usize,
+}
+
+impl Iterator for MoveArrayIntoIter {
+    type Item = Move;
+
+    fn next(&mut self) -> Option<Move> {
+        let mv = self.array.as_slice().get(self.index).copied();
+        self.index += 1;
+        mv
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.array.len().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for MoveArrayIntoIter {}
+
+impl MoveArray {
+    pub fn new() -> MoveArray {
+        MoveArray {
+            moves: [NULL_MOVE; MAX_MOVES],
+            len: 0,
+        }
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, Move> {
+        self.as_slice().iter()
+    }
+
+    pub fn as_slice(&self) -> &[Move] {
+        &self.moves[..self.len]
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [Move] {
+        &mut self.moves[..self.len]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, mv: Move) {
+        debug_assert!(self.len < MAX_MOVES);
+        self.moves[self.len] = mv;
+        self.len += 1;
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.as_mut_slice().swap(a, b);
+    }
+
+    /// Keeps only the moves for which keep returns true, preserving their order
+    pub fn retain<F: FnMut(Move) -> bool>(&mut self, mut keep: F) {
+        let mut kept = 0;
+        for i in 0..self.len {
+            let mv = self.moves[i];
+            if keep(mv) {
+                self.moves[kept] = mv;
+                kept += 1;
+            }
+        }
+        self.len = kept;
+    }
+
+    /// Swaps the move with the highest key among those at index start or later into start and
+    /// returns it. Calling this for start = 0, 1, 2... is a selection sort that can stop early,
+    /// which is cheaper than sorting the whole list when a search usually cuts off after a few
+    /// moves
+    pub fn select_best_by_key<K: Ord, F: Fn(Move) -> K>(
+        &mut self,
+        start: usize,
+        key: F,
+    ) -> Option<Move> {
+        if start >= self.len {
+            return None;
+        }
+
+        let mut best = start;
+        let mut best_key = key(self.moves[start]);
+        for i in (start + 1)..self.len {
+            let k = key(self.moves[i]);
+            if k > best_key {
+                best = i;
+                best_key = k;
+            }
+        }
+
+        self.moves.swap(start, best);
+        Some(self.moves[start])
+    }
+
+    fn insert_moves<F: Fn(Square, Square) -> Move>(&mut self, from: Square, targets: BB, f: F) {
+        for (to, _) in targets.iter() {
+            self.push(f(from, to));
+        }
+    }
+
+    fn insert_moves_by_shift<F: Fn(Square, Square) -> Move>(
+        &mut self,
+        shift: usize,
+        targets: BB,
+        f: F,
+    ) {
+        for (to, _) in targets.iter() {
+            let from = to.rotate_right(shift as SquareInternal);
+            self.push(f(from, to));
+        }
+    }
+
+    fn insert_promos_by_shift<F: Fn(Square, Square, Kind) -> Move>(
+        &mut self,
+        shift: usize,
+        targets: BB,
+        f: F,
+    ) {
+        for (to, _) in targets.iter() {
+            let from = to.rotate_right(shift as SquareInternal);
+            self.push(f(from, to, QUEEN));
+            self.push(f(from, to, KNIGHT));
+            self.push(f(from, to, BISHOP));
+            self.push(f(from, to, ROOK));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generation::*;
+    use crate::mv_list::MoveVec;
+    use crate::position::*;
+    use crate::square::*;
+
+    #[test]
+    fn test_move_array() {
+        let position = &Position::from_fen(STARTING_POSITION_FEN).unwrap();
+        let mut list = MoveArray::new();
+
+        legal_moves(position, &mut list);
+
+        assert_eq!(list.len(), 20);
+    }
+
+    #[test]
+    fn test_same_moves_as_move_vec() {
+        let position =
+            &Position::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -")
+                .unwrap();
+        let mut array = MoveArray::new();
+        let mut vec = MoveVec::new();
+
+        legal_moves(position, &mut array);
+        legal_moves(position, &mut vec);
+
+        assert_eq!(array.as_slice(), vec.iter().copied().collect::<Vec<Move>>());
+        assert_eq!(array.clone().into_iter().len(), 48);
+    }
+
+    #[test]
+    fn test_max_moves() {
+        // 218 legal moves, the most known for any position
+        let position =
+            &Position::from_fen("R6R/3Q4/1Q4Q1/4Q3/2Q4Q/Q4Q2/pp1Q4/kBNN1KB1 w - - 0 1").unwrap();
+        let mut list = MoveArray::new();
+
+        legal_moves(position, &mut list);
+
+        assert_eq!(list.len(), 218);
+    }
+
+    #[test]
+    fn test_retain() {
+        let position = &Position::from_fen(STARTING_POSITION_FEN).unwrap();
+        let mut list = MoveArray::new();
+        legal_moves(position, &mut list);
+
+        list.retain(|mv| mv.from() == B1 || mv.from() == G1);
+
+        assert_eq!(list.len(), 4);
+        assert!(list.iter().all(|mv| mv.from() == B1 || mv.from() == G1));
+    }
+
+    #[test]
+    fn test_select_best_by_key() {
+        let position = &Position::from_fen(STARTING_POSITION_FEN).unwrap();
+        let mut list = MoveArray::new();
+        legal_moves(position, &mut list);
+
+        let mut selected = Vec::new();
+        let mut i = 0;
+        while let Some(mv) = list.select_best_by_key(i, |mv| mv.to().to_usize()) {
+            selected.push(mv.to().to_usize());
+            i += 1;
+        }
+
+        assert_eq!(selected.len(), 20);
+        assert!(selected.windows(2).all(|pair| pair[0] >= pair[1]));
+    }
+}
@@ -2,7 +2,7 @@ use crate::bb::{BB, END_ROWS};
 use crate::castle::Castle;
 use crate::mv_list::MoveAdder;
 use crate::square::Square;
-use std::ops;
+use core::ops;
 
 /// MoveCounter implements MoveAdder and keeps a count of different types of moves added to it.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
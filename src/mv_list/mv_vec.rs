@@ -4,8 +4,9 @@ use crate::mv::Move;
 use crate::mv_list::MoveAdder;
 use crate::piece::*;
 use crate::square::{Square, SquareInternal};
-use std;
-use std::fmt;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
 
 /// MoveVec implements MoveAdder and collects moves in a vector.
 /// Use `iter` to access the moves once they have been added.
@@ -81,7 +82,7 @@ impl MoveVec {
         }
     }
 
-    pub fn iter(&self) -> std::slice::Iter<Move> {
+    pub fn iter(&self) -> core::slice::Iter<Move> {
         self.moves.iter()
     }
 
@@ -2,6 +2,7 @@ use crate::castle::Castle;
 use crate::castle::*;
 use crate::piece::*;
 use crate::square::*;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
 
 /// PieceSquareTable gives scores for pieces on squares from the perspective
 /// of white. See https://www.chessprogramming.org/Piece-Square_Tables
@@ -49,3 +50,129 @@ impl PieceSquareTable {
         }
     }
 }
+
+/// Game phase of the starting position. Knights and bishops count 1, rooks 2 and queens 4
+pub const MAX_PHASE: i32 = 24;
+
+/// Midgame and endgame scores, interpolated by game phase into a single score
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TaperedScore {
+    pub mg: i16,
+    pub eg: i16,
+}
+
+impl TaperedScore {
+    pub const fn new(mg: i16, eg: i16) -> TaperedScore {
+        TaperedScore { mg, eg }
+    }
+
+    /// Blends the midgame and endgame scores, phase runs from 0 (endgame) to MAX_PHASE (midgame)
+    pub fn interpolate(self, phase: i32) -> i16 {
+        let phase = phase.clamp(0, MAX_PHASE);
+        ((self.mg as i32 * phase + self.eg as i32 * (MAX_PHASE - phase)) / MAX_PHASE) as i16
+    }
+}
+
+impl Add for TaperedScore {
+    type Output = TaperedScore;
+
+    fn add(self, other: TaperedScore) -> TaperedScore {
+        TaperedScore::new(self.mg + other.mg, self.eg + other.eg)
+    }
+}
+
+impl AddAssign for TaperedScore {
+    fn add_assign(&mut self, other: TaperedScore) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for TaperedScore {
+    type Output = TaperedScore;
+
+    fn sub(self, other: TaperedScore) -> TaperedScore {
+        TaperedScore::new(self.mg - other.mg, self.eg - other.eg)
+    }
+}
+
+impl SubAssign for TaperedScore {
+    fn sub_assign(&mut self, other: TaperedScore) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for TaperedScore {
+    type Output = TaperedScore;
+
+    fn neg(self) -> TaperedScore {
+        TaperedScore::new(-self.mg, -self.eg)
+    }
+}
+
+/// TaperedPieceSquareTable holds separate midgame and endgame piece-square tables, see
+/// https://www.chessprogramming.org/Tapered_Eval
+#[derive(Clone)]
+pub struct TaperedPieceSquareTable {
+    mg: PieceSquareTable,
+    eg: PieceSquareTable,
+}
+
+impl TaperedPieceSquareTable {
+    /// Values encode material and positional value like PieceSquareTable::new
+    pub fn new(
+        mg_piece_square_values: [[i16; 64]; 6],
+        eg_piece_square_values: [[i16; 64]; 6],
+    ) -> TaperedPieceSquareTable {
+        TaperedPieceSquareTable {
+            mg: PieceSquareTable::new(mg_piece_square_values),
+            eg: PieceSquareTable::new(eg_piece_square_values),
+        }
+    }
+
+    pub fn mg(&self) -> &PieceSquareTable {
+        &self.mg
+    }
+
+    pub fn eg(&self) -> &PieceSquareTable {
+        &self.eg
+    }
+
+    /// Score relative to white, see PieceSquareTable::score
+    pub fn score(&self, kind: Kind, sq: Square) -> TaperedScore {
+        TaperedScore::new(self.mg.score(kind, sq), self.eg.score(kind, sq))
+    }
+
+    pub fn castle_score(&self, castle: Castle) -> TaperedScore {
+        TaperedScore::new(self.mg.castle_score(castle), self.eg.castle_score(castle))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_interpolate() {
+        let score = TaperedScore::new(100, 20);
+
+        assert_eq!(score.interpolate(MAX_PHASE), 100);
+        assert_eq!(score.interpolate(0), 20);
+        assert_eq!(score.interpolate(MAX_PHASE / 2), 60);
+        assert_eq!(score.interpolate(MAX_PHASE + 8), 100);
+    }
+
+    #[test]
+    fn test_tapered_castle_score() {
+        let mut mg = [[0i16; 64]; 6];
+        let mut eg = [[0i16; 64]; 6];
+        mg[KING.to_usize()][G1.to_usize()] = 30;
+        eg[KING.to_usize()][G1.to_usize()] = -10;
+        let table = TaperedPieceSquareTable::new(mg, eg);
+
+        assert_eq!(table.castle_score(KING_SIDE), TaperedScore::new(30, -10));
+        assert_eq!(
+            table.score(KING, G1) - table.score(KING, E1),
+            TaperedScore::new(30, -10)
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use crate::mv::{Move, MoveScore, NULL_MOVE};
+use crate::mv_list::{MAX_MOVES, ScoredMoves, SortedMoveHeapItem};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+#[allow(dead_code)]
+const EMPTY_ITEM: SortedMoveHeapItem = SortedMoveHeapItem::new(MoveScore::new(NULL_MOVE, 0), 0);
+
+/// ScoredMoveList stores the moves of a SortedMoveAdder in a fixed-capacity array and selects
+/// the best remaining move lazily. Unlike SortedMoveHeap nothing is sorted up front, so a search
+/// that cuts off after the first few moves only pays for the moves it looks at
+#[allow(dead_code)]
+#[derive(Clone)]
+pub struct ScoredMoveList {
+    items: [SortedMoveHeapItem; MAX_MOVES],
+    len: usize,
+    cursor: usize,
+}
+
+impl fmt::Display for ScoredMoveList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.iter()
+                .map(|item| item.move_score().to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+impl fmt::Debug for ScoredMoveList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl ScoredMoves for ScoredMoveList {
+    fn push(&mut self, item: SortedMoveHeapItem) {
+        debug_assert!(self.len < MAX_MOVES);
+        self.items[self.len] = item;
+        self.len += 1;
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn items(&self) -> core::slice::Iter<'_, SortedMoveHeapItem> {
+        self.iter()
+    }
+}
+
+impl Default for ScoredMoveList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(dead_code)]
+impl ScoredMoveList {
+    pub fn new() -> ScoredMoveList {
+        ScoredMoveList {
+            items: [EMPTY_ITEM; MAX_MOVES],
+            len: 0,
+            cursor: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of moves not yet returned by next_best
+    pub fn remaining(&self) -> usize {
+        self.len - self.cursor
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.cursor = 0;
+    }
+
+    /// Starts selecting from the best move again, keeping the moves already stored
+    pub fn reset(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// All stored moves, those already returned by next_best first in the order they were returned
+    pub fn iter(&self) -> core::slice::Iter<'_, SortedMoveHeapItem> {
+        self.items[..self.len].iter()
+    }
+
+    /// Returns the remaining move with the highest ordering score. Ties keep insertion order
+    pub fn next_best(&mut self) -> Option<SortedMoveHeapItem> {
+        if self.cursor >= self.len {
+            return None;
+        }
+
+        let mut best = self.cursor;
+        for i in (self.cursor + 1)..self.len {
+            if self.items[i].ordering_score() > self.items[best].ordering_score() {
+                best = i;
+            }
+        }
+
+        self.items[self.cursor..=best].rotate_right(1);
+        let item = self.items[self.cursor];
+        self.cursor += 1;
+        Some(item)
+    }
+
+    /// Moves mv ahead of every other remaining move, eg for the hash move. Returns false if mv
+    /// is not among the remaining moves
+    pub fn prioritise(&mut self, mv: Move) -> bool {
+        match self.items[self.cursor..self.len]
+            .iter()
+            .position(|item| item.move_score().mv() == mv)
+        {
+            Some(i) => {
+                self.items[self.cursor + i].set_ordering_score(i16::MAX);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Drains the remaining moves best first
+    pub fn into_sorted_vec(mut self) -> Vec<MoveScore> {
+        let mut sorted = Vec::with_capacity(self.remaining());
+        while let Some(item) = self.next_best() {
+            sorted.push(*item.move_score());
+        }
+        sorted
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generation::*;
+    use crate::mv_list::{PieceSquareTable, SortedMoveAdder, SortedMoveHeap};
+    use crate::position::*;
+
+    const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -";
+
+    fn scored_moves(fen: &str) -> (ScoredMoveList, SortedMoveHeap) {
+        let position = &Position::from_fen(fen).unwrap();
+        let piece_square_table = PieceSquareTable::new([[100i16; 64]; 6]);
+
+        let mut list = ScoredMoveList::new();
+        let mut adder = SortedMoveAdder::new(
+            &piece_square_table,
+            position.grid(),
+            position.state().stm,
+            &mut list,
+        );
+        legal_moves(position, &mut adder);
+
+        let mut heap = SortedMoveHeap::new(64);
+        let mut adder = SortedMoveAdder::new(
+            &piece_square_table,
+            position.grid(),
+            position.state().stm,
+            &mut heap,
+        );
+        legal_moves(position, &mut adder);
+
+        (list, heap)
+    }
+
+    #[test]
+    fn test_scored_move_list() {
+        let (list, _) = scored_moves(STARTING_POSITION_FEN);
+
+        assert_eq!(list.len(), 20);
+        assert_eq!(list.remaining(), 20);
+    }
+
+    #[test]
+    fn test_same_order_as_heap() {
+        let (list, heap) = scored_moves(KIWIPETE);
+
+        let ordering = |items: Vec<SortedMoveHeapItem>| -> Vec<i16> {
+            items.iter().map(|item| *item.ordering_score()).collect()
+        };
+        let mut list_items = Vec::new();
+        let mut list = list;
+        while let Some(item) = list.next_best() {
+            list_items.push(item);
+        }
+
+        assert_eq!(list_items.len(), 48);
+        assert_eq!(ordering(list_items), ordering(heap.iter().collect()));
+    }
+
+    #[test]
+    fn test_prioritise() {
+        let (mut list, _) = scored_moves(KIWIPETE);
+        let worst = *list.clone().into_sorted_vec().last().unwrap();
+
+        assert!(list.prioritise(worst.mv()));
+        assert_eq!(list.next_best().unwrap().move_score().mv(), worst.mv());
+        assert!(!list.prioritise(worst.mv()));
+        assert_eq!(list.remaining(), 47);
+
+        list.reset();
+        assert_eq!(list.remaining(), 48);
+        assert_eq!(list.next_best().unwrap().move_score().mv(), worst.mv());
+    }
+}
@@ -1,3 +1,4 @@
+use super::move_scorer::{DefaultMoveScorer, MoveScorer};
 use super::piece_square_table::PieceSquareTable;
 use crate::bb::{BB, EMPTY, END_ROWS};
 use crate::castle::Castle;
@@ -6,9 +7,11 @@ use crate::mv_list::MoveAdder;
 use crate::piece::*;
 use crate::side::Side;
 use crate::square::*;
-use std::cmp::Ordering;
-use std::collections::BinaryHeap;
-use std::fmt;
+use alloc::collections::BinaryHeap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
 
 /// SortedMoveAdder collects moves including the piece-square score for making the move
 /// Moves are sorted by an 'ordering score' and stored in a provided SortedMoveHeap
@@ -27,7 +30,7 @@ use std::fmt;
 ///     Rook: 4
 ///     Queen: 5
 ///
-/// Move ordering priorities:
+/// Ordering scores come from a MoveScorer, by default DefaultMoveScorer with priorities:
 /// -250: promotion to bishop
 /// -200: promotion to rook
 /// -145..-145: capture and promotion to bishop (exact score based on MVV-LVA)
@@ -52,21 +55,24 @@ use std::fmt;
 /// 145..155: capture and promotion to queen (exact score based on MVV-LVA)
 ///
 /// Note: rook and bishop promotions are penalized because queen promotion is nearly always a better choice
-pub struct SortedMoveAdder<'a> {
-    moves: &'a mut SortedMoveHeap,
+///
+/// Moves are stored in a SortedMoveHeap by default, or in any other ScoredMoves such as
+/// ScoredMoveList
+pub struct SortedMoveAdder<'a, L: ScoredMoves = SortedMoveHeap, S: MoveScorer = DefaultMoveScorer> {
+    moves: &'a mut L,
     piece_square_table: &'a PieceSquareTable,
     piece_grid: &'a [Piece; 64],
     stm: Side,
+    scorer: S,
 }
 
-impl<'a> fmt::Display for SortedMoveAdder<'a> {
+impl<'a, L: ScoredMoves, S: MoveScorer> fmt::Display for SortedMoveAdder<'a, L, S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
             "{}",
             self.moves
-                .0
-                .iter()
+                .items()
                 .map(|item: &SortedMoveHeapItem| item.move_score().to_string())
                 .collect::<Vec<String>>()
                 .join(", ")
@@ -74,14 +80,13 @@ impl<'a> fmt::Display for SortedMoveAdder<'a> {
     }
 }
 
-impl<'a> fmt::Debug for SortedMoveAdder<'a> {
+impl<'a, L: ScoredMoves, S: MoveScorer> fmt::Debug for SortedMoveAdder<'a, L, S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
             "{}",
             self.moves
-                .0
-                .iter()
+                .items()
                 .map(|item: &SortedMoveHeapItem| item.move_score().to_string())
                 .collect::<Vec<String>>()
                 .join(", ")
@@ -89,7 +94,7 @@ impl<'a> fmt::Debug for SortedMoveAdder<'a> {
     }
 }
 
-impl<'a> MoveAdder for SortedMoveAdder<'a> {
+impl<'a, L: ScoredMoves, S: MoveScorer> MoveAdder for SortedMoveAdder<'a, L, S> {
     fn add_captures(&mut self, from: Square, targets: BB) {
         if targets == EMPTY {
             return;
@@ -110,10 +115,11 @@ impl<'a> MoveAdder for SortedMoveAdder<'a> {
                 .piece_square_table
                 .score(capture_kind, to.from_side(stm.flip()));
 
+            let mv = Move::new_capture(from, to);
             self.insert(
-                Move::new_capture(from, to),
+                mv,
                 -from_score + to_score + capture_score,
-                self.capture_ordering_score(from_kind, capture_kind),
+                self.scorer.capture(mv, from_kind, capture_kind),
             );
         }
     }
@@ -134,21 +140,19 @@ impl<'a> MoveAdder for SortedMoveAdder<'a> {
             let relative_to_sq = to.from_side(stm);
             let to_score = self.piece_square_table.score(from_kind, relative_to_sq);
 
+            let mv = Move::new_push(from, to);
             self.insert(
-                Move::new_push(from, to),
+                mv,
                 -from_score + to_score,
-                self.non_capture_ordering_score(relative_to_sq),
+                self.scorer.non_capture(mv, from_kind, relative_to_sq),
             );
         }
     }
 
     fn add_castle(&mut self, castle: Castle) {
         let score = self.piece_square_table.castle_score(castle);
-        self.insert(
-            Move::new_castle(castle),
-            score,
-            self.castle_ordering_score(),
-        );
+        let mv = Move::new_castle(castle);
+        self.insert(mv, score, self.scorer.castle(mv));
     }
 
     fn add_pawn_ep_capture(&mut self, from: Square, to: Square) {
@@ -165,11 +169,8 @@ impl<'a> MoveAdder for SortedMoveAdder<'a> {
 
         let score = -from_score + to_score + capture_score;
 
-        self.insert(
-            Move::new_ep_capture(from, to),
-            score,
-            self.capture_ordering_score(PAWN, PAWN),
-        );
+        let mv = Move::new_ep_capture(from, to);
+        self.insert(mv, score, self.scorer.capture(mv, PAWN, PAWN));
     }
 
     fn add_pawn_pushes(&mut self, shift: usize, targets: BB) {
@@ -184,13 +185,10 @@ impl<'a> MoveAdder for SortedMoveAdder<'a> {
             let relative_to_sq = to.from_side(stm);
             let to_score = self.piece_square_table.score(from_kind, relative_to_sq);
 
-            let move_ordering_score = self.pawn_non_capture_ordering_score(relative_to_sq);
+            let mv = Move::new_push(from, to);
+            let move_ordering_score = self.scorer.pawn_push(mv, relative_to_sq);
 
-            self.insert(
-                Move::new_push(from, to),
-                -from_score + to_score,
-                move_ordering_score,
-            );
+            self.insert(mv, -from_score + to_score, move_ordering_score);
         }
 
         for (to, _) in (targets & END_ROWS).iter() {
@@ -199,13 +197,14 @@ impl<'a> MoveAdder for SortedMoveAdder<'a> {
                 .piece_square_table
                 .score(from_kind, from.from_side(stm));
 
-            for (to_kind, move_ordering_score) in &Self::PROMOTION_ORDERING_SCORES {
-                let to_score = self.piece_square_table.score(*to_kind, to.from_side(stm));
+            for to_kind in PROMOTION_KINDS {
+                let to_score = self.piece_square_table.score(to_kind, to.from_side(stm));
+                let mv = Move::new_promotion(from, to, to_kind);
 
                 self.insert(
-                    Move::new_promotion(from, to, *to_kind),
+                    mv,
                     -from_score + to_score,
-                    *move_ordering_score,
+                    self.scorer.promotion(mv, to_kind),
                 );
             }
         }
@@ -227,10 +226,11 @@ impl<'a> MoveAdder for SortedMoveAdder<'a> {
                 .piece_square_table
                 .score(capture_kind, to.from_side(stm.flip()));
 
-            let move_ordering_score = self.capture_ordering_score(PAWN, capture_kind);
+            let mv = Move::new_capture(from, to);
+            let move_ordering_score = self.scorer.capture(mv, PAWN, capture_kind);
 
             self.insert(
-                Move::new_capture(from, to),
+                mv,
                 -from_score + to_score + capture_score,
                 move_ordering_score,
             );
@@ -247,13 +247,14 @@ impl<'a> MoveAdder for SortedMoveAdder<'a> {
                 .piece_square_table
                 .score(capture_kind, to.from_side(stm.flip()));
 
-            for (to_kind, promo_move_ordering_score) in &Self::PROMOTION_ORDERING_SCORES {
-                let to_score = self.piece_square_table.score(*to_kind, to.from_side(stm));
+            for to_kind in PROMOTION_KINDS {
+                let to_score = self.piece_square_table.score(to_kind, to.from_side(stm));
+                let mv = Move::new_capture_promotion(from, to, to_kind);
 
-                let move_ordering_score = 100i16 + promo_move_ordering_score;
+                let move_ordering_score = self.scorer.capture_promotion(mv, capture_kind, to_kind);
 
                 self.insert(
-                    Move::new_capture_promotion(from, to, *to_kind),
+                    mv,
                     -from_score + to_score + capture_score,
                     move_ordering_score,
                 );
@@ -262,29 +263,43 @@ impl<'a> MoveAdder for SortedMoveAdder<'a> {
     }
 }
 
-impl<'a> SortedMoveAdder<'a> {
-    // Maps promotion target pieces to base move ordering score
-    // reason: calculating rook and bishop promotions almost never gives
-    // an advantage over queen and knight promotions
-    const PROMOTION_ORDERING_SCORES: [(Kind, i16); 4] = [
-        (QUEEN, 50i16),
-        (KNIGHT, 25i16),
-        (ROOK, -200i16),
-        (BISHOP, -250i16),
-    ];
+// Promotions are inserted in this order
+const PROMOTION_KINDS: [Kind; 4] = [QUEEN, KNIGHT, ROOK, BISHOP];
 
+impl<'a, L: ScoredMoves> SortedMoveAdder<'a, L> {
     #[allow(dead_code)]
     pub fn new(
         piece_square_table: &'a PieceSquareTable,
         piece_grid: &'a [Piece; 64],
         stm: Side,
-        moves: &'a mut SortedMoveHeap,
-    ) -> SortedMoveAdder<'a> {
+        moves: &'a mut L,
+    ) -> SortedMoveAdder<'a, L> {
+        SortedMoveAdder::with_scorer(
+            piece_square_table,
+            piece_grid,
+            stm,
+            moves,
+            DefaultMoveScorer,
+        )
+    }
+}
+
+impl<'a, L: ScoredMoves, S: MoveScorer> SortedMoveAdder<'a, L, S> {
+    /// Like new but orders moves with scorer instead of DefaultMoveScorer
+    #[allow(dead_code)]
+    pub fn with_scorer(
+        piece_square_table: &'a PieceSquareTable,
+        piece_grid: &'a [Piece; 64],
+        stm: Side,
+        moves: &'a mut L,
+        scorer: S,
+    ) -> SortedMoveAdder<'a, L, S> {
         SortedMoveAdder {
             moves,
             piece_square_table,
             piece_grid,
             stm,
+            scorer,
         }
     }
 
@@ -300,63 +315,30 @@ impl<'a> SortedMoveAdder<'a> {
 
     fn insert(&mut self, mv: Move, piece_square_score: i16, move_ordering_score: i16) {
         let item =
-            SortedMoveHeapItem((MoveScore::new(mv, piece_square_score), move_ordering_score));
+            SortedMoveHeapItem::new(MoveScore::new(mv, piece_square_score), move_ordering_score);
 
         self.moves.push(item);
     }
-
-    // Captures are order based on
-    // most-valuable-victim - least-valuable-attacker
-    fn capture_ordering_score(&self, mover_kind: Kind, capture_kind: Kind) -> i16 {
-        debug_assert_ne!(capture_kind, KING);
-        100i16 + self.mvv_score(capture_kind) - self.mvv_score(mover_kind)
-    }
-
-    fn mvv_score(&self, kind: Kind) -> i16 {
-        const MVV_SCORE: [i16; 6] = [
-            3, // Bishop
-            5, // Queen
-            4, // Rook
-            2, // Knight
-            0, // Pawn
-            1, // King
-        ];
-        MVV_SCORE[kind.to_usize()]
-    }
-
-    fn non_capture_ordering_score(&self, relative_to_sq: Square) -> i16 {
-        let row = relative_to_sq.row();
-        // no bonus for moves to rank 1
-        if row == 0 {
-            return 0i16;
-        }
-        // +2 bonus compared to pawn moves
-        2i16 + (row as i16)
-    }
-
-    fn pawn_non_capture_ordering_score(&self, relative_to_sq: Square) -> i16 {
-        let row = relative_to_sq.row();
-
-        if row > 4 {
-            // bonus for moves to rank 6, 7, 8 (=row 5, 6, 7)
-            return row as i16 + 5i16;
-        }
-
-        row as i16
-    }
-
-    fn castle_ordering_score(&self) -> i16 {
-        20i16
-    }
 }
 
+/// A move with its piece-square score and the ordering score it is sorted by
+#[derive(Copy, Clone)]
 pub struct SortedMoveHeapItem((MoveScore, i16));
 
 impl SortedMoveHeapItem {
-    fn ordering_score(&self) -> &i16 {
+    pub const fn new(move_score: MoveScore, ordering_score: i16) -> SortedMoveHeapItem {
+        SortedMoveHeapItem((move_score, ordering_score))
+    }
+
+    pub fn ordering_score(&self) -> &i16 {
         &self.0.1
     }
 
+    #[allow(dead_code)]
+    pub fn set_ordering_score(&mut self, ordering_score: i16) {
+        self.0.1 = ordering_score;
+    }
+
     pub fn move_score(&self) -> &MoveScore {
         &self.0.0
     }
@@ -382,8 +364,37 @@ impl Ord for SortedMoveHeapItem {
     }
 }
 
+/// ScoredMoves stores the moves collected by a SortedMoveAdder
+pub trait ScoredMoves {
+    fn push(&mut self, item: SortedMoveHeapItem);
+
+    fn len(&self) -> usize;
+
+    #[allow(dead_code)]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Stored moves in no particular order
+    fn items(&self) -> core::slice::Iter<'_, SortedMoveHeapItem>;
+}
+
 pub struct SortedMoveHeap(BinaryHeap<SortedMoveHeapItem>);
 
+impl ScoredMoves for SortedMoveHeap {
+    fn push(&mut self, item: SortedMoveHeapItem) {
+        self.0.push(item);
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn items(&self) -> core::slice::Iter<'_, SortedMoveHeapItem> {
+        self.0.as_slice().iter()
+    }
+}
+
 impl SortedMoveHeap {
     #[allow(dead_code)]
     pub fn new(capacity: usize) -> SortedMoveHeap {
@@ -411,8 +422,10 @@ impl SortedMoveHeap {
     }
 
     #[allow(dead_code)]
-    pub fn iter(self) -> std::collections::binary_heap::IntoIterSorted<SortedMoveHeapItem> {
-        self.0.into_iter_sorted()
+    /// Pops moves best first
+    pub fn iter(self) -> impl Iterator<Item = SortedMoveHeapItem> {
+        let mut heap = self.0;
+        core::iter::from_fn(move || heap.pop())
     }
 
     #[allow(dead_code)]
@@ -528,6 +541,39 @@ mod test {
         assert_list_includes_moves(heap, &["b8xc6 (83)"]);
     }
 
+    #[test]
+    fn test_custom_scorer() {
+        // orders a killer move ahead of everything else
+        struct KillerScorer(Move);
+
+        impl MoveScorer for KillerScorer {
+            fn non_capture(&self, mv: Move, mover_kind: Kind, relative_to_sq: Square) -> i16 {
+                if mv == self.0 {
+                    return i16::MAX;
+                }
+                DefaultMoveScorer.non_capture(mv, mover_kind, relative_to_sq)
+            }
+        }
+
+        let position = &Position::from_fen(STARTING_POSITION_FEN).unwrap();
+        let piece_square_table = PieceSquareTable::new([[100i16; 64]; 6]);
+        let killer = Move::new_push(G1, H3);
+
+        let mut heap = SortedMoveHeap::new(32);
+        let mut list = SortedMoveAdder::with_scorer(
+            &piece_square_table,
+            position.grid(),
+            position.state().stm,
+            &mut heap,
+            KillerScorer(killer),
+        );
+
+        legal_moves(position, &mut list);
+
+        assert_eq!(heap.len(), 20);
+        assert_eq!(heap.peek().unwrap().move_score().mv(), killer);
+    }
+
     #[test]
     fn test_integrity() {
         // makes 30 random moves, and checks that adding the move scores adds up to the
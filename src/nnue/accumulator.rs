@@ -0,0 +1,305 @@
+use crate::mv::Move;
+use crate::nnue::{FeatureSet, FeatureUpdate};
+use crate::piece::Piece;
+use crate::position::Position;
+use crate::side::{BLACK, Side, WHITE};
+use crate::square::Square;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// AccumulatorOps adds feature transformer rows to accumulator values and subtracts them.
+/// Values wrap on overflow so every implementation gives the same result
+pub trait AccumulatorOps {
+    fn add(values: &mut [i16], row: &[i16]);
+    fn sub(values: &mut [i16], row: &[i16]);
+}
+
+/// Reference implementation, one value at a time
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ScalarOps;
+
+impl AccumulatorOps for ScalarOps {
+    #[inline(always)]
+    fn add(values: &mut [i16], row: &[i16]) {
+        for (value, &weight) in values.iter_mut().zip(row) {
+            *value = value.wrapping_add(weight);
+        }
+    }
+
+    #[inline(always)]
+    fn sub(values: &mut [i16], row: &[i16]) {
+        for (value, &weight) in values.iter_mut().zip(row) {
+            *value = value.wrapping_sub(weight);
+        }
+    }
+}
+
+/// Portable SIMD on nightly, 16 values per vector with any remainder done one at a time
+#[cfg(feature = "nightly")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SimdOps;
+
+#[cfg(feature = "nightly")]
+impl SimdOps {
+    const LANES: usize = 16;
+
+    #[inline(always)]
+    fn zip(
+        values: &mut [i16],
+        row: &[i16],
+        op: impl Fn(core::simd::i16x16, core::simd::i16x16) -> core::simd::i16x16,
+        scalar: impl Fn(&mut [i16], &[i16]),
+    ) {
+        use core::simd::i16x16;
+
+        let mut values_chunks = values.chunks_exact_mut(Self::LANES);
+        let mut row_chunks = row.chunks_exact(Self::LANES);
+        for (v, r) in (&mut values_chunks).zip(&mut row_chunks) {
+            op(i16x16::from_slice(v), i16x16::from_slice(r)).copy_to_slice(v);
+        }
+        scalar(values_chunks.into_remainder(), row_chunks.remainder());
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl AccumulatorOps for SimdOps {
+    #[inline(always)]
+    fn add(values: &mut [i16], row: &[i16]) {
+        Self::zip(values, row, |v, r| v + r, ScalarOps::add);
+    }
+
+    #[inline(always)]
+    fn sub(values: &mut [i16], row: &[i16]) {
+        Self::zip(values, row, |v, r| v - r, ScalarOps::sub);
+    }
+}
+
+/// SimdOps with the nightly feature, ScalarOps otherwise
+#[cfg(feature = "nightly")]
+pub type DefaultOps = SimdOps;
+/// SimdOps with the nightly feature, ScalarOps otherwise
+#[cfg(not(feature = "nightly"))]
+pub type DefaultOps = ScalarOps;
+
+/// Accumulator holds the first layer output of both perspectives for one position
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Accumulator<const H: usize> {
+    values: [[i16; H]; 2],
+}
+
+impl<const H: usize> Default for Accumulator<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const H: usize> Accumulator<H> {
+    pub fn new() -> Accumulator<H> {
+        Accumulator {
+            values: [[0; H]; 2],
+        }
+    }
+
+    pub fn values(&self, perspective: Side) -> &[i16; H] {
+        &self.values[perspective.to_usize()]
+    }
+}
+
+/// FeatureTransformer is the first layer of the network: a bias plus one row of H weights
+/// per feature of F. It fills an Accumulator from scratch or updates it incrementally as moves
+/// are made
+pub struct FeatureTransformer<F: FeatureSet, const H: usize> {
+    biases: [i16; H],
+    // F::NUM_FEATURES rows of H weights
+    weights: Vec<i16>,
+    features: PhantomData<F>,
+}
+
+impl<F: FeatureSet, const H: usize> FeatureTransformer<F, H> {
+    pub fn new(weights: Vec<i16>, biases: [i16; H]) -> Result<FeatureTransformer<F, H>, String> {
+        if weights.len() != F::NUM_FEATURES * H {
+            return Err(format!(
+                "Expected {} weights, got {}",
+                F::NUM_FEATURES * H,
+                weights.len()
+            ));
+        }
+
+        Ok(FeatureTransformer {
+            biases,
+            weights,
+            features: PhantomData,
+        })
+    }
+
+    /// Weights of feature
+    pub fn row(&self, feature: usize) -> &[i16] {
+        &self.weights[feature * H..(feature + 1) * H]
+    }
+
+    /// Recomputes the values of perspective from the active features of position
+    pub fn refresh(
+        &self,
+        accumulator: &mut Accumulator<H>,
+        position: &Position,
+        perspective: Side,
+    ) {
+        self.refresh_with::<DefaultOps>(accumulator, position, perspective);
+    }
+
+    pub fn refresh_with<O: AccumulatorOps>(
+        &self,
+        accumulator: &mut Accumulator<H>,
+        position: &Position,
+        perspective: Side,
+    ) {
+        let values = &mut accumulator.values[perspective.to_usize()];
+        *values = self.biases;
+        F::active_features(position, perspective, |feature| {
+            O::add(values, self.row(feature))
+        });
+    }
+
+    /// Updates both perspectives of an accumulator for mv. position is the position after the
+    /// move and capture is what make returned
+    pub fn update(
+        &self,
+        accumulator: &mut Accumulator<H>,
+        position: &Position,
+        mv: Move,
+        capture: Option<(Piece, Square)>,
+    ) {
+        self.update_with::<DefaultOps>(accumulator, position, mv, capture);
+    }
+
+    pub fn update_with<O: AccumulatorOps>(
+        &self,
+        accumulator: &mut Accumulator<H>,
+        position: &Position,
+        mv: Move,
+        capture: Option<(Piece, Square)>,
+    ) {
+        for perspective in [WHITE, BLACK] {
+            match F::update(position, mv, capture, perspective) {
+                FeatureUpdate::Refresh => {
+                    self.refresh_with::<O>(accumulator, position, perspective)
+                }
+                FeatureUpdate::Delta(delta) => {
+                    let values = &mut accumulator.values[perspective.to_usize()];
+                    for &feature in delta.removed() {
+                        O::sub(values, self.row(feature));
+                    }
+                    for &feature in delta.added() {
+                        O::add(values, self.row(feature));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generation::legal_moves;
+    use crate::mv_list::MoveVec;
+    use crate::nnue::{HalfKA, HalfKP};
+    use alloc::vec;
+
+    const FENS: [&str; 4] = [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+        "r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+    ];
+
+    // Deterministic weights, large enough for some values to wrap
+    fn transformer<F: FeatureSet, const H: usize>() -> FeatureTransformer<F, H> {
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as i16
+        };
+        let weights = (0..F::NUM_FEATURES * H).map(|_| next()).collect();
+        let biases = core::array::from_fn(|_| next());
+        FeatureTransformer::new(weights, biases).unwrap()
+    }
+
+    fn refreshed<F: FeatureSet, O: AccumulatorOps, const H: usize>(
+        transformer: &FeatureTransformer<F, H>,
+        position: &Position,
+    ) -> Accumulator<H> {
+        let mut accumulator = Accumulator::new();
+        for side in [WHITE, BLACK] {
+            transformer.refresh_with::<O>(&mut accumulator, position, side);
+        }
+        accumulator
+    }
+
+    // Plays a fixed sequence of moves from each position checking the incrementally updated
+    // accumulator against one computed from scratch
+    fn assert_incremental<F: FeatureSet, O: AccumulatorOps>() {
+        let transformer = transformer::<F, 40>();
+
+        for fen in FENS {
+            let mut position = Position::from_fen(fen).unwrap();
+            let mut accumulator = refreshed::<F, O, 40>(&transformer, &position);
+
+            for ply in 0..60 {
+                let mut moves = MoveVec::new();
+                legal_moves(&position, &mut moves);
+                if moves.is_empty() {
+                    break;
+                }
+                let mv = moves
+                    .iter()
+                    .nth((ply * 7 + 3) % moves.len())
+                    .cloned()
+                    .unwrap();
+
+                let capture = position.make(mv);
+                transformer.update_with::<O>(&mut accumulator, &position, mv, capture);
+                assert_eq!(
+                    accumulator,
+                    refreshed::<F, O, 40>(&transformer, &position),
+                    "{} ply {}",
+                    fen,
+                    ply
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_incremental_updates() {
+        assert_incremental::<HalfKP, ScalarOps>();
+        assert_incremental::<HalfKA, ScalarOps>();
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_simd_matches_scalar() {
+        assert_incremental::<HalfKA, SimdOps>();
+
+        let transformer = transformer::<HalfKA, 40>();
+        for fen in FENS {
+            let position = Position::from_fen(fen).unwrap();
+            assert_eq!(
+                refreshed::<HalfKA, SimdOps, 40>(&transformer, &position),
+                refreshed::<HalfKA, ScalarOps, 40>(&transformer, &position)
+            );
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(
+            FeatureTransformer::<HalfKP, 8>::new(vec![0; HalfKP::NUM_FEATURES * 8], [0; 8]).is_ok()
+        );
+        assert!(FeatureTransformer::<HalfKP, 8>::new(vec![0; 8], [0; 8]).is_err());
+    }
+}
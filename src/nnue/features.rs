@@ -0,0 +1,293 @@
+use crate::castle::{castle_king_squares, castle_rook_squares};
+use crate::mv::Move;
+use crate::piece::*;
+use crate::position::Position;
+use crate::side::Side;
+use crate::square::Square;
+
+/// Most features one perspective gains or loses in a move: a capture promotion removes the pawn
+/// and the captured piece, and castling moves the king and the rook
+pub const MAX_FEATURE_CHANGES: usize = 2;
+
+/// FeatureSet maps the pieces of a position to the input features of a network, seen from the
+/// perspective of one side. Squares are flipped vertically for black so both perspectives see
+/// their own pieces moving north
+pub trait FeatureSet {
+    /// Number of distinct features, the number of rows of the feature transformer
+    const NUM_FEATURES: usize;
+    /// Most features active in any position
+    const MAX_ACTIVE: usize;
+
+    /// Index of the feature for pc on sq seen from perspective, whose king is on king_sq.
+    /// Returns None for pieces that are not features
+    fn feature(perspective: Side, king_sq: Square, pc: Piece, sq: Square) -> Option<usize>;
+
+    /// Calls add with every feature active in position from perspective
+    fn active_features(position: &Position, perspective: Side, mut add: impl FnMut(usize)) {
+        let king_sq = position.king_square(perspective);
+        for (idx, &pc) in position.grid().iter().enumerate() {
+            if pc.is_some()
+                && let Some(feature) = Self::feature(perspective, king_sq, pc, Square(idx))
+            {
+                add(feature);
+            }
+        }
+    }
+
+    /// Returns the features perspective gains and loses by mv, where position is the position
+    /// after the move and capture is what make returned. Moves of perspective's own king change
+    /// every feature so need a refresh
+    fn update(
+        position: &Position,
+        mv: Move,
+        capture: Option<(Piece, Square)>,
+        perspective: Side,
+    ) -> FeatureUpdate {
+        let mover_side = position.state().stm.flip();
+        let king_sq = position.king_square(perspective);
+        let feature = |pc: Piece, sq: Square| Self::feature(perspective, king_sq, pc, sq);
+        let mut delta = FeatureDelta::new();
+
+        if mv.is_castle() {
+            if perspective == mover_side {
+                return FeatureUpdate::Refresh;
+            }
+
+            let castle = mv.castle();
+            let (king_from, king_to) = castle_king_squares(mover_side, castle);
+            let (rook_from, rook_to) = castle_rook_squares(mover_side, castle);
+            delta.remove(feature(KING.pc(mover_side), king_from));
+            delta.remove(feature(ROOK.pc(mover_side), rook_from));
+            delta.add(feature(KING.pc(mover_side), king_to));
+            delta.add(feature(ROOK.pc(mover_side), rook_to));
+            return FeatureUpdate::Delta(delta);
+        }
+
+        let moved = position.at(mv.to());
+        if moved.kind() == KING && perspective == mover_side {
+            return FeatureUpdate::Refresh;
+        }
+
+        let mover = if mv.is_promotion() {
+            PAWN.pc(mover_side)
+        } else {
+            moved
+        };
+        delta.remove(feature(mover, mv.from()));
+        if let Some((captured, capture_sq)) = capture {
+            delta.remove(feature(captured, capture_sq));
+        }
+        delta.add(feature(moved, mv.to()));
+
+        FeatureUpdate::Delta(delta)
+    }
+}
+
+/// How the features of one perspective change in a move
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FeatureUpdate {
+    /// Every feature may have changed, recompute from the position
+    Refresh,
+    Delta(FeatureDelta),
+}
+
+/// Features added and removed by a move
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FeatureDelta {
+    added: [usize; MAX_FEATURE_CHANGES],
+    removed: [usize; MAX_FEATURE_CHANGES],
+    num_added: usize,
+    num_removed: usize,
+}
+
+impl Default for FeatureDelta {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FeatureDelta {
+    pub fn new() -> FeatureDelta {
+        FeatureDelta {
+            added: [0; MAX_FEATURE_CHANGES],
+            removed: [0; MAX_FEATURE_CHANGES],
+            num_added: 0,
+            num_removed: 0,
+        }
+    }
+
+    pub fn added(&self) -> &[usize] {
+        &self.added[..self.num_added]
+    }
+
+    pub fn removed(&self) -> &[usize] {
+        &self.removed[..self.num_removed]
+    }
+
+    fn add(&mut self, feature: Option<usize>) {
+        if let Some(feature) = feature {
+            self.added[self.num_added] = feature;
+            self.num_added += 1;
+        }
+    }
+
+    fn remove(&mut self, feature: Option<usize>) {
+        if let Some(feature) = feature {
+            self.removed[self.num_removed] = feature;
+            self.num_removed += 1;
+        }
+    }
+}
+
+/// HalfKP: every piece other than the kings on every square, for every square of the
+/// perspective's own king. Indexed king * 640 + piece * 64 + square, where pieces run pawn,
+/// knight, bishop, rook, queen with the perspective's own piece before the enemy's
+#[derive(Copy, Clone, Debug, Default)]
+pub struct HalfKP;
+
+impl FeatureSet for HalfKP {
+    const NUM_FEATURES: usize = 64 * 10 * 64;
+    const MAX_ACTIVE: usize = 30;
+
+    fn feature(perspective: Side, king_sq: Square, pc: Piece, sq: Square) -> Option<usize> {
+        if pc.kind() == KING {
+            return None;
+        }
+        Some(
+            king_sq.from_side(perspective).to_usize() * 640
+                + piece_index(perspective, pc) * 64
+                + sq.from_side(perspective).to_usize(),
+        )
+    }
+}
+
+/// HalfKA: HalfKP with both kings as features too. Indexed king * 768 + piece * 64 + square
+#[derive(Copy, Clone, Debug, Default)]
+pub struct HalfKA;
+
+impl FeatureSet for HalfKA {
+    const NUM_FEATURES: usize = 64 * 12 * 64;
+    const MAX_ACTIVE: usize = 32;
+
+    fn feature(perspective: Side, king_sq: Square, pc: Piece, sq: Square) -> Option<usize> {
+        Some(
+            king_sq.from_side(perspective).to_usize() * 768
+                + piece_index(perspective, pc) * 64
+                + sq.from_side(perspective).to_usize(),
+        )
+    }
+}
+
+// Pieces ordered pawn, knight, bishop, rook, queen, king, own before enemy
+fn piece_index(perspective: Side, pc: Piece) -> usize {
+    // indexed by Kind: bishop, queen, rook, knight, pawn, king
+    const KIND_ORDER: [usize; 6] = [2, 4, 3, 1, 0, 5];
+    KIND_ORDER[pc.kind().to_usize()] * 2 + (pc.side() != perspective) as usize
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generation::legal_moves;
+    use crate::mv_list::MoveVec;
+    use crate::side::{BLACK, WHITE};
+    use crate::square::*;
+    use alloc::vec::Vec;
+
+    fn features<F: FeatureSet>(position: &Position, perspective: Side) -> Vec<usize> {
+        let mut features = Vec::new();
+        F::active_features(position, perspective, |f| features.push(f));
+        features.sort();
+        features
+    }
+
+    fn find_move(position: &Position, uci: &str) -> Move {
+        let mut moves = MoveVec::new();
+        legal_moves(position, &mut moves);
+        *moves
+            .iter()
+            .find(|mv| mv.to_uci(position.state().stm) == uci)
+            .unwrap()
+    }
+
+    // Checks the reported delta turns the features before the move into those after it
+    fn assert_update<F: FeatureSet>(fen: &str, uci: &str, refreshes: [bool; 2]) {
+        let mut position = Position::from_fen(fen).unwrap();
+        let mv = find_move(&position, uci);
+        let before = [
+            features::<F>(&position, WHITE),
+            features::<F>(&position, BLACK),
+        ];
+        let capture = position.make(mv);
+
+        for side in [WHITE, BLACK] {
+            let after = features::<F>(&position, side);
+            match F::update(&position, mv, capture, side) {
+                FeatureUpdate::Refresh => assert!(refreshes[side.to_usize()], "{} {}", fen, uci),
+                FeatureUpdate::Delta(delta) => {
+                    assert!(!refreshes[side.to_usize()], "{} {}", fen, uci);
+                    let mut updated = before[side.to_usize()].clone();
+                    for f in delta.removed() {
+                        let i = updated.iter().position(|g| g == f).unwrap();
+                        updated.remove(i);
+                    }
+                    updated.extend_from_slice(delta.added());
+                    updated.sort();
+                    assert_eq!(updated, after, "{} {}", fen, uci);
+                }
+            }
+        }
+    }
+
+    fn assert_updates<F: FeatureSet>() {
+        let kiwipete = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -";
+        // quiet move and capture
+        assert_update::<F>(kiwipete, "e5d7", [false, false]);
+        assert_update::<F>(kiwipete, "e5f7", [false, false]);
+        // castling and king moves refresh the mover's perspective
+        assert_update::<F>(kiwipete, "e1g1", [true, false]);
+        assert_update::<F>(kiwipete, "e1c1", [true, false]);
+        assert_update::<F>(kiwipete, "e1d1", [true, false]);
+        // en-passant
+        assert_update::<F>("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", "e5d6", [false, false]);
+        // promotion and capture promotion
+        assert_update::<F>("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7a8q", [false, false]);
+        assert_update::<F>("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7b8n", [false, false]);
+        // black castles
+        assert_update::<F>("r3k2r/8/8/8/8/8/8/4K3 b kq - 0 1", "e8c8", [false, true]);
+    }
+
+    #[test]
+    fn test_half_kp_updates() {
+        assert_updates::<HalfKP>();
+    }
+
+    #[test]
+    fn test_half_ka_updates() {
+        assert_updates::<HalfKA>();
+    }
+
+    #[test]
+    fn test_feature_indices() {
+        let position = Position::from_fen("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1").unwrap();
+
+        // own pawn on e2 with the king on e1
+        assert_eq!(
+            features::<HalfKP>(&position, WHITE),
+            [E1.to_usize() * 640 + E2.to_usize()]
+        );
+        // from black's side the enemy pawn is on e7 and its king on e1
+        assert_eq!(
+            features::<HalfKP>(&position, BLACK),
+            [E1.to_usize() * 640 + 64 + E7.to_usize()]
+        );
+        assert_eq!(features::<HalfKA>(&position, WHITE).len(), 3);
+
+        for (perspective, king_sq) in [(WHITE, E1), (BLACK, E8)] {
+            for f in features::<HalfKA>(&position, perspective) {
+                assert!(f < HalfKA::NUM_FEATURES);
+                assert_eq!(f / 768, king_sq.from_side(perspective).to_usize());
+            }
+        }
+    }
+}
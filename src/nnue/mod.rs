@@ -0,0 +1,9 @@
+// NNUE input features and accumulator, see https://www.chessprogramming.org/NNUE
+//
+// The first layer of the network only depends on which features are active, so it is kept in
+// an accumulator that is updated by the few features a move adds and removes
+mod accumulator;
+mod features;
+
+pub use self::accumulator::*;
+pub use self::features::*;
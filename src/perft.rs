@@ -1,15 +1,24 @@
 use crate::cache::Cache;
+#[cfg(target_arch = "x86_64")]
+use crate::generation::slider::Bmi2;
+use crate::generation::slider::{DefaultSliders, SliderAttacks, SliderBackend, slider_backend};
+#[cfg(feature = "std")]
 use crate::generation::legal_moves;
-use crate::mv_list::{MoveCounter, MoveVec};
+use crate::generation::legal_moves_with_sliders;
+use crate::mv_list::{MoveArray, MoveCounter};
 use crate::position::Position;
+#[cfg(feature = "std")]
 use num_cpus;
+#[cfg(feature = "std")]
 use std::sync::mpsc::channel;
+#[cfg(feature = "std")]
 use threadpool::ThreadPool;
 
 /// Returns the number of nodes at the provided depth
 /// cache_bytes_per_thread must be of form 2^N bytes
 /// if multi_threading_enabled is set to true search will
-/// run concurrently accross threads equal to your CPU count
+/// run concurrently accross threads equal to your CPU count.
+/// Without the std feature search always runs on one thread
 pub fn perft(
     position: &mut Position,
     depth: usize,
@@ -20,23 +29,38 @@ pub fn perft(
         return 1;
     }
 
+    let backend = slider_backend();
+
     if depth <= 3 {
-        return perft_inner(position, depth);
+        return perft_with_backend(backend, position, depth, None);
     }
 
-    if !multi_threading_enabled {
-        if cache_bytes_per_thread > 0 {
-            let mut cache = Cache::new(cache_bytes_per_thread).unwrap();
-            return perft_with_cache_inner(position, depth, &mut cache);
-        } else {
-            return perft_inner(position, depth);
-        }
+    #[cfg(feature = "std")]
+    if multi_threading_enabled {
+        return perft_parallel(backend, position, depth, cache_bytes_per_thread);
+    }
+    #[cfg(not(feature = "std"))]
+    let _ = multi_threading_enabled;
+
+    if cache_bytes_per_thread > 0 {
+        let mut cache = Cache::new(cache_bytes_per_thread).unwrap();
+        perft_with_backend(backend, position, depth, Some(&mut cache))
+    } else {
+        perft_with_backend(backend, position, depth, None)
     }
+}
 
+#[cfg(feature = "std")]
+fn perft_parallel(
+    backend: SliderBackend,
+    position: &mut Position,
+    depth: usize,
+    cache_bytes_per_thread: usize,
+) -> u64 {
     let pool = ThreadPool::new(num_cpus::get());
     let (tx, rx) = channel();
 
-    let mut moves = MoveVec::new();
+    let mut moves = MoveArray::new();
     legal_moves(position, &mut moves);
     let moves_len = moves.len();
 
@@ -50,9 +74,10 @@ pub fn perft(
             let count: u64;
             if cache_bytes_per_thread > 0 {
                 let mut cache = Cache::new(cache_bytes_per_thread).unwrap();
-                count = perft_with_cache_inner(&mut position_local, depth - 1, &mut cache);
+                count =
+                    perft_with_backend(backend, &mut position_local, depth - 1, Some(&mut cache));
             } else {
-                count = perft_inner(&mut position_local, depth - 1);
+                count = perft_with_backend(backend, &mut position_local, depth - 1, None);
             }
 
             tx.send(count).unwrap();
@@ -62,15 +87,55 @@ pub fn perft(
     return rx.iter().take(moves_len).sum();
 }
 
-pub fn perft_inner(position: &mut Position, depth: usize) -> u64 {
+// Counts nodes with generation monomorphised for backend, so the backend is chosen once per
+// search rather than at every node
+#[allow(unused_unsafe)]
+fn perft_with_backend(
+    backend: SliderBackend,
+    position: &mut Position,
+    depth: usize,
+    cache: Option<&mut Cache>,
+) -> u64 {
+    match backend {
+        // Bmi2 is only selected when the CPU supports it or by the slider-bmi2 feature
+        #[cfg(target_arch = "x86_64")]
+        SliderBackend::Bmi2 => unsafe { perft_bmi2(position, depth, cache) },
+        _ => perft_maybe_cached::<DefaultSliders>(position, depth, cache),
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "bmi2")]
+fn perft_bmi2(position: &mut Position, depth: usize, cache: Option<&mut Cache>) -> u64 {
+    perft_maybe_cached::<Bmi2>(position, depth, cache)
+}
+
+fn perft_maybe_cached<S: SliderAttacks>(
+    position: &mut Position,
+    depth: usize,
+    cache: Option<&mut Cache>,
+) -> u64 {
+    match cache {
+        Some(cache) => perft_with_cache_inner::<S>(position, depth, cache),
+        None => perft_with_sliders::<S>(position, depth),
+    }
+}
+
+/// Returns the number of nodes at the provided depth using the provided sliding piece attack
+/// backend. Runs on a single thread without a cache
+pub fn perft_with_sliders<S: SliderAttacks>(position: &mut Position, depth: usize) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
     if depth == 1 {
         let mut counter = MoveCounter::new();
-        legal_moves(position, &mut counter);
+        legal_moves_with_sliders::<S, MoveCounter>(position, &mut counter);
         return counter.moves;
     }
 
-    let mut moves = MoveVec::new();
-    legal_moves(position, &mut moves);
+    let mut moves = MoveArray::new();
+    legal_moves_with_sliders::<S, MoveArray>(position, &mut moves);
 
     let state = *position.state();
     let key = position.hash_key();
@@ -78,7 +143,7 @@ pub fn perft_inner(position: &mut Position, depth: usize) -> u64 {
     for &mv in moves.iter() {
         let capture = position.make(mv);
 
-        count += perft_inner(position, depth - 1);
+        count += perft_with_sliders::<S>(position, depth - 1);
 
         position.unmake(mv, capture, &state, key);
     }
@@ -86,7 +151,11 @@ pub fn perft_inner(position: &mut Position, depth: usize) -> u64 {
     count
 }
 
-fn perft_with_cache_inner(position: &mut Position, depth: usize, cache: &mut Cache) -> u64 {
+fn perft_with_cache_inner<S: SliderAttacks>(
+    position: &mut Position,
+    depth: usize,
+    cache: &mut Cache,
+) -> u64 {
     let key = position.hash_key();
 
     let result = cache.probe(key, depth);
@@ -97,18 +166,18 @@ fn perft_with_cache_inner(position: &mut Position, depth: usize, cache: &mut Cac
     let mut count = 0;
     if depth == 1 {
         let mut counter = MoveCounter::new();
-        legal_moves(&position, &mut counter);
+        legal_moves_with_sliders::<S, MoveCounter>(position, &mut counter);
         count = counter.moves as u64;
     } else {
-        let mut moves = MoveVec::new();
-        legal_moves(&position, &mut moves);
+        let mut moves = MoveArray::new();
+        legal_moves_with_sliders::<S, MoveArray>(position, &mut moves);
 
         let state = *position.state();
         let key = position.hash_key();
         for &mv in moves.iter() {
             let capture = position.make(mv);
 
-            count += perft_with_cache_inner(position, depth - 1, cache);
+            count += perft_with_cache_inner::<S>(position, depth - 1, cache);
 
             position.unmake(mv, capture, &state, key);
         }
@@ -122,7 +191,8 @@ fn perft_with_cache_inner(position: &mut Position, depth: usize, cache: &mut Cac
 /// Returns the number of moves, captures, promotions, castles and
 /// en-passant captures at the provided depth
 /// if multi_threading_enabled is set to true search will
-/// run concurrently accross threads equal to your CPU count
+/// run concurrently accross threads equal to your CPU count.
+/// Without the std feature search always runs on one thread
 #[allow(dead_code)]
 pub fn perft_detailed(
     position: &mut Position,
@@ -133,18 +203,32 @@ pub fn perft_detailed(
         return MoveCounter::new();
     }
 
+    let backend = slider_backend();
+
     if depth <= 3 {
-        return perft_detailed_inner(position, depth);
+        return perft_detailed_with_backend(backend, position, depth);
     }
 
-    if !multi_threading_enabled {
-        return perft_detailed_inner(position, depth);
+    #[cfg(feature = "std")]
+    if multi_threading_enabled {
+        return perft_detailed_parallel(backend, position, depth);
     }
+    #[cfg(not(feature = "std"))]
+    let _ = multi_threading_enabled;
+
+    perft_detailed_with_backend(backend, position, depth)
+}
 
+#[cfg(feature = "std")]
+fn perft_detailed_parallel(
+    backend: SliderBackend,
+    position: &mut Position,
+    depth: usize,
+) -> MoveCounter {
     let pool = ThreadPool::new(num_cpus::get());
     let (tx, rx) = channel();
 
-    let mut moves = MoveVec::new();
+    let mut moves = MoveArray::new();
     legal_moves(position, &mut moves);
     let moves_len = moves.len();
 
@@ -156,7 +240,7 @@ pub fn perft_detailed(
             position_local.make(mv);
 
             let counter: MoveCounter;
-            counter = perft_detailed_inner(&mut position_local, depth - 1);
+            counter = perft_detailed_with_backend(backend, &mut position_local, depth - 1);
 
             tx.send(counter).unwrap();
         });
@@ -170,24 +254,47 @@ pub fn perft_detailed(
     counter
 }
 
+#[allow(dead_code, unused_unsafe)]
+fn perft_detailed_with_backend(
+    backend: SliderBackend,
+    position: &mut Position,
+    depth: usize,
+) -> MoveCounter {
+    match backend {
+        #[cfg(target_arch = "x86_64")]
+        SliderBackend::Bmi2 => unsafe { perft_detailed_bmi2(position, depth) },
+        _ => perft_detailed_inner::<DefaultSliders>(position, depth),
+    }
+}
+
+#[allow(dead_code)]
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "bmi2")]
+fn perft_detailed_bmi2(position: &mut Position, depth: usize) -> MoveCounter {
+    perft_detailed_inner::<Bmi2>(position, depth)
+}
+
 #[allow(dead_code)]
-pub fn perft_detailed_inner(position: &mut Position, depth: usize) -> MoveCounter {
+pub fn perft_detailed_inner<S: SliderAttacks>(
+    position: &mut Position,
+    depth: usize,
+) -> MoveCounter {
     let mut counter = MoveCounter::new();
 
     if depth == 1 {
-        legal_moves(position, &mut counter);
+        legal_moves_with_sliders::<S, MoveCounter>(position, &mut counter);
         return counter;
     }
 
-    let mut moves = MoveVec::new();
-    legal_moves(position, &mut moves);
+    let mut moves = MoveArray::new();
+    legal_moves_with_sliders::<S, MoveArray>(position, &mut moves);
 
     let state = *position.state();
     let key = position.hash_key();
     for &mv in moves.iter() {
         let capture = position.make(mv);
 
-        counter += perft_detailed_inner(position, depth - 1);
+        counter += perft_detailed_inner::<S>(position, depth - 1);
 
         position.unmake(mv, capture, &state, key);
     }
@@ -198,8 +305,8 @@ pub fn perft_detailed_inner(position: &mut Position, depth: usize) -> MoveCounte
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::generation::slider::{BlackMagic, Hyperbola, KoggeStone, Magic, Subtract};
     use crate::position::{Position, STARTING_POSITION_FEN};
-    use ::test;
 
     #[test]
     fn perft_test_3() {
@@ -229,6 +336,33 @@ mod test {
         assert_eq!(perft(&mut position, 4, false, 1024 * 1024), 197281);
     }
 
+    #[test]
+    fn perft_with_cache_each_backend() {
+        fn count<S: SliderAttacks>() -> (u64, MoveCounter) {
+            let mut position = Position::from_fen(
+                "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+            )
+            .unwrap();
+            let mut cache = Cache::new(1024 * 1024).unwrap();
+            (
+                perft_with_cache_inner::<S>(&mut position, 3, &mut cache),
+                perft_detailed_inner::<S>(&mut position, 2),
+            )
+        }
+
+        for (nodes, counter) in [
+            count::<Magic>(),
+            count::<BlackMagic>(),
+            count::<Hyperbola>(),
+            count::<KoggeStone>(),
+            count::<Subtract>(),
+        ] {
+            assert_eq!(nodes, 97862);
+            assert_eq!(counter.moves, 2039);
+            assert_eq!(counter.captures, 351);
+        }
+    }
+
     #[test]
     // https://www.chessprogramming.org/Perft_Results#Position_2
     fn perft_detailed_position_2_depth_3() {
@@ -296,11 +430,4 @@ mod test {
 
         assert_eq!(perft(&mut position, 1, false, 0), 3);
     }
-
-    #[bench]
-    fn perft_bench_starting_position(b: &mut test::Bencher) {
-        let mut position = Position::from_fen(STARTING_POSITION_FEN).unwrap();
-
-        b.iter(|| -> u64 { perft(&mut position, 2, false, 0) });
-    }
 }
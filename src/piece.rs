@@ -1,5 +1,7 @@
 use crate::side::Side;
-use std::fmt;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
 
 type PieceInternal = u8;
 
@@ -31,7 +33,7 @@ const NAMES: [&str; 12] = [
 pub struct Kind(pub PieceInternal);
 
 impl Kind {
-    pub fn pc(self, side: Side) -> Piece {
+    pub const fn pc(self, side: Side) -> Piece {
         Piece((self.0 << 1) | side.raw() as PieceInternal)
     }
 
@@ -142,7 +144,7 @@ pub const BLACK_KING: Piece = Piece(11);
 pub const NULL_PIECE: Piece = Piece(12);
 
 impl Piece {
-    pub fn to_usize(&self) -> usize {
+    pub const fn to_usize(&self) -> usize {
         self.0 as usize
     }
 
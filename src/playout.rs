@@ -0,0 +1,288 @@
+use crate::game_result::GameResult;
+use crate::generation::legal_moves;
+use crate::mv::Move;
+use crate::mv_list::{MoveVec, PieceSquareTable, SortedMoveAdder, SortedMoveHeap};
+use crate::piece::*;
+use crate::position::Position;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// How moves are picked during a playout
+#[derive(Clone, Copy)]
+pub enum MoveSelection<'a> {
+    /// Every legal move is equally likely
+    Uniform,
+    /// Moves are weighted by exp(score / temperature) where score is the piece-square score of
+    /// the move from SortedMoveAdder
+    Weighted {
+        piece_square_table: &'a PieceSquareTable,
+        temperature: f64,
+    },
+}
+
+/// Options used when playing out games
+#[derive(Clone, Copy)]
+pub struct PlayoutOptions<'a> {
+    pub move_selection: MoveSelection<'a>,
+    /// Game is adjudicated as a draw after this many half-moves
+    pub max_plies: usize,
+    /// Draw when the half-move clock reaches 100
+    pub fifty_move_rule: bool,
+    /// Draw when a position occurs for the third time
+    pub threefold_repetition: bool,
+    /// Draw when neither side can checkmate (KvK, KvK+minor)
+    pub insufficient_material: bool,
+}
+
+impl Default for PlayoutOptions<'_> {
+    fn default() -> Self {
+        PlayoutOptions {
+            move_selection: MoveSelection::Uniform,
+            max_plies: 400,
+            fifty_move_rule: true,
+            threefold_repetition: true,
+            insufficient_material: true,
+        }
+    }
+}
+
+/// Reason a playout ended
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Termination {
+    Checkmate,
+    Stalemate,
+    FiftyMoveRule,
+    ThreefoldRepetition,
+    InsufficientMaterial,
+    MaxPlies,
+}
+
+/// Playout is a finished game with the moves played from the starting position
+#[derive(Clone)]
+pub struct Playout {
+    pub moves: Vec<Move>,
+    pub result: GameResult,
+    pub termination: Termination,
+    /// Position after the last move
+    pub position: Position,
+}
+
+/// Plays a game from the provided position until it ends or is adjudicated. The same seed and
+/// options always give the same game
+pub fn playout(position: &Position, seed: u64, options: &PlayoutOptions) -> Playout {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut position = position.clone();
+    let mut moves = Vec::new();
+    let mut keys = vec![position.hash_key()];
+
+    let (result, termination) = loop {
+        let mut list = MoveVec::new();
+        let in_check = legal_moves(&position, &mut list);
+        let stm = position.state().stm;
+
+        if list.is_empty() {
+            break if in_check {
+                (GameResult::win_for(stm.flip()), Termination::Checkmate)
+            } else {
+                (GameResult::Draw, Termination::Stalemate)
+            };
+        }
+
+        if options.fifty_move_rule && position.state().half_move_clock >= 100 {
+            break (GameResult::Draw, Termination::FiftyMoveRule);
+        }
+
+        if options.threefold_repetition && is_threefold_repetition(&position, &keys) {
+            break (GameResult::Draw, Termination::ThreefoldRepetition);
+        }
+
+        if options.insufficient_material && is_insufficient_material(&position) {
+            break (GameResult::Draw, Termination::InsufficientMaterial);
+        }
+
+        if moves.len() >= options.max_plies {
+            break (GameResult::Draw, Termination::MaxPlies);
+        }
+
+        let mv = match options.move_selection {
+            MoveSelection::Uniform => *list.iter().nth(rng.gen_range(0..list.len())).unwrap(),
+            MoveSelection::Weighted {
+                piece_square_table,
+                temperature,
+            } => weighted_move(&position, piece_square_table, temperature, &mut rng),
+        };
+
+        position.make(mv);
+        moves.push(mv);
+        keys.push(position.hash_key());
+    };
+
+    Playout {
+        moves,
+        result,
+        termination,
+        position,
+    }
+}
+
+fn weighted_move(
+    position: &Position,
+    piece_square_table: &PieceSquareTable,
+    temperature: f64,
+    rng: &mut StdRng,
+) -> Move {
+    let mut heap = SortedMoveHeap::new(64);
+    legal_moves(
+        position,
+        &mut SortedMoveAdder::new(
+            piece_square_table,
+            position.grid(),
+            position.state().stm,
+            &mut heap,
+        ),
+    );
+
+    let scored = heap.into_sorted_vec();
+    let max = scored.iter().map(|ms| ms.score()).max().unwrap() as f64;
+    let weights = scored
+        .iter()
+        .map(|ms| ((ms.score() as f64 - max) / temperature).exp())
+        .collect::<Vec<f64>>();
+
+    match WeightedIndex::new(&weights) {
+        Ok(distribution) => scored[distribution.sample(rng)].mv(),
+        // weights underflow with a tiny temperature, so pick the best move
+        Err(_) => scored[0].mv(),
+    }
+}
+
+/// Positions can only repeat since the last capture or pawn move, and only with the same side to
+/// move, so keys are checked every other ply within the half-move clock
+fn is_threefold_repetition(position: &Position, keys: &[u64]) -> bool {
+    let key = position.hash_key();
+    let window = (position.state().half_move_clock as usize + 1).min(keys.len());
+
+    keys[keys.len() - window..]
+        .iter()
+        .rev()
+        .step_by(2)
+        .filter(|&&k| k == key)
+        .count()
+        >= 3
+}
+
+fn is_insufficient_material(position: &Position) -> bool {
+    let occupied = position.bb_occupied().pop_count();
+    if occupied == 2 {
+        return true;
+    }
+
+    let minors = position.bb_pc(WHITE_KNIGHT)
+        | position.bb_pc(BLACK_KNIGHT)
+        | position.bb_pc(WHITE_BISHOP)
+        | position.bb_pc(BLACK_BISHOP);
+
+    occupied == 3 && minors.pop_count() == 1
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::position::STARTING_POSITION_FEN;
+
+    #[test]
+    fn test_deterministic() {
+        let position = Position::from_fen(STARTING_POSITION_FEN).unwrap();
+        let options = PlayoutOptions::default();
+
+        let a = playout(&position, 3, &options);
+        let b = playout(&position, 3, &options);
+        assert_eq!(a.moves, b.moves);
+        assert!(a.result == b.result);
+        assert!(a.moves.len() <= options.max_plies);
+    }
+
+    #[test]
+    fn test_checkmate() {
+        // Qb7# is one of white's moves, other moves are adjudicated after one ply
+        let position = Position::from_fen("k7/7Q/1K6/8/8/8/8/8 w - - 0 1").unwrap();
+        let options = PlayoutOptions {
+            max_plies: 1,
+            ..Default::default()
+        };
+
+        for seed in 0..20 {
+            let game = playout(&position, seed, &options);
+            if game.termination == Termination::Checkmate {
+                assert!(game.result == GameResult::WhiteWin);
+                assert_eq!(game.moves.len(), 1);
+                return;
+            }
+        }
+        panic!("no checkmate found");
+    }
+
+    #[test]
+    fn test_adjudication() {
+        let position = Position::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let game = playout(&position, 1, &PlayoutOptions::default());
+        assert_eq!(game.termination, Termination::InsufficientMaterial);
+        assert!(game.moves.is_empty());
+
+        let position = Position::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 100 80").unwrap();
+        let game = playout(&position, 1, &PlayoutOptions::default());
+        assert_eq!(game.termination, Termination::FiftyMoveRule);
+
+        let position = Position::from_fen("4k3/pppppppp/8/8/8/8/PPPPPPPP/4K3 w - - 0 1").unwrap();
+        let options = PlayoutOptions {
+            max_plies: 5,
+            ..Default::default()
+        };
+        let game = playout(&position, 1, &options);
+        assert_eq!(game.termination, Termination::MaxPlies);
+        assert!(game.result == GameResult::Draw);
+        assert_eq!(game.moves.len(), 5);
+    }
+
+    #[test]
+    fn test_threefold_repetition() {
+        // rooks shuffling back and forth repeat the starting position
+        let position = Position::from_fen("7k/R7/8/8/8/8/r7/7K w - - 0 1").unwrap();
+        let mut keys = vec![position.hash_key()];
+        let mut position = position;
+        for _ in 0..2 {
+            for uci in ["a7b7", "a2b2", "b7a7", "b2a2"].iter() {
+                let mut list = MoveVec::new();
+                legal_moves(&position, &mut list);
+                let mv = *list
+                    .iter()
+                    .find(|mv| mv.to_uci(position.state().stm) == *uci)
+                    .unwrap();
+                position.make(mv);
+                keys.push(position.hash_key());
+            }
+        }
+        assert!(is_threefold_repetition(&position, &keys));
+        assert!(!is_threefold_repetition(&position, &keys[4..]));
+    }
+
+    #[test]
+    fn test_weighted() {
+        let piece_square_table = PieceSquareTable::new([[0; 64]; 6]);
+        let position = Position::from_fen(STARTING_POSITION_FEN).unwrap();
+        let options = PlayoutOptions {
+            move_selection: MoveSelection::Weighted {
+                piece_square_table: &piece_square_table,
+                temperature: 100.0,
+            },
+            ..Default::default()
+        };
+
+        let game = playout(&position, 5, &options);
+        let mut replay = position.clone();
+        for &mv in game.moves.iter() {
+            replay.make(mv);
+        }
+        assert_eq!(replay.to_fen(), game.position.to_fen());
+    }
+}
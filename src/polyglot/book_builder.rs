@@ -0,0 +1,247 @@
+use super::{BookEntry, polyglot_key, polyglot_move};
+use crate::game_result::GameResult;
+use crate::mv::Move;
+use crate::position::Position;
+use std::collections::HashMap;
+use std::io;
+
+/// Options used when building a Polyglot book
+#[derive(Debug, Clone, Copy)]
+pub struct BookBuilderOptions {
+    /// Moves played in fewer games than this are left out of the book
+    pub min_games: u32,
+    /// Only the first max_ply half-moves of each game are added
+    pub max_ply: usize,
+    /// Score given to a move each time the mover went on to win
+    pub win_score: u32,
+    /// Score given to a move each time the game was drawn
+    pub draw_score: u32,
+    /// Score given to a move each time the mover went on to lose
+    pub loss_score: u32,
+}
+
+impl Default for BookBuilderOptions {
+    /// Same defaults as the original Polyglot make-book command
+    fn default() -> Self {
+        BookBuilderOptions {
+            min_games: 3,
+            max_ply: 1024,
+            win_score: 2,
+            draw_score: 1,
+            loss_score: 0,
+        }
+    }
+}
+
+#[derive(Default, Clone, Copy)]
+struct MoveStats {
+    games: u32,
+    score: u64,
+}
+
+/// BookBuilder counts how often each move is played in each position over a set of games
+/// and produces Polyglot book entries weighted by how well the moves scored
+///
+/// Example usage:
+///
+/// let mut builder = BookBuilder::new(BookBuilderOptions::default());
+/// builder.add_line(&start, &moves, GameResult::Draw);
+/// builder.write(&mut file)?;
+///
+pub struct BookBuilder {
+    options: BookBuilderOptions,
+    stats: HashMap<(u64, u16), MoveStats>,
+    games: usize,
+}
+
+impl BookBuilder {
+    pub fn new(options: BookBuilderOptions) -> BookBuilder {
+        BookBuilder {
+            options,
+            stats: HashMap::new(),
+            games: 0,
+        }
+    }
+
+    /// Number of games added to the builder
+    pub fn games(&self) -> usize {
+        self.games
+    }
+
+    /// Adds a game as a sequence of positions and the move played in each position
+    pub fn add_game<'a, I>(&mut self, game: I, result: GameResult)
+    where
+        I: IntoIterator<Item = (&'a Position, Move)>,
+    {
+        for (position, mv) in game.into_iter().take(self.options.max_ply) {
+            let stm = position.state().stm;
+            let score = match result.score_for(stm) {
+                1 => self.options.win_score,
+                0 => self.options.draw_score,
+                _ => self.options.loss_score,
+            };
+
+            let stats = self
+                .stats
+                .entry((polyglot_key(position), polyglot_move(mv, stm)))
+                .or_default();
+            stats.games += 1;
+            stats.score += score as u64;
+        }
+
+        self.games += 1;
+    }
+
+    /// Adds a game by replaying moves from a starting position
+    pub fn add_line(&mut self, start: &Position, moves: &[Move], result: GameResult) {
+        let mut position = start.clone();
+        let mut positions = Vec::with_capacity(moves.len().min(self.options.max_ply));
+
+        for &mv in moves.iter().take(self.options.max_ply) {
+            positions.push(position.clone());
+            position.make(mv);
+        }
+
+        self.add_game(positions.iter().zip(moves.iter().cloned()), result);
+    }
+
+    /// Returns book entries sorted by key, with the highest weighted move first for each key.
+    /// Weights are scaled down if any move's score does not fit in 16 bits. Moves with zero
+    /// weight are left out of the book
+    pub fn entries(&self) -> Vec<BookEntry> {
+        let max_score = self
+            .stats
+            .values()
+            .filter(|stats| stats.games >= self.options.min_games)
+            .map(|stats| stats.score)
+            .max()
+            .unwrap_or(0);
+
+        let mut entries = self
+            .stats
+            .iter()
+            .filter(|(_, stats)| stats.games >= self.options.min_games)
+            .map(|(&(key, mv), stats)| {
+                let weight = if max_score > u16::MAX as u64 {
+                    stats.score * (u16::MAX as u64) / max_score
+                } else {
+                    stats.score
+                };
+                BookEntry {
+                    key,
+                    mv,
+                    weight: weight as u16,
+                    learn: 0,
+                }
+            })
+            .filter(|entry| entry.weight > 0)
+            .collect::<Vec<BookEntry>>();
+
+        entries.sort_by(|a, b| {
+            a.key
+                .cmp(&b.key)
+                .then(b.weight.cmp(&a.weight))
+                .then(a.mv.cmp(&b.mv))
+        });
+
+        entries
+    }
+
+    /// Writes the book in Polyglot .bin format
+    pub fn write<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        for entry in self.entries() {
+            writer.write_all(&entry.to_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::position::STARTING_POSITION_FEN;
+    use crate::side::{BLACK, WHITE};
+    use crate::square::*;
+
+    fn builder(min_games: u32) -> BookBuilder {
+        BookBuilder::new(BookBuilderOptions {
+            min_games,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_weights() {
+        let start = Position::from_fen(STARTING_POSITION_FEN).unwrap();
+        let e4 = Move::new_push(E2, E4);
+        let d4 = Move::new_push(D2, D4);
+        let e5 = Move::new_push(E7, E5);
+
+        let mut builder = builder(1);
+        builder.add_line(&start, &[e4, e5], GameResult::WhiteWin);
+        builder.add_line(&start, &[e4, e5], GameResult::Draw);
+        builder.add_line(&start, &[d4], GameResult::BlackWin);
+
+        let entries = builder.entries();
+        let start_key = polyglot_key(&start);
+
+        // d4 only lost so has zero weight
+        let start_entries = entries
+            .iter()
+            .filter(|e| e.key == start_key)
+            .collect::<Vec<&BookEntry>>();
+        assert_eq!(start_entries.len(), 1);
+        assert_eq!(start_entries[0].mv, polyglot_move(e4, WHITE));
+        assert_eq!(start_entries[0].weight, 3);
+
+        let mut after_e4 = start.clone();
+        after_e4.make(e4);
+        let e5_entry = entries
+            .iter()
+            .find(|e| e.key == polyglot_key(&after_e4))
+            .unwrap();
+        assert_eq!(e5_entry.mv, polyglot_move(e5, BLACK));
+        assert_eq!(e5_entry.weight, 1);
+    }
+
+    #[test]
+    fn test_min_games_and_max_ply() {
+        let start = Position::from_fen(STARTING_POSITION_FEN).unwrap();
+        let line = [Move::new_push(E2, E4), Move::new_push(E7, E5)];
+
+        let mut builder = BookBuilder::new(BookBuilderOptions {
+            min_games: 2,
+            max_ply: 1,
+            ..Default::default()
+        });
+        builder.add_line(&start, &line, GameResult::WhiteWin);
+        assert!(builder.entries().is_empty());
+
+        builder.add_line(&start, &line, GameResult::WhiteWin);
+        let entries = builder.entries();
+        assert_eq!(builder.games(), 2);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].key, polyglot_key(&start));
+    }
+
+    #[test]
+    fn test_write_sorted() {
+        let start = Position::from_fen(STARTING_POSITION_FEN).unwrap();
+        let mut builder = builder(1);
+        for &(from, to) in [(E2, E4), (D2, D4), (G1, F3), (C2, C4)].iter() {
+            builder.add_line(&start, &[Move::new_push(from, to)], GameResult::Draw);
+        }
+        builder.add_line(&start, &[Move::new_push(G1, F3)], GameResult::WhiteWin);
+
+        let mut bytes = Vec::new();
+        builder.write(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 16 * 4);
+
+        let entries = bytes
+            .chunks(16)
+            .map(|chunk| BookEntry::from_bytes(chunk.try_into().unwrap()))
+            .collect::<Vec<BookEntry>>();
+        assert_eq!(entries[0].mv, polyglot_move(Move::new_push(G1, F3), WHITE));
+        assert!(entries.windows(2).all(|w| w[0].weight >= w[1].weight));
+    }
+}
@@ -0,0 +1,297 @@
+// Polyglot opening book format
+// http://hgm.nubati.net/book_format.html
+
+mod book_builder;
+mod keys;
+
+pub use self::book_builder::{BookBuilder, BookBuilderOptions};
+pub use self::keys::RANDOM64;
+
+use crate::castle::{KING_SIDE, QUEEN_SIDE};
+use crate::hash::Zobrist;
+use crate::mv::Move;
+use crate::position::Position;
+use crate::side::{Side, WHITE};
+use crate::square::*;
+use std::fmt;
+
+const CASTLING_OFFSET: usize = 768;
+const EN_PASSANT_OFFSET: usize = 772;
+const TURN_OFFSET: usize = 780;
+
+// Maps Kind to the Polyglot piece ordering (pawn, knight, bishop, rook, queen, king)
+const POLYGLOT_KINDS: [usize; 6] = [
+    2, // Bishop
+    4, // Queen
+    3, // Rook
+    1, // Knight
+    0, // Pawn
+    5, // King
+];
+
+// Polyglot castling keys in order of CastlingRights bits (WHITE_QS, BLACK_QS, WHITE_KS, BLACK_KS)
+const CASTLING_KEY_OFFSETS: [usize; 4] = [1, 3, 0, 2];
+
+/// Zobrist keys compatible with Polyglot opening books. Positions created with these keys
+/// (see Position::new_with_hash) have hash keys which can be used to probe Polyglot books
+pub static POLYGLOT_ZOBRIST_HASH: Zobrist = Zobrist::from_tables(
+    polyglot_piece_keys(),
+    polyglot_castling_keys(),
+    polyglot_ep_keys(),
+    RANDOM64[TURN_OFFSET],
+    WHITE,
+)
+.with_capturable_ep_only();
+
+const fn polyglot_piece_keys() -> [[u64; 64]; 12] {
+    let mut pieces = [[0u64; 64]; 12];
+    let mut pc = 0;
+    while pc < 12 {
+        // pieces are ordered by kind then side, with white = 0
+        let kind_of_piece = POLYGLOT_KINDS[pc >> 1] * 2 + (pc & 1 == 0) as usize;
+        let mut sq = 0;
+        while sq < 64 {
+            pieces[pc][sq] = RANDOM64[64 * kind_of_piece + sq];
+            sq += 1;
+        }
+        pc += 1;
+    }
+    pieces
+}
+
+const fn polyglot_castling_keys() -> [u64; 16] {
+    let mut keys = [0u64; 16];
+    let mut rights = 0;
+    while rights < 16 {
+        let mut i = 0;
+        while i < 4 {
+            if rights & (1 << i) != 0 {
+                keys[rights] ^= RANDOM64[CASTLING_OFFSET + CASTLING_KEY_OFFSETS[i]];
+            }
+            i += 1;
+        }
+        rights += 1;
+    }
+    keys
+}
+
+const fn polyglot_ep_keys() -> [u64; 8] {
+    let mut keys = [0u64; 8];
+    let mut col = 0;
+    while col < 8 {
+        keys[col] = RANDOM64[EN_PASSANT_OFFSET + col];
+        col += 1;
+    }
+    keys
+}
+
+/// Generates the Polyglot key of the entire position
+pub fn polyglot_key(position: &Position) -> u64 {
+    POLYGLOT_ZOBRIST_HASH.position(position.grid(), position.state())
+}
+
+/// Encodes a move in Polyglot format. Castles are encoded as the king capturing its own rook
+pub fn polyglot_move(mv: Move, stm: Side) -> u16 {
+    if mv.is_castle() {
+        let king_from = if stm == WHITE { E1 } else { E8 };
+        let rook_from = match (mv.castle(), stm == WHITE) {
+            (QUEEN_SIDE, true) => A1,
+            (KING_SIDE, true) => H1,
+            (QUEEN_SIDE, false) => A8,
+            _ => H8,
+        };
+        return (king_from.to_u8() as u16) << 6 | rook_from.to_u8() as u16;
+    }
+
+    let mut encoded = (mv.from().to_u8() as u16) << 6 | mv.to().to_u8() as u16;
+
+    if mv.is_promotion() {
+        encoded |= (POLYGLOT_KINDS[mv.promote_to().to_usize()] as u16) << 12;
+    }
+
+    encoded
+}
+
+/// BookEntry represents a single 16 byte entry in a Polyglot book
+#[derive(PartialEq, Eq, Copy, Clone)]
+pub struct BookEntry {
+    pub key: u64,
+    pub mv: u16,
+    pub weight: u16,
+    pub learn: u32,
+}
+
+impl BookEntry {
+    /// Encodes the entry as big-endian bytes
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        bytes[0..8].copy_from_slice(&self.key.to_be_bytes());
+        bytes[8..10].copy_from_slice(&self.mv.to_be_bytes());
+        bytes[10..12].copy_from_slice(&self.weight.to_be_bytes());
+        bytes[12..16].copy_from_slice(&self.learn.to_be_bytes());
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8; 16]) -> BookEntry {
+        BookEntry {
+            key: u64::from_be_bytes(bytes[0..8].try_into().unwrap()),
+            mv: u16::from_be_bytes([bytes[8], bytes[9]]),
+            weight: u16::from_be_bytes([bytes[10], bytes[11]]),
+            learn: u32::from_be_bytes(bytes[12..16].try_into().unwrap()),
+        }
+    }
+}
+
+impl fmt::Debug for BookEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:016X} {}{} weight={} learn={}",
+            self.key,
+            Square::new(((self.mv >> 6) & 63) as SquareInternal),
+            Square::new((self.mv & 63) as SquareInternal),
+            self.weight,
+            self.learn
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::piece::*;
+    use crate::position::STARTING_POSITION_FEN;
+
+    // Reference keys from http://hgm.nubati.net/book_format.html
+    const REFERENCE_KEYS: [(&str, u64); 9] = [
+        (STARTING_POSITION_FEN, 0x463b96181691fc9c),
+        (
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+            0x823c9b50fd114196,
+        ),
+        (
+            "rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq d6 0 2",
+            0x0756b94461c50fb0,
+        ),
+        (
+            "rnbqkbnr/ppp1pppp/8/3pP3/8/8/PPPP1PPP/RNBQKBNR b KQkq - 0 2",
+            0x662fafb965db29d4,
+        ),
+        (
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+            0x22a48b5a8e47ff78,
+        ),
+        (
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPPKPPP/RNBQ1BNR b kq - 0 3",
+            0x652a607ca3f242c1,
+        ),
+        (
+            "rnbq1bnr/ppp1pkpp/8/3pPp2/8/8/PPPPKPPP/RNBQ1BNR w - - 0 4",
+            0x00fdd303c946bdd9,
+        ),
+        (
+            "rnbqkbnr/p1pppppp/8/8/PpP4P/8/1P1PPPP1/RNBQKBNR b KQkq c3 0 3",
+            0x3c8123ea7b067637,
+        ),
+        (
+            "rnbqkbnr/p1pppppp/8/8/P6P/R1p5/1P1PPPP1/1NBQKBNR b Kkq - 0 4",
+            0x5c3f9b829b279560,
+        ),
+    ];
+
+    #[test]
+    fn test_polyglot_key() {
+        for &(fen, expected) in REFERENCE_KEYS.iter() {
+            let position = Position::from_fen(fen).unwrap();
+            assert_eq!(polyglot_key(&position), expected, "{}", fen);
+        }
+    }
+
+    #[test]
+    fn test_polyglot_hash_make() {
+        // the reference keys above are reached by playing these moves from the start
+        let moves = [
+            Move::new_push(E2, E4),
+            Move::new_push(D7, D5),
+            Move::new_push(E4, E5),
+            Move::new_push(F7, F5),
+            Move::new_push(E1, E2),
+            Move::new_push(E8, F7),
+        ];
+
+        let mut position =
+            Position::from_fen_with_hash(STARTING_POSITION_FEN, &POLYGLOT_ZOBRIST_HASH).unwrap();
+        assert_eq!(position.hash_key(), REFERENCE_KEYS[0].1);
+
+        for (i, &mv) in moves.iter().enumerate() {
+            let state = *position.state();
+            let key = position.hash_key();
+            let capture = position.make(mv);
+            assert_eq!(position.hash_key(), REFERENCE_KEYS[i + 1].1);
+
+            position.unmake(mv, capture, &state, key);
+            assert_eq!(position.hash_key(), key);
+            position.make(mv);
+        }
+
+        let moves = [
+            Move::new_push(A2, A4),
+            Move::new_push(B7, B5),
+            Move::new_push(H2, H4),
+            Move::new_push(B5, B4),
+            Move::new_push(C2, C4),
+            Move::new_ep_capture(B4, C3),
+            Move::new_push(A1, A3),
+        ];
+        let mut position =
+            Position::from_fen_with_hash(STARTING_POSITION_FEN, &POLYGLOT_ZOBRIST_HASH).unwrap();
+        for (i, &mv) in moves.iter().enumerate() {
+            position.make(mv);
+            if i == 4 {
+                assert_eq!(position.hash_key(), REFERENCE_KEYS[7].1);
+            }
+        }
+        assert_eq!(position.hash_key(), REFERENCE_KEYS[8].1);
+    }
+
+    #[test]
+    fn test_set_zobrist() {
+        let mut position = Position::from_fen(REFERENCE_KEYS[2].0).unwrap();
+        position.set_zobrist(&POLYGLOT_ZOBRIST_HASH);
+        assert_eq!(position.hash_key(), REFERENCE_KEYS[2].1);
+    }
+
+    #[test]
+    fn test_polyglot_move() {
+        assert_eq!(polyglot_move(Move::new_push(E2, E4), WHITE), 0x031c);
+        assert_eq!(
+            polyglot_move(Move::new_castle(KING_SIDE), WHITE),
+            (E1.to_u8() as u16) << 6 | H1.to_u8() as u16
+        );
+        assert_eq!(
+            polyglot_move(Move::new_castle(QUEEN_SIDE), crate::side::BLACK),
+            (E8.to_u8() as u16) << 6 | A8.to_u8() as u16
+        );
+        assert_eq!(
+            polyglot_move(Move::new_promotion(B7, B8, QUEEN), WHITE) >> 12,
+            4
+        );
+        assert_eq!(
+            polyglot_move(Move::new_capture_promotion(B7, A8, KNIGHT), WHITE) >> 12,
+            1
+        );
+    }
+
+    #[test]
+    fn test_book_entry_bytes() {
+        let entry = BookEntry {
+            key: 0x463b96181691fc9c,
+            mv: 0x031c,
+            weight: 123,
+            learn: 0,
+        };
+        let bytes = entry.to_bytes();
+        assert_eq!(&bytes[0..2], &[0x46, 0x3b]);
+        assert_eq!(BookEntry::from_bytes(&bytes), entry);
+    }
+}
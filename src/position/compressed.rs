@@ -0,0 +1,317 @@
+use super::State;
+use crate::bb::*;
+use crate::castling_rights::*;
+use crate::piece::*;
+use crate::side::*;
+use crate::square::*;
+use alloc::format;
+use alloc::string::String;
+
+/*
+    Compressed position format (same as Stockfish's CompressedPosition):
+    - occupancy bitboard as big-endian u64
+    - one nibble per occupied square in square order, low nibble first
+    Piece codes:
+    - 0-11: white pawn, black pawn, white knight, ... white king, black king
+    - 12: pawn which has just been double-pushed (en-passant square behind it)
+    - 13/14: white/black rook which can still castle
+    - 15: black king with black to move
+    Castling rights without a rook on its original square and en-passant squares
+    without a pawn in front of them cannot be represented, so compress rejects them.
+*/
+
+pub const COMPRESSED_POSITION_SIZE: usize = 24;
+
+const PAWN_WITH_EP: u8 = 12;
+const WHITE_ROOK_WITH_CASTLING: u8 = 13;
+const BLACK_ROOK_WITH_CASTLING: u8 = 14;
+const BLACK_KING_TO_MOVE: u8 = 15;
+
+// Maps Piece to compressed piece codes
+const PIECE_CODES: [u8; 12] = [
+    4, 5, // bishops
+    8, 9, // queens
+    6, 7, // rooks
+    2, 3, // knights
+    0, 1, // pawns
+    10, 11, // kings
+];
+
+// Maps compressed piece codes (0-11) to Piece
+const CODE_PIECES: [Piece; 12] = [
+    WHITE_PAWN,
+    BLACK_PAWN,
+    WHITE_KNIGHT,
+    BLACK_KNIGHT,
+    WHITE_BISHOP,
+    BLACK_BISHOP,
+    WHITE_ROOK,
+    BLACK_ROOK,
+    WHITE_QUEEN,
+    BLACK_QUEEN,
+    WHITE_KING,
+    BLACK_KING,
+];
+
+// Castling right of a rook on each corner square
+const ROOK_CASTLING_RIGHTS: [(Square, CastlingRights); 4] = [
+    (A1, WHITE_QS),
+    (H1, WHITE_KS),
+    (A8, BLACK_QS),
+    (H8, BLACK_KS),
+];
+
+/// Number of bytes used to compress a position with the provided occupancy
+pub fn compressed_size(occupied: BB) -> usize {
+    8 + (occupied.pop_count() as usize).div_ceil(2)
+}
+
+/// Compress the grid, side to move, castling rights and en-passant square. Bytes not needed
+/// for the pieces on the board are left as zero. Fails if there are more than 32 pieces, a
+/// castling right without its rook or an en-passant square without the pushed pawn
+pub fn compress(
+    grid: &[Piece; 64],
+    state: &State,
+) -> Result<[u8; COMPRESSED_POSITION_SIZE], String> {
+    let num_pieces = grid.iter().filter(|pc| pc.is_some()).count();
+    if num_pieces > 32 {
+        return Err(format!(
+            "Cannot compress position with {} pieces, at most 32 are supported",
+            num_pieces
+        ));
+    }
+
+    for &(rook_sq, right) in ROOK_CASTLING_RIGHTS.iter() {
+        if state.castling_rights.has(right)
+            && grid[rook_sq.to_usize()] != ROOK.pc(right_side(right))
+        {
+            return Err(format!(
+                "Cannot compress castling right without a rook on {}",
+                rook_sq
+            ));
+        }
+    }
+
+    let ep_pawn = state
+        .ep_square
+        .map(|sq| sq.change_row(if state.stm == WHITE { 4 } else { 3 }));
+
+    if let (Some(ep_square), Some(pawn_sq)) = (state.ep_square, ep_pawn)
+        && (ep_square.row() != if state.stm == WHITE { 5 } else { 2 }
+            || grid[pawn_sq.to_usize()] != PAWN.pc(state.stm.flip()))
+    {
+        return Err(format!(
+            "Cannot compress en-passant square {} without a pushed pawn on {}",
+            ep_square, pawn_sq
+        ));
+    }
+
+    let mut bytes = [0u8; COMPRESSED_POSITION_SIZE];
+    let mut occupied = 0u64;
+
+    for (count, (idx, &pc)) in grid
+        .iter()
+        .enumerate()
+        .filter(|&(_, &pc)| pc.is_some())
+        .enumerate()
+    {
+        let code = piece_code(pc, Square(idx), state, ep_pawn);
+        occupied |= 1u64 << idx;
+        bytes[8 + count / 2] |= code << ((count & 1) * 4);
+    }
+
+    bytes[0..8].copy_from_slice(&occupied.to_be_bytes());
+
+    Ok(bytes)
+}
+
+fn piece_code(pc: Piece, sq: Square, state: &State, ep_pawn: Option<Square>) -> u8 {
+    if pc.kind() == PAWN && ep_pawn == Some(sq) {
+        return PAWN_WITH_EP;
+    }
+
+    if pc.kind() == ROOK {
+        for &(rook_sq, right) in ROOK_CASTLING_RIGHTS.iter() {
+            if rook_sq == sq && state.castling_rights.has(right) && pc.side() == right_side(right) {
+                return if pc.side() == WHITE {
+                    WHITE_ROOK_WITH_CASTLING
+                } else {
+                    BLACK_ROOK_WITH_CASTLING
+                };
+            }
+        }
+    }
+
+    if pc == BLACK_KING && state.stm == BLACK {
+        return BLACK_KING_TO_MOVE;
+    }
+
+    PIECE_CODES[pc.to_usize()]
+}
+
+fn right_side(right: CastlingRights) -> Side {
+    if right.has(WHITE_QS) || right.has(WHITE_KS) {
+        WHITE
+    } else {
+        BLACK
+    }
+}
+
+/// Decompress a position. Returns the grid, state (with a half-move clock of 0 and full-move
+/// number of 1) and number of bytes read
+pub fn decompress(bytes: &[u8]) -> Result<([Piece; 64], State, usize), String> {
+    if bytes.len() < 8 {
+        return Err(format!(
+            "Not enough bytes for compressed position: {}",
+            bytes.len()
+        ));
+    }
+
+    let occupied = BB(u64::from_be_bytes(bytes[0..8].try_into().unwrap()));
+    let size = compressed_size(occupied);
+
+    if occupied.pop_count() > 32 {
+        return Err(format!(
+            "Too many pieces in compressed position: {}",
+            occupied.pop_count()
+        ));
+    }
+
+    if bytes.len() < size {
+        return Err(format!(
+            "Not enough bytes for compressed position with {} pieces: {}",
+            occupied.pop_count(),
+            bytes.len()
+        ));
+    }
+
+    let mut grid = [NULL_PIECE; 64];
+    let mut state = State::default();
+
+    for (count, (sq, _)) in occupied.iter().enumerate() {
+        let code = (bytes[8 + count / 2] >> ((count & 1) * 4)) & 0xF;
+
+        grid[sq.to_usize()] = match code {
+            PAWN_WITH_EP => match sq.row() {
+                3 => {
+                    state.ep_square = Some(sq.change_row(2));
+                    WHITE_PAWN
+                }
+                4 => {
+                    state.ep_square = Some(sq.change_row(5));
+                    BLACK_PAWN
+                }
+                _ => return Err(format!("Invalid en-passant pawn square: {}", sq)),
+            },
+            WHITE_ROOK_WITH_CASTLING | BLACK_ROOK_WITH_CASTLING => {
+                let side = if code == WHITE_ROOK_WITH_CASTLING {
+                    WHITE
+                } else {
+                    BLACK
+                };
+                let right = ROOK_CASTLING_RIGHTS
+                    .iter()
+                    .find(|&&(rook_sq, right)| rook_sq == sq && right_side(right) == side)
+                    .map(|&(_, right)| right)
+                    .ok_or_else(|| format!("Invalid castling rook square: {}", sq))?;
+                state.castling_rights.set(right);
+                ROOK.pc(side)
+            }
+            BLACK_KING_TO_MOVE => {
+                state.stm = BLACK;
+                BLACK_KING
+            }
+            _ => CODE_PIECES[code as usize],
+        };
+    }
+
+    // en-passant pawn side must agree with the side to move
+    if let Some(ep_square) = state.ep_square
+        && (ep_square.row() == 2) != (state.stm == BLACK)
+    {
+        return Err(format!(
+            "En-passant square {} inconsistent with side to move",
+            ep_square
+        ));
+    }
+
+    Ok((grid, state, size))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::position::fen::*;
+
+    fn round_trip(fen: &str) {
+        let (grid, state) = from_fen(fen).unwrap();
+        let bytes = compress(&grid, &state).unwrap();
+        let (grid_2, state_2, size) = decompress(&bytes).unwrap();
+        assert_eq!(
+            size,
+            compressed_size(BB(u64::from_be_bytes(bytes[0..8].try_into().unwrap())))
+        );
+
+        let expected = State {
+            half_move_clock: 0,
+            full_move_number: 1,
+            ..state
+        };
+        assert_eq!(to_fen(&grid_2, &state_2), to_fen(&grid, &expected));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        round_trip("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+        round_trip("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1");
+        round_trip("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3");
+        round_trip("r3k2r/8/8/8/8/8/8/R3K2R b Kq - 5 20");
+        round_trip("4k3/8/8/8/8/8/8/4K3 w - - 0 1");
+    }
+
+    #[test]
+    fn test_compressed_size() {
+        let (grid, state) = from_fen("4k3/8/8/8/8/8/8/4KR2 w - - 0 1").unwrap();
+        let bytes = compress(&grid, &state).unwrap();
+        let (_, _, size) = decompress(&bytes).unwrap();
+        assert_eq!(size, 10);
+        assert!(decompress(&bytes[0..9]).is_err());
+    }
+
+    #[test]
+    fn test_piece_codes() {
+        // white king on e1 = 10, black king to move on e8 = 15
+        let (grid, state) = from_fen("4k3/8/8/8/8/8/8/4K3 b - - 0 1").unwrap();
+        let bytes = compress(&grid, &state).unwrap();
+        assert_eq!(bytes[8], 10 | (15 << 4));
+    }
+
+    #[test]
+    fn test_too_many_pieces() {
+        // 33 pieces: a full set plus an extra white knight
+        let (grid, state) =
+            from_fen("rnbqkbnr/pppppppp/8/8/8/5N2/PPPPPPPP/RNBQKBNR w KQkq - 0 1").unwrap();
+        assert!(compress(&grid, &state).is_err());
+
+        let (grid, state) =
+            from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").unwrap();
+        assert!(compress(&grid, &state).is_ok());
+    }
+
+    #[test]
+    fn test_unrepresentable_state() {
+        for fen in [
+            // castling rights without the rook on its corner
+            "4k3/8/8/8/8/8/8/4K3 w K - 0 1",
+            "r3k3/8/8/8/8/8/8/4K3 w k - 0 1",
+            "4k3/8/8/8/8/8/8/R3K3 b Kq - 0 1",
+            // en-passant squares without a pushed pawn in front
+            "4k3/8/8/8/8/8/8/4K3 w - d6 0 1",
+            "4k3/8/8/4P3/8/8/8/4K3 w - e6 0 1",
+            "4k3/8/8/8/3P4/8/8/4K3 w - d3 0 1",
+        ] {
+            let (grid, state) = from_fen(fen).unwrap();
+            assert!(compress(&grid, &state).is_err(), "{}", fen);
+        }
+    }
+}
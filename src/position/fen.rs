@@ -3,6 +3,9 @@ use crate::castling_rights::*;
 use crate::piece::*;
 use crate::side::*;
 use crate::square::{Square, SquareInternal};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 pub fn from_fen(fen: &str) -> Result<([Piece; 64], State), String> {
     let mut state = State {
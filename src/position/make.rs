@@ -24,6 +24,9 @@ impl Position {
         let initial_state = self.state;
         let mut move_resets_half_move_clock = false;
 
+        // en-passant keys depend on pawn positions so are removed before the move is made
+        let mut xor_key = self.hash.ep(&self.grid, &self.state);
+
         // increment full move clock if black moved
         if self.state.stm == BLACK {
             self.state.full_move_number += 1;
@@ -33,8 +36,6 @@ impl Position {
         self.state.ep_square = None;
         let mut captured = None;
 
-        let mut xor_key = 0u64;
-
         if mv.is_castle() {
             let castle = mv.castle();
             self.state.castling_rights.clear_side(stm);
@@ -105,15 +106,21 @@ impl Position {
         }
 
         xor_key ^= self.hash.state(&initial_state, &self.state);
+        xor_key ^= self.hash.ep(&self.grid, &self.state);
 
         self.key ^= xor_key;
 
+        #[cfg(feature = "verify")]
+        self.verify("make", mv, &initial_state);
+
         captured
     }
 
     pub fn make_null_move(&mut self) -> Option<(Piece, Square)> {
         let initial_state = self.state;
 
+        let mut xor_key = self.hash.ep(&self.grid, &self.state);
+
         // increment full move clock if black moved
         if self.state.stm == BLACK {
             self.state.full_move_number += 1;
@@ -122,12 +129,13 @@ impl Position {
         self.state.stm = self.state.stm.flip();
         self.state.ep_square = None;
 
-        let mut xor_key = 0u64;
-
         xor_key ^= self.hash.state(&initial_state, &self.state);
 
         self.key ^= xor_key;
 
+        #[cfg(feature = "verify")]
+        self.verify("make_null_move", NULL_MOVE, &initial_state);
+
         None
     }
 
@@ -145,6 +153,10 @@ impl Position {
 
         if mv.is_castle() {
             self.unmake_castle(mv.castle(), original_state.stm);
+
+            #[cfg(feature = "verify")]
+            self.verify("unmake", mv, original_state);
+
             return;
         }
 
@@ -158,25 +170,33 @@ impl Position {
         if let Some((captured_piece, capture_sq)) = capture {
             self.put_piece(captured_piece, capture_sq);
         }
+
+        #[cfg(feature = "verify")]
+        self.verify("unmake", mv, original_state);
     }
 
     fn unmake_castle(&mut self, castle: Castle, stm: Side) {
         let (to, from) = castle_king_squares(stm, castle);
-        self.move_piece(from, to);
+        self.shift_piece(from, to);
         let (to, from) = castle_rook_squares(stm, castle);
-        self.move_piece(from, to);
+        self.shift_piece(from, to);
+        self.update_castle_scores(castle, stm, true);
     }
 
     fn make_castle(&mut self, castle: Castle, stm: Side) {
         let (from, to) = castle_king_squares(stm, castle);
-        self.move_piece(from, to);
+        self.shift_piece(from, to);
         let (from, to) = castle_rook_squares(stm, castle);
-        self.move_piece(from, to);
+        self.shift_piece(from, to);
+        self.update_castle_scores(castle, stm, false);
     }
 
     pub fn unmake_null_move(&mut self, original_state: &State, original_hash_key: u64) {
         self.state = *original_state;
         self.key = original_hash_key;
+
+        #[cfg(feature = "verify")]
+        self.verify("unmake_null_move", NULL_MOVE, original_state);
     }
 }
 
@@ -186,7 +206,7 @@ mod test {
     use crate::integrity;
     use crate::mv::Move;
     use crate::piece::*;
-    use crate::position::Position;
+    use crate::position::{Position, STARTING_POSITION_FEN};
     use crate::square::*;
 
     fn test_make_unmake(initial_fen: &'static str, expected_fen: &'static str, mv: Move) {
@@ -213,7 +233,7 @@ mod test {
     #[test]
     fn test_hmc_incremented_by_non_pawn_non_capture() {
         let mut position =
-            Position::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBKQBNR w QqKk - 20 1")
+            Position::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w QqKk - 20 1")
                 .unwrap();
         position.make(Move::new_push(B1, C3));
 
@@ -223,7 +243,7 @@ mod test {
     #[test]
     fn test_hmc_reset_by_pawn_non_capture() {
         let mut position =
-            Position::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBKQBNR w QqKk - 20 1")
+            Position::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w QqKk - 20 1")
                 .unwrap();
         position.make(Move::new_push(A2, A3));
 
@@ -233,7 +253,7 @@ mod test {
     #[test]
     fn test_hmc_reset_by_non_pawn_capture() {
         let mut position =
-            Position::from_fen("rnbqkbnr/1ppppppp/8/8/8/p7/PPPPPPPP/RNBKQBNR w QqKk - 20 1")
+            Position::from_fen("rnbqkbnr/1ppppppp/8/8/8/p7/PPPPPPPP/RNBQKBNR w QqKk - 20 1")
                 .unwrap();
         position.make(Move::new_capture(B1, A3));
 
@@ -260,6 +280,59 @@ mod test {
         assert_eq!(position_1.hash_key(), position_2.hash_key());
     }
 
+    #[test]
+    fn test_hash_matches_fen() {
+        let mut position = Position::from_fen(STARTING_POSITION_FEN).unwrap();
+        for &mv in [
+            Move::new_push(E2, E4),
+            Move::new_push(D7, D5),
+            Move::new_push(E4, E5),
+            Move::new_push(F7, F5),
+            Move::new_ep_capture(E5, F6),
+            Move::new_push(C7, C5),
+        ]
+        .iter()
+        {
+            position.make(mv);
+            let from_fen = Position::from_fen(&position.to_fen()).unwrap();
+            assert_eq!(
+                position.hash_key(),
+                from_fen.hash_key(),
+                "{}",
+                position.to_fen()
+            );
+        }
+
+        position.make_null_move();
+        let from_fen = Position::from_fen(&position.to_fen()).unwrap();
+        assert_eq!(position.hash_key(), from_fen.hash_key());
+    }
+
+    #[test]
+    fn test_pawn_and_material_keys() {
+        let mut position = Position::from_fen("r3k2r/1P6/8/8/8/8/8/R3K2R b KQkq - 0 1").unwrap();
+        let pawn_key = position.pawn_key();
+        let material_key = position.material_key();
+
+        position.make(Move::new_castle(KING_SIDE));
+        assert_eq!(position.pawn_key(), pawn_key);
+        assert_eq!(position.material_key(), material_key);
+
+        let mv = Move::new_capture_promotion(B7, A8, QUEEN);
+        let state = *position.state();
+        let key = position.hash_key();
+        let capture = position.make(mv);
+        let expected = Position::from_fen(&position.to_fen()).unwrap();
+        assert_eq!(position.pawn_key(), expected.pawn_key());
+        assert_eq!(position.material_key(), expected.material_key());
+        assert_ne!(position.pawn_key(), pawn_key);
+        assert_ne!(position.material_key(), material_key);
+
+        position.unmake(mv, capture, &state, key);
+        assert_eq!(position.pawn_key(), pawn_key);
+        assert_eq!(position.material_key(), material_key);
+    }
+
     #[test]
     fn test_make_unmake_simple_push() {
         test_make_unmake(
@@ -1,22 +1,32 @@
+pub mod compressed;
 pub mod fen;
 pub mod make;
+#[cfg(feature = "verify")]
+mod verify;
 
+use self::compressed::*;
 use self::fen::*;
 use super::util::grid_to_string_with_props;
 use crate::bb::*;
+use crate::castle::Castle;
 use crate::castling_rights::*;
 use crate::hash::{DEFAULT_ZOBRISH_HASH, Zobrist};
-use crate::mv_list::PieceSquareTable;
+use crate::mv_list::{MAX_PHASE, PieceSquareTable, TaperedPieceSquareTable, TaperedScore};
 use crate::piece::*;
 use crate::side::Side;
 use crate::side::*;
 use crate::square::{Square, SquareInternal};
-use std::fmt;
-
-use std;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
 
 pub const STARTING_POSITION_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w QqKk - 0 1";
 
+/// Size of fixed-size binary position encoding (see Position::to_fixed_bytes)
+pub const POSITION_FIXED_BYTES: usize = 32;
+
 /// State encodes all game state except position
 #[derive(Debug, Clone, Copy)]
 pub struct State {
@@ -51,11 +61,22 @@ pub struct Position {
     state: State,
 
     key: u64,
+    // pawn_key is a hash of pawn positions only
+    pawn_key: u64,
+    // material_key is a hash of the number of each piece
+    material_key: u64,
 
     hash: &'static Zobrist,
+
+    // psqt_score and tapered_score are piece-square scores relative to white, kept up to date
+    // while the matching table is set
+    psqt_table: Option<&'static PieceSquareTable>,
+    psqt_score: i16,
+    tapered_table: Option<&'static TaperedPieceSquareTable>,
+    tapered_score: TaperedScore,
 }
 
-impl std::clone::Clone for Position {
+impl core::clone::Clone for Position {
     fn clone(&self) -> Self {
         Position {
             grid: self.grid,
@@ -63,7 +84,13 @@ impl std::clone::Clone for Position {
             bb_pieces: self.bb_pieces,
             state: self.state,
             key: self.key,
+            pawn_key: self.pawn_key,
+            material_key: self.material_key,
             hash: self.hash,
+            psqt_table: self.psqt_table,
+            psqt_score: self.psqt_score,
+            tapered_table: self.tapered_table,
+            tapered_score: self.tapered_score,
         }
     }
 }
@@ -104,6 +131,11 @@ impl fmt::Display for Position {
 
 impl Position {
     pub fn new(grid: [Piece; 64], state: State) -> Position {
+        Position::new_with_hash(grid, state, &DEFAULT_ZOBRISH_HASH)
+    }
+
+    /// Construct a new position which uses the provided Zobrist keys (eg POLYGLOT_ZOBRIST_HASH)
+    pub fn new_with_hash(grid: [Piece; 64], state: State, hash: &'static Zobrist) -> Position {
         let mut bb_pieces = [EMPTY; 12];
         let mut bb_sides = [EMPTY; 2];
 
@@ -113,16 +145,23 @@ impl Position {
             bb_pieces[pc.to_usize()] |= bb_mask;
         }
 
-        let hash = &DEFAULT_ZOBRISH_HASH;
         let key = hash.position(&grid, &state);
+        let pawn_key = hash.pawns(&grid);
+        let material_key = hash.material(&grid);
 
         Position {
             grid,
             bb_pieces,
             bb_sides,
             state,
-            hash: &DEFAULT_ZOBRISH_HASH,
+            hash,
             key,
+            pawn_key,
+            material_key,
+            psqt_table: None,
+            psqt_score: 0,
+            tapered_table: None,
+            tapered_score: TaperedScore::default(),
         }
     }
 
@@ -131,15 +170,229 @@ impl Position {
         from_fen(fen).map(|(grid, state)| Position::new(grid, state))
     }
 
+    /// Construct a new position from a FEN string which uses the provided Zobrist keys
+    pub fn from_fen_with_hash(fen: &str, hash: &'static Zobrist) -> Result<Position, String> {
+        from_fen(fen).map(|(grid, state)| Position::new_with_hash(grid, state, hash))
+    }
+
     // Convert position to FEN representation
     pub fn to_fen(&self) -> String {
         to_fen(&self.grid, &self.state)
     }
 
+    /// Encode position in a compact binary format of at most 27 bytes: the compressed
+    /// position (see compressed.rs) followed by the half-move clock and full-move number.
+    /// Fails for positions with more than 32 pieces, castling rights without their rook or an
+    /// en-passant square without the pushed pawn
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        let compressed = compress(&self.grid, &self.state)?;
+        let size = compressed_size(self.bb_occupied());
+
+        let mut bytes = Vec::with_capacity(size + 3);
+        bytes.extend_from_slice(&compressed[0..size]);
+        bytes.push(self.state.half_move_clock);
+        bytes.extend_from_slice(&self.state.full_move_number.to_be_bytes());
+        Ok(bytes)
+    }
+
+    /// Decode position encoded with to_bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Position, String> {
+        let (grid, mut state, size) = decompress(bytes)?;
+
+        if bytes.len() != size + 3 {
+            return Err(format!(
+                "Expected {} bytes for position but found {}",
+                size + 3,
+                bytes.len()
+            ));
+        }
+
+        state.half_move_clock = bytes[size];
+        state.full_move_number = u16::from_be_bytes([bytes[size + 1], bytes[size + 2]]);
+
+        Ok(Position::new(grid, state))
+    }
+
+    /// Encode position in a fixed-size binary format, for use in arrays of positions (eg
+    /// memory-mapped files). Same as to_bytes, but with the compressed position zero padded
+    /// to 24 bytes and the total padded to 32 bytes
+    pub fn to_fixed_bytes(&self) -> Result<[u8; POSITION_FIXED_BYTES], String> {
+        let mut bytes = [0u8; POSITION_FIXED_BYTES];
+        bytes[0..COMPRESSED_POSITION_SIZE].copy_from_slice(&compress(&self.grid, &self.state)?);
+        bytes[COMPRESSED_POSITION_SIZE] = self.state.half_move_clock;
+        bytes[COMPRESSED_POSITION_SIZE + 1..COMPRESSED_POSITION_SIZE + 3]
+            .copy_from_slice(&self.state.full_move_number.to_be_bytes());
+        Ok(bytes)
+    }
+
+    /// Decode position encoded with to_fixed_bytes
+    pub fn from_fixed_bytes(bytes: &[u8; POSITION_FIXED_BYTES]) -> Result<Position, String> {
+        let (grid, mut state, _) = decompress(bytes)?;
+
+        state.half_move_clock = bytes[COMPRESSED_POSITION_SIZE];
+        state.full_move_number = u16::from_be_bytes([
+            bytes[COMPRESSED_POSITION_SIZE + 1],
+            bytes[COMPRESSED_POSITION_SIZE + 2],
+        ]);
+
+        Ok(Position::new(grid, state))
+    }
+
     pub fn hash_key(&self) -> u64 {
         self.key
     }
 
+    /// Hash key of pawn positions only
+    pub fn pawn_key(&self) -> u64 {
+        self.pawn_key
+    }
+
+    /// Hash key of the number of each piece (eg for material imbalance caches)
+    pub fn material_key(&self) -> u64 {
+        self.material_key
+    }
+
+    /// Zobrist keys used to hash the position
+    pub fn zobrist(&self) -> &'static Zobrist {
+        self.hash
+    }
+
+    /// Switch to a different set of Zobrist keys, recomputing the hash key
+    pub fn set_zobrist(&mut self, hash: &'static Zobrist) {
+        self.hash = hash;
+        self.key = hash.position(&self.grid, &self.state);
+        self.pawn_key = hash.pawns(&self.grid);
+        self.material_key = hash.material(&self.grid);
+    }
+
+    /// Keeps a piece-square score up to date as moves are made and unmade, see psqt_score.
+    /// Pass None to stop
+    #[allow(dead_code)]
+    pub fn set_piece_square_table(&mut self, table: Option<&'static PieceSquareTable>) {
+        self.psqt_table = table;
+        self.psqt_score = self.psqt_score_from_scratch();
+    }
+
+    /// Piece-square score relative to the side to move, maintained incrementally. Equal to
+    /// piece_square_score with the table set by set_piece_square_table, or zero if none is set
+    #[allow(dead_code)]
+    pub fn psqt_score(&self) -> i16 {
+        if self.state.stm == WHITE {
+            self.psqt_score
+        } else {
+            -self.psqt_score
+        }
+    }
+
+    /// Keeps a tapered piece-square score up to date as moves are made and unmade, see
+    /// tapered_psqt_score. Pass None to stop
+    #[allow(dead_code)]
+    pub fn set_tapered_piece_square_table(
+        &mut self,
+        table: Option<&'static TaperedPieceSquareTable>,
+    ) {
+        self.tapered_table = table;
+        self.tapered_score = self.tapered_score_from_scratch();
+    }
+
+    /// Midgame and endgame piece-square score relative to the side to move, maintained
+    /// incrementally. Zero unless a table is set with set_tapered_piece_square_table
+    #[allow(dead_code)]
+    pub fn tapered_psqt_score(&self) -> TaperedScore {
+        if self.state.stm == WHITE {
+            self.tapered_score
+        } else {
+            -self.tapered_score
+        }
+    }
+
+    /// tapered_psqt_score interpolated by game_phase
+    #[allow(dead_code)]
+    pub fn tapered_psqt_eval(&self) -> i16 {
+        self.tapered_psqt_score().interpolate(self.game_phase())
+    }
+
+    /// Game phase from remaining material, from MAX_PHASE with all pieces on the board down to
+    /// 0 with only kings and pawns. Knights and bishops count 1, rooks 2 and queens 4
+    #[allow(dead_code)]
+    pub fn game_phase(&self) -> i32 {
+        let count = |kind: Kind| {
+            (self.bb_pc(kind.pc(WHITE)) | self.bb_pc(kind.pc(BLACK))).pop_count() as i32
+        };
+        let phase = count(KNIGHT) + count(BISHOP) + 2 * count(ROOK) + 4 * count(QUEEN);
+        phase.min(MAX_PHASE)
+    }
+
+    fn psqt_score_from_scratch(&self) -> i16 {
+        let Some(table) = self.psqt_table else {
+            return 0;
+        };
+        let score = self.piece_square_score(table);
+        if self.state.stm == WHITE {
+            score
+        } else {
+            -score
+        }
+    }
+
+    fn tapered_score_from_scratch(&self) -> TaperedScore {
+        let mut score = TaperedScore::default();
+        let Some(table) = self.tapered_table else {
+            return score;
+        };
+
+        for (idx, &pc) in self.grid().iter().enumerate() {
+            if pc.is_some() {
+                score += Self::signed_tapered_score(table, pc, Square(idx));
+            }
+        }
+
+        score
+    }
+
+    // Score of pc on sq relative to white
+    fn signed_tapered_score(
+        table: &TaperedPieceSquareTable,
+        pc: Piece,
+        sq: Square,
+    ) -> TaperedScore {
+        let score = table.score(pc.kind(), sq.from_side(pc.side()));
+        if pc.side() == WHITE { score } else { -score }
+    }
+
+    fn add_piece_scores(&mut self, pc: Piece, sq: Square) {
+        if let Some(table) = self.psqt_table {
+            let score = table.score(pc.kind(), sq.from_side(pc.side()));
+            self.psqt_score += if pc.side() == WHITE { score } else { -score };
+        }
+        if let Some(table) = self.tapered_table {
+            self.tapered_score += Self::signed_tapered_score(table, pc, sq);
+        }
+    }
+
+    fn remove_piece_scores(&mut self, pc: Piece, sq: Square) {
+        if let Some(table) = self.psqt_table {
+            let score = table.score(pc.kind(), sq.from_side(pc.side()));
+            self.psqt_score -= if pc.side() == WHITE { score } else { -score };
+        }
+        if let Some(table) = self.tapered_table {
+            self.tapered_score -= Self::signed_tapered_score(table, pc, sq);
+        }
+    }
+
+    // Applies the score change of stm castling, or reverts it if undo is set
+    fn update_castle_scores(&mut self, castle: Castle, stm: Side, undo: bool) {
+        let negate = (stm == WHITE) == undo;
+        if let Some(table) = self.psqt_table {
+            let score = table.castle_score(castle);
+            self.psqt_score += if negate { -score } else { score };
+        }
+        if let Some(table) = self.tapered_table {
+            let score = table.castle_score(castle);
+            self.tapered_score += if negate { -score } else { score };
+        }
+    }
+
     /// Get position non-positional state
     pub fn state(&self) -> &State {
         &self.state
@@ -160,6 +413,15 @@ impl Position {
         self.bb_pc(pc).bitscan()
     }
 
+    /// Toggles a piece in the pawn and material keys, where n is the number of pieces of the
+    /// same type excluding this one
+    fn update_piece_keys(&mut self, pc: Piece, sq: Square, n: usize) {
+        if pc.kind() == PAWN {
+            self.pawn_key ^= self.hash.piece_square(pc, sq);
+        }
+        self.material_key ^= self.hash.material_count(pc, n);
+    }
+
     fn put_piece(&mut self, pc: Piece, sq: Square) {
         debug_assert!(self.at(sq).is_none());
 
@@ -170,6 +432,10 @@ impl Position {
         debug_assert_eq!(self.bb_pc(pc) & bb_mask, EMPTY);
         debug_assert_eq!(self.bb_side(pc.side()) & bb_mask, EMPTY);
 
+        self.update_piece_keys(pc, sq, self.bb_pc(pc).pop_count() as usize);
+
+        self.add_piece_scores(pc, sq);
+
         unsafe {
             *self.bb_pieces.get_unchecked_mut(pc.to_usize()) ^= bb_mask;
             *self.bb_sides.get_unchecked_mut(pc.side().to_usize()) ^= bb_mask;
@@ -187,6 +453,10 @@ impl Position {
         debug_assert_eq!(self.bb_pc(pc) & bb_mask, bb_mask);
         debug_assert_eq!(self.bb_side(pc.side()) & bb_mask, bb_mask);
 
+        self.update_piece_keys(pc, sq, self.bb_pc(pc).pop_count() as usize - 1);
+
+        self.remove_piece_scores(pc, sq);
+
         unsafe {
             *self.bb_pieces.get_unchecked_mut(pc.to_usize()) ^= bb_mask;
             *self.bb_sides.get_unchecked_mut(pc.side().to_usize()) ^= bb_mask;
@@ -194,6 +464,15 @@ impl Position {
     }
 
     fn move_piece(&mut self, from: Square, to: Square) -> BB {
+        let pc = self.at(from);
+        self.remove_piece_scores(pc, from);
+        self.add_piece_scores(pc, to);
+        self.shift_piece(from, to)
+    }
+
+    // Moves a piece without updating piece-square scores, eg for castling which updates them
+    // all at once
+    fn shift_piece(&mut self, from: Square, to: Square) -> BB {
         debug_assert!(self.at(from).is_some());
         debug_assert!(self.at(to).is_none());
 
@@ -208,6 +487,10 @@ impl Position {
         self.update_grid(from, NULL_PIECE);
         self.update_grid(to, pc);
 
+        if pc.kind() == PAWN {
+            self.pawn_key ^= self.hash.push(pc, from, pc, to);
+        }
+
         unsafe {
             *self.bb_pieces.get_unchecked_mut(pc.to_usize()) ^= bb_mask;
             *self.bb_sides.get_unchecked_mut(pc.side().to_usize()) ^= bb_mask;
@@ -229,6 +512,12 @@ impl Position {
         debug_assert_eq!(self.bb_pc(new_pc) & bb_mask, EMPTY);
         debug_assert_eq!(self.bb_side(old_pc.side()) & bb_mask, bb_mask);
 
+        self.update_piece_keys(old_pc, sq, self.bb_pc(old_pc).pop_count() as usize - 1);
+        self.update_piece_keys(new_pc, sq, self.bb_pc(new_pc).pop_count() as usize);
+
+        self.remove_piece_scores(old_pc, sq);
+        self.add_piece_scores(new_pc, sq);
+
         unsafe {
             *(self.bb_pieces.get_unchecked_mut(old_pc.to_usize())) ^= bb_mask;
             *(self.bb_pieces.get_unchecked_mut(new_pc.to_usize())) |= bb_mask;
@@ -298,9 +587,171 @@ mod test {
     use super::*;
     use unindent;
 
+    #[test]
+    fn test_bytes() {
+        for fen in [
+            STARTING_POSITION_FEN,
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+            "r3k2r/8/8/8/8/8/8/R3K2R b Kq - 5 200",
+        ] {
+            let position = Position::from_fen(fen).unwrap();
+
+            let bytes = position.to_bytes().unwrap();
+            assert!(bytes.len() <= 27);
+            let decoded = Position::from_bytes(&bytes).unwrap();
+            assert_eq!(decoded.to_fen(), position.to_fen());
+            assert_eq!(decoded.hash_key(), position.hash_key());
+
+            let fixed = position.to_fixed_bytes().unwrap();
+            assert_eq!(&fixed[0..bytes.len() - 3], &bytes[0..bytes.len() - 3]);
+            assert_eq!(
+                Position::from_fixed_bytes(&fixed).unwrap().to_fen(),
+                position.to_fen()
+            );
+        }
+
+        let bytes = Position::from_fen(STARTING_POSITION_FEN)
+            .unwrap()
+            .to_bytes()
+            .unwrap();
+        assert_eq!(bytes.len(), 27);
+        assert!(Position::from_bytes(&bytes[0..26]).is_err());
+
+        // 33 pieces
+        let position =
+            Position::from_fen("rnbqkbnr/pppppppp/8/8/8/5N2/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
+                .unwrap();
+        assert!(position.to_bytes().is_err());
+        assert!(position.to_fixed_bytes().is_err());
+
+        // castling right without its rook and en-passant square without a pushed pawn
+        for fen in [
+            "4k3/8/8/8/8/8/8/4K3 w K - 0 1",
+            "4k3/8/8/8/8/8/8/4K3 w - d6 0 1",
+        ] {
+            let position = Position::from_fen(fen).unwrap();
+            assert!(position.to_bytes().is_err(), "{}", fen);
+            assert!(position.to_fixed_bytes().is_err(), "{}", fen);
+        }
+    }
+
+    #[test]
+    fn test_tapered_psqt_score() {
+        use crate::generation::legal_moves;
+        use crate::mv_list::MoveVec;
+        use alloc::boxed::Box;
+        use rand::seq::SliceRandom;
+
+        let mut mg = [[0i16; 64]; 6];
+        let mut eg = [[0i16; 64]; 6];
+        for (kind, (mg_values, eg_values)) in mg.iter_mut().zip(eg.iter_mut()).enumerate() {
+            for sq in 0..64 {
+                mg_values[sq] = (kind * 100 + sq) as i16;
+                eg_values[sq] = (kind * 50) as i16 - sq as i16;
+            }
+        }
+        let table: &'static TaperedPieceSquareTable =
+            Box::leak(Box::new(TaperedPieceSquareTable::new(mg, eg)));
+
+        let mut position =
+            Position::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -")
+                .unwrap();
+        assert_eq!(position.tapered_psqt_score(), TaperedScore::default());
+        position.set_tapered_piece_square_table(Some(table));
+        let initial_score = position.tapered_psqt_score();
+        assert_ne!(initial_score, TaperedScore::default());
+        assert_eq!(position.game_phase(), MAX_PHASE);
+
+        let mut history = Vec::new();
+        for _ in 0..40 {
+            let mut moves = MoveVec::new();
+            legal_moves(&position, &mut moves);
+            let Some(&mv) = moves
+                .iter()
+                .collect::<Vec<_>>()
+                .choose(&mut rand::thread_rng())
+            else {
+                break;
+            };
+
+            let state = *position.state();
+            let key = position.hash_key();
+            let capture = position.make(*mv);
+            history.push((*mv, capture, state, key));
+
+            assert_eq!(
+                position.tapered_score,
+                position.tapered_score_from_scratch()
+            );
+        }
+
+        while let Some((mv, capture, state, key)) = history.pop() {
+            position.unmake(mv, capture, &state, key);
+        }
+        assert_eq!(position.tapered_psqt_score(), initial_score);
+
+        position.set_tapered_piece_square_table(None);
+        assert_eq!(position.tapered_psqt_score(), TaperedScore::default());
+    }
+
+    #[test]
+    fn test_psqt_score() {
+        use crate::castle::{KING_SIDE, QUEEN_SIDE};
+        use crate::generation::legal_moves;
+        use crate::mv::Move;
+        use crate::mv_list::MoveVec;
+        use alloc::boxed::Box;
+        use rand::Rng;
+
+        let mut values = [[0i16; 64]; 6];
+        for kind_values in values.iter_mut() {
+            for value in kind_values.iter_mut() {
+                *value = rand::thread_rng().gen_range(100..=200);
+            }
+        }
+        let table: &'static PieceSquareTable = Box::leak(Box::new(PieceSquareTable::new(values)));
+
+        for fen in [
+            "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1",
+            "r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+        ] {
+            let mut position = Position::from_fen(fen).unwrap();
+            assert_eq!(position.psqt_score(), 0);
+            position.set_piece_square_table(Some(table));
+            let initial_score = position.psqt_score();
+            assert_eq!(initial_score, position.piece_square_score(table));
+
+            let mut moves = MoveVec::new();
+            legal_moves(&position, &mut moves);
+            for &mv in moves.iter() {
+                let state = *position.state();
+                let key = position.hash_key();
+
+                let capture = position.make(mv);
+                assert_eq!(position.psqt_score(), position.piece_square_score(table));
+                position.unmake(mv, capture, &state, key);
+                assert_eq!(position.psqt_score(), initial_score);
+            }
+
+            assert!(moves.iter().any(|&mv| mv == Move::new_castle(KING_SIDE)));
+            assert!(moves.iter().any(|&mv| mv == Move::new_castle(QUEEN_SIDE)));
+        }
+    }
+
+    #[test]
+    fn test_game_phase() {
+        let position = Position::from_fen("4k3/pppp4/8/8/8/8/4PPPP/R3K3 w - - 0 1").unwrap();
+        assert_eq!(position.game_phase(), 2);
+
+        let position = Position::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(position.game_phase(), 0);
+        assert_eq!(position.tapered_psqt_eval(), 0);
+    }
+
     #[test]
     fn test_is_not_too_big() {
-        assert_eq!(std::mem::size_of::<Position>(), 224);
+        assert_eq!(core::mem::size_of::<Position>(), 264);
     }
 
     #[test]
@@ -0,0 +1,138 @@
+use super::{Position, State};
+use crate::integrity;
+use crate::mv::Move;
+use crate::piece::*;
+use crate::side::*;
+use crate::util::grid_to_string;
+use alloc::format;
+use alloc::vec::Vec;
+
+/*
+    Enabled with the "verify" feature. After every make, unmake and null move the
+    incrementally updated parts of the position are compared against a position built
+    from scratch from the grid and state. Panics with a description of every difference.
+*/
+
+impl Position {
+    pub fn verify(&self, action: &str, mv: Move, original_state: &State) {
+        let expected = Position::new_with_hash(self.grid, self.state, self.hash);
+        let mut errors = Vec::new();
+
+        // grid as implied by the bitboards
+        let mut bb_grid = [NULL_PIECE; 64];
+        for pc in Piece::iter() {
+            for (sq, _) in self.bb_pc(pc).iter() {
+                bb_grid[sq.to_usize()] = pc;
+            }
+        }
+
+        if bb_grid != self.grid {
+            errors.push(format!(
+                "grid does not match bitboards\nexpected (from bitboards):\n{}\nactual:\n{}",
+                grid_to_string(|sq| piece_char(bb_grid[sq.to_usize()])),
+                grid_to_string(|sq| piece_char(self.grid[sq.to_usize()])),
+            ));
+        }
+
+        for &side in &[WHITE, BLACK] {
+            if expected.bb_side(side) != self.bb_side(side) {
+                errors.push(format!(
+                    "{} bitboard\nexpected:\n{}\nactual:\n{}",
+                    side.to_str(),
+                    expected.bb_side(side),
+                    self.bb_side(side)
+                ));
+            }
+        }
+
+        for pc in Piece::iter() {
+            if expected.bb_pc(pc) != self.bb_pc(pc) {
+                errors.push(format!(
+                    "{} bitboard\nexpected:\n{}\nactual:\n{}",
+                    pc.to_string(),
+                    expected.bb_pc(pc),
+                    self.bb_pc(pc)
+                ));
+            }
+        }
+
+        let keys = [
+            ("hash key", expected.key, self.key),
+            ("pawn key", expected.pawn_key, self.pawn_key),
+            ("material key", expected.material_key, self.material_key),
+        ];
+        for &(name, expected, actual) in keys.iter() {
+            if expected != actual {
+                errors.push(format!(
+                    "{}: expected {:016X} actual {:016X}",
+                    name, expected, actual
+                ));
+            }
+        }
+
+        if self.psqt_score != self.psqt_score_from_scratch() {
+            errors.push(format!(
+                "psqt score: expected {} actual {}",
+                self.psqt_score_from_scratch(),
+                self.psqt_score
+            ));
+        }
+
+        if self.tapered_score != self.tapered_score_from_scratch() {
+            errors.push(format!(
+                "tapered score: expected {:?} actual {:?}",
+                self.tapered_score_from_scratch(),
+                self.tapered_score
+            ));
+        }
+
+        if let Some(err) = integrity::test(self) {
+            errors.push(format!("integrity: {}", err));
+        }
+
+        if !errors.is_empty() {
+            panic!(
+                "position inconsistent after {} {}\nstate before: {:?}\nstate after: {:?}\n{}\n{}",
+                action,
+                mv,
+                original_state,
+                self.state,
+                self,
+                errors.join("\n")
+            );
+        }
+    }
+}
+
+fn piece_char(pc: Piece) -> char {
+    if pc.is_none() { '.' } else { pc.to_char() }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::mv::Move;
+    use crate::position::Position;
+    use crate::square::*;
+
+    #[test]
+    fn test_verify_ok() {
+        let position = Position::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+        position.verify("make", Move::new_push(A1, B1), position.state());
+    }
+
+    #[test]
+    #[should_panic(expected = "hash key")]
+    fn test_verify_bad_key() {
+        let mut position = Position::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+        position.key ^= 1;
+        position.verify("make", Move::new_push(A1, B1), position.state());
+    }
+
+    #[test]
+    #[should_panic(expected = "psqt score")]
+    fn test_verify_bad_psqt_score() {
+        let mut position = Position::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+        position.psqt_score += 1;
+        position.verify("make", Move::new_push(A1, B1), position.state());
+    }
+}
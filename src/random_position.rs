@@ -0,0 +1,387 @@
+use crate::castling_rights::*;
+use crate::generation::movegen_preprocessing;
+use crate::integrity;
+use crate::piece::*;
+use crate::position::{Position, State};
+use crate::side::*;
+use crate::square::*;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::ops::RangeInclusive;
+
+const MAX_ATTEMPTS: usize = 10_000;
+
+// Pieces other than pawns and kings at the start of the game
+const STARTING_PIECES: [Kind; 7] = [KNIGHT, KNIGHT, BISHOP, BISHOP, ROOK, ROOK, QUEEN];
+
+/// Options used when generating random positions
+#[derive(Debug, Clone)]
+pub struct RandomPositionOptions {
+    /// Number of pawns for each side
+    pub pawns: RangeInclusive<usize>,
+    /// Number of pieces other than pawns and kings for each side
+    pub pieces: RangeInclusive<usize>,
+    /// Exact material for both sides (eg "KRPvKR"), overrides pawns and pieces
+    pub material: Option<String>,
+    /// Probability of each castling right being kept when king and rook are in place
+    pub castling_probability: f64,
+    /// Probability of setting the en-passant square when a pawn could have just double-pushed
+    pub ep_probability: f64,
+}
+
+impl Default for RandomPositionOptions {
+    fn default() -> Self {
+        RandomPositionOptions {
+            pawns: 0..=8,
+            pieces: 0..=7,
+            material: None,
+            castling_probability: 0.5,
+            ep_probability: 0.5,
+        }
+    }
+}
+
+/// RandomPositionGenerator generates random legal positions from a seed. Positions pass the
+/// integrity checks, the side not to move is never in check and piece counts could be reached
+/// by promotions
+///
+/// Example usage:
+///
+/// let mut generator = RandomPositionGenerator::new(1, RandomPositionOptions::default())?;
+/// for position in generator.take(100) {
+///     ...
+/// }
+///
+pub struct RandomPositionGenerator {
+    rng: StdRng,
+    options: RandomPositionOptions,
+    // material for each side if provided (pawns, other pieces)
+    material: Option<[(usize, Vec<Kind>); 2]>,
+}
+
+impl RandomPositionGenerator {
+    pub fn new(
+        seed: u64,
+        options: RandomPositionOptions,
+    ) -> Result<RandomPositionGenerator, String> {
+        let material = match options.material {
+            Some(ref signature) => Some(parse_material(signature)?),
+            None => None,
+        };
+
+        if options.pawns.is_empty() || options.pieces.is_empty() {
+            return Err("Empty pawn or piece range".to_string());
+        }
+
+        Ok(RandomPositionGenerator {
+            rng: StdRng::seed_from_u64(seed),
+            options,
+            material,
+        })
+    }
+
+    /// Generates a random position. Returns an error if no legal position is found after many
+    /// attempts (eg the material can only be placed with a side in check)
+    pub fn generate(&mut self) -> Result<Position, String> {
+        for _ in 0..MAX_ATTEMPTS {
+            if let Some(position) = self.try_generate() {
+                return Ok(position);
+            }
+        }
+
+        Err(format!(
+            "Could not generate legal position after {} attempts",
+            MAX_ATTEMPTS
+        ))
+    }
+
+    fn try_generate(&mut self) -> Option<Position> {
+        let mut grid = [NULL_PIECE; 64];
+
+        // kings cannot be adjacent
+        let white_king = Square(self.rng.gen_range(0..64));
+        let black_king = Square(self.rng.gen_range(0..64));
+        if white_king == black_king || white_king.king_moves().is_set(black_king) {
+            return None;
+        }
+        grid[white_king.to_usize()] = WHITE_KING;
+        grid[black_king.to_usize()] = BLACK_KING;
+
+        for &side in [WHITE, BLACK].iter() {
+            let (pawns, pieces) = match self.material {
+                Some(ref material) => material[side.to_usize()].clone(),
+                None => self.random_material(),
+            };
+
+            for _ in 0..pawns {
+                // pawns cannot be on the first or last row
+                let sq = self.random_empty_square(&grid, 8..56)?;
+                grid[sq.to_usize()] = PAWN.pc(side);
+            }
+
+            for kind in pieces {
+                let sq = self.random_empty_square(&grid, 0..64)?;
+                grid[sq.to_usize()] = kind.pc(side);
+            }
+        }
+
+        let mut state = State {
+            stm: if self.rng.gen_bool(0.5) { WHITE } else { BLACK },
+            castling_rights: self.random_castling_rights(&grid),
+            ..Default::default()
+        };
+
+        // side not to move cannot be in check
+        let opponent = Position::new(
+            grid,
+            State {
+                stm: state.stm.flip(),
+                castling_rights: NO_RIGHTS,
+                ..Default::default()
+            },
+        );
+        if movegen_preprocessing(&opponent).in_check() {
+            return None;
+        }
+
+        let mut position = Position::new(grid, state);
+
+        // checks are not consistent with an en-passant square unless given by the pushed pawn,
+        // so only set the en-passant square if the side to move is not in check
+        if !movegen_preprocessing(&position).in_check() {
+            state.ep_square = self.random_ep_square(&grid, state.stm);
+            if state.ep_square.is_some() {
+                position = Position::new(grid, state);
+            }
+        }
+
+        if integrity::test(&position).is_some() {
+            return None;
+        }
+
+        Some(position)
+    }
+
+    /// Random number of pawns and random pieces for one side, where any pieces in addition to
+    /// the starting pieces are promoted pawns
+    fn random_material(&mut self) -> (usize, Vec<Kind>) {
+        let pawns = self.rng.gen_range(self.options.pawns.clone()).min(8);
+        let max_pieces = STARTING_PIECES.len() + 8 - pawns;
+        let count = self
+            .rng
+            .gen_range(self.options.pieces.clone())
+            .min(max_pieces);
+
+        let mut pieces = STARTING_PIECES.to_vec();
+        pieces.shuffle(&mut self.rng);
+        pieces.truncate(count);
+
+        while pieces.len() < count {
+            let promoted = [QUEEN, QUEEN, ROOK, BISHOP, KNIGHT];
+            pieces.push(*promoted.choose(&mut self.rng).unwrap());
+        }
+
+        (pawns, pieces)
+    }
+
+    fn random_empty_square(
+        &mut self,
+        grid: &[Piece; 64],
+        squares: std::ops::Range<usize>,
+    ) -> Option<Square> {
+        let empty = squares
+            .filter(|&idx| grid[idx].is_none())
+            .collect::<Vec<usize>>();
+        empty.choose(&mut self.rng).map(|&idx| Square(idx))
+    }
+
+    fn random_castling_rights(&mut self, grid: &[Piece; 64]) -> CastlingRights {
+        let mut rights = NO_RIGHTS;
+        for &(right, king_sq, rook_sq, side) in [
+            (WHITE_QS, E1, A1, WHITE),
+            (WHITE_KS, E1, H1, WHITE),
+            (BLACK_QS, E8, A8, BLACK),
+            (BLACK_KS, E8, H8, BLACK),
+        ]
+        .iter()
+        {
+            if grid[king_sq.to_usize()] == KING.pc(side)
+                && grid[rook_sq.to_usize()] == ROOK.pc(side)
+                && self.rng.gen_bool(self.options.castling_probability)
+            {
+                rights.set(right);
+            }
+        }
+        rights
+    }
+
+    /// Picks an en-passant square behind a pawn of the side not to move which could have just
+    /// double-pushed
+    fn random_ep_square(&mut self, grid: &[Piece; 64], stm: Side) -> Option<Square> {
+        if !self.rng.gen_bool(self.options.ep_probability) {
+            return None;
+        }
+
+        let (pawn_row, ep_row, from_row) = if stm == WHITE { (4, 5, 6) } else { (3, 2, 1) };
+        let pawn = PAWN.pc(stm.flip());
+
+        let candidates = (0..8)
+            .filter(|&col| {
+                grid[Square::from(pawn_row, col).to_usize()] == pawn
+                    && grid[Square::from(ep_row, col).to_usize()].is_none()
+                    && grid[Square::from(from_row, col).to_usize()].is_none()
+            })
+            .collect::<Vec<usize>>();
+
+        candidates
+            .choose(&mut self.rng)
+            .map(|&col| Square::from(ep_row, col))
+    }
+}
+
+impl Iterator for RandomPositionGenerator {
+    type Item = Position;
+
+    /// Generates positions until no legal position can be found
+    fn next(&mut self) -> Option<Position> {
+        self.generate().ok()
+    }
+}
+
+/// Parses material signature (eg "KQPvKR") into pawn count and other pieces for each side
+fn parse_material(signature: &str) -> Result<[(usize, Vec<Kind>); 2], String> {
+    let sides = signature.split('v').collect::<Vec<&str>>();
+    if sides.len() != 2 {
+        return Err(format!("Invalid material signature: {}", signature));
+    }
+
+    let mut material = [(0, Vec::new()), (0, Vec::new())];
+
+    for (i, &side) in sides.iter().enumerate() {
+        let mut kings = 0;
+        for chr in side.chars() {
+            let kind = Piece::parse(chr)
+                .ok()
+                .filter(|pc| pc.side() == WHITE)
+                .ok_or_else(|| format!("Invalid piece '{}' in material: {}", chr, signature))?
+                .kind();
+
+            if kind == KING {
+                kings += 1;
+            } else if kind == PAWN {
+                material[i].0 += 1;
+            } else {
+                material[i].1.push(kind);
+            }
+        }
+
+        if kings != 1 {
+            return Err(format!(
+                "Material must have one king per side: {}",
+                signature
+            ));
+        }
+
+        let (pawns, ref pieces) = material[i];
+        let promoted = [(KNIGHT, 2), (BISHOP, 2), (ROOK, 2), (QUEEN, 1)]
+            .iter()
+            .map(|&(kind, start)| {
+                pieces
+                    .iter()
+                    .filter(|&&k| k == kind)
+                    .count()
+                    .saturating_sub(start)
+            })
+            .sum::<usize>();
+
+        if pawns > 8 || pawns + promoted > 8 {
+            return Err(format!("Too many pawns or promoted pieces: {}", signature));
+        }
+    }
+
+    Ok(material)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generation::legal_moves;
+    use crate::mv_list::MoveVec;
+
+    #[test]
+    fn test_positions_are_legal() {
+        let generator = RandomPositionGenerator::new(1, RandomPositionOptions::default()).unwrap();
+
+        for position in generator.take(500) {
+            assert!(integrity::test(&position).is_none(), "{}", position);
+
+            // make and unmake every move
+            let mut list = MoveVec::new();
+            legal_moves(&position, &mut list);
+            let mut position = position.clone();
+            for &mv in list.iter() {
+                let state = *position.state();
+                let key = position.hash_key();
+                let capture = position.make(mv);
+                position.unmake(mv, capture, &state, key);
+                assert_eq!(position.hash_key(), key);
+            }
+        }
+    }
+
+    #[test]
+    fn test_seed() {
+        let a = RandomPositionGenerator::new(7, RandomPositionOptions::default()).unwrap();
+        let b = RandomPositionGenerator::new(7, RandomPositionOptions::default()).unwrap();
+
+        for (a, b) in a.zip(b).take(20) {
+            assert_eq!(a.to_fen(), b.to_fen());
+        }
+    }
+
+    #[test]
+    fn test_material() {
+        let options = RandomPositionOptions {
+            material: Some("KRPPvKR".to_string()),
+            ..Default::default()
+        };
+        let mut generator = RandomPositionGenerator::new(3, options).unwrap();
+
+        for _ in 0..20 {
+            let position = generator.generate().unwrap();
+            assert_eq!(position.bb_pc(WHITE_PAWN).pop_count(), 2);
+            assert_eq!(position.bb_pc(WHITE_ROOK).pop_count(), 1);
+            assert_eq!(position.bb_pc(BLACK_ROOK).pop_count(), 1);
+            assert_eq!(position.bb_occupied().pop_count(), 6);
+        }
+    }
+
+    #[test]
+    fn test_invalid_material() {
+        for signature in ["KQvKQv", "KQvQ", "KKvK", "KXvK", "KQQQQQQQQQPPvK"].iter() {
+            let options = RandomPositionOptions {
+                material: Some(signature.to_string()),
+                ..Default::default()
+            };
+            assert!(
+                RandomPositionGenerator::new(1, options).is_err(),
+                "{}",
+                signature
+            );
+        }
+    }
+
+    #[test]
+    fn test_ranges() {
+        let options = RandomPositionOptions {
+            pawns: 8..=8,
+            pieces: 0..=0,
+            ..Default::default()
+        };
+        let generator = RandomPositionGenerator::new(5, options).unwrap();
+
+        for position in generator.take(20) {
+            assert_eq!(position.bb_occupied().pop_count(), 18);
+        }
+    }
+}
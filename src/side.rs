@@ -1,4 +1,6 @@
-use std::fmt;
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
 
 type SideInternal = usize;
 
@@ -18,7 +20,7 @@ impl Side {
         self.0
     }
 
-    pub fn raw(self) -> SideInternal {
+    pub const fn raw(self) -> SideInternal {
         self.0
     }
 
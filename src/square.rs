@@ -2,7 +2,9 @@ use crate::bb::{
     ANTI_DIAGONALS, BB, BISHOP_RAYS, DIAGONALS, FILE_A, KING_MOVES, KNIGHT_MOVES, ROOK_RAYS,
 };
 use crate::side::{BLACK, Side};
-use std::fmt;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
 
 #[cfg(test)]
 use rand;
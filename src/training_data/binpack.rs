@@ -0,0 +1,786 @@
+use super::{TrainingDataEntry, canonical_state, legal_move_vec};
+use crate::bb::*;
+use crate::castle::*;
+use crate::castling_rights::*;
+use crate::generation::slider::{bishop_attacks_from_sq, rook_attacks_from_sq};
+use crate::mv::Move;
+use crate::mv_list::MoveVec;
+use crate::piece::*;
+use crate::position::compressed::{COMPRESSED_POSITION_SIZE, compress, decompress};
+use crate::position::{Position, State};
+use crate::side::*;
+use crate::square::*;
+use std::io;
+
+/*
+    BINPACK FORMAT
+    The file is a sequence of chunks: "BINP", chunk size (u32 little-endian), chunk data.
+    Chunk data is a sequence of chains. Each chain starts with a 32 byte stem entry:
+    - compressed position (24 bytes, see position/compressed.rs)
+    - move (u16): type (2 bits), from (6 bits), to (6 bits), promotion (2 bits)
+    - score (u16)
+    - ply (14 bits) and result (2 bits)
+    - half-move clock (u16)
+    All stem fields are big-endian and signed values are zig-zag encoded. The stem is followed
+    by the number of entries continuing the game (u16) and a bit stream with the move and score
+    delta of each. Moves are encoded as the index of the piece among the side to move's pieces
+    and the index of the destination among the piece's pseudo-legal destinations.
+*/
+
+const CHUNK_HEADER: &[u8; 4] = b"BINP";
+const CHUNK_SIZE: usize = 1024 * 1024;
+const STEM_SIZE: usize = 32;
+const SCORE_VLE_BLOCK_SIZE: usize = 4;
+
+const NORMAL_MOVE: u16 = 0;
+const PROMOTION_MOVE: u16 = 1;
+const CASTLE_MOVE: u16 = 2;
+const EP_MOVE: u16 = 3;
+
+// Promotion index of each Kind (knight = 0, bishop, rook, queen)
+const PROMOTION_INDEX: [u16; 4] = [
+    1, // Bishop
+    3, // Queen
+    2, // Rook
+    0, // Knight
+];
+
+fn signed_to_unsigned(a: i16) -> u16 {
+    let mut r = a as u16;
+    if r & 0x8000 != 0 {
+        r ^= 0x7FFF;
+    }
+    r.rotate_left(1)
+}
+
+fn unsigned_to_signed(r: u16) -> i16 {
+    let mut r = r.rotate_right(1);
+    if r & 0x8000 != 0 {
+        r ^= 0x7FFF;
+    }
+    r as i16
+}
+
+fn move_from(mv: Move, stm: Side) -> Square {
+    if mv.is_castle() {
+        castle_king_squares(stm, mv.castle()).0
+    } else {
+        mv.from()
+    }
+}
+
+fn compress_move(mv: Move, stm: Side) -> u16 {
+    if mv.is_castle() {
+        let (king_from, _) = castle_king_squares(stm, mv.castle());
+        let (rook_from, _) = castle_rook_squares(stm, mv.castle());
+        return (CASTLE_MOVE << 14) | (king_from.0 as u16) << 8 | (rook_from.0 as u16) << 2;
+    }
+
+    let mut packed = (mv.from().0 as u16) << 8 | (mv.to().0 as u16) << 2;
+
+    if mv.is_promotion() {
+        packed |= PROMOTION_MOVE << 14 | PROMOTION_INDEX[mv.promote_to().to_usize()];
+    } else if mv.is_ep_capture() {
+        packed |= EP_MOVE << 14;
+    } else {
+        packed |= NORMAL_MOVE << 14;
+    }
+
+    packed
+}
+
+/// Squares below sq
+fn before(sq: Square) -> BB {
+    BB((1u64 << sq.0) - 1)
+}
+
+fn used_bits(n: usize) -> usize {
+    if n == 0 {
+        0
+    } else {
+        (usize::BITS - (n - 1).leading_zeros()) as usize
+    }
+}
+
+/// Move indexing for the binpack bit stream
+struct MoveIndexer<'a> {
+    position: &'a Position,
+    state: State,
+}
+
+impl<'a> MoveIndexer<'a> {
+    fn new(position: &'a Position, moves: &MoveVec) -> MoveIndexer<'a> {
+        MoveIndexer {
+            position,
+            state: canonical_state(position, moves),
+        }
+    }
+
+    fn ours(&self) -> BB {
+        self.position.bb_side(self.state.stm)
+    }
+
+    /// Pseudo-legal destinations of the piece on from, not including castles
+    fn destinations(&self, from: Square) -> BB {
+        let stm = self.state.stm;
+        let ours = self.ours();
+        let theirs = self.position.bb_side(stm.flip());
+        let occupied = ours | theirs;
+
+        let kind = self.position.at(from).kind();
+
+        if kind == PAWN {
+            let from_bb = BB::new(from);
+            let mut targets = theirs;
+            if let Some(ep_square) = self.state.ep_square {
+                targets |= BB::new(ep_square);
+            }
+
+            let (attacks, forward, start_row) = if stm == WHITE {
+                (
+                    ((from_bb << 7) & NOT_FILE_H) | ((from_bb << 9) & NOT_FILE_A),
+                    Square(from.0 + 8),
+                    1,
+                )
+            } else {
+                (
+                    ((from_bb >> 9) & NOT_FILE_H) | ((from_bb >> 7) & NOT_FILE_A),
+                    Square(from.0 - 8),
+                    6,
+                )
+            };
+
+            let mut destinations = attacks & targets;
+            if !occupied.is_set(forward) {
+                destinations |= BB::new(forward);
+
+                let forward_2 = if stm == WHITE {
+                    Square(forward.0 + 8)
+                } else {
+                    Square(forward.0 - 8)
+                };
+                if from.row() == start_row && !occupied.is_set(forward_2) {
+                    destinations |= BB::new(forward_2);
+                }
+            }
+            return destinations;
+        }
+
+        let attacks = if kind == KING {
+            from.king_moves()
+        } else if kind == KNIGHT {
+            from.knight_moves()
+        } else if kind == BISHOP {
+            bishop_attacks_from_sq(from, occupied)
+        } else if kind == ROOK {
+            rook_attacks_from_sq(from, occupied)
+        } else {
+            bishop_attacks_from_sq(from, occupied) | rook_attacks_from_sq(from, occupied)
+        };
+
+        attacks & !ours
+    }
+
+    fn is_promotion_row(&self, from: Square) -> bool {
+        self.position.at(from).kind() == PAWN
+            && from.row() == if self.state.stm == WHITE { 6 } else { 1 }
+    }
+
+    fn castles(&self) -> usize {
+        let rights = self.state.castling_rights;
+        Castle::iter()
+            .filter(|&castle| rights.has(CastlingRights::from(castle, self.state.stm)))
+            .count()
+    }
+
+    fn num_pieces(&self) -> usize {
+        self.ours().pop_count() as usize
+    }
+
+    fn num_moves(&self, from: Square) -> usize {
+        let count = self.destinations(from).pop_count() as usize;
+
+        if self.is_promotion_row(from) {
+            count * 4
+        } else if self.position.at(from).kind() == KING {
+            count + self.castles()
+        } else {
+            count
+        }
+    }
+
+    fn piece_id(&self, from: Square) -> usize {
+        (self.ours() & before(from)).pop_count() as usize
+    }
+
+    fn move_id(&self, mv: Move) -> usize {
+        let stm = self.state.stm;
+        let from = move_from(mv, stm);
+        let destinations = self.destinations(from);
+
+        if mv.is_castle() {
+            let has_queen_side = self
+                .state
+                .castling_rights
+                .has(CastlingRights::from(QUEEN_SIDE, stm));
+            return destinations.pop_count() as usize - 1
+                + has_queen_side as usize
+                + (mv.castle() == KING_SIDE) as usize;
+        }
+
+        let id = (destinations & before(mv.to())).pop_count() as usize;
+        if self.is_promotion_row(from) {
+            id * 4 + PROMOTION_INDEX[mv.promote_to().to_usize()] as usize
+        } else {
+            id
+        }
+    }
+}
+
+/// Writes bits most significant bit first
+struct BitWriter {
+    bytes: Vec<u8>,
+    bits: usize,
+}
+
+impl BitWriter {
+    fn new() -> BitWriter {
+        BitWriter {
+            bytes: Vec::new(),
+            bits: 0,
+        }
+    }
+
+    fn write(&mut self, value: usize, count: usize) {
+        for i in (0..count).rev() {
+            if self.bits.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if (value >> i) & 1 != 0 {
+                *self.bytes.last_mut().unwrap() |= 0x80 >> (self.bits % 8);
+            }
+            self.bits += 1;
+        }
+    }
+
+    fn write_vle(&mut self, mut value: u16) {
+        let mask = (1 << SCORE_VLE_BLOCK_SIZE) - 1;
+        loop {
+            let more = value > mask;
+            self.write(
+                (value & mask) as usize | (more as usize) << SCORE_VLE_BLOCK_SIZE,
+                SCORE_VLE_BLOCK_SIZE + 1,
+            );
+            value >>= SCORE_VLE_BLOCK_SIZE;
+            if value == 0 {
+                break;
+            }
+        }
+    }
+}
+
+/// Reads bits most significant bit first
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    bits: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn read(&mut self, count: usize) -> Result<usize, String> {
+        let mut value = 0;
+        for _ in 0..count {
+            let byte = self
+                .bytes
+                .get(self.bits / 8)
+                .ok_or("Unexpected end of binpack chain")?;
+            value = (value << 1) | ((byte >> (7 - self.bits % 8)) & 1) as usize;
+            self.bits += 1;
+        }
+        Ok(value)
+    }
+
+    fn read_vle(&mut self) -> Result<u16, String> {
+        let mask = (1 << SCORE_VLE_BLOCK_SIZE) - 1;
+        let mut value = 0u16;
+        let mut offset = 0;
+        loop {
+            let block = self.read(SCORE_VLE_BLOCK_SIZE + 1)?;
+            if offset >= 16 {
+                return Err("Invalid binpack score".to_string());
+            }
+            value |= ((block & mask) as u16) << offset;
+            if block >> SCORE_VLE_BLOCK_SIZE == 0 {
+                return Ok(value);
+            }
+            offset += SCORE_VLE_BLOCK_SIZE;
+        }
+    }
+}
+
+struct WriteChain {
+    // position after the last entry's move
+    next_position: Position,
+    ply: u16,
+    result: i8,
+    last_score: i16,
+    num_plies: u16,
+    movetext: BitWriter,
+}
+
+impl WriteChain {
+    fn new(entry: &TrainingDataEntry) -> WriteChain {
+        let mut chain = WriteChain {
+            next_position: entry.position.clone(),
+            ply: entry.ply,
+            result: entry.result,
+            last_score: entry.score.wrapping_neg(),
+            num_plies: 0,
+            movetext: BitWriter::new(),
+        };
+        chain.next_position.make(entry.mv);
+        chain
+    }
+
+    fn is_continuation(&self, entry: &TrainingDataEntry) -> bool {
+        if self.num_plies == u16::MAX
+            || entry.ply != self.ply.wrapping_add(1)
+            || entry.result != -self.result
+            || entry.position.state().half_move_clock != self.next_position.state().half_move_clock
+        {
+            return false;
+        }
+
+        let state = canonical_state(&self.next_position, &legal_move_vec(&self.next_position));
+        let entry_state = canonical_state(&entry.position, &legal_move_vec(&entry.position));
+
+        match (
+            compress(self.next_position.grid(), &state),
+            compress(entry.position.grid(), &entry_state),
+        ) {
+            (Ok(next), Ok(entry)) => next == entry,
+            _ => false,
+        }
+    }
+
+    fn add(&mut self, entry: &TrainingDataEntry) {
+        let moves = legal_move_vec(&entry.position);
+        let indexer = MoveIndexer::new(&entry.position, &moves);
+        let from = move_from(entry.mv, entry.position.state().stm);
+
+        self.movetext
+            .write(indexer.piece_id(from), used_bits(indexer.num_pieces()));
+        self.movetext.write(
+            indexer.move_id(entry.mv),
+            used_bits(indexer.num_moves(from)),
+        );
+        self.movetext.write_vle(signed_to_unsigned(
+            entry.score.wrapping_sub(self.last_score),
+        ));
+
+        self.last_score = entry.score.wrapping_neg();
+        self.num_plies += 1;
+        self.ply = entry.ply;
+        self.result = entry.result;
+        self.next_position.make(entry.mv);
+    }
+}
+
+/// BinpackWriter writes entries in Stockfish's .binpack format. Consecutive entries from the
+/// same game are compressed to a few bits each. finish() must be called to write the last chunk
+pub struct BinpackWriter<W: io::Write> {
+    writer: W,
+    chunk: Vec<u8>,
+    chain: Option<WriteChain>,
+}
+
+impl<W: io::Write> BinpackWriter<W> {
+    pub fn new(writer: W) -> BinpackWriter<W> {
+        BinpackWriter {
+            writer,
+            chunk: Vec::new(),
+            chain: None,
+        }
+    }
+
+    pub fn add(&mut self, entry: &TrainingDataEntry) -> io::Result<()> {
+        if let Some(chain) = self.chain.as_mut()
+            && chain.is_continuation(entry)
+        {
+            chain.add(entry);
+            return Ok(());
+        }
+
+        let position = &entry.position;
+        let state = canonical_state(position, &legal_move_vec(position));
+        let compressed = compress(position.grid(), &state)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+
+        self.finish_chain();
+
+        if self.chunk.len() >= CHUNK_SIZE {
+            self.write_chunk()?;
+        }
+
+        let mut stem = [0u8; STEM_SIZE];
+        stem[0..COMPRESSED_POSITION_SIZE].copy_from_slice(&compressed);
+        stem[24..26].copy_from_slice(&compress_move(entry.mv, state.stm).to_be_bytes());
+        stem[26..28].copy_from_slice(&signed_to_unsigned(entry.score).to_be_bytes());
+        let ply_and_result = entry.ply | signed_to_unsigned(entry.result as i16) << 14;
+        stem[28..30].copy_from_slice(&ply_and_result.to_be_bytes());
+        stem[30..32].copy_from_slice(&(state.half_move_clock as u16).to_be_bytes());
+        self.chunk.extend_from_slice(&stem);
+
+        self.chain = Some(WriteChain::new(entry));
+
+        Ok(())
+    }
+
+    fn finish_chain(&mut self) {
+        if let Some(chain) = self.chain.take() {
+            self.chunk.extend_from_slice(&chain.num_plies.to_be_bytes());
+            self.chunk.extend_from_slice(&chain.movetext.bytes);
+        }
+    }
+
+    fn write_chunk(&mut self) -> io::Result<()> {
+        self.writer.write_all(CHUNK_HEADER)?;
+        self.writer
+            .write_all(&(self.chunk.len() as u32).to_le_bytes())?;
+        self.writer.write_all(&self.chunk)?;
+        self.chunk.clear();
+        Ok(())
+    }
+
+    /// Writes any remaining entries and returns the underlying writer
+    pub fn finish(mut self) -> io::Result<W> {
+        self.finish_chain();
+        if !self.chunk.is_empty() {
+            self.write_chunk()?;
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+struct ReadChain {
+    entry: TrainingDataEntry,
+    remaining: u16,
+    // bit offset of the next move from the start of the chain's movetext
+    bits: usize,
+    movetext_offset: usize,
+    last_score: i16,
+}
+
+/// BinpackReader reads entries in Stockfish's .binpack format
+pub struct BinpackReader<R: io::Read> {
+    reader: R,
+    chunk: Vec<u8>,
+    offset: usize,
+    chain: Option<ReadChain>,
+}
+
+impl<R: io::Read> BinpackReader<R> {
+    pub fn new(reader: R) -> BinpackReader<R> {
+        BinpackReader {
+            reader,
+            chunk: Vec::new(),
+            offset: 0,
+            chain: None,
+        }
+    }
+
+    /// Reads the next chunk, returning false at the end of the file
+    fn read_chunk(&mut self) -> Result<bool, String> {
+        let mut header = [0u8; 8];
+        let mut read = 0;
+        while read < header.len() {
+            match self.reader.read(&mut header[read..]) {
+                Ok(0) if read == 0 => return Ok(false),
+                Ok(0) => return Err("Unexpected end of binpack chunk header".to_string()),
+                Ok(n) => read += n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err.to_string()),
+            }
+        }
+
+        if &header[0..4] != CHUNK_HEADER {
+            return Err("Invalid binpack chunk header".to_string());
+        }
+
+        let size = u32::from_le_bytes(header[4..8].try_into().unwrap()) as usize;
+        self.chunk.resize(size, 0);
+        self.reader
+            .read_exact(&mut self.chunk)
+            .map_err(|err| err.to_string())?;
+        self.offset = 0;
+
+        Ok(true)
+    }
+
+    fn read_stem(&mut self) -> Result<TrainingDataEntry, String> {
+        let bytes = self
+            .chunk
+            .get(self.offset..self.offset + STEM_SIZE + 2)
+            .ok_or("Unexpected end of binpack chunk")?;
+
+        let (grid, mut state, _) = decompress(&bytes[0..COMPRESSED_POSITION_SIZE])?;
+        let packed_move = u16::from_be_bytes([bytes[24], bytes[25]]);
+        let score = unsigned_to_signed(u16::from_be_bytes([bytes[26], bytes[27]]));
+        let ply_and_result = u16::from_be_bytes([bytes[28], bytes[29]]);
+        let half_move_clock = u16::from_be_bytes([bytes[30], bytes[31]]);
+        let num_plies = u16::from_be_bytes([bytes[32], bytes[33]]);
+
+        let ply = ply_and_result & 0x3FFF;
+        state.half_move_clock = half_move_clock.min(u8::MAX as u16) as u8;
+        state.full_move_number = ply / 2 + 1;
+
+        let position = Position::new(grid, state);
+        let stm = state.stm;
+        let mv = legal_move_vec(&position)
+            .iter()
+            .find(|&&mv| compress_move(mv, stm) == packed_move)
+            .cloned()
+            .ok_or_else(|| {
+                format!(
+                    "Illegal binpack move {:04X} in {}",
+                    packed_move,
+                    position.to_fen()
+                )
+            })?;
+
+        let entry = TrainingDataEntry {
+            position,
+            mv,
+            score,
+            ply,
+            result: unsigned_to_signed(ply_and_result >> 14) as i8,
+        };
+
+        self.offset += STEM_SIZE + 2;
+        self.chain = Some(ReadChain {
+            entry: entry.clone(),
+            remaining: num_plies,
+            bits: 0,
+            movetext_offset: self.offset,
+            last_score: score.wrapping_neg(),
+        });
+
+        Ok(entry)
+    }
+
+    fn read_continuation(&mut self) -> Result<TrainingDataEntry, String> {
+        let chain = self.chain.as_mut().unwrap();
+
+        let mut position = chain.entry.position.clone();
+        position.make(chain.entry.mv);
+
+        // positions are read with en-passant squares only set if capture is possible
+        let moves = legal_move_vec(&position);
+        let position = Position::new(*position.grid(), canonical_state(&position, &moves));
+
+        let indexer = MoveIndexer::new(&position, &moves);
+        let stm = indexer.state.stm;
+        let mut reader = BitReader {
+            bytes: &self.chunk[chain.movetext_offset..],
+            bits: chain.bits,
+        };
+
+        let piece_id = reader.read(used_bits(indexer.num_pieces()))?;
+        let (from, _) = indexer
+            .ours()
+            .iter()
+            .nth(piece_id)
+            .ok_or("Invalid binpack piece")?;
+        let move_id = reader.read(used_bits(indexer.num_moves(from)))?;
+        let mv = moves
+            .iter()
+            .find(|&&mv| move_from(mv, stm) == from && indexer.move_id(mv) == move_id)
+            .cloned()
+            .ok_or_else(|| format!("Invalid binpack move in {}", position.to_fen()))?;
+        let score = chain
+            .last_score
+            .wrapping_add(unsigned_to_signed(reader.read_vle()?));
+
+        chain.bits = reader.bits;
+        chain.remaining -= 1;
+        chain.last_score = score.wrapping_neg();
+        chain.entry = TrainingDataEntry {
+            position,
+            mv,
+            score,
+            ply: chain.entry.ply.wrapping_add(1),
+            result: -chain.entry.result,
+        };
+
+        Ok(chain.entry.clone())
+    }
+
+    fn read_entry(&mut self) -> Result<Option<TrainingDataEntry>, String> {
+        if let Some(chain) = self.chain.as_ref() {
+            if chain.remaining > 0 {
+                return self.read_continuation().map(Some);
+            }
+            self.offset = chain.movetext_offset + chain.bits.div_ceil(8);
+            self.chain = None;
+        }
+
+        if self.offset >= self.chunk.len() && !self.read_chunk()? {
+            return Ok(None);
+        }
+
+        self.read_stem().map(Some)
+    }
+}
+
+impl<R: io::Read> Iterator for BinpackReader<R> {
+    type Item = Result<TrainingDataEntry, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.read_entry();
+        if entry.is_err() {
+            // stop reading after an error
+            self.chain = None;
+            self.chunk.clear();
+            self.offset = 0;
+        }
+        entry.transpose()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::position::STARTING_POSITION_FEN;
+
+    fn game_entries(fen: &str, moves: &[Move], first_ply: u16) -> Vec<TrainingDataEntry> {
+        let mut position = Position::from_fen(fen).unwrap();
+        let mut entries = Vec::new();
+        for (i, &mv) in moves.iter().enumerate() {
+            entries.push(TrainingDataEntry {
+                position: position.clone(),
+                mv,
+                score: (i as i16 - 3) * 37,
+                ply: first_ply + i as u16,
+                result: if i % 2 == 0 { 1 } else { -1 },
+            });
+            position.make(mv);
+        }
+        entries
+    }
+
+    fn round_trip(entries: &[TrainingDataEntry]) -> Vec<u8> {
+        let mut writer = BinpackWriter::new(Vec::new());
+        for entry in entries {
+            writer.add(entry).unwrap();
+        }
+        let bytes = writer.finish().unwrap();
+
+        let read = BinpackReader::new(&bytes[..])
+            .collect::<Result<Vec<TrainingDataEntry>, String>>()
+            .unwrap();
+
+        assert_eq!(read.len(), entries.len());
+        for (a, b) in read.iter().zip(entries.iter()) {
+            let expected_state = canonical_state(&b.position, &legal_move_vec(&b.position));
+            assert_eq!(
+                a.position.to_fen(),
+                crate::position::fen::to_fen(b.position.grid(), &expected_state)
+            );
+            assert_eq!(a.mv, b.mv);
+            assert_eq!(a.score, b.score);
+            assert_eq!(a.ply, b.ply);
+            assert_eq!(a.result, b.result);
+        }
+
+        bytes
+    }
+
+    #[test]
+    fn test_signed_to_unsigned() {
+        for &a in [0i16, 1, -1, 2, -2, 1000, -1000, i16::MAX, i16::MIN].iter() {
+            assert_eq!(unsigned_to_signed(signed_to_unsigned(a)), a);
+        }
+        assert_eq!(signed_to_unsigned(0), 0);
+        assert_eq!(signed_to_unsigned(-1), 1);
+        assert_eq!(signed_to_unsigned(1), 2);
+    }
+
+    #[test]
+    fn test_compress_move() {
+        assert_eq!(
+            compress_move(Move::new_push(E2, E4), WHITE),
+            (E2.0 as u16) << 8 | (E4.0 as u16) << 2
+        );
+        assert_eq!(
+            compress_move(Move::new_castle(KING_SIDE), BLACK),
+            2 << 14 | (E8.0 as u16) << 8 | (H8.0 as u16) << 2
+        );
+        assert_eq!(
+            compress_move(Move::new_promotion(A7, A8, QUEEN), WHITE),
+            1 << 14 | (A7.0 as u16) << 8 | (A8.0 as u16) << 2 | 3
+        );
+    }
+
+    #[test]
+    fn test_chain() {
+        // includes castles, en-passant and promotion
+        let moves = [
+            Move::new_push(E2, E4),
+            Move::new_push(G8, F6),
+            Move::new_push(E4, E5),
+            Move::new_push(D7, D5),
+            Move::new_ep_capture(E5, D6),
+            Move::new_push(E7, E6),
+            Move::new_push(G1, F3),
+            Move::new_push(F8, E7),
+            Move::new_push(F1, C4),
+            Move::new_castle(KING_SIDE),
+            Move::new_castle(KING_SIDE),
+            Move::new_push(B8, C6),
+            Move::new_capture(D6, C7),
+            Move::new_push(A7, A6),
+            Move::new_capture_promotion(C7, D8, KNIGHT),
+        ];
+        let entries = game_entries(STARTING_POSITION_FEN, &moves, 0);
+        let bytes = round_trip(&entries);
+
+        // one chunk with one chain: header + stem + continuation bits
+        assert_eq!(&bytes[0..4], CHUNK_HEADER);
+        assert!(bytes.len() < 8 + STEM_SIZE + 2 + moves.len() * 3);
+    }
+
+    #[test]
+    fn test_multiple_chains() {
+        let mut entries = game_entries(
+            STARTING_POSITION_FEN,
+            &[Move::new_push(D2, D4), Move::new_push(D7, D5)],
+            0,
+        );
+        // not a continuation as position does not follow
+        entries.extend(game_entries(
+            "r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 3 20",
+            &[Move::new_castle(QUEEN_SIDE), Move::new_castle(KING_SIDE)],
+            39,
+        ));
+        // not a continuation as result does not alternate
+        let mut entry = entries[0].clone();
+        entry.result = 0;
+        entries.push(entry);
+
+        round_trip(&entries);
+    }
+
+    #[test]
+    fn test_empty() {
+        let bytes = BinpackWriter::new(Vec::new()).finish().unwrap();
+        assert!(bytes.is_empty());
+        assert!(BinpackReader::new(&bytes[..]).next().is_none());
+    }
+
+    #[test]
+    fn test_invalid() {
+        let bytes = b"BINX\x00\x00\x00\x00";
+        let mut reader = BinpackReader::new(&bytes[..]);
+        assert!(reader.next().unwrap().is_err());
+    }
+}
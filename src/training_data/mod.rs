@@ -0,0 +1,55 @@
+// Stockfish NNUE training data formats (.plain and .binpack)
+// https://github.com/official-stockfish/nnue-pytorch/blob/master/lib/nnue_training_data_formats.h
+
+mod binpack;
+mod plain;
+
+pub use self::binpack::{BinpackReader, BinpackWriter};
+pub use self::plain::{PlainReader, PlainWriter};
+
+use crate::generation::legal_moves;
+use crate::mv::Move;
+use crate::mv_list::MoveVec;
+use crate::position::{Position, State};
+use crate::side::Side;
+
+/// TrainingDataEntry is a single training position with the move played, its score and the
+/// game result
+#[derive(Clone, Debug)]
+pub struct TrainingDataEntry {
+    pub position: Position,
+    pub mv: Move,
+    /// Score from the side to move's point of view
+    pub score: i16,
+    /// Number of half-moves played since the start of the game
+    pub ply: u16,
+    /// Game result from the side to move's point of view: 1 win, 0 draw, -1 loss
+    pub result: i8,
+}
+
+/// Returns the state with the en-passant square removed if no legal en-passant capture is
+/// possible. Stockfish only sets the en-passant square when the capture is legal, and the
+/// formats rely on positions being equal
+fn canonical_state(position: &Position, moves: &MoveVec) -> State {
+    let mut state = *position.state();
+    if !moves.iter().any(|mv| mv.is_ep_capture()) {
+        state.ep_square = None;
+    }
+    state
+}
+
+fn legal_move_vec(position: &Position) -> MoveVec {
+    let mut moves = MoveVec::new();
+    legal_moves::<MoveVec>(position, &mut moves);
+    moves
+}
+
+/// Finds the legal move in a position with the provided UCI notation
+fn parse_uci_move(position: &Position, uci: &str) -> Result<Move, String> {
+    let stm: Side = position.state().stm;
+    legal_move_vec(position)
+        .iter()
+        .find(|mv| mv.to_uci(stm) == uci)
+        .cloned()
+        .ok_or_else(|| format!("Illegal move {} in {}", uci, position.to_fen()))
+}
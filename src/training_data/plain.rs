@@ -0,0 +1,170 @@
+use super::{TrainingDataEntry, canonical_state, legal_move_vec, parse_uci_move};
+use crate::position::Position;
+use crate::position::fen::to_fen;
+use std::io;
+
+/// PlainWriter writes entries in Stockfish's text .plain format, eg:
+///
+/// fen rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w QqKk - 0 1
+/// move e2e4
+/// score 30
+/// ply 0
+/// result 0
+/// e
+///
+pub struct PlainWriter<W: io::Write> {
+    writer: W,
+}
+
+impl<W: io::Write> PlainWriter<W> {
+    pub fn new(writer: W) -> PlainWriter<W> {
+        PlainWriter { writer }
+    }
+
+    pub fn add(&mut self, entry: &TrainingDataEntry) -> io::Result<()> {
+        let position = &entry.position;
+        let state = canonical_state(position, &legal_move_vec(position));
+
+        writeln!(self.writer, "fen {}", to_fen(position.grid(), &state))?;
+        writeln!(self.writer, "move {}", entry.mv.to_uci(state.stm))?;
+        writeln!(self.writer, "score {}", entry.score)?;
+        writeln!(self.writer, "ply {}", entry.ply)?;
+        writeln!(self.writer, "result {}", entry.result)?;
+        writeln!(self.writer, "e")
+    }
+
+    /// Flushes and returns the underlying writer
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// PlainReader reads entries in Stockfish's text .plain format
+pub struct PlainReader<R: io::BufRead> {
+    lines: io::Lines<R>,
+}
+
+impl<R: io::BufRead> PlainReader<R> {
+    pub fn new(reader: R) -> PlainReader<R> {
+        PlainReader {
+            lines: reader.lines(),
+        }
+    }
+
+    fn read_entry(&mut self) -> Result<Option<TrainingDataEntry>, String> {
+        let mut fen = None;
+        let mut mv = None;
+        let mut score = None;
+        let mut ply = None;
+        let mut result = None;
+
+        loop {
+            let line = match self.lines.next() {
+                Some(line) => line.map_err(|err| err.to_string())?,
+                None if fen.is_none() => return Ok(None),
+                None => return Err("Unexpected end of file".to_string()),
+            };
+
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if line == "e" {
+                break;
+            }
+
+            let (key, value) = line
+                .split_once(' ')
+                .ok_or_else(|| format!("Invalid line: {}", line))?;
+
+            match key {
+                "fen" => fen = Some(value.to_string()),
+                "move" => mv = Some(value.to_string()),
+                "score" => score = Some(value.parse::<i16>().map_err(|err| err.to_string())?),
+                "ply" => ply = Some(value.parse::<u16>().map_err(|err| err.to_string())?),
+                "result" => result = Some(value.parse::<i8>().map_err(|err| err.to_string())?),
+                _ => return Err(format!("Unknown key: {}", key)),
+            }
+        }
+
+        let position = Position::from_fen(&fen.ok_or("Missing fen")?)?;
+        let mv = parse_uci_move(&position, &mv.ok_or("Missing move")?)?;
+
+        Ok(Some(TrainingDataEntry {
+            position,
+            mv,
+            score: score.ok_or("Missing score")?,
+            ply: ply.ok_or("Missing ply")?,
+            result: result.ok_or("Missing result")?,
+        }))
+    }
+}
+
+impl<R: io::BufRead> Iterator for PlainReader<R> {
+    type Item = Result<TrainingDataEntry, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_entry().transpose()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mv::Move;
+    use crate::position::STARTING_POSITION_FEN;
+    use crate::square::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut position = Position::from_fen(STARTING_POSITION_FEN).unwrap();
+        let mut entries = Vec::new();
+        for (ply, &mv) in [Move::new_push(E2, E4), Move::new_push(E7, E5)]
+            .iter()
+            .enumerate()
+        {
+            entries.push(TrainingDataEntry {
+                position: position.clone(),
+                mv,
+                score: 25 - ply as i16 * 50,
+                ply: ply as u16,
+                result: if ply == 0 { 1 } else { -1 },
+            });
+            position.make(mv);
+        }
+
+        let mut writer = PlainWriter::new(Vec::new());
+        for entry in entries.iter() {
+            writer.add(entry).unwrap();
+        }
+        let bytes = writer.finish().unwrap();
+
+        let text = String::from_utf8(bytes.clone()).unwrap();
+        assert!(text.starts_with(
+            "fen rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w QqKk - 0 1\nmove e2e4\nscore 25\n"
+        ));
+        // en-passant square is left out as no capture is possible
+        assert!(text.contains("fen rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b QqKk - 0 1\n"));
+
+        let read = PlainReader::new(&bytes[..])
+            .collect::<Result<Vec<TrainingDataEntry>, String>>()
+            .unwrap();
+        assert_eq!(read.len(), 2);
+        for (a, b) in read.iter().zip(entries.iter()) {
+            assert_eq!(a.mv, b.mv);
+            assert_eq!(a.score, b.score);
+            assert_eq!(a.ply, b.ply);
+            assert_eq!(a.result, b.result);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let text = "fen 4k3/8/8/8/8/8/8/4K3 w - - 0 1\nmove e1e3\nscore 0\nply 0\nresult 0\ne\n";
+        assert!(PlainReader::new(text.as_bytes()).next().unwrap().is_err());
+
+        let text = "fen 4k3/8/8/8/8/8/8/4K3 w - - 0 1\nmove e1e2\n";
+        assert!(PlainReader::new(text.as_bytes()).next().unwrap().is_err());
+    }
+}
@@ -1,4 +1,6 @@
 use crate::square::{Square, SquareInternal};
+use alloc::format;
+use alloc::string::{String, ToString};
 
 pub fn grid_to_string_with_props<F: Fn(Square) -> char>(
     char_at: F,
@@ -0,0 +1,270 @@
+// Represents N bitboards in one SIMD vector, 4 lanes fill an AVX2 register and 8 lanes an
+// AVX-512 register. Each lane is filled in its own direction so one pass covers several rays
+// https://www.chessprogramming.org/Kogge-Stone_Algorithm#Generalized_Rays
+use core::ops::*;
+
+use crate::bb::*;
+
+use self::lanes::Lanes;
+#[cfg(feature = "nightly")]
+use core::simd::num::SimdUint;
+
+/// Quad bitboard used with AVX2
+#[allow(dead_code, clippy::upper_case_acronyms)]
+pub type QBB = WBB<4>;
+/// Octo bitboard used with AVX-512
+#[allow(dead_code, clippy::upper_case_acronyms)]
+pub type OBB = WBB<8>;
+
+/// Wide bitboard
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone)]
+pub struct WBB<const N: usize>(Lanes<N>);
+
+/// Ray direction of each lane as a left rotation and the squares a rotation may not wrap onto
+#[derive(Copy, Clone)]
+pub struct Directions<const N: usize> {
+    rotations: [Lanes<N>; 3],
+    avoid_wrap: Lanes<N>,
+}
+
+// (rotation, avoid wrap mask) for each direction
+const NORTH: (u32, u64) = (8, !ROW_1.0);
+const NORTH_EAST: (u32, u64) = (9, !(ROW_1.0 | FILE_A.0));
+const EAST: (u32, u64) = (1, !FILE_A.0);
+const SOUTH_EAST: (u32, u64) = (57, !(ROW_8.0 | FILE_A.0));
+const SOUTH: (u32, u64) = (56, !ROW_8.0);
+const SOUTH_WEST: (u32, u64) = (55, !(ROW_8.0 | FILE_H.0));
+const WEST: (u32, u64) = (63, !FILE_H.0);
+const NORTH_WEST: (u32, u64) = (7, !(ROW_1.0 | FILE_H.0));
+
+// portable SIMD on nightly
+#[cfg(feature = "nightly")]
+mod lanes {
+    pub type Lanes<const N: usize> = core::simd::Simd<u64, N>;
+}
+
+// plain arrays on stable, LLVM vectorises the lane-wise loops when AVX2 or AVX-512 is enabled
+#[cfg(not(feature = "nightly"))]
+mod lanes {
+    use core::ops::*;
+
+    #[derive(Copy, Clone)]
+    pub struct Lanes<const N: usize>([u64; N]);
+
+    impl<const N: usize> Lanes<N> {
+        pub const fn from_array(lanes: [u64; N]) -> Lanes<N> {
+            Lanes(lanes)
+        }
+
+        pub fn splat(value: u64) -> Lanes<N> {
+            Lanes([value; N])
+        }
+
+        pub fn to_array(self) -> [u64; N] {
+            self.0
+        }
+
+        pub fn reduce_or(self) -> u64 {
+            self.0.iter().fold(0, |acc, lane| acc | lane)
+        }
+
+        pub fn rotate_elements_left<const OFFSET: usize>(self) -> Lanes<N> {
+            let mut lanes = self.0;
+            lanes.rotate_left(OFFSET % N);
+            Lanes(lanes)
+        }
+
+        #[inline(always)]
+        fn zip(self, other: Lanes<N>, op: impl Fn(u64, u64) -> u64) -> Lanes<N> {
+            Lanes(core::array::from_fn(|i| op(self.0[i], other.0[i])))
+        }
+    }
+
+    macro_rules! binary_op {
+        ($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $op:expr) => {
+            impl<const N: usize> $trait for Lanes<N> {
+                type Output = Lanes<N>;
+
+                #[inline(always)]
+                fn $fn(self, other: Lanes<N>) -> Lanes<N> {
+                    self.zip(other, $op)
+                }
+            }
+
+            impl<const N: usize> $assign_trait for Lanes<N> {
+                #[inline(always)]
+                fn $assign_fn(&mut self, other: Lanes<N>) {
+                    *self = self.zip(other, $op);
+                }
+            }
+        };
+    }
+
+    binary_op!(BitAnd, bitand, BitAndAssign, bitand_assign, |a, b| a & b);
+    binary_op!(BitOr, bitor, BitOrAssign, bitor_assign, |a, b| a | b);
+    binary_op!(Sub, sub, SubAssign, sub_assign, u64::wrapping_sub);
+    binary_op!(Shl, shl, ShlAssign, shl_assign, |a: u64, b: u64| a << b);
+    binary_op!(Shr, shr, ShrAssign, shr_assign, |a: u64, b: u64| a >> b);
+}
+
+impl<const N: usize> Directions<N> {
+    const fn new(directions: [(u32, u64); N]) -> Directions<N> {
+        let mut rotations = [[0; N]; 3];
+        let mut avoid_wrap = [0; N];
+        let mut i = 0;
+        while i < N {
+            let (rotation, mask) = directions[i];
+            rotations[0][i] = rotation as u64;
+            rotations[1][i] = (rotation as u64 * 2) % 64;
+            rotations[2][i] = (rotation as u64 * 4) % 64;
+            avoid_wrap[i] = mask;
+            i += 1;
+        }
+
+        Directions {
+            rotations: [
+                Lanes::from_array(rotations[0]),
+                Lanes::from_array(rotations[1]),
+                Lanes::from_array(rotations[2]),
+            ],
+            avoid_wrap: Lanes::from_array(avoid_wrap),
+        }
+    }
+}
+
+/// North, east, south and west. Opposite directions are two lanes apart
+#[allow(dead_code)]
+pub const ORTHOGONAL: Directions<4> = Directions::new([NORTH, EAST, SOUTH, WEST]);
+
+/// North east, south east, south west and north west. Opposite directions are two lanes apart
+#[allow(dead_code)]
+pub const DIAGONAL: Directions<4> =
+    Directions::new([NORTH_EAST, SOUTH_EAST, SOUTH_WEST, NORTH_WEST]);
+
+/// All eight directions clockwise from north. Even lanes are orthogonal and odd lanes diagonal,
+/// opposite directions are four lanes apart
+#[allow(dead_code)]
+pub const ALL_DIRECTIONS: Directions<8> = Directions::new([
+    NORTH, NORTH_EAST, EAST, SOUTH_EAST, SOUTH, SOUTH_WEST, WEST, NORTH_WEST,
+]);
+
+impl<const N: usize> BitAnd for WBB<N> {
+    type Output = WBB<N>;
+
+    fn bitand(self, other: WBB<N>) -> WBB<N> {
+        WBB(self.0 & other.0)
+    }
+}
+
+impl<const N: usize> BitOr for WBB<N> {
+    type Output = WBB<N>;
+
+    fn bitor(self, other: WBB<N>) -> WBB<N> {
+        WBB(self.0 | other.0)
+    }
+}
+
+#[allow(dead_code)]
+impl<const N: usize> WBB<N> {
+    pub fn new(lanes: [BB; N]) -> WBB<N> {
+        WBB(Lanes::from_array(lanes.map(|bb| bb.0)))
+    }
+
+    pub fn splat(source: BB) -> WBB<N> {
+        WBB(Lanes::splat(source.0))
+    }
+
+    pub fn extract(&self) -> [BB; N] {
+        self.0.to_array().map(BB)
+    }
+
+    /// Union of all lanes
+    pub fn reduce_or(&self) -> BB {
+        BB(self.0.reduce_or())
+    }
+
+    /// Moves lanes towards the start so lane i holds lane i + OFFSET
+    pub fn rotate_lanes<const OFFSET: usize>(&self) -> WBB<N> {
+        WBB(self.0.rotate_elements_left::<OFFSET>())
+    }
+
+    fn rotate_left(value: Lanes<N>, rotation: Lanes<N>) -> Lanes<N> {
+        (value << rotation) | (value >> (Lanes::splat(64) - rotation))
+    }
+
+    pub fn occluded_fill(&self, empty: WBB<N>, directions: &Directions<N>) -> WBB<N> {
+        let [r1, r2, r4] = directions.rotations;
+        let mut prop = empty.0 & directions.avoid_wrap;
+        let mut generator = self.0;
+
+        generator |= prop & Self::rotate_left(generator, r1);
+        prop &= Self::rotate_left(prop, r1);
+        generator |= prop & Self::rotate_left(generator, r2);
+        prop &= Self::rotate_left(prop, r2);
+        generator |= prop & Self::rotate_left(generator, r4);
+
+        WBB(generator)
+    }
+
+    pub fn attacks(&self, empty: WBB<N>, directions: &Directions<N>) -> WBB<N> {
+        let generator = self.occluded_fill(empty, directions);
+
+        WBB(Self::rotate_left(generator.0, directions.rotations[0]) & directions.avoid_wrap)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_attacks() {
+        for _ in 0..100 {
+            let source = BB::random(0.1);
+            let empty = !BB::random(0.3);
+
+            let [north, east, south, west] = QBB::splat(source)
+                .attacks(QBB::splat(empty), &ORTHOGONAL)
+                .extract();
+            assert_eq!(north, source.north_attacks(empty));
+            assert_eq!(east, source.east_attacks(empty));
+            assert_eq!(south, source.south_attacks(empty));
+            assert_eq!(west, source.west_attacks(empty));
+
+            let all = OBB::splat(source)
+                .attacks(OBB::splat(empty), &ALL_DIRECTIONS)
+                .extract();
+            assert_eq!(all[1], source.north_east_attacks(empty));
+            assert_eq!(all[3], source.south_east_attacks(empty));
+            assert_eq!(all[5], source.south_west_attacks(empty));
+            assert_eq!(all[7], source.north_west_attacks(empty));
+        }
+    }
+
+    #[test]
+    fn test_occluded_fill() {
+        for _ in 0..100 {
+            let source = BB::random(0.1);
+            let empty = !BB::random(0.3);
+
+            let [north_east, south_east, south_west, north_west] = QBB::splat(source)
+                .occluded_fill(QBB::splat(empty), &DIAGONAL)
+                .extract();
+            assert_eq!(north_east, source.occluded_north_east_fill(empty));
+            assert_eq!(south_east, source.occluded_south_east_fill(empty));
+            assert_eq!(south_west, source.occluded_south_west_fill(empty));
+            assert_eq!(north_west, source.occluded_north_west_fill(empty));
+        }
+    }
+
+    #[test]
+    fn test_rotate_lanes() {
+        let wbb = QBB::new([BB(1), BB(2), BB(3), BB(4)]);
+        assert_eq!(
+            wbb.rotate_lanes::<2>().extract(),
+            [BB(3), BB(4), BB(1), BB(2)]
+        );
+        assert_eq!(wbb.reduce_or(), BB(7));
+    }
+}